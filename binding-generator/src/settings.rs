@@ -573,7 +573,7 @@ pub static ELEMENT_IGNORE: Lazy<HashSet<&str>> = Lazy::new(|| {
 		"FILE",
 		"HG_AUTOSIZE", // 3.2
 		"cv::ErrorCallback",
-		"cv::MatAllocator",    // doesn't handle cpp part too well
+		"cv::MatAllocator",    // implemented manually in src/manual/core/mat/allocator.rs
 		"cv::NAryMatIterator", // uses pointers of pointers
 		"cv::Node",            // template class
 		"cv::gapi::own::Mat",  // internal alias to Mat
@@ -778,6 +778,50 @@ pub static FUNC_MANUAL: Lazy<HashMap<&str, CompiledInterpolation>> = Lazy::new(|
 			"cv_Mat_at_const_const_intX",
 			include_str!("../tpl/settings/rust_mat_at_const.tpl.rs").compile_interpolation(),
 		),
+		(
+			"cv_Mat_getPropFlags_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_getPropDims_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_getPropRows_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_getPropCols_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_getPropData",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_type_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_depth_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_channels_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_isContinuous_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_empty_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
+		(
+			"cv_Mat_total_const",
+			include_str!("../tpl/settings/rust_mat_header.tpl.rs").compile_interpolation(),
+		),
 	])
 });
 
@@ -946,6 +990,7 @@ pub static IMPLEMENTED_GENERICS: Lazy<HashSet<&str>> = Lazy::new(|| {
 		"cv::Matx",
 		"cv::Point3_",
 		"cv::Point_",
+		"cv::Quat",
 		"cv::Rect_",
 		"cv::Scalar_",
 		"cv::Size_",
@@ -6,4 +6,6 @@ impl {{base_rust_full}} for {{rust_local}} {
 	#[inline] fn as_raw_mut_{{base_rust_local}}(&mut self) -> {{base_rust_extern_mut}} { self.as_raw_mut() }
 }
 
+boxed_ref! { {{rust_local}}, {{base_const_rust_full}}, as_raw_{{base_rust_local}}, {{base_rust_full}}, as_raw_mut_{{base_rust_local}} }
+
 
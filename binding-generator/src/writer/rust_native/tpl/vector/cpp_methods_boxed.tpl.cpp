@@ -0,0 +1,8 @@
+const {{inner_cpp_full}}* cv_{{rust_localalias}}_get_ref(const {{cpp_full}}* instance, size_t index) {
+	return &(*instance)[index];
+}
+
+{{inner_cpp_full}}* cv_{{rust_localalias}}_get_mut({{cpp_full}}* instance, size_t index) {
+	return &(*instance)[index];
+}
+
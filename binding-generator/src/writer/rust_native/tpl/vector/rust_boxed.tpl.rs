@@ -0,0 +1 @@
+vector_boxed! { {{clone}}{{inner_rust_full}}, cv_{{rust_localalias}}_get_ref, cv_{{rust_localalias}}_get_mut }
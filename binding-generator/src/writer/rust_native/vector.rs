@@ -57,6 +57,9 @@ impl RustNativeGeneratedElement for Vector<'_, '_> {
 		static ADD_NON_COPY_OR_BOOL_TPL: Lazy<CompiledInterpolation> =
 			Lazy::new(|| include_str!("tpl/vector/rust_non_copy_or_bool.tpl.rs").compile_interpolation());
 
+		static ADD_BOXED_TPL: Lazy<CompiledInterpolation> =
+			Lazy::new(|| include_str!("tpl/vector/rust_boxed.tpl.rs").compile_interpolation());
+
 		static INPUT_OUTPUT_ARRAY_TPL: Lazy<CompiledInterpolation> =
 			Lazy::new(|| include_str!("tpl/vector/rust_input_output_array.tpl.rs").compile_interpolation());
 
@@ -93,7 +96,11 @@ impl RustNativeGeneratedElement for Vector<'_, '_> {
 					}
 					.into(),
 				);
-				additional_methods += &ADD_NON_COPY_OR_BOOL_TPL.interpolate(&inter_vars);
+				if is_element_boxed(&element_type) {
+					additional_methods += &ADD_BOXED_TPL.interpolate(&inter_vars);
+				} else {
+					additional_methods += &ADD_NON_COPY_OR_BOOL_TPL.interpolate(&inter_vars);
+				}
 			}
 			if self.is_data_type(&element_type) {
 				impls += &INPUT_OUTPUT_ARRAY_TPL.interpolate(&inter_vars);
@@ -113,6 +120,9 @@ impl RustNativeGeneratedElement for Vector<'_, '_> {
 		static METHODS_COPY_NON_BOOL_TPL: Lazy<CompiledInterpolation> =
 			Lazy::new(|| include_str!("tpl/vector/cpp_methods_copy_non_bool.tpl.cpp").compile_interpolation());
 
+		static METHODS_BOXED_TPL: Lazy<CompiledInterpolation> =
+			Lazy::new(|| include_str!("tpl/vector/cpp_methods_boxed.tpl.cpp").compile_interpolation());
+
 		let vec_type = self.type_ref();
 		if vec_type.constness().is_const() {
 			// todo we should generate smth like VectorRef in this case
@@ -180,6 +190,8 @@ impl RustNativeGeneratedElement for Vector<'_, '_> {
 		let mut exports = String::new();
 		if element_type.is_copy() && !element_is_bool {
 			exports += &METHODS_COPY_NON_BOOL_TPL.interpolate(&inter_vars);
+		} else if is_element_boxed(&element_type) {
+			exports += &METHODS_BOXED_TPL.interpolate(&inter_vars);
 		}
 		inter_vars.insert("exports", exports.into());
 
@@ -202,6 +214,12 @@ impl VectorExt for Vector<'_, '_> {
 	}
 }
 
+/// Boxed elements are stored by value inside the vector, but are referenced by pointer on the Rust side so they can be
+/// borrowed directly from the vector storage
+fn is_element_boxed(element_type: &TypeRef) -> bool {
+	element_type.is_extern_by_ptr() && element_type.as_pointer().is_none() && element_type.as_reference().is_none()
+}
+
 fn method_new(rust_localalias: &str, vector_class_desc: &ClassDesc, vec_type: &TypeRef) -> String {
 	CppFuncDesc {
 		extern_name: format!("cv_{rust_localalias}_new").into(),
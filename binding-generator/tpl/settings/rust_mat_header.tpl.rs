@@ -0,0 +1,6 @@
+{{doc_comment}}
+{{debug}}
+#[inline]
+{{visibility}}fn {{name}}({{decl_args}}) -> {{rv_rust_full}} { core::mat_forward::{{name}}(self) }
+
+
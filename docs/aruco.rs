@@ -635,6 +635,8 @@ pub mod aruco {
 		#[inline] fn as_raw_mut_EstimateParameters(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EstimateParameters, crate::aruco::EstimateParametersTraitConst, as_raw_EstimateParameters, crate::aruco::EstimateParametersTrait, as_raw_mut_EstimateParameters }
+	
 	impl EstimateParameters {
 		#[inline]
 		pub fn default() -> Result<crate::aruco::EstimateParameters> {
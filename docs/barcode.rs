@@ -119,6 +119,8 @@ pub mod barcode {
 		#[inline] fn as_raw_mut_BarcodeDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BarcodeDetector, crate::barcode::BarcodeDetectorTraitConst, as_raw_BarcodeDetector, crate::barcode::BarcodeDetectorTrait, as_raw_mut_BarcodeDetector }
+	
 	impl BarcodeDetector {
 		/// Initialize the BarcodeDetector.
 		/// ## Parameters
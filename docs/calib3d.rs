@@ -5610,6 +5610,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LMSolver, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::calib3d::LMSolverTraitConst for LMSolver {
 		#[inline] fn as_raw_LMSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5618,6 +5620,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_LMSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LMSolver, crate::calib3d::LMSolverTraitConst, as_raw_LMSolver, crate::calib3d::LMSolverTrait, as_raw_mut_LMSolver }
+	
 	impl LMSolver {
 		/// Creates Levenberg-Marquard solver
 		/// 
@@ -5708,6 +5712,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_LMSolver_Callback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LMSolver_Callback, crate::calib3d::LMSolver_CallbackTraitConst, as_raw_LMSolver_Callback, crate::calib3d::LMSolver_CallbackTrait, as_raw_mut_LMSolver_Callback }
+	
 	impl LMSolver_Callback {
 	}
 	
@@ -5893,6 +5899,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoBM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::calib3d::StereoMatcherTraitConst for StereoBM {
 		#[inline] fn as_raw_StereoMatcher(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5901,6 +5909,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_StereoMatcher(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoBM, crate::calib3d::StereoMatcherTraitConst, as_raw_StereoMatcher, crate::calib3d::StereoMatcherTrait, as_raw_mut_StereoMatcher }
+	
 	impl crate::calib3d::StereoBMTraitConst for StereoBM {
 		#[inline] fn as_raw_StereoBM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5909,6 +5919,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_StereoBM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoBM, crate::calib3d::StereoBMTraitConst, as_raw_StereoBM, crate::calib3d::StereoBMTrait, as_raw_mut_StereoBM }
+	
 	impl StereoBM {
 		/// Creates StereoBM object
 		/// 
@@ -6106,6 +6118,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoMatcher, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::calib3d::StereoMatcherTraitConst for StereoMatcher {
 		#[inline] fn as_raw_StereoMatcher(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6114,6 +6128,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_StereoMatcher(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoMatcher, crate::calib3d::StereoMatcherTraitConst, as_raw_StereoMatcher, crate::calib3d::StereoMatcherTrait, as_raw_mut_StereoMatcher }
+	
 	impl StereoMatcher {
 	}
 	
@@ -6263,6 +6279,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoSGBM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::calib3d::StereoMatcherTraitConst for StereoSGBM {
 		#[inline] fn as_raw_StereoMatcher(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6271,6 +6289,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_StereoMatcher(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoSGBM, crate::calib3d::StereoMatcherTraitConst, as_raw_StereoMatcher, crate::calib3d::StereoMatcherTrait, as_raw_mut_StereoMatcher }
+	
 	impl crate::calib3d::StereoSGBMTraitConst for StereoSGBM {
 		#[inline] fn as_raw_StereoSGBM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6279,6 +6299,8 @@ pub mod calib3d {
 		#[inline] fn as_raw_mut_StereoSGBM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StereoSGBM, crate::calib3d::StereoSGBMTraitConst, as_raw_StereoSGBM, crate::calib3d::StereoSGBMTrait, as_raw_mut_StereoSGBM }
+	
 	impl StereoSGBM {
 		/// Creates StereoSGBM object
 		/// 
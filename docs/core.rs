@@ -8862,6 +8862,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Algorithm, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl Algorithm {
 		#[inline]
 		pub fn default() -> Result<core::Algorithm> {
@@ -8998,6 +9000,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_AsyncArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsyncArray, core::AsyncArrayTraitConst, as_raw_AsyncArray, core::AsyncArrayTrait, as_raw_mut_AsyncArray }
+	
 	impl AsyncArray {
 		#[inline]
 		pub fn default() -> core::AsyncArray {
@@ -9120,6 +9124,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_AsyncPromise(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsyncPromise, core::AsyncPromiseTraitConst, as_raw_AsyncPromise, core::AsyncPromiseTrait, as_raw_mut_AsyncPromise }
+	
 	impl AsyncPromise {
 		#[inline]
 		pub fn default() -> core::AsyncPromise {
@@ -9356,6 +9362,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_CommandLineParser(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CommandLineParser, core::CommandLineParserTraitConst, as_raw_CommandLineParser, core::CommandLineParserTrait, as_raw_mut_CommandLineParser }
+	
 	impl CommandLineParser {
 		/// Constructor
 		/// 
@@ -9461,6 +9469,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConjGradSolver, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl core::MinProblemSolverTraitConst for ConjGradSolver {
 		#[inline] fn as_raw_MinProblemSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9469,6 +9479,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MinProblemSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConjGradSolver, core::MinProblemSolverTraitConst, as_raw_MinProblemSolver, core::MinProblemSolverTrait, as_raw_mut_MinProblemSolver }
+	
 	impl core::ConjGradSolverTraitConst for ConjGradSolver {
 		#[inline] fn as_raw_ConjGradSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9477,6 +9489,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_ConjGradSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConjGradSolver, core::ConjGradSolverTraitConst, as_raw_ConjGradSolver, core::ConjGradSolverTrait, as_raw_mut_ConjGradSolver }
+	
 	impl ConjGradSolver {
 		/// This function returns the reference to the ready-to-use ConjGradSolver object.
 		/// 
@@ -9676,6 +9690,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DownhillSolver, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl core::MinProblemSolverTraitConst for DownhillSolver {
 		#[inline] fn as_raw_MinProblemSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9684,6 +9700,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MinProblemSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DownhillSolver, core::MinProblemSolverTraitConst, as_raw_MinProblemSolver, core::MinProblemSolverTrait, as_raw_mut_MinProblemSolver }
+	
 	impl core::DownhillSolverTraitConst for DownhillSolver {
 		#[inline] fn as_raw_DownhillSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9692,6 +9710,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_DownhillSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DownhillSolver, core::DownhillSolverTraitConst, as_raw_DownhillSolver, core::DownhillSolverTrait, as_raw_mut_DownhillSolver }
+	
 	impl DownhillSolver {
 		/// This function returns the reference to the ready-to-use DownhillSolver object.
 		/// 
@@ -9885,6 +9905,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Exception(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Exception, core::ExceptionTraitConst, as_raw_Exception, core::ExceptionTrait, as_raw_mut_Exception }
+	
 	impl Exception {
 		/// !
 		/// Default constructor
@@ -10323,6 +10345,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_FileNode(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FileNode, core::FileNodeTraitConst, as_raw_FileNode, core::FileNodeTrait, as_raw_mut_FileNode }
+	
 	impl FileNode {
 		/// The constructors.
 		/// 
@@ -10538,6 +10562,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_FileNodeIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FileNodeIterator, core::FileNodeIteratorTraitConst, as_raw_FileNodeIterator, core::FileNodeIteratorTrait, as_raw_mut_FileNodeIterator }
+	
 	impl FileNodeIterator {
 		/// The constructors.
 		/// 
@@ -10957,6 +10983,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_FileStorage(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FileStorage, core::FileStorageTraitConst, as_raw_FileStorage, core::FileStorageTrait, as_raw_mut_FileStorage }
+	
 	impl FileStorage {
 		/// The constructors.
 		/// 
@@ -11069,6 +11097,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Formatted(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Formatted, core::FormattedTraitConst, as_raw_Formatted, core::FormattedTrait, as_raw_mut_Formatted }
+	
 	impl Formatted {
 	}
 	
@@ -11163,6 +11193,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Formatter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Formatter, core::FormatterTraitConst, as_raw_Formatter, core::FormatterTrait, as_raw_mut_Formatter }
+	
 	impl Formatter {
 		/// ## C++ default parameters
 		/// * fmt: FMT_DEFAULT
@@ -11224,6 +11256,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Hamming(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Hamming, core::HammingTraitConst, as_raw_Hamming, core::HammingTrait, as_raw_mut_Hamming }
+	
 	impl Hamming {
 		pub const normType: u32 = 6;
 	}
@@ -11373,6 +11407,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_KeyPoint(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KeyPoint, core::KeyPointTraitConst, as_raw_KeyPoint, core::KeyPointTrait, as_raw_mut_KeyPoint }
+	
 	impl KeyPoint {
 		/// the default constructor
 		#[inline]
@@ -11649,6 +11685,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_LDA(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LDA, core::LDATraitConst, as_raw_LDA, core::LDATrait, as_raw_mut_LDA }
+	
 	impl LDA {
 		/// constructor
 		/// Initializes a LDA with num_components (default 0).
@@ -11722,31 +11760,19 @@ pub mod core {
 		/// - depth
 		/// - number of channels
 		#[inline]
-		fn flags(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_getPropFlags_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn flags(&self) -> i32 { core::mat_forward::flags(self) }
 		
 		/// the matrix dimensionality, >= 2
 		#[inline]
-		fn dims(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_getPropDims_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn dims(&self) -> i32 { core::mat_forward::dims(self) }
 		
 		/// the number of rows and columns or (-1, -1) when the matrix has more than 2 dimensions
 		#[inline]
-		fn rows(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_getPropRows_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn rows(&self) -> i32 { core::mat_forward::rows(self) }
 		
 		/// the number of rows and columns or (-1, -1) when the matrix has more than 2 dimensions
 		#[inline]
-		fn cols(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_getPropCols_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn cols(&self) -> i32 { core::mat_forward::cols(self) }
 		
 		/// helper fields used in locateROI and adjustROI
 		#[inline]
@@ -12511,10 +12537,7 @@ pub mod core {
 		/// allocated arrays are always continuous, you still need to check the destination array because
 		/// Mat::create does not always allocate a new matrix.
 		#[inline]
-		fn is_continuous(&self) -> bool {
-			let ret = unsafe { sys::cv_Mat_isContinuous_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn is_continuous(&self) -> bool { core::mat_forward::is_continuous(self) }
 		
 		/// returns true if the matrix is a submatrix of another matrix
 		#[inline]
@@ -12551,10 +12574,7 @@ pub mod core {
 		/// The method returns a matrix element type. This is an identifier compatible with the CvMat type
 		/// system, like CV_16SC3 or 16-bit signed 3-channel array, and so on.
 		#[inline]
-		fn typ(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_type_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn typ(&self) -> i32 { core::mat_forward::typ(self) }
 		
 		/// Returns the depth of a matrix element.
 		/// 
@@ -12569,19 +12589,13 @@ pub mod core {
 		/// *   CV_32F - 32-bit floating-point numbers ( -FLT_MAX..FLT_MAX, INF, NAN )
 		/// *   CV_64F - 64-bit floating-point numbers ( -DBL_MAX..DBL_MAX, INF, NAN )
 		#[inline]
-		fn depth(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_depth_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn depth(&self) -> i32 { core::mat_forward::depth(self) }
 		
 		/// Returns the number of matrix channels.
 		/// 
 		/// The method returns the number of matrix channels.
 		#[inline]
-		fn channels(&self) -> i32 {
-			let ret = unsafe { sys::cv_Mat_channels_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn channels(&self) -> i32 { core::mat_forward::channels(self) }
 		
 		/// Returns a normalized step.
 		/// 
@@ -12604,20 +12618,14 @@ pub mod core {
 		/// The method returns true if Mat::total() is 0 or if Mat::data is NULL. Because of pop_back() and
 		/// resize() methods `M.total() == 0` does not imply that `M.data == NULL`.
 		#[inline]
-		fn empty(&self) -> bool {
-			let ret = unsafe { sys::cv_Mat_empty_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn empty(&self) -> bool { core::mat_forward::empty(self) }
 		
 		/// Returns the total number of array elements.
 		/// 
 		/// The method returns the number of array elements (a number of pixels if the array represents an
 		/// image).
 		#[inline]
-		fn total(&self) -> size_t {
-			let ret = unsafe { sys::cv_Mat_total_const(self.as_raw_Mat()) };
-			ret
-		}
+		fn total(&self) -> size_t { core::mat_forward::total(self) }
 		
 		/// Returns the total number of array elements.
 		/// 
@@ -12974,10 +12982,7 @@ pub mod core {
 		
 		/// pointer to the data
 		#[inline]
-		fn data_mut(&mut self) -> *mut u8 {
-			let ret = unsafe { sys::cv_Mat_getPropData(self.as_raw_mut_Mat()) };
-			ret
-		}
+		fn data_mut(&mut self) -> *mut u8 { core::mat_forward::data_mut(self) }
 		
 		/// pointer to the data
 		#[inline]
@@ -13907,6 +13912,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Mat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Mat, core::MatTraitConst, as_raw_Mat, core::MatTrait, as_raw_mut_Mat }
+	
 	impl Mat {
 		/// These are various constructors that form a matrix. As noted in the AutomaticAllocation, often
 		/// the default constructor is enough, and the proper matrix will be allocated by an OpenCV function.
@@ -14802,6 +14809,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MatConstIterator, core::MatConstIteratorTraitConst, as_raw_MatConstIterator, core::MatConstIteratorTrait, as_raw_mut_MatConstIterator }
+	
 	impl MatConstIterator {
 		/// default constructor
 		#[inline]
@@ -15189,6 +15198,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MatExpr(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MatExpr, core::MatExprTraitConst, as_raw_MatExpr, core::MatExprTrait, as_raw_mut_MatExpr }
+	
 	impl MatExpr {
 		#[inline]
 		pub fn default() -> Result<core::MatExpr> {
@@ -15497,6 +15508,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MatOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MatOp, core::MatOpTraitConst, as_raw_MatOp, core::MatOpTrait, as_raw_mut_MatOp }
+	
 	impl MatOp {
 	}
 	
@@ -15599,6 +15612,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MatSize(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MatSize, core::MatSizeTraitConst, as_raw_MatSize, core::MatSizeTrait, as_raw_mut_MatSize }
+	
 	impl MatSize {
 		/// ////////////////////////// MatSize ////////////////////////////
 		#[inline]
@@ -15686,6 +15701,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MatStep(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MatStep, core::MatStepTraitConst, as_raw_MatStep, core::MatStepTrait, as_raw_mut_MatStep }
+	
 	impl MatStep {
 		/// ////////////////////////// MatStep ////////////////////////////
 		#[inline]
@@ -15749,6 +15766,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_AddOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_AddOp, core::Matx_AddOpTraitConst, as_raw_Matx_AddOp, core::Matx_AddOpTrait, as_raw_mut_Matx_AddOp }
+	
 	impl Matx_AddOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_AddOp> {
@@ -15808,6 +15827,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_DivOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_DivOp, core::Matx_DivOpTraitConst, as_raw_Matx_DivOp, core::Matx_DivOpTrait, as_raw_mut_Matx_DivOp }
+	
 	impl Matx_DivOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_DivOp> {
@@ -15867,6 +15888,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_MatMulOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_MatMulOp, core::Matx_MatMulOpTraitConst, as_raw_Matx_MatMulOp, core::Matx_MatMulOpTrait, as_raw_mut_Matx_MatMulOp }
+	
 	impl Matx_MatMulOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_MatMulOp> {
@@ -15926,6 +15949,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_MulOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_MulOp, core::Matx_MulOpTraitConst, as_raw_Matx_MulOp, core::Matx_MulOpTrait, as_raw_mut_Matx_MulOp }
+	
 	impl Matx_MulOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_MulOp> {
@@ -15985,6 +16010,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_ScaleOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_ScaleOp, core::Matx_ScaleOpTraitConst, as_raw_Matx_ScaleOp, core::Matx_ScaleOpTrait, as_raw_mut_Matx_ScaleOp }
+	
 	impl Matx_ScaleOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_ScaleOp> {
@@ -16044,6 +16071,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_SubOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_SubOp, core::Matx_SubOpTraitConst, as_raw_Matx_SubOp, core::Matx_SubOpTrait, as_raw_mut_Matx_SubOp }
+	
 	impl Matx_SubOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_SubOp> {
@@ -16103,6 +16132,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Matx_TOp(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Matx_TOp, core::Matx_TOpTraitConst, as_raw_Matx_TOp, core::Matx_TOpTrait, as_raw_mut_Matx_TOp }
+	
 	impl Matx_TOp {
 		#[inline]
 		pub fn default() -> Result<core::Matx_TOp> {
@@ -16252,6 +16283,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MinProblemSolver, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl core::MinProblemSolverTraitConst for MinProblemSolver {
 		#[inline] fn as_raw_MinProblemSolver(&self) -> *const c_void { self.as_raw() }
 	}
@@ -16260,6 +16293,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MinProblemSolver(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MinProblemSolver, core::MinProblemSolverTraitConst, as_raw_MinProblemSolver, core::MinProblemSolverTrait, as_raw_mut_MinProblemSolver }
+	
 	impl MinProblemSolver {
 	}
 	
@@ -16338,6 +16373,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_MinProblemSolver_Function(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MinProblemSolver_Function, core::MinProblemSolver_FunctionTraitConst, as_raw_MinProblemSolver_Function, core::MinProblemSolver_FunctionTrait, as_raw_mut_MinProblemSolver_Function }
+	
 	impl MinProblemSolver_Function {
 	}
 	
@@ -16818,6 +16855,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_PCA(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PCA, core::PCATraitConst, as_raw_PCA, core::PCATrait, as_raw_mut_PCA }
+	
 	impl PCA {
 		/// default constructor
 		/// 
@@ -16941,6 +16980,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_ParallelLoopBody(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ParallelLoopBody, core::ParallelLoopBodyTraitConst, as_raw_ParallelLoopBody, core::ParallelLoopBodyTrait, as_raw_mut_ParallelLoopBody }
+	
 	impl ParallelLoopBody {
 	}
 	
@@ -17350,6 +17391,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_RNG(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RNG, core::RNGTraitConst, as_raw_RNG, core::RNGTrait, as_raw_mut_RNG }
+	
 	impl RNG {
 		/// constructor
 		/// 
@@ -17534,6 +17577,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_RNG_MT19937(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RNG_MT19937, core::RNG_MT19937TraitConst, as_raw_RNG_MT19937, core::RNG_MT19937Trait, as_raw_mut_RNG_MT19937 }
+	
 	impl RNG_MT19937 {
 		#[inline]
 		pub fn default() -> Result<core::RNG_MT19937> {
@@ -17658,6 +17703,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Range(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Range, core::RangeTraitConst, as_raw_Range, core::RangeTrait, as_raw_mut_Range }
+	
 	impl Range {
 		/// ////////////////////////////// Range /////////////////////////////////
 		#[inline]
@@ -17816,6 +17863,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_RotatedRect(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RotatedRect, core::RotatedRectTraitConst, as_raw_RotatedRect, core::RotatedRectTrait, as_raw_mut_RotatedRect }
+	
 	impl RotatedRect {
 		/// default constructor
 		#[inline]
@@ -18005,6 +18054,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SVD(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVD, core::SVDTraitConst, as_raw_SVD, core::SVDTrait, as_raw_mut_SVD }
+	
 	impl SVD {
 		/// the default constructor
 		/// 
@@ -18729,6 +18780,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMat, core::SparseMatTraitConst, as_raw_SparseMat, core::SparseMatTrait, as_raw_mut_SparseMat }
+	
 	impl SparseMat {
 		/// Various SparseMat constructors.
 		#[inline]
@@ -18954,6 +19007,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMat_Hdr(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMat_Hdr, core::SparseMat_HdrTraitConst, as_raw_SparseMat_Hdr, core::SparseMat_HdrTrait, as_raw_mut_SparseMat_Hdr }
+	
 	impl SparseMat_Hdr {
 		#[inline]
 		pub fn new(_sizes: &[i32], _type: i32) -> Result<core::SparseMat_Hdr> {
@@ -19040,6 +19095,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMat_Node(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMat_Node, core::SparseMat_NodeTraitConst, as_raw_SparseMat_Node, core::SparseMat_NodeTrait, as_raw_mut_SparseMat_Node }
+	
 	impl SparseMat_Node {
 	}
 	
@@ -19165,6 +19222,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMatConstIterator, core::SparseMatConstIteratorTraitConst, as_raw_SparseMatConstIterator, core::SparseMatConstIteratorTrait, as_raw_mut_SparseMatConstIterator }
+	
 	impl SparseMatConstIterator {
 		/// the default constructor
 		#[inline]
@@ -19263,6 +19322,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMatIterator, core::SparseMatConstIteratorTraitConst, as_raw_SparseMatConstIterator, core::SparseMatConstIteratorTrait, as_raw_mut_SparseMatConstIterator }
+	
 	impl core::SparseMatIteratorTraitConst for SparseMatIterator {
 		#[inline] fn as_raw_SparseMatIterator(&self) -> *const c_void { self.as_raw() }
 	}
@@ -19271,6 +19332,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_SparseMatIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseMatIterator, core::SparseMatIteratorTraitConst, as_raw_SparseMatIterator, core::SparseMatIteratorTrait, as_raw_mut_SparseMatIterator }
+	
 	impl SparseMatIterator {
 		/// the default constructor
 		#[inline]
@@ -19359,6 +19422,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_TLSDataContainer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TLSDataContainer, core::TLSDataContainerTraitConst, as_raw_TLSDataContainer, core::TLSDataContainerTrait, as_raw_mut_TLSDataContainer }
+	
 	impl TLSDataContainer {
 	}
 	
@@ -19570,6 +19635,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_TickMeter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TickMeter, core::TickMeterTraitConst, as_raw_TickMeter, core::TickMeterTrait, as_raw_mut_TickMeter }
+	
 	impl TickMeter {
 		/// the default constructor
 		#[inline]
@@ -20251,6 +20318,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_UMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { UMat, core::UMatTraitConst, as_raw_UMat, core::UMatTrait, as_raw_mut_UMat }
+	
 	impl UMat {
 		/// default constructor
 		/// 
@@ -20876,6 +20945,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_UMatData(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { UMatData, core::UMatDataTraitConst, as_raw_UMatData, core::UMatDataTrait, as_raw_mut_UMatData }
+	
 	impl UMatData {
 	}
 	
@@ -21375,6 +21446,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__InputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _InputArray, core::_InputArrayTraitConst, as_raw__InputArray, core::_InputArrayTrait, as_raw_mut__InputArray }
+	
 	impl _InputArray {
 		#[inline]
 		pub fn default() -> Result<core::_InputArray> {
@@ -21544,6 +21617,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__InputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _InputOutputArray, core::_InputArrayTraitConst, as_raw__InputArray, core::_InputArrayTrait, as_raw_mut__InputArray }
+	
 	impl core::_OutputArrayTraitConst for _InputOutputArray {
 		#[inline] fn as_raw__OutputArray(&self) -> *const c_void { self.as_raw() }
 	}
@@ -21552,6 +21627,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__OutputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _InputOutputArray, core::_OutputArrayTraitConst, as_raw__OutputArray, core::_OutputArrayTrait, as_raw_mut__OutputArray }
+	
 	impl core::_InputOutputArrayTraitConst for _InputOutputArray {
 		#[inline] fn as_raw__InputOutputArray(&self) -> *const c_void { self.as_raw() }
 	}
@@ -21560,6 +21637,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__InputOutputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _InputOutputArray, core::_InputOutputArrayTraitConst, as_raw__InputOutputArray, core::_InputOutputArrayTrait, as_raw_mut__InputOutputArray }
+	
 	impl _InputOutputArray {
 		/// ////////////////////////////////////////////////////////////////////////////////////////
 		#[inline]
@@ -22024,6 +22103,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__InputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _OutputArray, core::_InputArrayTraitConst, as_raw__InputArray, core::_InputArrayTrait, as_raw_mut__InputArray }
+	
 	impl core::_OutputArrayTraitConst for _OutputArray {
 		#[inline] fn as_raw__OutputArray(&self) -> *const c_void { self.as_raw() }
 	}
@@ -22032,6 +22113,8 @@ pub mod core {
 		#[inline] fn as_raw_mut__OutputArray(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _OutputArray, core::_OutputArrayTraitConst, as_raw__OutputArray, core::_OutputArrayTrait, as_raw_mut__OutputArray }
+	
 	impl _OutputArray {
 		/// /////////////////////////////////////////////////////////////////////////////////////
 		#[inline]
@@ -22411,6 +22494,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_BufferPool(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BufferPool, core::BufferPoolTraitConst, as_raw_BufferPool, core::BufferPoolTrait, as_raw_mut_BufferPool }
+	
 	impl BufferPool {
 		/// Gets the BufferPool for the given stream.
 		#[inline]
@@ -23049,6 +23134,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_DeviceInfo(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DeviceInfo, core::DeviceInfoTraitConst, as_raw_DeviceInfo, core::DeviceInfoTrait, as_raw_mut_DeviceInfo }
+	
 	impl DeviceInfo {
 		/// creates DeviceInfo object for the current GPU
 		#[inline]
@@ -23149,6 +23236,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Event(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Event, core::EventTraitConst, as_raw_Event, core::EventTrait, as_raw_mut_Event }
+	
 	impl Event {
 		/// ## C++ default parameters
 		/// * flags: Event::CreateFlags::DEFAULT
@@ -23234,6 +23323,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_GpuData(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GpuData, core::GpuDataTraitConst, as_raw_GpuData, core::GpuDataTrait, as_raw_mut_GpuData }
+	
 	impl GpuData {
 		#[inline]
 		pub fn new(_size: size_t) -> Result<core::GpuData> {
@@ -23972,6 +24063,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_GpuMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GpuMat, core::GpuMatTraitConst, as_raw_GpuMat, core::GpuMatTrait, as_raw_mut_GpuMat }
+	
 	impl GpuMat {
 		/// default allocator
 		#[inline]
@@ -24196,6 +24289,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_GpuMat_Allocator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GpuMat_Allocator, core::GpuMat_AllocatorTraitConst, as_raw_GpuMat_Allocator, core::GpuMat_AllocatorTrait, as_raw_mut_GpuMat_Allocator }
+	
 	impl GpuMat_Allocator {
 	}
 	
@@ -24591,6 +24686,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_GpuMatND(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GpuMatND, core::GpuMatNDTraitConst, as_raw_GpuMatND, core::GpuMatNDTrait, as_raw_mut_GpuMatND }
+	
 	impl GpuMatND {
 		/// default constructor
 		#[inline]
@@ -25001,6 +25098,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_HostMem(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HostMem, core::HostMemTraitConst, as_raw_HostMem, core::HostMemTrait, as_raw_mut_HostMem }
+	
 	impl HostMem {
 		/// ## C++ default parameters
 		/// * alloc_type: HostMem::AllocType::PAGE_LOCKED
@@ -25198,6 +25297,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Stream(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Stream, core::StreamTraitConst, as_raw_Stream, core::StreamTrait, as_raw_mut_Stream }
+	
 	impl Stream {
 		/// creates a new asynchronous stream
 		#[inline]
@@ -25296,6 +25397,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_TargetArchs(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TargetArchs, core::TargetArchsTraitConst, as_raw_TargetArchs, core::TargetArchsTrait, as_raw_mut_TargetArchs }
+	
 	impl TargetArchs {
 		/// The following method checks whether the module was built with the support of the given feature:
 		/// 
@@ -25478,6 +25581,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Detail_CheckContext(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Detail_CheckContext, core::Detail_CheckContextTraitConst, as_raw_Detail_CheckContext, core::Detail_CheckContextTrait, as_raw_mut_Detail_CheckContext }
+	
 	impl Detail_CheckContext {
 		fn default() -> Self {
 			extern "C" { fn cv_Detail_CheckContext_default_new() -> extern_receive!(Detail_CheckContext: 'static); }
@@ -25689,6 +25794,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_NodeData(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NodeData, core::NodeDataTraitConst, as_raw_NodeData, core::NodeDataTrait, as_raw_mut_NodeData }
+	
 	impl NodeData {
 		/// ## C++ default parameters
 		/// * fun_name: 0
@@ -25758,6 +25865,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_WriteStructContext(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WriteStructContext, core::WriteStructContextTraitConst, as_raw_WriteStructContext, core::WriteStructContextTrait, as_raw_mut_WriteStructContext }
+	
 	impl WriteStructContext {
 		/// ## C++ default parameters
 		/// * type_name: String()
@@ -25933,6 +26042,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Context(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Context, core::ContextTraitConst, as_raw_Context, core::ContextTrait, as_raw_mut_Context }
+	
 	impl Context {
 		#[inline]
 		pub fn default() -> core::Context {
@@ -26059,6 +26170,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Context_UserContext(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Context_UserContext, core::Context_UserContextTraitConst, as_raw_Context_UserContext, core::Context_UserContextTrait, as_raw_mut_Context_UserContext }
+	
 	impl Context_UserContext {
 	}
 	
@@ -26791,6 +26904,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Device(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Device, core::DeviceTraitConst, as_raw_Device, core::DeviceTrait, as_raw_mut_Device }
+	
 	impl Device {
 		#[inline]
 		pub fn default() -> core::Device {
@@ -26916,6 +27031,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Image2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Image2D, core::Image2DTraitConst, as_raw_Image2D, core::Image2DTrait, as_raw_mut_Image2D }
+	
 	impl Image2D {
 		#[inline]
 		pub fn default() -> core::Image2D {
@@ -27216,6 +27333,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Kernel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kernel, core::KernelTraitConst, as_raw_Kernel, core::KernelTrait, as_raw_mut_Kernel }
+	
 	impl Kernel {
 		#[inline]
 		pub fn default() -> core::Kernel {
@@ -27382,6 +27501,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_KernelArg(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KernelArg, core::KernelArgTraitConst, as_raw_KernelArg, core::KernelArgTrait, as_raw_mut_KernelArg }
+	
 	impl KernelArg {
 		/// ## C++ default parameters
 		/// * wscale: 1
@@ -27694,6 +27815,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_OpenCLExecutionContext(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OpenCLExecutionContext, core::OpenCLExecutionContextTraitConst, as_raw_OpenCLExecutionContext, core::OpenCLExecutionContextTrait, as_raw_mut_OpenCLExecutionContext }
+	
 	impl OpenCLExecutionContext {
 		#[inline]
 		pub fn default() -> core::OpenCLExecutionContext {
@@ -27867,6 +27990,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Platform(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Platform, core::PlatformTraitConst, as_raw_Platform, core::PlatformTrait, as_raw_mut_Platform }
+	
 	impl Platform {
 		#[inline]
 		pub fn default() -> core::Platform {
@@ -28025,6 +28150,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_PlatformInfo(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PlatformInfo, core::PlatformInfoTraitConst, as_raw_PlatformInfo, core::PlatformInfoTrait, as_raw_mut_PlatformInfo }
+	
 	impl PlatformInfo {
 		#[inline]
 		pub fn default() -> core::PlatformInfo {
@@ -28197,6 +28324,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Program(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Program, core::ProgramTraitConst, as_raw_Program, core::ProgramTrait, as_raw_mut_Program }
+	
 	impl Program {
 		#[inline]
 		pub fn default() -> core::Program {
@@ -28320,6 +28449,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_ProgramSource(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ProgramSource, core::ProgramSourceTraitConst, as_raw_ProgramSource, core::ProgramSourceTrait, as_raw_mut_ProgramSource }
+	
 	impl ProgramSource {
 		#[inline]
 		pub fn default() -> core::ProgramSource {
@@ -28535,6 +28666,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Queue(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Queue, core::QueueTraitConst, as_raw_Queue, core::QueueTrait, as_raw_mut_Queue }
+	
 	impl Queue {
 		#[inline]
 		pub fn default() -> core::Queue {
@@ -28655,6 +28788,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Timer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Timer, core::TimerTraitConst, as_raw_Timer, core::TimerTrait, as_raw_mut_Timer }
+	
 	impl Timer {
 		#[inline]
 		pub fn new(q: &core::Queue) -> Result<core::Timer> {
@@ -28850,6 +28985,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Arrays(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Arrays, core::ArraysTraitConst, as_raw_Arrays, core::ArraysTrait, as_raw_mut_Arrays }
+	
 	impl Arrays {
 		/// Default constructor
 		#[inline]
@@ -29263,6 +29400,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Buffer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Buffer, core::BufferTraitConst, as_raw_Buffer, core::BufferTrait, as_raw_mut_Buffer }
+	
 	impl Buffer {
 		/// The constructors.
 		/// 
@@ -29639,6 +29778,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_Texture2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Texture2D, core::Texture2DTraitConst, as_raw_Texture2D, core::Texture2DTrait, as_raw_mut_Texture2D }
+	
 	impl Texture2D {
 		/// The constructors.
 		/// 
@@ -29848,6 +29989,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_LogTag(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogTag, core::LogTagTraitConst, as_raw_LogTag, core::LogTagTrait, as_raw_mut_LogTag }
+	
 	impl LogTag {
 		#[inline]
 		pub fn new(_name: &str, _level: core::LogLevel) -> Result<core::LogTag> {
@@ -29916,6 +30059,8 @@ pub mod core {
 		#[inline] fn as_raw_mut_OriginalClassName(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OriginalClassName, core::OriginalClassNameTraitConst, as_raw_OriginalClassName, core::OriginalClassNameTrait, as_raw_mut_OriginalClassName }
+	
 	impl OriginalClassName {
 		/// ## C++ default parameters
 		/// * params: OriginalClassName::Params()
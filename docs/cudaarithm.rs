@@ -1828,6 +1828,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Convolution, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::cudaarithm::ConvolutionTraitConst for Convolution {
 		#[inline] fn as_raw_Convolution(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1836,6 +1838,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_Convolution(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Convolution, crate::cudaarithm::ConvolutionTraitConst, as_raw_Convolution, crate::cudaarithm::ConvolutionTrait, as_raw_mut_Convolution }
+	
 	impl Convolution {
 	}
 	
@@ -1898,6 +1902,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DFT, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::cudaarithm::DFTTraitConst for DFT {
 		#[inline] fn as_raw_DFT(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1906,6 +1912,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_DFT(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DFT, crate::cudaarithm::DFTTraitConst, as_raw_DFT, crate::cudaarithm::DFTTrait, as_raw_mut_DFT }
+	
 	impl DFT {
 	}
 	
@@ -1969,6 +1977,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LookUpTable, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::cudaarithm::LookUpTableTraitConst for LookUpTable {
 		#[inline] fn as_raw_LookUpTable(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1977,6 +1987,8 @@ pub mod cudaarithm {
 		#[inline] fn as_raw_mut_LookUpTable(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LookUpTable, crate::cudaarithm::LookUpTableTraitConst, as_raw_LookUpTable, crate::cudaarithm::LookUpTableTrait, as_raw_mut_LookUpTable }
+	
 	impl LookUpTable {
 	}
 	
@@ -515,6 +515,8 @@ pub mod cudacodec {
 		#[inline] fn as_raw_mut_EncoderCallback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EncoderCallback, crate::cudacodec::EncoderCallbackTraitConst, as_raw_EncoderCallback, crate::cudacodec::EncoderCallbackTrait, as_raw_mut_EncoderCallback }
+	
 	impl EncoderCallback {
 	}
 	
@@ -712,6 +714,8 @@ pub mod cudacodec {
 		#[inline] fn as_raw_mut_RawVideoSource(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RawVideoSource, crate::cudacodec::RawVideoSourceTraitConst, as_raw_RawVideoSource, crate::cudacodec::RawVideoSourceTrait, as_raw_mut_RawVideoSource }
+	
 	impl RawVideoSource {
 	}
 	
@@ -971,6 +975,8 @@ pub mod cudacodec {
 		#[inline] fn as_raw_mut_VideoReader(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { VideoReader, crate::cudacodec::VideoReaderTraitConst, as_raw_VideoReader, crate::cudacodec::VideoReaderTrait, as_raw_mut_VideoReader }
+	
 	impl VideoReader {
 	}
 	
@@ -1098,6 +1104,8 @@ pub mod cudacodec {
 		#[inline] fn as_raw_mut_VideoWriter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { VideoWriter, crate::cudacodec::VideoWriterTraitConst, as_raw_VideoWriter, crate::cudacodec::VideoWriterTrait, as_raw_mut_VideoWriter }
+	
 	impl VideoWriter {
 	}
 }
@@ -457,6 +457,8 @@ pub mod cudafilters {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Filter, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::cudafilters::FilterTraitConst for Filter {
 		#[inline] fn as_raw_Filter(&self) -> *const c_void { self.as_raw() }
 	}
@@ -465,6 +467,8 @@ pub mod cudafilters {
 		#[inline] fn as_raw_mut_Filter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Filter, crate::cudafilters::FilterTraitConst, as_raw_Filter, crate::cudafilters::FilterTrait, as_raw_mut_Filter }
+	
 	impl Filter {
 	}
 	
@@ -135,6 +135,8 @@ pub mod cvv {
 		#[inline] fn as_raw_mut_CallMetaData(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CallMetaData, crate::cvv::CallMetaDataTraitConst, as_raw_CallMetaData, crate::cvv::CallMetaDataTrait, as_raw_mut_CallMetaData }
+	
 	impl CallMetaData {
 		/// Creates an unknown location.
 		#[inline]
@@ -1158,6 +1158,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AbsLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AbsLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1166,6 +1168,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AbsLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AbsLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1174,6 +1178,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AbsLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AbsLayerTraitConst for AbsLayer {
 		#[inline] fn as_raw_AbsLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1182,6 +1188,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AbsLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AbsLayer, crate::dnn::AbsLayerTraitConst, as_raw_AbsLayer, crate::dnn::AbsLayerTrait, as_raw_mut_AbsLayer }
+	
 	impl AbsLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AbsLayer>> {
@@ -1237,6 +1245,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AccumLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AccumLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1245,6 +1255,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AccumLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AccumLayerTraitConst for AccumLayer {
 		#[inline] fn as_raw_AccumLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1253,6 +1265,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AccumLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AccumLayer, crate::dnn::AccumLayerTraitConst, as_raw_AccumLayer, crate::dnn::AccumLayerTrait, as_raw_mut_AccumLayer }
+	
 	impl AccumLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AccumLayer>> {
@@ -1306,6 +1320,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcosLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AcosLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1314,6 +1330,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcosLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AcosLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1322,6 +1340,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcosLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AcosLayerTraitConst for AcosLayer {
 		#[inline] fn as_raw_AcosLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1330,6 +1350,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AcosLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcosLayer, crate::dnn::AcosLayerTraitConst, as_raw_AcosLayer, crate::dnn::AcosLayerTrait, as_raw_mut_AcosLayer }
+	
 	impl AcosLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AcosLayer>> {
@@ -1385,6 +1407,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcoshLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AcoshLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1393,6 +1417,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcoshLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AcoshLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1401,6 +1427,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcoshLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AcoshLayerTraitConst for AcoshLayer {
 		#[inline] fn as_raw_AcoshLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1409,6 +1437,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AcoshLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AcoshLayer, crate::dnn::AcoshLayerTraitConst, as_raw_AcoshLayer, crate::dnn::AcoshLayerTrait, as_raw_mut_AcoshLayer }
+	
 	impl AcoshLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AcoshLayer>> {
@@ -1482,6 +1512,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ActivationLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1490,6 +1522,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ActivationLayerTraitConst for ActivationLayer {
 		#[inline] fn as_raw_ActivationLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1498,6 +1532,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl ActivationLayer {
 	}
 	
@@ -1625,6 +1661,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayerInt8, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ActivationLayerInt8 {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1633,6 +1671,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ActivationLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1641,6 +1681,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ActivationLayerInt8TraitConst for ActivationLayerInt8 {
 		#[inline] fn as_raw_ActivationLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1649,6 +1691,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ActivationLayerInt8, crate::dnn::ActivationLayerInt8TraitConst, as_raw_ActivationLayerInt8, crate::dnn::ActivationLayerInt8Trait, as_raw_mut_ActivationLayerInt8 }
+	
 	impl ActivationLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ActivationLayerInt8>> {
@@ -1707,6 +1751,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ArgLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ArgLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1715,6 +1761,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ArgLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ArgLayerTraitConst for ArgLayer {
 		#[inline] fn as_raw_ArgLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1723,6 +1771,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ArgLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ArgLayer, crate::dnn::ArgLayerTraitConst, as_raw_ArgLayer, crate::dnn::ArgLayerTrait, as_raw_mut_ArgLayer }
+	
 	impl ArgLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ArgLayer>> {
@@ -1776,6 +1826,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AsinLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1784,6 +1836,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AsinLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1792,6 +1846,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AsinLayerTraitConst for AsinLayer {
 		#[inline] fn as_raw_AsinLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1800,6 +1856,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AsinLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinLayer, crate::dnn::AsinLayerTraitConst, as_raw_AsinLayer, crate::dnn::AsinLayerTrait, as_raw_mut_AsinLayer }
+	
 	impl AsinLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AsinLayer>> {
@@ -1855,6 +1913,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinhLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AsinhLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1863,6 +1923,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinhLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AsinhLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1871,6 +1933,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinhLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AsinhLayerTraitConst for AsinhLayer {
 		#[inline] fn as_raw_AsinhLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1879,6 +1943,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AsinhLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AsinhLayer, crate::dnn::AsinhLayerTraitConst, as_raw_AsinhLayer, crate::dnn::AsinhLayerTrait, as_raw_mut_AsinhLayer }
+	
 	impl AsinhLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AsinhLayer>> {
@@ -1934,6 +2000,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AtanLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1942,6 +2010,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AtanLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1950,6 +2020,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AtanLayerTraitConst for AtanLayer {
 		#[inline] fn as_raw_AtanLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1958,6 +2030,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AtanLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanLayer, crate::dnn::AtanLayerTraitConst, as_raw_AtanLayer, crate::dnn::AtanLayerTrait, as_raw_mut_AtanLayer }
+	
 	impl AtanLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AtanLayer>> {
@@ -2013,6 +2087,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanhLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for AtanhLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2021,6 +2097,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanhLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for AtanhLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2029,6 +2107,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanhLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::AtanhLayerTraitConst for AtanhLayer {
 		#[inline] fn as_raw_AtanhLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2037,6 +2117,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_AtanhLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AtanhLayer, crate::dnn::AtanhLayerTraitConst, as_raw_AtanhLayer, crate::dnn::AtanhLayerTrait, as_raw_mut_AtanhLayer }
+	
 	impl AtanhLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::AtanhLayer>> {
@@ -2092,6 +2174,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BNLLLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for BNLLLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2100,6 +2184,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BNLLLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for BNLLLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2108,6 +2194,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BNLLLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::BNLLLayerTraitConst for BNLLLayer {
 		#[inline] fn as_raw_BNLLLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2116,6 +2204,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BNLLLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BNLLLayer, crate::dnn::BNLLLayerTraitConst, as_raw_BNLLLayer, crate::dnn::BNLLLayerTrait, as_raw_mut_BNLLLayer }
+	
 	impl BNLLLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BNLLLayer>> {
@@ -2186,6 +2276,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BackendNode(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BackendNode, crate::dnn::BackendNodeTraitConst, as_raw_BackendNode, crate::dnn::BackendNodeTrait, as_raw_mut_BackendNode }
+	
 	impl BackendNode {
 	}
 	
@@ -2274,6 +2366,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BackendWrapper(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BackendWrapper, crate::dnn::BackendWrapperTraitConst, as_raw_BackendWrapper, crate::dnn::BackendWrapperTrait, as_raw_mut_BackendWrapper }
+	
 	impl BackendWrapper {
 	}
 	
@@ -2487,6 +2581,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseConvolutionLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for BaseConvolutionLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2495,6 +2591,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseConvolutionLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::BaseConvolutionLayerTraitConst for BaseConvolutionLayer {
 		#[inline] fn as_raw_BaseConvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2503,6 +2601,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BaseConvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseConvolutionLayer, crate::dnn::BaseConvolutionLayerTraitConst, as_raw_BaseConvolutionLayer, crate::dnn::BaseConvolutionLayerTrait, as_raw_mut_BaseConvolutionLayer }
+	
 	impl BaseConvolutionLayer {
 	}
 	
@@ -2582,6 +2682,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for BatchNormLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2590,6 +2692,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for BatchNormLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2598,6 +2702,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::BatchNormLayerTraitConst for BatchNormLayer {
 		#[inline] fn as_raw_BatchNormLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2606,6 +2712,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BatchNormLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayer, crate::dnn::BatchNormLayerTraitConst, as_raw_BatchNormLayer, crate::dnn::BatchNormLayerTrait, as_raw_mut_BatchNormLayer }
+	
 	impl BatchNormLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BatchNormLayer>> {
@@ -2709,6 +2817,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayerInt8, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for BatchNormLayerInt8 {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2717,6 +2827,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::BatchNormLayerTraitConst for BatchNormLayerInt8 {
 		#[inline] fn as_raw_BatchNormLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2725,6 +2837,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BatchNormLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayerInt8, crate::dnn::BatchNormLayerTraitConst, as_raw_BatchNormLayer, crate::dnn::BatchNormLayerTrait, as_raw_mut_BatchNormLayer }
+	
 	impl crate::dnn::LayerTraitConst for BatchNormLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2733,6 +2847,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::BatchNormLayerInt8TraitConst for BatchNormLayerInt8 {
 		#[inline] fn as_raw_BatchNormLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2741,6 +2857,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BatchNormLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BatchNormLayerInt8, crate::dnn::BatchNormLayerInt8TraitConst, as_raw_BatchNormLayerInt8, crate::dnn::BatchNormLayerInt8Trait, as_raw_mut_BatchNormLayerInt8 }
+	
 	impl BatchNormLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BatchNormLayerInt8>> {
@@ -2817,6 +2935,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlankLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for BlankLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2825,6 +2945,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlankLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::BlankLayerTraitConst for BlankLayer {
 		#[inline] fn as_raw_BlankLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2833,6 +2955,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BlankLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlankLayer, crate::dnn::BlankLayerTraitConst, as_raw_BlankLayer, crate::dnn::BlankLayerTrait, as_raw_mut_BlankLayer }
+	
 	impl BlankLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -2886,6 +3010,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeilLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for CeilLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2894,6 +3020,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeilLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CeilLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2902,6 +3030,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeilLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CeilLayerTraitConst for CeilLayer {
 		#[inline] fn as_raw_CeilLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2910,6 +3040,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CeilLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeilLayer, crate::dnn::CeilLayerTraitConst, as_raw_CeilLayer, crate::dnn::CeilLayerTrait, as_raw_mut_CeilLayer }
+	
 	impl CeilLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CeilLayer>> {
@@ -2977,6 +3109,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeluLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for CeluLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2985,6 +3119,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeluLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CeluLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2993,6 +3129,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeluLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CeluLayerTraitConst for CeluLayer {
 		#[inline] fn as_raw_CeluLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3001,6 +3139,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CeluLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CeluLayer, crate::dnn::CeluLayerTraitConst, as_raw_CeluLayer, crate::dnn::CeluLayerTrait, as_raw_mut_CeluLayer }
+	
 	impl CeluLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CeluLayer>> {
@@ -3056,6 +3196,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ChannelsPReLULayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ChannelsPReLULayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3064,6 +3206,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ChannelsPReLULayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ChannelsPReLULayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3072,6 +3216,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ChannelsPReLULayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ChannelsPReLULayerTraitConst for ChannelsPReLULayer {
 		#[inline] fn as_raw_ChannelsPReLULayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3080,6 +3226,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ChannelsPReLULayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ChannelsPReLULayer, crate::dnn::ChannelsPReLULayerTraitConst, as_raw_ChannelsPReLULayer, crate::dnn::ChannelsPReLULayerTrait, as_raw_mut_ChannelsPReLULayer }
+	
 	impl ChannelsPReLULayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -3199,6 +3347,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ClassificationModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::ClassificationModelTraitConst for ClassificationModel {
 		#[inline] fn as_raw_ClassificationModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3207,6 +3357,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ClassificationModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ClassificationModel, crate::dnn::ClassificationModelTraitConst, as_raw_ClassificationModel, crate::dnn::ClassificationModelTrait, as_raw_mut_ClassificationModel }
+	
 	impl ClassificationModel {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::ClassificationModel> {
@@ -3299,6 +3451,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CompareLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CompareLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3307,6 +3461,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CompareLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CompareLayerTraitConst for CompareLayer {
 		#[inline] fn as_raw_CompareLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3315,6 +3471,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CompareLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CompareLayer, crate::dnn::CompareLayerTraitConst, as_raw_CompareLayer, crate::dnn::CompareLayerTrait, as_raw_mut_CompareLayer }
+	
 	impl CompareLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -3412,6 +3570,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConcatLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ConcatLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3420,6 +3580,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConcatLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ConcatLayerTraitConst for ConcatLayer {
 		#[inline] fn as_raw_ConcatLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3428,6 +3590,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ConcatLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConcatLayer, crate::dnn::ConcatLayerTraitConst, as_raw_ConcatLayer, crate::dnn::ConcatLayerTrait, as_raw_mut_ConcatLayer }
+	
 	impl ConcatLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ConcatLayer>> {
@@ -3482,6 +3646,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConstLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ConstLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3490,6 +3656,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConstLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ConstLayerTraitConst for ConstLayer {
 		#[inline] fn as_raw_ConstLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3498,6 +3666,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ConstLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConstLayer, crate::dnn::ConstLayerTraitConst, as_raw_ConstLayer, crate::dnn::ConstLayerTrait, as_raw_mut_ConstLayer }
+	
 	impl ConstLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -3607,6 +3777,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::BaseConvolutionLayerTraitConst for ConvolutionLayer {
 		#[inline] fn as_raw_BaseConvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3615,6 +3787,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BaseConvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayer, crate::dnn::BaseConvolutionLayerTraitConst, as_raw_BaseConvolutionLayer, crate::dnn::BaseConvolutionLayerTrait, as_raw_mut_BaseConvolutionLayer }
+	
 	impl crate::dnn::LayerTraitConst for ConvolutionLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3623,6 +3797,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ConvolutionLayerTraitConst for ConvolutionLayer {
 		#[inline] fn as_raw_ConvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3631,6 +3807,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ConvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayer, crate::dnn::ConvolutionLayerTraitConst, as_raw_ConvolutionLayer, crate::dnn::ConvolutionLayerTrait, as_raw_mut_ConvolutionLayer }
+	
 	impl ConvolutionLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
@@ -3760,6 +3938,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::BaseConvolutionLayerTraitConst for ConvolutionLayerInt8 {
 		#[inline] fn as_raw_BaseConvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3768,6 +3948,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BaseConvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayerInt8, crate::dnn::BaseConvolutionLayerTraitConst, as_raw_BaseConvolutionLayer, crate::dnn::BaseConvolutionLayerTrait, as_raw_mut_BaseConvolutionLayer }
+	
 	impl crate::dnn::LayerTraitConst for ConvolutionLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3776,6 +3958,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ConvolutionLayerInt8TraitConst for ConvolutionLayerInt8 {
 		#[inline] fn as_raw_ConvolutionLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3784,6 +3968,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ConvolutionLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ConvolutionLayerInt8, crate::dnn::ConvolutionLayerInt8TraitConst, as_raw_ConvolutionLayerInt8, crate::dnn::ConvolutionLayerInt8Trait, as_raw_mut_ConvolutionLayerInt8 }
+	
 	impl ConvolutionLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
@@ -3839,6 +4025,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CorrelationLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CorrelationLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3847,6 +4035,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CorrelationLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CorrelationLayerTraitConst for CorrelationLayer {
 		#[inline] fn as_raw_CorrelationLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3855,6 +4045,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CorrelationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CorrelationLayer, crate::dnn::CorrelationLayerTraitConst, as_raw_CorrelationLayer, crate::dnn::CorrelationLayerTrait, as_raw_mut_CorrelationLayer }
+	
 	impl CorrelationLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CorrelationLayer>> {
@@ -3908,6 +4100,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CosLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for CosLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3916,6 +4110,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CosLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CosLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3924,6 +4120,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CosLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CosLayerTraitConst for CosLayer {
 		#[inline] fn as_raw_CosLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3932,6 +4130,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CosLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CosLayer, crate::dnn::CosLayerTraitConst, as_raw_CosLayer, crate::dnn::CosLayerTrait, as_raw_mut_CosLayer }
+	
 	impl CosLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CosLayer>> {
@@ -3987,6 +4187,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CoshLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for CoshLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3995,6 +4197,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CoshLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CoshLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4003,6 +4207,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CoshLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CoshLayerTraitConst for CoshLayer {
 		#[inline] fn as_raw_CoshLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4011,6 +4217,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CoshLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CoshLayer, crate::dnn::CoshLayerTraitConst, as_raw_CoshLayer, crate::dnn::CoshLayerTrait, as_raw_mut_CoshLayer }
+	
 	impl CoshLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CoshLayer>> {
@@ -4066,6 +4274,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropAndResizeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CropAndResizeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4074,6 +4284,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropAndResizeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CropAndResizeLayerTraitConst for CropAndResizeLayer {
 		#[inline] fn as_raw_CropAndResizeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4082,6 +4294,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CropAndResizeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropAndResizeLayer, crate::dnn::CropAndResizeLayerTraitConst, as_raw_CropAndResizeLayer, crate::dnn::CropAndResizeLayerTrait, as_raw_mut_CropAndResizeLayer }
+	
 	impl CropAndResizeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -4135,6 +4349,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CropLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4143,6 +4359,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CropLayerTraitConst for CropLayer {
 		#[inline] fn as_raw_CropLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4151,6 +4369,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CropLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CropLayer, crate::dnn::CropLayerTraitConst, as_raw_CropLayer, crate::dnn::CropLayerTrait, as_raw_mut_CropLayer }
+	
 	impl CropLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -4228,6 +4448,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CumSumLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for CumSumLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4236,6 +4458,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CumSumLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::CumSumLayerTraitConst for CumSumLayer {
 		#[inline] fn as_raw_CumSumLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4244,6 +4468,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_CumSumLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CumSumLayer, crate::dnn::CumSumLayerTraitConst, as_raw_CumSumLayer, crate::dnn::CumSumLayerTrait, as_raw_mut_CumSumLayer }
+	
 	impl CumSumLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::CumSumLayer>> {
@@ -4297,6 +4523,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DataAugmentationLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for DataAugmentationLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4305,6 +4533,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DataAugmentationLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::DataAugmentationLayerTraitConst for DataAugmentationLayer {
 		#[inline] fn as_raw_DataAugmentationLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4313,6 +4543,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DataAugmentationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DataAugmentationLayer, crate::dnn::DataAugmentationLayerTraitConst, as_raw_DataAugmentationLayer, crate::dnn::DataAugmentationLayerTrait, as_raw_mut_DataAugmentationLayer }
+	
 	impl DataAugmentationLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DataAugmentationLayer>> {
@@ -4366,6 +4598,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DeconvolutionLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::BaseConvolutionLayerTraitConst for DeconvolutionLayer {
 		#[inline] fn as_raw_BaseConvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4374,6 +4608,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_BaseConvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DeconvolutionLayer, crate::dnn::BaseConvolutionLayerTraitConst, as_raw_BaseConvolutionLayer, crate::dnn::BaseConvolutionLayerTrait, as_raw_mut_BaseConvolutionLayer }
+	
 	impl crate::dnn::LayerTraitConst for DeconvolutionLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4382,6 +4618,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DeconvolutionLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::DeconvolutionLayerTraitConst for DeconvolutionLayer {
 		#[inline] fn as_raw_DeconvolutionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4390,6 +4628,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DeconvolutionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DeconvolutionLayer, crate::dnn::DeconvolutionLayerTraitConst, as_raw_DeconvolutionLayer, crate::dnn::DeconvolutionLayerTrait, as_raw_mut_DeconvolutionLayer }
+	
 	impl DeconvolutionLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::BaseConvolutionLayer>> {
@@ -4471,6 +4711,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DequantizeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for DequantizeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4479,6 +4721,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DequantizeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::DequantizeLayerTraitConst for DequantizeLayer {
 		#[inline] fn as_raw_DequantizeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4487,6 +4731,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DequantizeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DequantizeLayer, crate::dnn::DequantizeLayerTraitConst, as_raw_DequantizeLayer, crate::dnn::DequantizeLayerTrait, as_raw_mut_DequantizeLayer }
+	
 	impl DequantizeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DequantizeLayer>> {
@@ -4594,6 +4840,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::DetectionModelTraitConst for DetectionModel {
 		#[inline] fn as_raw_DetectionModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4602,6 +4850,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DetectionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionModel, crate::dnn::DetectionModelTraitConst, as_raw_DetectionModel, crate::dnn::DetectionModelTrait, as_raw_mut_DetectionModel }
+	
 	impl DetectionModel {
 		/// Create detection model from network represented in one of the supported formats.
 		/// An order of @p model and @p config arguments does not matter.
@@ -4700,6 +4950,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionOutputLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for DetectionOutputLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4708,6 +4960,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionOutputLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::DetectionOutputLayerTraitConst for DetectionOutputLayer {
 		#[inline] fn as_raw_DetectionOutputLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4716,6 +4970,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DetectionOutputLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionOutputLayer, crate::dnn::DetectionOutputLayerTraitConst, as_raw_DetectionOutputLayer, crate::dnn::DetectionOutputLayerTrait, as_raw_mut_DetectionOutputLayer }
+	
 	impl DetectionOutputLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::DetectionOutputLayer>> {
@@ -4877,6 +5133,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Dict(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Dict, crate::dnn::DictTraitConst, as_raw_Dict, crate::dnn::DictTrait, as_raw_mut_Dict }
+	
 	impl Dict {
 	}
 	
@@ -5033,6 +5291,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_DictValue(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DictValue, crate::dnn::DictValueTraitConst, as_raw_DictValue, crate::dnn::DictValueTrait, as_raw_mut_DictValue }
+	
 	impl DictValue {
 		#[inline]
 		pub fn copy(r: &crate::dnn::DictValue) -> Result<crate::dnn::DictValue> {
@@ -5157,6 +5417,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ELULayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ELULayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5165,6 +5427,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ELULayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ELULayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5173,6 +5437,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ELULayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ELULayerTraitConst for ELULayer {
 		#[inline] fn as_raw_ELULayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5181,6 +5447,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ELULayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ELULayer, crate::dnn::ELULayerTraitConst, as_raw_ELULayer, crate::dnn::ELULayerTrait, as_raw_mut_ELULayer }
+	
 	impl ELULayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ELULayer>> {
@@ -5242,6 +5510,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for EltwiseLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5250,6 +5520,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::EltwiseLayerTraitConst for EltwiseLayer {
 		#[inline] fn as_raw_EltwiseLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5258,6 +5530,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_EltwiseLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayer, crate::dnn::EltwiseLayerTraitConst, as_raw_EltwiseLayer, crate::dnn::EltwiseLayerTrait, as_raw_mut_EltwiseLayer }
+	
 	impl EltwiseLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::EltwiseLayer>> {
@@ -5311,6 +5585,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for EltwiseLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5319,6 +5595,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::EltwiseLayerInt8TraitConst for EltwiseLayerInt8 {
 		#[inline] fn as_raw_EltwiseLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5327,6 +5605,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_EltwiseLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EltwiseLayerInt8, crate::dnn::EltwiseLayerInt8TraitConst, as_raw_EltwiseLayerInt8, crate::dnn::EltwiseLayerInt8Trait, as_raw_mut_EltwiseLayerInt8 }
+	
 	impl EltwiseLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::EltwiseLayerInt8>> {
@@ -5380,6 +5660,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ErfLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ErfLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5388,6 +5670,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ErfLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ErfLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5396,6 +5680,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ErfLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ErfLayerTraitConst for ErfLayer {
 		#[inline] fn as_raw_ErfLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5404,6 +5690,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ErfLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ErfLayer, crate::dnn::ErfLayerTraitConst, as_raw_ErfLayer, crate::dnn::ErfLayerTrait, as_raw_mut_ErfLayer }
+	
 	impl ErfLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ErfLayer>> {
@@ -5495,6 +5783,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExpLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ExpLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5503,6 +5793,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExpLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ExpLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5511,6 +5803,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExpLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ExpLayerTraitConst for ExpLayer {
 		#[inline] fn as_raw_ExpLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5519,6 +5813,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ExpLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExpLayer, crate::dnn::ExpLayerTraitConst, as_raw_ExpLayer, crate::dnn::ExpLayerTrait, as_raw_mut_ExpLayer }
+	
 	impl ExpLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ExpLayer>> {
@@ -5574,6 +5870,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlattenLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for FlattenLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5582,6 +5880,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlattenLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::FlattenLayerTraitConst for FlattenLayer {
 		#[inline] fn as_raw_FlattenLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5590,6 +5890,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_FlattenLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlattenLayer, crate::dnn::FlattenLayerTraitConst, as_raw_FlattenLayer, crate::dnn::FlattenLayerTrait, as_raw_mut_FlattenLayer }
+	
 	impl FlattenLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FlattenLayer>> {
@@ -5643,6 +5945,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FloorLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for FloorLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5651,6 +5955,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FloorLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for FloorLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5659,6 +5965,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FloorLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::FloorLayerTraitConst for FloorLayer {
 		#[inline] fn as_raw_FloorLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5667,6 +5975,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_FloorLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FloorLayer, crate::dnn::FloorLayerTraitConst, as_raw_FloorLayer, crate::dnn::FloorLayerTrait, as_raw_mut_FloorLayer }
+	
 	impl FloorLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FloorLayer>> {
@@ -5722,6 +6032,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlowWarpLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for FlowWarpLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5730,6 +6042,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlowWarpLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::FlowWarpLayerTraitConst for FlowWarpLayer {
 		#[inline] fn as_raw_FlowWarpLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5738,6 +6052,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_FlowWarpLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FlowWarpLayer, crate::dnn::FlowWarpLayerTraitConst, as_raw_FlowWarpLayer, crate::dnn::FlowWarpLayerTrait, as_raw_mut_FlowWarpLayer }
+	
 	impl FlowWarpLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::FlowWarpLayer>> {
@@ -5817,6 +6133,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GRULayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for GRULayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5825,6 +6143,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GRULayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::GRULayerTraitConst for GRULayer {
 		#[inline] fn as_raw_GRULayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5833,6 +6153,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_GRULayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GRULayer, crate::dnn::GRULayerTraitConst, as_raw_GRULayer, crate::dnn::GRULayerTrait, as_raw_mut_GRULayer }
+	
 	impl GRULayer {
 		/// Creates instance of GRU layer
 		#[inline]
@@ -5888,6 +6210,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GatherLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for GatherLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5896,6 +6220,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GatherLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::GatherLayerTraitConst for GatherLayer {
 		#[inline] fn as_raw_GatherLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5904,6 +6230,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_GatherLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GatherLayer, crate::dnn::GatherLayerTraitConst, as_raw_GatherLayer, crate::dnn::GatherLayerTrait, as_raw_mut_GatherLayer }
+	
 	impl GatherLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::GatherLayer>> {
@@ -5981,6 +6309,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSigmoidLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for HardSigmoidLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5989,6 +6319,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSigmoidLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for HardSigmoidLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5997,6 +6329,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSigmoidLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::HardSigmoidLayerTraitConst for HardSigmoidLayer {
 		#[inline] fn as_raw_HardSigmoidLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6005,6 +6339,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_HardSigmoidLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSigmoidLayer, crate::dnn::HardSigmoidLayerTraitConst, as_raw_HardSigmoidLayer, crate::dnn::HardSigmoidLayerTrait, as_raw_mut_HardSigmoidLayer }
+	
 	impl HardSigmoidLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::HardSigmoidLayer>> {
@@ -6060,6 +6396,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSwishLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for HardSwishLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6068,6 +6406,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSwishLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for HardSwishLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6076,6 +6416,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSwishLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::HardSwishLayerTraitConst for HardSwishLayer {
 		#[inline] fn as_raw_HardSwishLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6084,6 +6426,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_HardSwishLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HardSwishLayer, crate::dnn::HardSwishLayerTraitConst, as_raw_HardSwishLayer, crate::dnn::HardSwishLayerTrait, as_raw_mut_HardSwishLayer }
+	
 	impl HardSwishLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::HardSwishLayer>> {
@@ -6151,6 +6495,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for InnerProductLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6159,6 +6505,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::InnerProductLayerTraitConst for InnerProductLayer {
 		#[inline] fn as_raw_InnerProductLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6167,6 +6515,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_InnerProductLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayer, crate::dnn::InnerProductLayerTraitConst, as_raw_InnerProductLayer, crate::dnn::InnerProductLayerTrait, as_raw_mut_InnerProductLayer }
+	
 	impl InnerProductLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::InnerProductLayer>> {
@@ -6280,6 +6630,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::InnerProductLayerTraitConst for InnerProductLayerInt8 {
 		#[inline] fn as_raw_InnerProductLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6288,6 +6640,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_InnerProductLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayerInt8, crate::dnn::InnerProductLayerTraitConst, as_raw_InnerProductLayer, crate::dnn::InnerProductLayerTrait, as_raw_mut_InnerProductLayer }
+	
 	impl crate::dnn::LayerTraitConst for InnerProductLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6296,6 +6650,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::InnerProductLayerInt8TraitConst for InnerProductLayerInt8 {
 		#[inline] fn as_raw_InnerProductLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6304,6 +6660,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_InnerProductLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InnerProductLayerInt8, crate::dnn::InnerProductLayerInt8TraitConst, as_raw_InnerProductLayerInt8, crate::dnn::InnerProductLayerInt8Trait, as_raw_mut_InnerProductLayerInt8 }
+	
 	impl InnerProductLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::InnerProductLayerInt8>> {
@@ -6362,6 +6720,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InterpLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for InterpLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6370,6 +6730,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InterpLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::InterpLayerTraitConst for InterpLayer {
 		#[inline] fn as_raw_InterpLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6378,6 +6740,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_InterpLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { InterpLayer, crate::dnn::InterpLayerTraitConst, as_raw_InterpLayer, crate::dnn::InterpLayerTrait, as_raw_mut_InterpLayer }
+	
 	impl InterpLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -6456,6 +6820,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KeypointsModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::KeypointsModelTraitConst for KeypointsModel {
 		#[inline] fn as_raw_KeypointsModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6464,6 +6830,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_KeypointsModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KeypointsModel, crate::dnn::KeypointsModelTraitConst, as_raw_KeypointsModel, crate::dnn::KeypointsModelTrait, as_raw_mut_KeypointsModel }
+	
 	impl KeypointsModel {
 		/// Create keypoints model from network represented in one of the supported formats.
 		/// An order of @p model and @p config arguments does not matter.
@@ -6618,6 +6986,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LRNLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for LRNLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6626,6 +6996,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LRNLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::LRNLayerTraitConst for LRNLayer {
 		#[inline] fn as_raw_LRNLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6634,6 +7006,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_LRNLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LRNLayer, crate::dnn::LRNLayerTraitConst, as_raw_LRNLayer, crate::dnn::LRNLayerTrait, as_raw_mut_LRNLayer }
+	
 	impl LRNLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::LRNLayer>> {
@@ -6805,6 +7179,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LSTMLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for LSTMLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6813,6 +7189,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LSTMLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::LSTMLayerTraitConst for LSTMLayer {
 		#[inline] fn as_raw_LSTMLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6821,6 +7199,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_LSTMLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LSTMLayer, crate::dnn::LSTMLayerTraitConst, as_raw_LSTMLayer, crate::dnn::LSTMLayerTrait, as_raw_mut_LSTMLayer }
+	
 	impl LSTMLayer {
 		/// Creates instance of LSTM layer
 		#[inline]
@@ -7376,6 +7756,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Layer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for Layer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7384,6 +7766,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Layer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl Layer {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::Layer> {
@@ -7546,6 +7930,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_LayerFactory(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LayerFactory, crate::dnn::LayerFactoryTraitConst, as_raw_LayerFactory, crate::dnn::LayerFactoryTrait, as_raw_mut_LayerFactory }
+	
 	impl LayerFactory {
 		/// Registers the layer class with typename @p type and specified @p constructor. Thread-safe.
 		#[inline]
@@ -7686,6 +8072,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Dict(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LayerParams, crate::dnn::DictTraitConst, as_raw_Dict, crate::dnn::DictTrait, as_raw_mut_Dict }
+	
 	impl crate::dnn::LayerParamsTraitConst for LayerParams {
 		#[inline] fn as_raw_LayerParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7694,6 +8082,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_LayerParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LayerParams, crate::dnn::LayerParamsTraitConst, as_raw_LayerParams, crate::dnn::LayerParamsTrait, as_raw_mut_LayerParams }
+	
 	impl LayerParams {
 	}
 	
@@ -7735,6 +8125,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for LogLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7743,6 +8135,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for LogLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7751,6 +8145,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::LogLayerTraitConst for LogLayer {
 		#[inline] fn as_raw_LogLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7759,6 +8155,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_LogLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogLayer, crate::dnn::LogLayerTraitConst, as_raw_LogLayer, crate::dnn::LogLayerTrait, as_raw_mut_LogLayer }
+	
 	impl LogLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::LogLayer>> {
@@ -7850,6 +8248,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MVNLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for MVNLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7858,6 +8258,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MVNLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::MVNLayerTraitConst for MVNLayer {
 		#[inline] fn as_raw_MVNLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7866,6 +8268,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_MVNLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MVNLayer, crate::dnn::MVNLayerTraitConst, as_raw_MVNLayer, crate::dnn::MVNLayerTrait, as_raw_mut_MVNLayer }
+	
 	impl MVNLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MVNLayer>> {
@@ -7961,6 +8365,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MaxUnpoolLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for MaxUnpoolLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7969,6 +8375,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MaxUnpoolLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::MaxUnpoolLayerTraitConst for MaxUnpoolLayer {
 		#[inline] fn as_raw_MaxUnpoolLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7977,6 +8385,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_MaxUnpoolLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MaxUnpoolLayer, crate::dnn::MaxUnpoolLayerTraitConst, as_raw_MaxUnpoolLayer, crate::dnn::MaxUnpoolLayerTrait, as_raw_mut_MaxUnpoolLayer }
+	
 	impl MaxUnpoolLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MaxUnpoolLayer>> {
@@ -8030,6 +8440,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MishLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for MishLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8038,6 +8450,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MishLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for MishLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8046,6 +8460,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MishLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::MishLayerTraitConst for MishLayer {
 		#[inline] fn as_raw_MishLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8054,6 +8470,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_MishLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MishLayer, crate::dnn::MishLayerTraitConst, as_raw_MishLayer, crate::dnn::MishLayerTrait, as_raw_mut_MishLayer }
+	
 	impl MishLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::MishLayer>> {
@@ -8284,6 +8702,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Model, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl Model {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::Model> {
@@ -8388,6 +8808,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NaryEltwiseLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for NaryEltwiseLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8396,6 +8818,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NaryEltwiseLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::NaryEltwiseLayerTraitConst for NaryEltwiseLayer {
 		#[inline] fn as_raw_NaryEltwiseLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8404,6 +8828,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_NaryEltwiseLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NaryEltwiseLayer, crate::dnn::NaryEltwiseLayerTraitConst, as_raw_NaryEltwiseLayer, crate::dnn::NaryEltwiseLayerTrait, as_raw_mut_NaryEltwiseLayer }
+	
 	impl NaryEltwiseLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::NaryEltwiseLayer>> {
@@ -9361,6 +9787,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Net(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Net, crate::dnn::NetTraitConst, as_raw_Net, crate::dnn::NetTrait, as_raw_mut_Net }
+	
 	impl Net {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::Net> {
@@ -9530,6 +9958,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalizeBBoxLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for NormalizeBBoxLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9538,6 +9968,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalizeBBoxLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::NormalizeBBoxLayerTraitConst for NormalizeBBoxLayer {
 		#[inline] fn as_raw_NormalizeBBoxLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9546,6 +9978,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_NormalizeBBoxLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalizeBBoxLayer, crate::dnn::NormalizeBBoxLayerTraitConst, as_raw_NormalizeBBoxLayer, crate::dnn::NormalizeBBoxLayerTrait, as_raw_mut_NormalizeBBoxLayer }
+	
 	impl NormalizeBBoxLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::NormalizeBBoxLayer>> {
@@ -9599,6 +10033,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NotLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for NotLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9607,6 +10043,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NotLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for NotLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9615,6 +10053,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NotLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::NotLayerTraitConst for NotLayer {
 		#[inline] fn as_raw_NotLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9623,6 +10063,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_NotLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NotLayer, crate::dnn::NotLayerTraitConst, as_raw_NotLayer, crate::dnn::NotLayerTrait, as_raw_mut_NotLayer }
+	
 	impl NotLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::NotLayer>> {
@@ -9698,6 +10140,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PaddingLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PaddingLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9706,6 +10150,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PaddingLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PaddingLayerTraitConst for PaddingLayer {
 		#[inline] fn as_raw_PaddingLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9714,6 +10160,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PaddingLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PaddingLayer, crate::dnn::PaddingLayerTraitConst, as_raw_PaddingLayer, crate::dnn::PaddingLayerTrait, as_raw_mut_PaddingLayer }
+	
 	impl PaddingLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PaddingLayer>> {
@@ -9767,6 +10215,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PermuteLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PermuteLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9775,6 +10225,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PermuteLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PermuteLayerTraitConst for PermuteLayer {
 		#[inline] fn as_raw_PermuteLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -9783,6 +10235,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PermuteLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PermuteLayer, crate::dnn::PermuteLayerTraitConst, as_raw_PermuteLayer, crate::dnn::PermuteLayerTrait, as_raw_mut_PermuteLayer }
+	
 	impl PermuteLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PermuteLayer>> {
@@ -10015,6 +10469,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PoolingLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10023,6 +10479,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PoolingLayerTraitConst for PoolingLayer {
 		#[inline] fn as_raw_PoolingLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10031,6 +10489,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PoolingLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayer, crate::dnn::PoolingLayerTraitConst, as_raw_PoolingLayer, crate::dnn::PoolingLayerTrait, as_raw_mut_PoolingLayer }
+	
 	impl PoolingLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PoolingLayer>> {
@@ -10132,6 +10592,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PoolingLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10140,6 +10602,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PoolingLayerTraitConst for PoolingLayerInt8 {
 		#[inline] fn as_raw_PoolingLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10148,6 +10612,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PoolingLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayerInt8, crate::dnn::PoolingLayerTraitConst, as_raw_PoolingLayer, crate::dnn::PoolingLayerTrait, as_raw_mut_PoolingLayer }
+	
 	impl crate::dnn::PoolingLayerInt8TraitConst for PoolingLayerInt8 {
 		#[inline] fn as_raw_PoolingLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10156,6 +10622,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PoolingLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoolingLayerInt8, crate::dnn::PoolingLayerInt8TraitConst, as_raw_PoolingLayerInt8, crate::dnn::PoolingLayerInt8Trait, as_raw_mut_PoolingLayerInt8 }
+	
 	impl PoolingLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PoolingLayerInt8>> {
@@ -10247,6 +10715,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PowerLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for PowerLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10255,6 +10725,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PowerLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PowerLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10263,6 +10735,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PowerLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PowerLayerTraitConst for PowerLayer {
 		#[inline] fn as_raw_PowerLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10271,6 +10745,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PowerLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PowerLayer, crate::dnn::PowerLayerTraitConst, as_raw_PowerLayer, crate::dnn::PowerLayerTrait, as_raw_mut_PowerLayer }
+	
 	impl PowerLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PowerLayer>> {
@@ -10326,6 +10802,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PriorBoxLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for PriorBoxLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10334,6 +10812,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PriorBoxLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::PriorBoxLayerTraitConst for PriorBoxLayer {
 		#[inline] fn as_raw_PriorBoxLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10342,6 +10822,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_PriorBoxLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PriorBoxLayer, crate::dnn::PriorBoxLayerTraitConst, as_raw_PriorBoxLayer, crate::dnn::PriorBoxLayerTrait, as_raw_mut_PriorBoxLayer }
+	
 	impl PriorBoxLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::PriorBoxLayer>> {
@@ -10395,6 +10877,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ProposalLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ProposalLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10403,6 +10887,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ProposalLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ProposalLayerTraitConst for ProposalLayer {
 		#[inline] fn as_raw_ProposalLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10411,6 +10897,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ProposalLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ProposalLayer, crate::dnn::ProposalLayerTraitConst, as_raw_ProposalLayer, crate::dnn::ProposalLayerTrait, as_raw_mut_ProposalLayer }
+	
 	impl ProposalLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ProposalLayer>> {
@@ -10490,6 +10978,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QuantizeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for QuantizeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10498,6 +10988,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QuantizeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::QuantizeLayerTraitConst for QuantizeLayer {
 		#[inline] fn as_raw_QuantizeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10506,6 +10998,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_QuantizeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QuantizeLayer, crate::dnn::QuantizeLayerTraitConst, as_raw_QuantizeLayer, crate::dnn::QuantizeLayerTrait, as_raw_mut_QuantizeLayer }
+	
 	impl QuantizeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::QuantizeLayer>> {
@@ -10608,6 +11102,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RNNLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for RNNLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10616,6 +11112,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RNNLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::RNNLayerTraitConst for RNNLayer {
 		#[inline] fn as_raw_RNNLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10624,6 +11122,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_RNNLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RNNLayer, crate::dnn::RNNLayerTraitConst, as_raw_RNNLayer, crate::dnn::RNNLayerTrait, as_raw_mut_RNNLayer }
+	
 	impl RNNLayer {
 		/// Creates instance of RNNLayer
 		#[inline]
@@ -10702,6 +11202,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLU6Layer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ReLU6Layer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10710,6 +11212,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLU6Layer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReLU6Layer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10718,6 +11222,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLU6Layer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReLU6LayerTraitConst for ReLU6Layer {
 		#[inline] fn as_raw_ReLU6Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10726,6 +11232,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReLU6Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLU6Layer, crate::dnn::ReLU6LayerTraitConst, as_raw_ReLU6Layer, crate::dnn::ReLU6LayerTrait, as_raw_mut_ReLU6Layer }
+	
 	impl ReLU6Layer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReLU6Layer>> {
@@ -10793,6 +11301,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLULayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ReLULayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10801,6 +11311,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLULayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReLULayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10809,6 +11321,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLULayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReLULayerTraitConst for ReLULayer {
 		#[inline] fn as_raw_ReLULayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10817,6 +11331,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReLULayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReLULayer, crate::dnn::ReLULayerTraitConst, as_raw_ReLULayer, crate::dnn::ReLULayerTrait, as_raw_mut_ReLULayer }
+	
 	impl ReLULayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReLULayer>> {
@@ -10872,6 +11388,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReciprocalLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ReciprocalLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10880,6 +11398,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReciprocalLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReciprocalLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10888,6 +11408,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReciprocalLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReciprocalLayerTraitConst for ReciprocalLayer {
 		#[inline] fn as_raw_ReciprocalLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10896,6 +11418,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReciprocalLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReciprocalLayer, crate::dnn::ReciprocalLayerTraitConst, as_raw_ReciprocalLayer, crate::dnn::ReciprocalLayerTrait, as_raw_mut_ReciprocalLayer }
+	
 	impl ReciprocalLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReciprocalLayer>> {
@@ -10989,6 +11513,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReduceLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -10997,6 +11523,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReduceLayerTraitConst for ReduceLayer {
 		#[inline] fn as_raw_ReduceLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11005,6 +11533,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReduceLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayer, crate::dnn::ReduceLayerTraitConst, as_raw_ReduceLayer, crate::dnn::ReduceLayerTrait, as_raw_mut_ReduceLayer }
+	
 	impl ReduceLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReduceLayer>> {
@@ -11058,6 +11588,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReduceLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11066,6 +11598,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReduceLayerTraitConst for ReduceLayerInt8 {
 		#[inline] fn as_raw_ReduceLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11074,6 +11608,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReduceLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayerInt8, crate::dnn::ReduceLayerTraitConst, as_raw_ReduceLayer, crate::dnn::ReduceLayerTrait, as_raw_mut_ReduceLayer }
+	
 	impl crate::dnn::ReduceLayerInt8TraitConst for ReduceLayerInt8 {
 		#[inline] fn as_raw_ReduceLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11082,6 +11618,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReduceLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReduceLayerInt8, crate::dnn::ReduceLayerInt8TraitConst, as_raw_ReduceLayerInt8, crate::dnn::ReduceLayerInt8Trait, as_raw_mut_ReduceLayerInt8 }
+	
 	impl ReduceLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReduceLayerInt8>> {
@@ -11149,6 +11687,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RegionLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for RegionLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11157,6 +11697,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RegionLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::RegionLayerTraitConst for RegionLayer {
 		#[inline] fn as_raw_RegionLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11165,6 +11707,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_RegionLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RegionLayer, crate::dnn::RegionLayerTraitConst, as_raw_RegionLayer, crate::dnn::RegionLayerTrait, as_raw_mut_RegionLayer }
+	
 	impl RegionLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RegionLayer>> {
@@ -11218,6 +11762,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReorgLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReorgLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11226,6 +11772,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReorgLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReorgLayerTraitConst for ReorgLayer {
 		#[inline] fn as_raw_ReorgLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11234,6 +11782,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReorgLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReorgLayer, crate::dnn::ReorgLayerTraitConst, as_raw_ReorgLayer, crate::dnn::ReorgLayerTrait, as_raw_mut_ReorgLayer }
+	
 	impl ReorgLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReorgLayer>> {
@@ -11311,6 +11861,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RequantizeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for RequantizeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11319,6 +11871,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RequantizeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::RequantizeLayerTraitConst for RequantizeLayer {
 		#[inline] fn as_raw_RequantizeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11327,6 +11881,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_RequantizeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RequantizeLayer, crate::dnn::RequantizeLayerTraitConst, as_raw_RequantizeLayer, crate::dnn::RequantizeLayerTrait, as_raw_mut_RequantizeLayer }
+	
 	impl RequantizeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RequantizeLayer>> {
@@ -11406,6 +11962,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReshapeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ReshapeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11414,6 +11972,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReshapeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ReshapeLayerTraitConst for ReshapeLayer {
 		#[inline] fn as_raw_ReshapeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11422,6 +11982,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ReshapeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ReshapeLayer, crate::dnn::ReshapeLayerTraitConst, as_raw_ReshapeLayer, crate::dnn::ReshapeLayerTrait, as_raw_mut_ReshapeLayer }
+	
 	impl ReshapeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ReshapeLayer>> {
@@ -11478,6 +12040,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ResizeLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ResizeLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11486,6 +12050,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ResizeLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ResizeLayerTraitConst for ResizeLayer {
 		#[inline] fn as_raw_ResizeLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11494,6 +12060,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ResizeLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ResizeLayer, crate::dnn::ResizeLayerTraitConst, as_raw_ResizeLayer, crate::dnn::ResizeLayerTrait, as_raw_mut_ResizeLayer }
+	
 	impl ResizeLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ResizeLayer>> {
@@ -11547,6 +12115,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RoundLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for RoundLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11555,6 +12125,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RoundLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for RoundLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11563,6 +12135,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RoundLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::RoundLayerTraitConst for RoundLayer {
 		#[inline] fn as_raw_RoundLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11571,6 +12145,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_RoundLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RoundLayer, crate::dnn::RoundLayerTraitConst, as_raw_RoundLayer, crate::dnn::RoundLayerTrait, as_raw_mut_RoundLayer }
+	
 	impl RoundLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::RoundLayer>> {
@@ -11664,6 +12240,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ScaleLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11672,6 +12250,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ScaleLayerTraitConst for ScaleLayer {
 		#[inline] fn as_raw_ScaleLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11680,6 +12260,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ScaleLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayer, crate::dnn::ScaleLayerTraitConst, as_raw_ScaleLayer, crate::dnn::ScaleLayerTrait, as_raw_mut_ScaleLayer }
+	
 	impl ScaleLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScaleLayer>> {
@@ -11757,6 +12339,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ScaleLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11765,6 +12349,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ScaleLayerTraitConst for ScaleLayerInt8 {
 		#[inline] fn as_raw_ScaleLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11773,6 +12359,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ScaleLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayerInt8, crate::dnn::ScaleLayerTraitConst, as_raw_ScaleLayer, crate::dnn::ScaleLayerTrait, as_raw_mut_ScaleLayer }
+	
 	impl crate::dnn::ScaleLayerInt8TraitConst for ScaleLayerInt8 {
 		#[inline] fn as_raw_ScaleLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11781,6 +12369,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ScaleLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScaleLayerInt8, crate::dnn::ScaleLayerInt8TraitConst, as_raw_ScaleLayerInt8, crate::dnn::ScaleLayerInt8Trait, as_raw_mut_ScaleLayerInt8 }
+	
 	impl ScaleLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScaleLayerInt8>> {
@@ -11836,6 +12426,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ScatterLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11844,6 +12436,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ScatterLayerTraitConst for ScatterLayer {
 		#[inline] fn as_raw_ScatterLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11852,6 +12446,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ScatterLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterLayer, crate::dnn::ScatterLayerTraitConst, as_raw_ScatterLayer, crate::dnn::ScatterLayerTrait, as_raw_mut_ScatterLayer }
+	
 	impl ScatterLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScatterLayer>> {
@@ -11905,6 +12501,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterNDLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ScatterNDLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11913,6 +12511,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterNDLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ScatterNDLayerTraitConst for ScatterNDLayer {
 		#[inline] fn as_raw_ScatterNDLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -11921,6 +12521,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ScatterNDLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ScatterNDLayer, crate::dnn::ScatterNDLayerTraitConst, as_raw_ScatterNDLayer, crate::dnn::ScatterNDLayerTrait, as_raw_mut_ScatterNDLayer }
+	
 	impl ScatterNDLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ScatterNDLayer>> {
@@ -11994,6 +12596,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SegmentationModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::SegmentationModelTraitConst for SegmentationModel {
 		#[inline] fn as_raw_SegmentationModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12002,6 +12606,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SegmentationModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SegmentationModel, crate::dnn::SegmentationModelTraitConst, as_raw_SegmentationModel, crate::dnn::SegmentationModelTrait, as_raw_mut_SegmentationModel }
+	
 	impl SegmentationModel {
 		/// Create segmentation model from network represented in one of the supported formats.
 		/// An order of @p model and @p config arguments does not matter.
@@ -12108,6 +12714,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SeluLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SeluLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12116,6 +12724,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SeluLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SeluLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12124,6 +12734,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SeluLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SeluLayerTraitConst for SeluLayer {
 		#[inline] fn as_raw_SeluLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12132,6 +12744,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SeluLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SeluLayer, crate::dnn::SeluLayerTraitConst, as_raw_SeluLayer, crate::dnn::SeluLayerTrait, as_raw_mut_SeluLayer }
+	
 	impl SeluLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SeluLayer>> {
@@ -12187,6 +12801,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ShiftLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12195,6 +12811,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ShiftLayerTraitConst for ShiftLayer {
 		#[inline] fn as_raw_ShiftLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12203,6 +12821,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ShiftLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayer, crate::dnn::ShiftLayerTraitConst, as_raw_ShiftLayer, crate::dnn::ShiftLayerTrait, as_raw_mut_ShiftLayer }
+	
 	impl ShiftLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -12256,6 +12876,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ShiftLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12264,6 +12886,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ShiftLayerInt8TraitConst for ShiftLayerInt8 {
 		#[inline] fn as_raw_ShiftLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12272,6 +12896,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ShiftLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShiftLayerInt8, crate::dnn::ShiftLayerInt8TraitConst, as_raw_ShiftLayerInt8, crate::dnn::ShiftLayerInt8Trait, as_raw_mut_ShiftLayerInt8 }
+	
 	impl ShiftLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -12349,6 +12975,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShrinkLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ShrinkLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12357,6 +12985,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShrinkLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ShrinkLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12365,6 +12995,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShrinkLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ShrinkLayerTraitConst for ShrinkLayer {
 		#[inline] fn as_raw_ShrinkLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12373,6 +13005,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ShrinkLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShrinkLayer, crate::dnn::ShrinkLayerTraitConst, as_raw_ShrinkLayer, crate::dnn::ShrinkLayerTrait, as_raw_mut_ShrinkLayer }
+	
 	impl ShrinkLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ShrinkLayer>> {
@@ -12448,6 +13082,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShuffleChannelLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ShuffleChannelLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12456,6 +13092,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShuffleChannelLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ShuffleChannelLayerTraitConst for ShuffleChannelLayer {
 		#[inline] fn as_raw_ShuffleChannelLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12464,6 +13102,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ShuffleChannelLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ShuffleChannelLayer, crate::dnn::ShuffleChannelLayerTraitConst, as_raw_ShuffleChannelLayer, crate::dnn::ShuffleChannelLayerTrait, as_raw_mut_ShuffleChannelLayer }
+	
 	impl ShuffleChannelLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::Layer>> {
@@ -12517,6 +13157,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SigmoidLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SigmoidLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12525,6 +13167,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SigmoidLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SigmoidLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12533,6 +13177,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SigmoidLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SigmoidLayerTraitConst for SigmoidLayer {
 		#[inline] fn as_raw_SigmoidLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12541,6 +13187,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SigmoidLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SigmoidLayer, crate::dnn::SigmoidLayerTraitConst, as_raw_SigmoidLayer, crate::dnn::SigmoidLayerTrait, as_raw_mut_SigmoidLayer }
+	
 	impl SigmoidLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SigmoidLayer>> {
@@ -12596,6 +13244,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SignLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SignLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12604,6 +13254,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SignLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SignLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12612,6 +13264,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SignLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SignLayerTraitConst for SignLayer {
 		#[inline] fn as_raw_SignLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12620,6 +13274,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SignLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SignLayer, crate::dnn::SignLayerTraitConst, as_raw_SignLayer, crate::dnn::SignLayerTrait, as_raw_mut_SignLayer }
+	
 	impl SignLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SignLayer>> {
@@ -12675,6 +13331,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SinLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12683,6 +13341,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SinLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12691,6 +13351,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SinLayerTraitConst for SinLayer {
 		#[inline] fn as_raw_SinLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12699,6 +13361,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SinLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinLayer, crate::dnn::SinLayerTraitConst, as_raw_SinLayer, crate::dnn::SinLayerTrait, as_raw_mut_SinLayer }
+	
 	impl SinLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SinLayer>> {
@@ -12754,6 +13418,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinhLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SinhLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12762,6 +13428,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinhLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SinhLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12770,6 +13438,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinhLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SinhLayerTraitConst for SinhLayer {
 		#[inline] fn as_raw_SinhLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12778,6 +13448,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SinhLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinhLayer, crate::dnn::SinhLayerTraitConst, as_raw_SinhLayer, crate::dnn::SinhLayerTrait, as_raw_mut_SinhLayer }
+	
 	impl SinhLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SinhLayer>> {
@@ -12914,6 +13586,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SliceLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SliceLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12922,6 +13596,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SliceLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SliceLayerTraitConst for SliceLayer {
 		#[inline] fn as_raw_SliceLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -12930,6 +13606,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SliceLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SliceLayer, crate::dnn::SliceLayerTraitConst, as_raw_SliceLayer, crate::dnn::SliceLayerTrait, as_raw_mut_SliceLayer }
+	
 	impl SliceLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SliceLayer>> {
@@ -12995,6 +13673,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SoftmaxLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13003,6 +13683,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SoftmaxLayerTraitConst for SoftmaxLayer {
 		#[inline] fn as_raw_SoftmaxLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13011,6 +13693,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SoftmaxLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayer, crate::dnn::SoftmaxLayerTraitConst, as_raw_SoftmaxLayer, crate::dnn::SoftmaxLayerTrait, as_raw_mut_SoftmaxLayer }
+	
 	impl SoftmaxLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftmaxLayer>> {
@@ -13088,6 +13772,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayerInt8, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SoftmaxLayerInt8 {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13096,6 +13782,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayerInt8, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SoftmaxLayerTraitConst for SoftmaxLayerInt8 {
 		#[inline] fn as_raw_SoftmaxLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13104,6 +13792,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SoftmaxLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayerInt8, crate::dnn::SoftmaxLayerTraitConst, as_raw_SoftmaxLayer, crate::dnn::SoftmaxLayerTrait, as_raw_mut_SoftmaxLayer }
+	
 	impl crate::dnn::SoftmaxLayerInt8TraitConst for SoftmaxLayerInt8 {
 		#[inline] fn as_raw_SoftmaxLayerInt8(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13112,6 +13802,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SoftmaxLayerInt8(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftmaxLayerInt8, crate::dnn::SoftmaxLayerInt8TraitConst, as_raw_SoftmaxLayerInt8, crate::dnn::SoftmaxLayerInt8Trait, as_raw_mut_SoftmaxLayerInt8 }
+	
 	impl SoftmaxLayerInt8 {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftmaxLayerInt8>> {
@@ -13167,6 +13859,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftplusLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SoftplusLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13175,6 +13869,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftplusLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SoftplusLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13183,6 +13879,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftplusLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SoftplusLayerTraitConst for SoftplusLayer {
 		#[inline] fn as_raw_SoftplusLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13191,6 +13889,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SoftplusLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftplusLayer, crate::dnn::SoftplusLayerTraitConst, as_raw_SoftplusLayer, crate::dnn::SoftplusLayerTrait, as_raw_mut_SoftplusLayer }
+	
 	impl SoftplusLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftplusLayer>> {
@@ -13246,6 +13946,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftsignLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SoftsignLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13254,6 +13956,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftsignLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SoftsignLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13262,6 +13966,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftsignLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SoftsignLayerTraitConst for SoftsignLayer {
 		#[inline] fn as_raw_SoftsignLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13270,6 +13976,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SoftsignLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SoftsignLayer, crate::dnn::SoftsignLayerTraitConst, as_raw_SoftsignLayer, crate::dnn::SoftsignLayerTrait, as_raw_mut_SoftsignLayer }
+	
 	impl SoftsignLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SoftsignLayer>> {
@@ -13339,6 +14047,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SplitLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SplitLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13347,6 +14057,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SplitLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SplitLayerTraitConst for SplitLayer {
 		#[inline] fn as_raw_SplitLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13355,6 +14067,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SplitLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SplitLayer, crate::dnn::SplitLayerTraitConst, as_raw_SplitLayer, crate::dnn::SplitLayerTrait, as_raw_mut_SplitLayer }
+	
 	impl SplitLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SplitLayer>> {
@@ -13408,6 +14122,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SqrtLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SqrtLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13416,6 +14132,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SqrtLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SqrtLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13424,6 +14142,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SqrtLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SqrtLayerTraitConst for SqrtLayer {
 		#[inline] fn as_raw_SqrtLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13432,6 +14152,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SqrtLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SqrtLayer, crate::dnn::SqrtLayerTraitConst, as_raw_SqrtLayer, crate::dnn::SqrtLayerTrait, as_raw_mut_SqrtLayer }
+	
 	impl SqrtLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SqrtLayer>> {
@@ -13487,6 +14209,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SwishLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for SwishLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13495,6 +14219,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SwishLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for SwishLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13503,6 +14229,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SwishLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::SwishLayerTraitConst for SwishLayer {
 		#[inline] fn as_raw_SwishLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13511,6 +14239,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_SwishLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SwishLayer, crate::dnn::SwishLayerTraitConst, as_raw_SwishLayer, crate::dnn::SwishLayerTrait, as_raw_mut_SwishLayer }
+	
 	impl SwishLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::SwishLayer>> {
@@ -13566,6 +14296,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanHLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for TanHLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13574,6 +14306,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanHLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for TanHLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13582,6 +14316,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanHLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::TanHLayerTraitConst for TanHLayer {
 		#[inline] fn as_raw_TanHLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13590,6 +14326,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TanHLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanHLayer, crate::dnn::TanHLayerTraitConst, as_raw_TanHLayer, crate::dnn::TanHLayerTrait, as_raw_mut_TanHLayer }
+	
 	impl TanHLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::TanHLayer>> {
@@ -13645,6 +14383,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for TanLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13653,6 +14393,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for TanLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13661,6 +14403,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::TanLayerTraitConst for TanLayer {
 		#[inline] fn as_raw_TanLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13669,6 +14413,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TanLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TanLayer, crate::dnn::TanLayerTraitConst, as_raw_TanLayer, crate::dnn::TanLayerTrait, as_raw_mut_TanLayer }
+	
 	impl TanLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::TanLayer>> {
@@ -13833,6 +14579,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::TextDetectionModelTraitConst for TextDetectionModel {
 		#[inline] fn as_raw_TextDetectionModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13841,6 +14589,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextDetectionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel, crate::dnn::TextDetectionModelTraitConst, as_raw_TextDetectionModel, crate::dnn::TextDetectionModelTrait, as_raw_mut_TextDetectionModel }
+	
 	impl TextDetectionModel {
 	}
 	
@@ -13969,6 +14719,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_DB, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::TextDetectionModelTraitConst for TextDetectionModel_DB {
 		#[inline] fn as_raw_TextDetectionModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13977,6 +14729,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextDetectionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_DB, crate::dnn::TextDetectionModelTraitConst, as_raw_TextDetectionModel, crate::dnn::TextDetectionModelTrait, as_raw_mut_TextDetectionModel }
+	
 	impl crate::dnn::TextDetectionModel_DBTraitConst for TextDetectionModel_DB {
 		#[inline] fn as_raw_TextDetectionModel_DB(&self) -> *const c_void { self.as_raw() }
 	}
@@ -13985,6 +14739,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextDetectionModel_DB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_DB, crate::dnn::TextDetectionModel_DBTraitConst, as_raw_TextDetectionModel_DB, crate::dnn::TextDetectionModel_DBTrait, as_raw_mut_TextDetectionModel_DB }
+	
 	impl TextDetectionModel_DB {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::TextDetectionModel_DB> {
@@ -14130,6 +14886,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_EAST, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::TextDetectionModelTraitConst for TextDetectionModel_EAST {
 		#[inline] fn as_raw_TextDetectionModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14138,6 +14896,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextDetectionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_EAST, crate::dnn::TextDetectionModelTraitConst, as_raw_TextDetectionModel, crate::dnn::TextDetectionModelTrait, as_raw_mut_TextDetectionModel }
+	
 	impl crate::dnn::TextDetectionModel_EASTTraitConst for TextDetectionModel_EAST {
 		#[inline] fn as_raw_TextDetectionModel_EAST(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14146,6 +14906,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextDetectionModel_EAST(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectionModel_EAST, crate::dnn::TextDetectionModel_EASTTraitConst, as_raw_TextDetectionModel_EAST, crate::dnn::TextDetectionModel_EASTTrait, as_raw_mut_TextDetectionModel_EAST }
+	
 	impl TextDetectionModel_EAST {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::TextDetectionModel_EAST> {
@@ -14351,6 +15113,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextRecognitionModel, crate::dnn::ModelTraitConst, as_raw_Model, crate::dnn::ModelTrait, as_raw_mut_Model }
+	
 	impl crate::dnn::TextRecognitionModelTraitConst for TextRecognitionModel {
 		#[inline] fn as_raw_TextRecognitionModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14359,6 +15123,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TextRecognitionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextRecognitionModel, crate::dnn::TextRecognitionModelTraitConst, as_raw_TextRecognitionModel, crate::dnn::TextRecognitionModelTrait, as_raw_mut_TextRecognitionModel }
+	
 	impl TextRecognitionModel {
 		#[inline]
 		pub fn default() -> Result<crate::dnn::TextRecognitionModel> {
@@ -14464,6 +15230,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ActivationLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ThresholdedReluLayer, crate::dnn::ActivationLayerTraitConst, as_raw_ActivationLayer, crate::dnn::ActivationLayerTrait, as_raw_mut_ActivationLayer }
+	
 	impl core::AlgorithmTraitConst for ThresholdedReluLayer {
 		#[inline] fn as_raw_Algorithm(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14472,6 +15240,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ThresholdedReluLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for ThresholdedReluLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14480,6 +15250,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ThresholdedReluLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::ThresholdedReluLayerTraitConst for ThresholdedReluLayer {
 		#[inline] fn as_raw_ThresholdedReluLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14488,6 +15260,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_ThresholdedReluLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ThresholdedReluLayer, crate::dnn::ThresholdedReluLayerTraitConst, as_raw_ThresholdedReluLayer, crate::dnn::ThresholdedReluLayerTrait, as_raw_mut_ThresholdedReluLayer }
+	
 	impl ThresholdedReluLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::ThresholdedReluLayer>> {
@@ -14543,6 +15317,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TileLayer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::dnn::LayerTraitConst for TileLayer {
 		#[inline] fn as_raw_Layer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14551,6 +15327,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Layer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TileLayer, crate::dnn::LayerTraitConst, as_raw_Layer, crate::dnn::LayerTrait, as_raw_mut_Layer }
+	
 	impl crate::dnn::TileLayerTraitConst for TileLayer {
 		#[inline] fn as_raw_TileLayer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14559,6 +15337,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_TileLayer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TileLayer, crate::dnn::TileLayerTraitConst, as_raw_TileLayer, crate::dnn::TileLayerTrait, as_raw_mut_TileLayer }
+	
 	impl TileLayer {
 		#[inline]
 		pub fn create(params: &crate::dnn::LayerParams) -> Result<core::Ptr<crate::dnn::TileLayer>> {
@@ -14612,6 +15392,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut_Range(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _Range, core::RangeTraitConst, as_raw_Range, core::RangeTrait, as_raw_mut_Range }
+	
 	impl crate::dnn::_RangeTraitConst for _Range {
 		#[inline] fn as_raw__Range(&self) -> *const c_void { self.as_raw() }
 	}
@@ -14620,6 +15402,8 @@ pub mod dnn {
 		#[inline] fn as_raw_mut__Range(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { _Range, crate::dnn::_RangeTraitConst, as_raw__Range, crate::dnn::_RangeTrait, as_raw_mut__Range }
+	
 	impl _Range {
 		#[inline]
 		pub fn new(r: &core::Range) -> Result<crate::dnn::_Range> {
@@ -117,6 +117,8 @@ pub mod dpm {
 		#[inline] fn as_raw_mut_DPMDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DPMDetector, crate::dpm::DPMDetectorTraitConst, as_raw_DPMDetector, crate::dpm::DPMDetectorTrait, as_raw_mut_DPMDetector }
+	
 	impl DPMDetector {
 		/// Load the trained models from given .xml files and return cv::Ptr\<DPMDetector\>.
 		/// ## Parameters
@@ -214,6 +216,8 @@ pub mod dpm {
 		#[inline] fn as_raw_mut_DPMDetector_ObjectDetection(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DPMDetector_ObjectDetection, crate::dpm::DPMDetector_ObjectDetectionTraitConst, as_raw_DPMDetector_ObjectDetection, crate::dpm::DPMDetector_ObjectDetectionTrait, as_raw_mut_DPMDetector_ObjectDetection }
+	
 	impl DPMDetector_ObjectDetection {
 		#[inline]
 		pub fn default() -> Result<crate::dpm::DPMDetector_ObjectDetection> {
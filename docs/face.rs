@@ -385,6 +385,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BIF, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::BIFTraitConst for BIF {
 		#[inline] fn as_raw_BIF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -393,6 +395,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_BIF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BIF, crate::face::BIFTraitConst, as_raw_BIF, crate::face::BIFTrait, as_raw_mut_BIF }
+	
 	impl BIF {
 		/// ## Parameters
 		/// * num_bands: The number of filter bands (<=8) used for computing BIF.
@@ -574,6 +578,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BasicFaceRecognizer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FaceRecognizerTraitConst for BasicFaceRecognizer {
 		#[inline] fn as_raw_FaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -582,6 +588,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BasicFaceRecognizer, crate::face::FaceRecognizerTraitConst, as_raw_FaceRecognizer, crate::face::FaceRecognizerTrait, as_raw_mut_FaceRecognizer }
+	
 	impl crate::face::BasicFaceRecognizerTraitConst for BasicFaceRecognizer {
 		#[inline] fn as_raw_BasicFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -590,6 +598,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_BasicFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BasicFaceRecognizer, crate::face::BasicFaceRecognizerTraitConst, as_raw_BasicFaceRecognizer, crate::face::BasicFaceRecognizerTrait, as_raw_mut_BasicFaceRecognizer }
+	
 	impl BasicFaceRecognizer {
 	}
 	
@@ -720,6 +730,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_CParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CParams, crate::face::CParamsTraitConst, as_raw_CParams, crate::face::CParamsTrait, as_raw_mut_CParams }
+	
 	impl CParams {
 		/// ## C++ default parameters
 		/// * sf: 1.1
@@ -775,6 +787,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EigenFaceRecognizer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::BasicFaceRecognizerTraitConst for EigenFaceRecognizer {
 		#[inline] fn as_raw_BasicFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -783,6 +797,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_BasicFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EigenFaceRecognizer, crate::face::BasicFaceRecognizerTraitConst, as_raw_BasicFaceRecognizer, crate::face::BasicFaceRecognizerTrait, as_raw_mut_BasicFaceRecognizer }
+	
 	impl crate::face::FaceRecognizerTraitConst for EigenFaceRecognizer {
 		#[inline] fn as_raw_FaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -791,6 +807,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EigenFaceRecognizer, crate::face::FaceRecognizerTraitConst, as_raw_FaceRecognizer, crate::face::FaceRecognizerTrait, as_raw_mut_FaceRecognizer }
+	
 	impl crate::face::EigenFaceRecognizerTraitConst for EigenFaceRecognizer {
 		#[inline] fn as_raw_EigenFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -799,6 +817,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_EigenFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EigenFaceRecognizer, crate::face::EigenFaceRecognizerTraitConst, as_raw_EigenFaceRecognizer, crate::face::EigenFaceRecognizerTrait, as_raw_mut_EigenFaceRecognizer }
+	
 	impl EigenFaceRecognizer {
 		/// ## Parameters
 		/// * num_components: The number of components (read: Eigenfaces) kept for this Principal
@@ -1373,6 +1393,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FaceRecognizer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FaceRecognizerTraitConst for FaceRecognizer {
 		#[inline] fn as_raw_FaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1381,6 +1403,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FaceRecognizer, crate::face::FaceRecognizerTraitConst, as_raw_FaceRecognizer, crate::face::FaceRecognizerTrait, as_raw_mut_FaceRecognizer }
+	
 	impl FaceRecognizer {
 	}
 	
@@ -1486,6 +1510,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Facemark, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FacemarkTraitConst for Facemark {
 		#[inline] fn as_raw_Facemark(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1494,6 +1520,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Facemark(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Facemark, crate::face::FacemarkTraitConst, as_raw_Facemark, crate::face::FacemarkTrait, as_raw_mut_Facemark }
+	
 	impl Facemark {
 	}
 	
@@ -1548,6 +1576,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FacemarkTraitConst for FacemarkAAM {
 		#[inline] fn as_raw_Facemark(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1556,6 +1586,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Facemark(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM, crate::face::FacemarkTraitConst, as_raw_Facemark, crate::face::FacemarkTrait, as_raw_mut_Facemark }
+	
 	impl crate::face::FacemarkTrainTraitConst for FacemarkAAM {
 		#[inline] fn as_raw_FacemarkTrain(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1564,6 +1596,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkTrain(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM, crate::face::FacemarkTrainTraitConst, as_raw_FacemarkTrain, crate::face::FacemarkTrainTrait, as_raw_mut_FacemarkTrain }
+	
 	impl crate::face::FacemarkAAMTraitConst for FacemarkAAM {
 		#[inline] fn as_raw_FacemarkAAM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1572,6 +1606,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM, crate::face::FacemarkAAMTraitConst, as_raw_FacemarkAAM, crate::face::FacemarkAAMTrait, as_raw_mut_FacemarkAAM }
+	
 	impl FacemarkAAM {
 		/// initializer
 		/// 
@@ -1679,6 +1715,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM_Config(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM_Config, crate::face::FacemarkAAM_ConfigTraitConst, as_raw_FacemarkAAM_Config, crate::face::FacemarkAAM_ConfigTrait, as_raw_mut_FacemarkAAM_Config }
+	
 	impl FacemarkAAM_Config {
 		/// ## C++ default parameters
 		/// * rot: Mat::eye(2,2,CV_32F)
@@ -1747,6 +1785,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM_Data(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM_Data, crate::face::FacemarkAAM_DataTraitConst, as_raw_FacemarkAAM_Data, crate::face::FacemarkAAM_DataTrait, as_raw_mut_FacemarkAAM_Data }
+	
 	impl FacemarkAAM_Data {
 	}
 	
@@ -1865,6 +1905,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM_Model(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM_Model, crate::face::FacemarkAAM_ModelTraitConst, as_raw_FacemarkAAM_Model, crate::face::FacemarkAAM_ModelTrait, as_raw_mut_FacemarkAAM_Model }
+	
 	impl FacemarkAAM_Model {
 	}
 	
@@ -2036,6 +2078,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM_Model_Texture(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM_Model_Texture, crate::face::FacemarkAAM_Model_TextureTraitConst, as_raw_FacemarkAAM_Model_Texture, crate::face::FacemarkAAM_Model_TextureTrait, as_raw_mut_FacemarkAAM_Model_Texture }
+	
 	impl FacemarkAAM_Model_Texture {
 	}
 	
@@ -2218,6 +2262,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkAAM_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkAAM_Params, crate::face::FacemarkAAM_ParamsTraitConst, as_raw_FacemarkAAM_Params, crate::face::FacemarkAAM_ParamsTrait, as_raw_mut_FacemarkAAM_Params }
+	
 	impl FacemarkAAM_Params {
 		/// \brief Constructor
 		#[inline]
@@ -2316,6 +2362,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkKazemi, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FacemarkTraitConst for FacemarkKazemi {
 		#[inline] fn as_raw_Facemark(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2324,6 +2372,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Facemark(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkKazemi, crate::face::FacemarkTraitConst, as_raw_Facemark, crate::face::FacemarkTrait, as_raw_mut_Facemark }
+	
 	impl crate::face::FacemarkKazemiTraitConst for FacemarkKazemi {
 		#[inline] fn as_raw_FacemarkKazemi(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2332,6 +2382,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkKazemi(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkKazemi, crate::face::FacemarkKazemiTraitConst, as_raw_FacemarkKazemi, crate::face::FacemarkKazemiTrait, as_raw_mut_FacemarkKazemi }
+	
 	impl FacemarkKazemi {
 		/// ## C++ default parameters
 		/// * parameters: FacemarkKazemi::Params()
@@ -2513,6 +2565,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkKazemi_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkKazemi_Params, crate::face::FacemarkKazemi_ParamsTraitConst, as_raw_FacemarkKazemi_Params, crate::face::FacemarkKazemi_ParamsTrait, as_raw_mut_FacemarkKazemi_Params }
+	
 	impl FacemarkKazemi_Params {
 		/// \brief Constructor
 		#[inline]
@@ -2563,6 +2617,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkLBF, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FacemarkTraitConst for FacemarkLBF {
 		#[inline] fn as_raw_Facemark(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2571,6 +2627,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Facemark(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkLBF, crate::face::FacemarkTraitConst, as_raw_Facemark, crate::face::FacemarkTrait, as_raw_mut_Facemark }
+	
 	impl crate::face::FacemarkTrainTraitConst for FacemarkLBF {
 		#[inline] fn as_raw_FacemarkTrain(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2579,6 +2637,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkTrain(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkLBF, crate::face::FacemarkTrainTraitConst, as_raw_FacemarkTrain, crate::face::FacemarkTrainTrait, as_raw_mut_FacemarkTrain }
+	
 	impl crate::face::FacemarkLBFTraitConst for FacemarkLBF {
 		#[inline] fn as_raw_FacemarkLBF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2587,6 +2647,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkLBF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkLBF, crate::face::FacemarkLBFTraitConst, as_raw_FacemarkLBF, crate::face::FacemarkLBFTrait, as_raw_mut_FacemarkLBF }
+	
 	impl FacemarkLBF {
 		/// ## C++ default parameters
 		/// * parameters: FacemarkLBF::Params()
@@ -2850,6 +2912,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkLBF_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkLBF_Params, crate::face::FacemarkLBF_ParamsTraitConst, as_raw_FacemarkLBF_Params, crate::face::FacemarkLBF_ParamsTrait, as_raw_mut_FacemarkLBF_Params }
+	
 	impl FacemarkLBF_Params {
 		/// \brief Constructor
 		#[inline]
@@ -3106,6 +3170,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkTrain, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FacemarkTraitConst for FacemarkTrain {
 		#[inline] fn as_raw_Facemark(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3114,6 +3180,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Facemark(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkTrain, crate::face::FacemarkTraitConst, as_raw_Facemark, crate::face::FacemarkTrait, as_raw_mut_Facemark }
+	
 	impl crate::face::FacemarkTrainTraitConst for FacemarkTrain {
 		#[inline] fn as_raw_FacemarkTrain(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3122,6 +3190,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FacemarkTrain(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FacemarkTrain, crate::face::FacemarkTrainTraitConst, as_raw_FacemarkTrain, crate::face::FacemarkTrainTrait, as_raw_mut_FacemarkTrain }
+	
 	impl FacemarkTrain {
 	}
 	
@@ -3163,6 +3233,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FisherFaceRecognizer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::BasicFaceRecognizerTraitConst for FisherFaceRecognizer {
 		#[inline] fn as_raw_BasicFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3171,6 +3243,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_BasicFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FisherFaceRecognizer, crate::face::BasicFaceRecognizerTraitConst, as_raw_BasicFaceRecognizer, crate::face::BasicFaceRecognizerTrait, as_raw_mut_BasicFaceRecognizer }
+	
 	impl crate::face::FaceRecognizerTraitConst for FisherFaceRecognizer {
 		#[inline] fn as_raw_FaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3179,6 +3253,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FisherFaceRecognizer, crate::face::FaceRecognizerTraitConst, as_raw_FaceRecognizer, crate::face::FaceRecognizerTrait, as_raw_mut_FaceRecognizer }
+	
 	impl crate::face::FisherFaceRecognizerTraitConst for FisherFaceRecognizer {
 		#[inline] fn as_raw_FisherFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3187,6 +3263,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FisherFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FisherFaceRecognizer, crate::face::FisherFaceRecognizerTraitConst, as_raw_FisherFaceRecognizer, crate::face::FisherFaceRecognizerTrait, as_raw_mut_FisherFaceRecognizer }
+	
 	impl FisherFaceRecognizer {
 		/// ## Parameters
 		/// * num_components: The number of components (read: Fisherfaces) kept for this Linear
@@ -3401,6 +3479,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LBPHFaceRecognizer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::FaceRecognizerTraitConst for LBPHFaceRecognizer {
 		#[inline] fn as_raw_FaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3409,6 +3489,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_FaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LBPHFaceRecognizer, crate::face::FaceRecognizerTraitConst, as_raw_FaceRecognizer, crate::face::FaceRecognizerTrait, as_raw_mut_FaceRecognizer }
+	
 	impl crate::face::LBPHFaceRecognizerTraitConst for LBPHFaceRecognizer {
 		#[inline] fn as_raw_LBPHFaceRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3417,6 +3499,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_LBPHFaceRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LBPHFaceRecognizer, crate::face::LBPHFaceRecognizerTraitConst, as_raw_LBPHFaceRecognizer, crate::face::LBPHFaceRecognizerTrait, as_raw_mut_LBPHFaceRecognizer }
+	
 	impl LBPHFaceRecognizer {
 		/// ## Parameters
 		/// * radius: The radius used for building the Circular Local Binary Pattern. The greater the
@@ -3599,6 +3683,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MACE, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::face::MACETraitConst for MACE {
 		#[inline] fn as_raw_MACE(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3607,6 +3693,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_MACE(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MACE, crate::face::MACETraitConst, as_raw_MACE, crate::face::MACETrait, as_raw_mut_MACE }
+	
 	impl MACE {
 		/// constructor
 		/// ## Parameters
@@ -3709,6 +3797,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_PredictCollector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PredictCollector, crate::face::PredictCollectorTraitConst, as_raw_PredictCollector, crate::face::PredictCollectorTrait, as_raw_mut_PredictCollector }
+	
 	impl PredictCollector {
 	}
 	
@@ -3810,6 +3900,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_PredictCollector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StandardCollector, crate::face::PredictCollectorTraitConst, as_raw_PredictCollector, crate::face::PredictCollectorTrait, as_raw_mut_PredictCollector }
+	
 	impl crate::face::StandardCollectorTraitConst for StandardCollector {
 		#[inline] fn as_raw_StandardCollector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3818,6 +3910,8 @@ pub mod face {
 		#[inline] fn as_raw_mut_StandardCollector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StandardCollector, crate::face::StandardCollectorTraitConst, as_raw_StandardCollector, crate::face::StandardCollectorTrait, as_raw_mut_StandardCollector }
+	
 	impl StandardCollector {
 		/// Constructor
 		/// ## Parameters
@@ -299,6 +299,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AutotunedIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::AutotunedIndexParamsTraitConst for AutotunedIndexParams {
 		#[inline] fn as_raw_AutotunedIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -307,6 +309,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_AutotunedIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AutotunedIndexParams, crate::flann::AutotunedIndexParamsTraitConst, as_raw_AutotunedIndexParams, crate::flann::AutotunedIndexParamsTrait, as_raw_mut_AutotunedIndexParams }
+	
 	impl AutotunedIndexParams {
 		/// ## C++ default parameters
 		/// * target_precision: 0.8f
@@ -363,6 +367,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CompositeIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::CompositeIndexParamsTraitConst for CompositeIndexParams {
 		#[inline] fn as_raw_CompositeIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -371,6 +377,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_CompositeIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CompositeIndexParams, crate::flann::CompositeIndexParamsTraitConst, as_raw_CompositeIndexParams, crate::flann::CompositeIndexParamsTrait, as_raw_mut_CompositeIndexParams }
+	
 	impl CompositeIndexParams {
 		/// ## C++ default parameters
 		/// * trees: 4
@@ -428,6 +436,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HierarchicalClusteringIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::HierarchicalClusteringIndexParamsTraitConst for HierarchicalClusteringIndexParams {
 		#[inline] fn as_raw_HierarchicalClusteringIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -436,6 +446,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_HierarchicalClusteringIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HierarchicalClusteringIndexParams, crate::flann::HierarchicalClusteringIndexParamsTraitConst, as_raw_HierarchicalClusteringIndexParams, crate::flann::HierarchicalClusteringIndexParamsTrait, as_raw_mut_HierarchicalClusteringIndexParams }
+	
 	impl HierarchicalClusteringIndexParams {
 		/// ## C++ default parameters
 		/// * branching: 32
@@ -580,6 +592,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_Index(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Index, crate::flann::IndexTraitConst, as_raw_Index, crate::flann::IndexTrait, as_raw_mut_Index }
+	
 	impl Index {
 		#[inline]
 		pub fn default() -> Result<crate::flann::Index> {
@@ -761,6 +775,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { IndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl IndexParams {
 		#[inline]
 		pub fn default() -> Result<crate::flann::IndexParams> {
@@ -810,6 +826,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KDTreeIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::KDTreeIndexParamsTraitConst for KDTreeIndexParams {
 		#[inline] fn as_raw_KDTreeIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -818,6 +836,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_KDTreeIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KDTreeIndexParams, crate::flann::KDTreeIndexParamsTraitConst, as_raw_KDTreeIndexParams, crate::flann::KDTreeIndexParamsTrait, as_raw_mut_KDTreeIndexParams }
+	
 	impl KDTreeIndexParams {
 		/// ## C++ default parameters
 		/// * trees: 4
@@ -871,6 +891,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KMeansIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::KMeansIndexParamsTraitConst for KMeansIndexParams {
 		#[inline] fn as_raw_KMeansIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -879,6 +901,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_KMeansIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KMeansIndexParams, crate::flann::KMeansIndexParamsTraitConst, as_raw_KMeansIndexParams, crate::flann::KMeansIndexParamsTrait, as_raw_mut_KMeansIndexParams }
+	
 	impl KMeansIndexParams {
 		/// ## C++ default parameters
 		/// * branching: 32
@@ -935,6 +959,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LinearIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::LinearIndexParamsTraitConst for LinearIndexParams {
 		#[inline] fn as_raw_LinearIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -943,6 +969,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_LinearIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LinearIndexParams, crate::flann::LinearIndexParamsTraitConst, as_raw_LinearIndexParams, crate::flann::LinearIndexParamsTrait, as_raw_mut_LinearIndexParams }
+	
 	impl LinearIndexParams {
 		#[inline]
 		pub fn default() -> Result<crate::flann::LinearIndexParams> {
@@ -994,6 +1022,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LshIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::LshIndexParamsTraitConst for LshIndexParams {
 		#[inline] fn as_raw_LshIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1002,6 +1032,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_LshIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LshIndexParams, crate::flann::LshIndexParamsTraitConst, as_raw_LshIndexParams, crate::flann::LshIndexParamsTrait, as_raw_mut_LshIndexParams }
+	
 	impl LshIndexParams {
 		#[inline]
 		pub fn new(table_number: i32, key_size: i32, multi_probe_level: i32) -> Result<crate::flann::LshIndexParams> {
@@ -1053,6 +1085,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SavedIndexParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::SavedIndexParamsTraitConst for SavedIndexParams {
 		#[inline] fn as_raw_SavedIndexParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1061,6 +1095,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_SavedIndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SavedIndexParams, crate::flann::SavedIndexParamsTraitConst, as_raw_SavedIndexParams, crate::flann::SavedIndexParamsTrait, as_raw_mut_SavedIndexParams }
+	
 	impl SavedIndexParams {
 		#[inline]
 		pub fn new(filename: &str) -> Result<crate::flann::SavedIndexParams> {
@@ -1113,6 +1149,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_IndexParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SearchParams, crate::flann::IndexParamsTraitConst, as_raw_IndexParams, crate::flann::IndexParamsTrait, as_raw_mut_IndexParams }
+	
 	impl crate::flann::SearchParamsTraitConst for SearchParams {
 		#[inline] fn as_raw_SearchParams(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1121,6 +1159,8 @@ pub mod flann {
 		#[inline] fn as_raw_mut_SearchParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SearchParams, crate::flann::SearchParamsTraitConst, as_raw_SearchParams, crate::flann::SearchParamsTrait, as_raw_mut_SearchParams }
+	
 	impl SearchParams {
 		#[inline]
 		pub fn new(checks: i32, eps: f32, sorted: bool, explore_all_trees: bool) -> Result<crate::flann::SearchParams> {
@@ -190,6 +190,8 @@ pub mod freetype {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FreeType2, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::freetype::FreeType2TraitConst for FreeType2 {
 		#[inline] fn as_raw_FreeType2(&self) -> *const c_void { self.as_raw() }
 	}
@@ -198,6 +200,8 @@ pub mod freetype {
 		#[inline] fn as_raw_mut_FreeType2(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FreeType2, crate::freetype::FreeType2TraitConst, as_raw_FreeType2, crate::freetype::FreeType2Trait, as_raw_mut_FreeType2 }
+	
 	impl FreeType2 {
 	}
 	
@@ -4537,6 +4537,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GArg(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GArg, crate::gapi::GArgTraitConst, as_raw_GArg, crate::gapi::GArgTrait, as_raw_mut_GArg }
+	
 	impl GArg {
 		#[inline]
 		pub fn default() -> Result<crate::gapi::GArg> {
@@ -4596,6 +4598,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GArrayDesc(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GArrayDesc, crate::gapi::GArrayDescTraitConst, as_raw_GArrayDesc, crate::gapi::GArrayDescTrait, as_raw_mut_GArrayDesc }
+	
 	impl GArrayDesc {
 		fn default() -> Self {
 			extern "C" { fn cv_GArrayDesc_default_new() -> extern_receive!(GArrayDesc: 'static); }
@@ -4733,6 +4737,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GCall(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GCall, crate::gapi::GCallTraitConst, as_raw_GCall, crate::gapi::GCallTrait, as_raw_mut_GCall }
+	
 	impl GCall {
 		#[inline]
 		pub fn new(k: &crate::gapi::GKernel) -> Result<crate::gapi::GCall> {
@@ -4805,6 +4811,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GCompileArg(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GCompileArg, crate::gapi::GCompileArgTraitConst, as_raw_GCompileArg, crate::gapi::GCompileArgTrait, as_raw_mut_GCompileArg }
+	
 	impl GCompileArg {
 		#[inline]
 		pub fn default() -> crate::gapi::GCompileArg {
@@ -5121,6 +5129,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GCompiled(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GCompiled, crate::gapi::GCompiledTraitConst, as_raw_GCompiled, crate::gapi::GCompiledTrait, as_raw_mut_GCompiled }
+	
 	impl GCompiled {
 		/// Constructs an empty object
 		#[inline]
@@ -5436,6 +5446,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GComputation(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GComputation, crate::gapi::GComputationTraitConst, as_raw_GComputation, crate::gapi::GComputationTrait, as_raw_mut_GComputation }
+	
 	impl GComputation {
 		/// Defines an unary (one input -- one output) computation
 		/// 
@@ -5677,6 +5689,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GFrame(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GFrame, crate::gapi::GFrameTraitConst, as_raw_GFrame, crate::gapi::GFrameTrait, as_raw_mut_GFrame }
+	
 	impl GFrame {
 		/// Constructs an empty GFrame
 		/// 
@@ -5778,6 +5792,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GFrameDesc(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GFrameDesc, crate::gapi::GFrameDescTraitConst, as_raw_GFrameDesc, crate::gapi::GFrameDescTrait, as_raw_mut_GFrameDesc }
+	
 	impl GFrameDesc {
 	}
 	
@@ -5871,6 +5887,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GKernel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GKernel, crate::gapi::GKernelTraitConst, as_raw_GKernel, crate::gapi::GKernelTrait, as_raw_mut_GKernel }
+	
 	impl GKernel {
 	}
 	
@@ -5923,6 +5941,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GKernelImpl(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GKernelImpl, crate::gapi::GKernelImplTraitConst, as_raw_GKernelImpl, crate::gapi::GKernelImplTrait, as_raw_mut_GKernelImpl }
+	
 	impl GKernelImpl {
 	}
 	
@@ -6091,6 +6111,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GKernelPackage(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GKernelPackage, crate::gapi::GKernelPackageTraitConst, as_raw_GKernelPackage, crate::gapi::GKernelPackageTrait, as_raw_mut_GKernelPackage }
+	
 	impl GKernelPackage {
 		fn default() -> Self {
 			extern "C" { fn cv_GKernelPackage_default_new() -> extern_receive!(GKernelPackage: 'static); }
@@ -6188,6 +6210,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GMat, crate::gapi::GMatTraitConst, as_raw_GMat, crate::gapi::GMatTrait, as_raw_mut_GMat }
+	
 	impl GMat {
 		/// Constructs an empty GMat
 		/// 
@@ -6440,6 +6464,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GMatDesc(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GMatDesc, crate::gapi::GMatDescTraitConst, as_raw_GMatDesc, crate::gapi::GMatDescTrait, as_raw_mut_GMatDesc }
+	
 	impl GMatDesc {
 		/// ## C++ default parameters
 		/// * p: false
@@ -6529,6 +6555,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GMatP, crate::gapi::GMatTraitConst, as_raw_GMat, crate::gapi::GMatTrait, as_raw_mut_GMat }
+	
 	impl crate::gapi::GMatPTraitConst for GMatP {
 		#[inline] fn as_raw_GMatP(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6537,6 +6565,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GMatP(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GMatP, crate::gapi::GMatPTraitConst, as_raw_GMatP, crate::gapi::GMatPTrait, as_raw_mut_GMatP }
+	
 	impl GMatP {
 	}
 	
@@ -6588,6 +6618,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GOpaqueDesc(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GOpaqueDesc, crate::gapi::GOpaqueDescTraitConst, as_raw_GOpaqueDesc, crate::gapi::GOpaqueDescTrait, as_raw_mut_GOpaqueDesc }
+	
 	impl GOpaqueDesc {
 		fn default() -> Self {
 			extern "C" { fn cv_GOpaqueDesc_default_new() -> extern_receive!(GOpaqueDesc: 'static); }
@@ -6648,6 +6680,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GRunArg(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GRunArg, crate::gapi::GRunArgTraitConst, as_raw_GRunArg, crate::gapi::GRunArgTrait, as_raw_mut_GRunArg }
+	
 	impl GRunArg {
 		#[inline]
 		pub fn default() -> Result<crate::gapi::GRunArg> {
@@ -6731,6 +6765,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GScalar(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GScalar, crate::gapi::GScalarTraitConst, as_raw_GScalar, crate::gapi::GScalarTrait, as_raw_mut_GScalar }
+	
 	impl GScalar {
 		/// Constructs an empty GScalar
 		/// 
@@ -6915,6 +6951,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GScalarDesc(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GScalarDesc, crate::gapi::GScalarDescTraitConst, as_raw_GScalarDesc, crate::gapi::GScalarDescTrait, as_raw_mut_GScalarDesc }
+	
 	impl GScalarDesc {
 		fn default() -> Self {
 			extern "C" { fn cv_GScalarDesc_default_new() -> extern_receive!(GScalarDesc: 'static); }
@@ -7134,6 +7172,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GStreamingCompiled(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GStreamingCompiled, crate::gapi::GStreamingCompiledTraitConst, as_raw_GStreamingCompiled, crate::gapi::GStreamingCompiledTrait, as_raw_mut_GStreamingCompiled }
+	
 	impl GStreamingCompiled {
 		#[inline]
 		pub fn default() -> Result<crate::gapi::GStreamingCompiled> {
@@ -7205,6 +7245,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GTransform(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GTransform, crate::gapi::GTransformTraitConst, as_raw_GTransform, crate::gapi::GTransformTrait, as_raw_mut_GTransform }
+	
 	impl GTransform {
 	}
 	
@@ -7272,6 +7314,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GTypeInfo(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GTypeInfo, crate::gapi::GTypeInfoTraitConst, as_raw_GTypeInfo, crate::gapi::GTypeInfoTrait, as_raw_mut_GTypeInfo }
+	
 	impl GTypeInfo {
 		fn default() -> Self {
 			extern "C" { fn cv_GTypeInfo_default_new() -> extern_receive!(GTypeInfo: 'static); }
@@ -7377,6 +7421,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_MediaFrame(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MediaFrame, crate::gapi::MediaFrameTraitConst, as_raw_MediaFrame, crate::gapi::MediaFrameTrait, as_raw_mut_MediaFrame }
+	
 	impl MediaFrame {
 		/// Constructs an empty MediaFrame
 		/// 
@@ -7458,6 +7504,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_MediaFrame_IAdapter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MediaFrame_IAdapter, crate::gapi::MediaFrame_IAdapterTraitConst, as_raw_MediaFrame_IAdapter, crate::gapi::MediaFrame_IAdapterTrait, as_raw_mut_MediaFrame_IAdapter }
+	
 	impl MediaFrame_IAdapter {
 	}
 	
@@ -7532,6 +7580,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_MediaFrame_View(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MediaFrame_View, crate::gapi::MediaFrame_ViewTraitConst, as_raw_MediaFrame_View, crate::gapi::MediaFrame_ViewTrait, as_raw_mut_MediaFrame_View }
+	
 	impl MediaFrame_View {
 		pub const MAX_PLANES: u32 = 4;
 		/// @private
@@ -7591,6 +7641,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_RMat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RMat, crate::gapi::RMatTraitConst, as_raw_RMat, crate::gapi::RMatTrait, as_raw_mut_RMat }
+	
 	impl RMat {
 		#[inline]
 		pub fn default() -> crate::gapi::RMat {
@@ -7655,6 +7707,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_RMat_IAdapter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RMat_IAdapter, crate::gapi::RMat_IAdapterTraitConst, as_raw_RMat_IAdapter, crate::gapi::RMat_IAdapterTrait, as_raw_mut_RMat_IAdapter }
+	
 	impl RMat_IAdapter {
 	}
 	
@@ -7788,6 +7842,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_RMat_View(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RMat_View, crate::gapi::RMat_ViewTraitConst, as_raw_RMat_View, crate::gapi::RMat_ViewTrait, as_raw_mut_RMat_View }
+	
 	impl RMat_View {
 		#[inline]
 		pub fn default() -> crate::gapi::RMat_View {
@@ -7859,6 +7915,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_ExtractArgsCallback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExtractArgsCallback, crate::gapi::ExtractArgsCallbackTraitConst, as_raw_ExtractArgsCallback, crate::gapi::ExtractArgsCallbackTrait, as_raw_mut_ExtractArgsCallback }
+	
 	impl ExtractArgsCallback {
 	}
 	
@@ -7898,6 +7956,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_ExtractMetaCallback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ExtractMetaCallback, crate::gapi::ExtractMetaCallbackTraitConst, as_raw_ExtractMetaCallback, crate::gapi::ExtractMetaCallbackTrait, as_raw_mut_ExtractMetaCallback }
+	
 	impl ExtractMetaCallback {
 	}
 	
@@ -7937,6 +7997,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GArrayU(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GArrayU, crate::gapi::GArrayUTraitConst, as_raw_GArrayU, crate::gapi::GArrayUTrait, as_raw_mut_GArrayU }
+	
 	impl GArrayU {
 	}
 	
@@ -7976,6 +8038,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GOpaqueU(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GOpaqueU, crate::gapi::GOpaqueUTraitConst, as_raw_GOpaqueU, crate::gapi::GOpaqueUTrait, as_raw_mut_GOpaqueU }
+	
 	impl GOpaqueU {
 	}
 	
@@ -8025,6 +8089,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GBackend(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GBackend, crate::gapi::GBackendTraitConst, as_raw_GBackend, crate::gapi::GBackendTrait, as_raw_mut_GBackend }
+	
 	impl GBackend {
 		#[inline]
 		pub fn default() -> Result<crate::gapi::GBackend> {
@@ -8105,6 +8171,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GFunctor(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GFunctor, crate::gapi::GFunctorTraitConst, as_raw_GFunctor, crate::gapi::GFunctorTrait, as_raw_mut_GFunctor }
+	
 	impl GFunctor {
 	}
 	
@@ -8169,6 +8237,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_Scalar(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Scalar, crate::gapi::ScalarTraitConst, as_raw_Scalar, crate::gapi::ScalarTrait, as_raw_mut_Scalar }
+	
 	impl Scalar {
 		#[inline]
 		pub fn default() -> crate::gapi::Scalar {
@@ -8301,6 +8371,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_use_only(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { use_only, crate::gapi::use_onlyTraitConst, as_raw_use_only, crate::gapi::use_onlyTrait, as_raw_mut_use_only }
+	
 	impl use_only {
 	}
 	
@@ -8345,6 +8417,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_GRunArg(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Data, crate::gapi::GRunArgTraitConst, as_raw_GRunArg, crate::gapi::GRunArgTrait, as_raw_mut_GRunArg }
+	
 	impl crate::gapi::DataTraitConst for Data {
 		#[inline] fn as_raw_Data(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8353,6 +8427,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_Data(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Data, crate::gapi::DataTraitConst, as_raw_Data, crate::gapi::DataTrait, as_raw_mut_Data }
+	
 	impl Data {
 	}
 	
@@ -8507,6 +8583,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_Image(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Image, crate::gapi::ImageTraitConst, as_raw_Image, crate::gapi::ImageTrait, as_raw_mut_Image }
+	
 	impl Image {
 		/// Mosaic constructor
 		/// 
@@ -8766,6 +8844,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_Poly(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Poly, crate::gapi::PolyTraitConst, as_raw_Poly, crate::gapi::PolyTrait, as_raw_mut_Poly }
+	
 	impl Poly {
 		/// Mosaic constructor
 		/// 
@@ -9033,6 +9113,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_Text(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Text, crate::gapi::TextTraitConst, as_raw_Text, crate::gapi::TextTrait, as_raw_mut_Text }
+	
 	impl Text {
 		/// Text constructor
 		/// 
@@ -9122,6 +9204,8 @@ pub mod gapi {
 		#[inline] fn as_raw_mut_any(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { any, crate::gapi::anyTraitConst, as_raw_any, crate::gapi::anyTrait, as_raw_mut_any }
+	
 	impl any {
 		#[inline]
 		pub fn copy(src: &crate::gapi::any) -> Result<crate::gapi::any> {
@@ -271,6 +271,8 @@ pub mod hfs {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HfsSegment, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::hfs::HfsSegmentTraitConst for HfsSegment {
 		#[inline] fn as_raw_HfsSegment(&self) -> *const c_void { self.as_raw() }
 	}
@@ -279,6 +281,8 @@ pub mod hfs {
 		#[inline] fn as_raw_mut_HfsSegment(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HfsSegment, crate::hfs::HfsSegmentTraitConst, as_raw_HfsSegment, crate::hfs::HfsSegmentTrait, as_raw_mut_HfsSegment }
+	
 	impl HfsSegment {
 		/// create a hfs object
 		/// ## Parameters
@@ -231,6 +231,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AverageHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for AverageHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -239,6 +241,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AverageHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::AverageHashTraitConst for AverageHash {
 		#[inline] fn as_raw_AverageHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -247,6 +251,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_AverageHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AverageHash, crate::img_hash::AverageHashTraitConst, as_raw_AverageHash, crate::img_hash::AverageHashTrait, as_raw_mut_AverageHash }
+	
 	impl AverageHash {
 		#[inline]
 		pub fn create() -> Result<core::Ptr<crate::img_hash::AverageHash>> {
@@ -325,6 +331,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlockMeanHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for BlockMeanHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -333,6 +341,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlockMeanHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::BlockMeanHashTraitConst for BlockMeanHash {
 		#[inline] fn as_raw_BlockMeanHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -341,6 +351,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_BlockMeanHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BlockMeanHash, crate::img_hash::BlockMeanHashTraitConst, as_raw_BlockMeanHash, crate::img_hash::BlockMeanHashTrait, as_raw_mut_BlockMeanHash }
+	
 	impl BlockMeanHash {
 		/// ## C++ default parameters
 		/// * mode: BLOCK_MEAN_HASH_MODE_0
@@ -399,6 +411,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColorMomentHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for ColorMomentHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -407,6 +421,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColorMomentHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::ColorMomentHashTraitConst for ColorMomentHash {
 		#[inline] fn as_raw_ColorMomentHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -415,6 +431,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ColorMomentHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColorMomentHash, crate::img_hash::ColorMomentHashTraitConst, as_raw_ColorMomentHash, crate::img_hash::ColorMomentHashTrait, as_raw_mut_ColorMomentHash }
+	
 	impl ColorMomentHash {
 		#[inline]
 		pub fn create() -> Result<core::Ptr<crate::img_hash::ColorMomentHash>> {
@@ -502,6 +520,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ImgHashBase, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for ImgHashBase {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -510,6 +530,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ImgHashBase, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl ImgHashBase {
 	}
 	
@@ -587,6 +609,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MarrHildrethHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for MarrHildrethHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -595,6 +619,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MarrHildrethHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::MarrHildrethHashTraitConst for MarrHildrethHash {
 		#[inline] fn as_raw_MarrHildrethHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -603,6 +629,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_MarrHildrethHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MarrHildrethHash, crate::img_hash::MarrHildrethHashTraitConst, as_raw_MarrHildrethHash, crate::img_hash::MarrHildrethHashTrait, as_raw_mut_MarrHildrethHash }
+	
 	impl MarrHildrethHash {
 		/// ## Parameters
 		/// * alpha: int scale factor for marr wavelet (default=2).
@@ -668,6 +696,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for PHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -676,6 +706,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::PHashTraitConst for PHash {
 		#[inline] fn as_raw_PHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -684,6 +716,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_PHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PHash, crate::img_hash::PHashTraitConst, as_raw_PHash, crate::img_hash::PHashTrait, as_raw_mut_PHash }
+	
 	impl PHash {
 		#[inline]
 		pub fn create() -> Result<core::Ptr<crate::img_hash::PHash>> {
@@ -816,6 +850,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RadialVarianceHash, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::img_hash::ImgHashBaseTraitConst for RadialVarianceHash {
 		#[inline] fn as_raw_ImgHashBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -824,6 +860,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_ImgHashBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RadialVarianceHash, crate::img_hash::ImgHashBaseTraitConst, as_raw_ImgHashBase, crate::img_hash::ImgHashBaseTrait, as_raw_mut_ImgHashBase }
+	
 	impl crate::img_hash::RadialVarianceHashTraitConst for RadialVarianceHash {
 		#[inline] fn as_raw_RadialVarianceHash(&self) -> *const c_void { self.as_raw() }
 	}
@@ -832,6 +870,8 @@ pub mod img_hash {
 		#[inline] fn as_raw_mut_RadialVarianceHash(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RadialVarianceHash, crate::img_hash::RadialVarianceHashTraitConst, as_raw_RadialVarianceHash, crate::img_hash::RadialVarianceHashTrait, as_raw_mut_RadialVarianceHash }
+	
 	impl RadialVarianceHash {
 		/// ## C++ default parameters
 		/// * sigma: 1
@@ -7087,6 +7087,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CLAHE, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::imgproc::CLAHETraitConst for CLAHE {
 		#[inline] fn as_raw_CLAHE(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7095,6 +7097,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_CLAHE(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CLAHE, crate::imgproc::CLAHETraitConst, as_raw_CLAHE, crate::imgproc::CLAHETrait, as_raw_mut_CLAHE }
+	
 	impl CLAHE {
 	}
 	
@@ -7292,6 +7296,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHough, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::imgproc::GeneralizedHoughTraitConst for GeneralizedHough {
 		#[inline] fn as_raw_GeneralizedHough(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7300,6 +7306,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_GeneralizedHough(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHough, crate::imgproc::GeneralizedHoughTraitConst, as_raw_GeneralizedHough, crate::imgproc::GeneralizedHoughTrait, as_raw_mut_GeneralizedHough }
+	
 	impl GeneralizedHough {
 	}
 	
@@ -7382,6 +7390,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughBallard, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::imgproc::GeneralizedHoughTraitConst for GeneralizedHoughBallard {
 		#[inline] fn as_raw_GeneralizedHough(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7390,6 +7400,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_GeneralizedHough(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughBallard, crate::imgproc::GeneralizedHoughTraitConst, as_raw_GeneralizedHough, crate::imgproc::GeneralizedHoughTrait, as_raw_mut_GeneralizedHough }
+	
 	impl crate::imgproc::GeneralizedHoughBallardTraitConst for GeneralizedHoughBallard {
 		#[inline] fn as_raw_GeneralizedHoughBallard(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7398,6 +7410,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_GeneralizedHoughBallard(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughBallard, crate::imgproc::GeneralizedHoughBallardTraitConst, as_raw_GeneralizedHoughBallard, crate::imgproc::GeneralizedHoughBallardTrait, as_raw_mut_GeneralizedHoughBallard }
+	
 	impl GeneralizedHoughBallard {
 	}
 	
@@ -7670,6 +7684,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughGuil, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::imgproc::GeneralizedHoughTraitConst for GeneralizedHoughGuil {
 		#[inline] fn as_raw_GeneralizedHough(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7678,6 +7694,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_GeneralizedHough(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughGuil, crate::imgproc::GeneralizedHoughTraitConst, as_raw_GeneralizedHough, crate::imgproc::GeneralizedHoughTrait, as_raw_mut_GeneralizedHough }
+	
 	impl crate::imgproc::GeneralizedHoughGuilTraitConst for GeneralizedHoughGuil {
 		#[inline] fn as_raw_GeneralizedHoughGuil(&self) -> *const c_void { self.as_raw() }
 	}
@@ -7686,6 +7704,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_GeneralizedHoughGuil(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GeneralizedHoughGuil, crate::imgproc::GeneralizedHoughGuilTraitConst, as_raw_GeneralizedHoughGuil, crate::imgproc::GeneralizedHoughGuilTrait, as_raw_mut_GeneralizedHoughGuil }
+	
 	impl GeneralizedHoughGuil {
 	}
 	
@@ -7963,6 +7983,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_LineIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LineIterator, crate::imgproc::LineIteratorTraitConst, as_raw_LineIterator, crate::imgproc::LineIteratorTrait, as_raw_mut_LineIterator }
+	
 	impl LineIterator {
 		/// Initializes iterator object for the given line and image.
 		/// 
@@ -8154,6 +8176,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LineSegmentDetector, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::imgproc::LineSegmentDetectorTraitConst for LineSegmentDetector {
 		#[inline] fn as_raw_LineSegmentDetector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -8162,6 +8186,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_LineSegmentDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LineSegmentDetector, crate::imgproc::LineSegmentDetectorTraitConst, as_raw_LineSegmentDetector, crate::imgproc::LineSegmentDetectorTrait, as_raw_mut_LineSegmentDetector }
+	
 	impl LineSegmentDetector {
 	}
 	
@@ -8505,6 +8531,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_Subdiv2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Subdiv2D, crate::imgproc::Subdiv2DTraitConst, as_raw_Subdiv2D, crate::imgproc::Subdiv2DTrait, as_raw_mut_Subdiv2D }
+	
 	impl Subdiv2D {
 		/// creates an empty Subdiv2D object.
 		/// To create a new empty Delaunay subdivision you need to use the #initDelaunay function.
@@ -8758,6 +8786,8 @@ pub mod imgproc {
 		#[inline] fn as_raw_mut_IntelligentScissorsMB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { IntelligentScissorsMB, crate::imgproc::IntelligentScissorsMBTraitConst, as_raw_IntelligentScissorsMB, crate::imgproc::IntelligentScissorsMBTrait, as_raw_mut_IntelligentScissorsMB }
+	
 	impl IntelligentScissorsMB {
 		#[inline]
 		pub fn default() -> Result<crate::imgproc::IntelligentScissorsMB> {
@@ -8778,4 +8808,5 @@ pub mod imgproc {
 			unsafe { Self::from_raw(cv_IntelligentScissorsMB_implicit_clone(self.as_raw_IntelligentScissorsMB())) }
 		}
 	}
+pub use crate::manual::imgproc::*;
 }
@@ -443,6 +443,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BinaryDescriptor, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::line_descriptor::BinaryDescriptorTraitConst for BinaryDescriptor {
 		#[inline] fn as_raw_BinaryDescriptor(&self) -> *const c_void { self.as_raw() }
 	}
@@ -451,6 +453,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_BinaryDescriptor(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BinaryDescriptor, crate::line_descriptor::BinaryDescriptorTraitConst, as_raw_BinaryDescriptor, crate::line_descriptor::BinaryDescriptorTrait, as_raw_mut_BinaryDescriptor }
+	
 	impl BinaryDescriptor {
 		/// Constructor
 		/// 
@@ -609,6 +613,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_BinaryDescriptor_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BinaryDescriptor_Params, crate::line_descriptor::BinaryDescriptor_ParamsTraitConst, as_raw_BinaryDescriptor_Params, crate::line_descriptor::BinaryDescriptor_ParamsTrait, as_raw_mut_BinaryDescriptor_Params }
+	
 	impl BinaryDescriptor_Params {
 		#[inline]
 		pub fn default() -> Result<crate::line_descriptor::BinaryDescriptor_Params> {
@@ -902,6 +908,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BinaryDescriptorMatcher, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::line_descriptor::BinaryDescriptorMatcherTraitConst for BinaryDescriptorMatcher {
 		#[inline] fn as_raw_BinaryDescriptorMatcher(&self) -> *const c_void { self.as_raw() }
 	}
@@ -910,6 +918,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_BinaryDescriptorMatcher(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BinaryDescriptorMatcher, crate::line_descriptor::BinaryDescriptorMatcherTraitConst, as_raw_BinaryDescriptorMatcher, crate::line_descriptor::BinaryDescriptorMatcherTrait, as_raw_mut_BinaryDescriptorMatcher }
+	
 	impl BinaryDescriptorMatcher {
 		/// Create a BinaryDescriptorMatcher object and return a smart pointer to it.
 		#[inline]
@@ -1132,6 +1142,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LSDDetector, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::line_descriptor::LSDDetectorTraitConst for LSDDetector {
 		#[inline] fn as_raw_LSDDetector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1140,6 +1152,8 @@ pub mod line_descriptor {
 		#[inline] fn as_raw_mut_LSDDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LSDDetector, crate::line_descriptor::LSDDetectorTraitConst, as_raw_LSDDetector, crate::line_descriptor::LSDDetectorTrait, as_raw_mut_LSDDetector }
+	
 	impl LSDDetector {
 		#[inline]
 		pub fn default() -> Result<crate::line_descriptor::LSDDetector> {
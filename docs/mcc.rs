@@ -781,6 +781,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_ColorCorrectionModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColorCorrectionModel, crate::mcc::ColorCorrectionModelTraitConst, as_raw_ColorCorrectionModel, crate::mcc::ColorCorrectionModelTrait, as_raw_mut_ColorCorrectionModel }
+	
 	impl ColorCorrectionModel {
 		/// Color Correction Model
 		/// 
@@ -999,6 +1001,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_MCC_CChecker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MCC_CChecker, crate::mcc::MCC_CCheckerTraitConst, as_raw_MCC_CChecker, crate::mcc::MCC_CCheckerTrait, as_raw_mut_MCC_CChecker }
+	
 	impl MCC_CChecker {
 		/// \brief Create a new CChecker object.
 		/// \return A pointer to the implementation of the CChecker
@@ -1163,6 +1167,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MCC_CCheckerDetector, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::mcc::MCC_CCheckerDetectorTraitConst for MCC_CCheckerDetector {
 		#[inline] fn as_raw_MCC_CCheckerDetector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1171,6 +1177,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_MCC_CCheckerDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MCC_CCheckerDetector, crate::mcc::MCC_CCheckerDetectorTraitConst, as_raw_MCC_CCheckerDetector, crate::mcc::MCC_CCheckerDetectorTrait, as_raw_mut_MCC_CCheckerDetector }
+	
 	impl MCC_CCheckerDetector {
 		/// \brief Returns the implementation of the CCheckerDetector.
 		#[inline]
@@ -1249,6 +1257,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_MCC_CCheckerDraw(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MCC_CCheckerDraw, crate::mcc::MCC_CCheckerDrawTraitConst, as_raw_MCC_CCheckerDraw, crate::mcc::MCC_CCheckerDrawTrait, as_raw_mut_MCC_CCheckerDraw }
+	
 	impl MCC_CCheckerDraw {
 		/// \brief Create a new CCheckerDraw object.
 		/// \param pChecker The checker which will be drawn by this object.
@@ -1572,6 +1582,8 @@ pub mod mcc {
 		#[inline] fn as_raw_mut_MCC_DetectorParameters(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MCC_DetectorParameters, crate::mcc::MCC_DetectorParametersTraitConst, as_raw_MCC_DetectorParameters, crate::mcc::MCC_DetectorParametersTrait, as_raw_mut_MCC_DetectorParameters }
+	
 	impl MCC_DetectorParameters {
 		#[inline]
 		pub fn default() -> Result<crate::mcc::MCC_DetectorParameters> {
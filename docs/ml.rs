@@ -965,6 +965,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ANN_MLP, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for ANN_MLP {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -973,6 +975,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ANN_MLP, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::ANN_MLPTraitConst for ANN_MLP {
 		#[inline] fn as_raw_ANN_MLP(&self) -> *const c_void { self.as_raw() }
 	}
@@ -981,6 +985,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_ANN_MLP(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ANN_MLP, crate::ml::ANN_MLPTraitConst, as_raw_ANN_MLP, crate::ml::ANN_MLPTrait, as_raw_mut_ANN_MLP }
+	
 	impl ANN_MLP {
 		/// Creates empty model
 		/// 
@@ -1137,6 +1143,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Boost, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::DTreesTraitConst for Boost {
 		#[inline] fn as_raw_DTrees(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1145,6 +1153,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_DTrees(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Boost, crate::ml::DTreesTraitConst, as_raw_DTrees, crate::ml::DTreesTrait, as_raw_mut_DTrees }
+	
 	impl crate::ml::StatModelTraitConst for Boost {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1153,6 +1163,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Boost, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::BoostTraitConst for Boost {
 		#[inline] fn as_raw_Boost(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1161,6 +1173,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Boost(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Boost, crate::ml::BoostTraitConst, as_raw_Boost, crate::ml::BoostTrait, as_raw_mut_Boost }
+	
 	impl Boost {
 		/// Creates the empty model.
 		/// Use StatModel::train to train the model, Algorithm::load\<Boost\>(filename) to load the pre-trained model.
@@ -1599,6 +1613,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DTrees, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for DTrees {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1607,6 +1623,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DTrees, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::DTreesTraitConst for DTrees {
 		#[inline] fn as_raw_DTrees(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1615,6 +1633,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_DTrees(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DTrees, crate::ml::DTreesTraitConst, as_raw_DTrees, crate::ml::DTreesTrait, as_raw_mut_DTrees }
+	
 	impl DTrees {
 		/// Creates the empty model
 		/// 
@@ -1800,6 +1820,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_DTrees_Node(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DTrees_Node, crate::ml::DTrees_NodeTraitConst, as_raw_DTrees_Node, crate::ml::DTrees_NodeTrait, as_raw_mut_DTrees_Node }
+	
 	impl DTrees_Node {
 		#[inline]
 		pub fn default() -> Result<crate::ml::DTrees_Node> {
@@ -1964,6 +1986,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_DTrees_Split(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DTrees_Split, crate::ml::DTrees_SplitTraitConst, as_raw_DTrees_Split, crate::ml::DTrees_SplitTrait, as_raw_mut_DTrees_Split }
+	
 	impl DTrees_Split {
 		#[inline]
 		pub fn default() -> Result<crate::ml::DTrees_Split> {
@@ -2320,6 +2344,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for EM {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2328,6 +2354,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EM, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::EMTraitConst for EM {
 		#[inline] fn as_raw_EM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2336,6 +2364,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_EM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { EM, crate::ml::EMTraitConst, as_raw_EM, crate::ml::EMTrait, as_raw_mut_EM }
+	
 	impl EM {
 		/// Creates empty %EM model.
 		/// The model should be trained then using StatModel::train(traindata, flags) method. Alternatively, you
@@ -2556,6 +2586,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KNearest, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for KNearest {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2564,6 +2596,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KNearest, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::KNearestTraitConst for KNearest {
 		#[inline] fn as_raw_KNearest(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2572,6 +2606,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_KNearest(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KNearest, crate::ml::KNearestTraitConst, as_raw_KNearest, crate::ml::KNearestTrait, as_raw_mut_KNearest }
+	
 	impl KNearest {
 		/// Creates the empty model
 		/// 
@@ -2831,6 +2867,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogisticRegression, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for LogisticRegression {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2839,6 +2877,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogisticRegression, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::LogisticRegressionTraitConst for LogisticRegression {
 		#[inline] fn as_raw_LogisticRegression(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2847,6 +2887,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_LogisticRegression(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LogisticRegression, crate::ml::LogisticRegressionTraitConst, as_raw_LogisticRegression, crate::ml::LogisticRegressionTrait, as_raw_mut_LogisticRegression }
+	
 	impl LogisticRegression {
 		/// Creates empty model.
 		/// 
@@ -2950,6 +2992,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalBayesClassifier, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for NormalBayesClassifier {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2958,6 +3002,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalBayesClassifier, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::NormalBayesClassifierTraitConst for NormalBayesClassifier {
 		#[inline] fn as_raw_NormalBayesClassifier(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2966,6 +3012,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_NormalBayesClassifier(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { NormalBayesClassifier, crate::ml::NormalBayesClassifierTraitConst, as_raw_NormalBayesClassifier, crate::ml::NormalBayesClassifierTrait, as_raw_mut_NormalBayesClassifier }
+	
 	impl NormalBayesClassifier {
 		/// Creates empty model
 		/// Use StatModel::train to train the model after creation.
@@ -3101,6 +3149,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_ParamGrid(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ParamGrid, crate::ml::ParamGridTraitConst, as_raw_ParamGrid, crate::ml::ParamGridTrait, as_raw_mut_ParamGrid }
+	
 	impl ParamGrid {
 		/// Default constructor
 		#[inline]
@@ -3321,6 +3371,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RTrees, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::DTreesTraitConst for RTrees {
 		#[inline] fn as_raw_DTrees(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3329,6 +3381,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_DTrees(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RTrees, crate::ml::DTreesTraitConst, as_raw_DTrees, crate::ml::DTreesTrait, as_raw_mut_DTrees }
+	
 	impl crate::ml::StatModelTraitConst for RTrees {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3337,6 +3391,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RTrees, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::RTreesTraitConst for RTrees {
 		#[inline] fn as_raw_RTrees(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3345,6 +3401,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_RTrees(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RTrees, crate::ml::RTreesTraitConst, as_raw_RTrees, crate::ml::RTreesTrait, as_raw_mut_RTrees }
+	
 	impl RTrees {
 		/// Creates the empty model.
 		/// Use StatModel::train to train the model, StatModel::train to create and train the model,
@@ -3861,6 +3919,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for SVM {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3869,6 +3929,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVM, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::SVMTraitConst for SVM {
 		#[inline] fn as_raw_SVM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3877,6 +3939,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_SVM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVM, crate::ml::SVMTraitConst, as_raw_SVM, crate::ml::SVMTrait, as_raw_mut_SVM }
+	
 	impl SVM {
 		/// Generates a grid for %SVM parameters.
 		/// 
@@ -4003,6 +4067,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVM_Kernel, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::SVM_KernelTraitConst for SVM_Kernel {
 		#[inline] fn as_raw_SVM_Kernel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4011,6 +4077,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_SVM_Kernel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVM_Kernel, crate::ml::SVM_KernelTraitConst, as_raw_SVM_Kernel, crate::ml::SVM_KernelTrait, as_raw_mut_SVM_Kernel }
+	
 	impl SVM_Kernel {
 	}
 	
@@ -4311,6 +4379,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVMSGD, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for SVMSGD {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4319,6 +4389,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVMSGD, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl crate::ml::SVMSGDTraitConst for SVMSGD {
 		#[inline] fn as_raw_SVMSGD(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4327,6 +4399,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_SVMSGD(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SVMSGD, crate::ml::SVMSGDTraitConst, as_raw_SVMSGD, crate::ml::SVMSGDTrait, as_raw_mut_SVMSGD }
+	
 	impl SVMSGD {
 		/// Creates empty model.
 		/// Use StatModel::train to train the model. Since %SVMSGD has several parameters, you may want to
@@ -4525,6 +4599,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StatModel, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::ml::StatModelTraitConst for StatModel {
 		#[inline] fn as_raw_StatModel(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4533,6 +4609,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_StatModel(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StatModel, crate::ml::StatModelTraitConst, as_raw_StatModel, crate::ml::StatModelTrait, as_raw_mut_StatModel }
+	
 	impl StatModel {
 	}
 	
@@ -4988,6 +5066,8 @@ pub mod ml {
 		#[inline] fn as_raw_mut_TrainData(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrainData, crate::ml::TrainDataTraitConst, as_raw_TrainData, crate::ml::TrainDataTrait, as_raw_mut_TrainData }
+	
 	impl TrainData {
 		#[inline]
 		pub fn missing_value() -> Result<f32> {
@@ -734,6 +734,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseCascadeClassifier, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::objdetect::BaseCascadeClassifierTraitConst for BaseCascadeClassifier {
 		#[inline] fn as_raw_BaseCascadeClassifier(&self) -> *const c_void { self.as_raw() }
 	}
@@ -742,6 +744,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_BaseCascadeClassifier(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseCascadeClassifier, crate::objdetect::BaseCascadeClassifierTraitConst, as_raw_BaseCascadeClassifier, crate::objdetect::BaseCascadeClassifierTrait, as_raw_mut_BaseCascadeClassifier }
+	
 	impl BaseCascadeClassifier {
 	}
 	
@@ -802,6 +806,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_BaseCascadeClassifier_MaskGenerator(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseCascadeClassifier_MaskGenerator, crate::objdetect::BaseCascadeClassifier_MaskGeneratorTraitConst, as_raw_BaseCascadeClassifier_MaskGenerator, crate::objdetect::BaseCascadeClassifier_MaskGeneratorTrait, as_raw_mut_BaseCascadeClassifier_MaskGenerator }
+	
 	impl BaseCascadeClassifier_MaskGenerator {
 	}
 	
@@ -1082,6 +1088,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_CascadeClassifier(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CascadeClassifier, crate::objdetect::CascadeClassifierTraitConst, as_raw_CascadeClassifier, crate::objdetect::CascadeClassifierTrait, as_raw_mut_CascadeClassifier }
+	
 	impl CascadeClassifier {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::CascadeClassifier> {
@@ -1248,6 +1256,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectionBasedTracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionBasedTracker, crate::objdetect::DetectionBasedTrackerTraitConst, as_raw_DetectionBasedTracker, crate::objdetect::DetectionBasedTrackerTrait, as_raw_mut_DetectionBasedTracker }
+	
 	impl DetectionBasedTracker {
 		#[inline]
 		pub fn new(mut main_detector: core::Ptr<crate::objdetect::DetectionBasedTracker_IDetector>, mut tracking_detector: core::Ptr<crate::objdetect::DetectionBasedTracker_IDetector>, params: &crate::objdetect::DetectionBasedTracker_Parameters) -> Result<crate::objdetect::DetectionBasedTracker> {
@@ -1337,6 +1347,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectionBasedTracker_ExtObject(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionBasedTracker_ExtObject, crate::objdetect::DetectionBasedTracker_ExtObjectTraitConst, as_raw_DetectionBasedTracker_ExtObject, crate::objdetect::DetectionBasedTracker_ExtObjectTrait, as_raw_mut_DetectionBasedTracker_ExtObject }
+	
 	impl DetectionBasedTracker_ExtObject {
 		#[inline]
 		pub fn new(_id: i32, _location: core::Rect, _status: crate::objdetect::DetectionBasedTracker_ObjectStatus) -> Result<crate::objdetect::DetectionBasedTracker_ExtObject> {
@@ -1475,6 +1487,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectionBasedTracker_IDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionBasedTracker_IDetector, crate::objdetect::DetectionBasedTracker_IDetectorTraitConst, as_raw_DetectionBasedTracker_IDetector, crate::objdetect::DetectionBasedTracker_IDetectorTrait, as_raw_mut_DetectionBasedTracker_IDetector }
+	
 	impl DetectionBasedTracker_IDetector {
 	}
 	
@@ -1538,6 +1552,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectionBasedTracker_Parameters(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionBasedTracker_Parameters, crate::objdetect::DetectionBasedTracker_ParametersTraitConst, as_raw_DetectionBasedTracker_Parameters, crate::objdetect::DetectionBasedTracker_ParametersTrait, as_raw_mut_DetectionBasedTracker_Parameters }
+	
 	impl DetectionBasedTracker_Parameters {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::DetectionBasedTracker_Parameters> {
@@ -1632,6 +1648,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectionROI(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectionROI, crate::objdetect::DetectionROITraitConst, as_raw_DetectionROI, crate::objdetect::DetectionROITrait, as_raw_mut_DetectionROI }
+	
 	impl DetectionROI {
 	}
 	
@@ -1778,6 +1796,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_FaceDetectorYN(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FaceDetectorYN, crate::objdetect::FaceDetectorYNTraitConst, as_raw_FaceDetectorYN, crate::objdetect::FaceDetectorYNTrait, as_raw_mut_FaceDetectorYN }
+	
 	impl FaceDetectorYN {
 		/// Creates an instance of this class with given parameters
 		/// 
@@ -1901,6 +1921,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_FaceRecognizerSF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FaceRecognizerSF, crate::objdetect::FaceRecognizerSFTraitConst, as_raw_FaceRecognizerSF, crate::objdetect::FaceRecognizerSFTrait, as_raw_mut_FaceRecognizerSF }
+	
 	impl FaceRecognizerSF {
 		/// Creates an instance of this class with given parameters
 		/// ## Parameters
@@ -2546,6 +2568,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_HOGDescriptor(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { HOGDescriptor, crate::objdetect::HOGDescriptorTraitConst, as_raw_HOGDescriptor, crate::objdetect::HOGDescriptorTrait, as_raw_mut_HOGDescriptor }
+	
 	impl HOGDescriptor {
 		/// Creates the HOG descriptor and detector with default parameters.
 		/// 
@@ -2880,6 +2904,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_QRCodeDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QRCodeDetector, crate::objdetect::QRCodeDetectorTraitConst, as_raw_QRCodeDetector, crate::objdetect::QRCodeDetectorTrait, as_raw_mut_QRCodeDetector }
+	
 	impl QRCodeDetector {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::QRCodeDetector> {
@@ -2959,6 +2985,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_QRCodeEncoder(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QRCodeEncoder, crate::objdetect::QRCodeEncoderTraitConst, as_raw_QRCodeEncoder, crate::objdetect::QRCodeEncoderTrait, as_raw_mut_QRCodeEncoder }
+	
 	impl QRCodeEncoder {
 		/// Constructor
 		/// ## Parameters
@@ -3068,6 +3096,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_SimilarRects(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SimilarRects, crate::objdetect::SimilarRectsTraitConst, as_raw_SimilarRects, crate::objdetect::SimilarRectsTrait, as_raw_mut_SimilarRects }
+	
 	impl SimilarRects {
 		#[inline]
 		pub fn new(_eps: f64) -> Result<crate::objdetect::SimilarRects> {
@@ -3291,6 +3321,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ArucoDetector, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::objdetect::ArucoDetectorTraitConst for ArucoDetector {
 		#[inline] fn as_raw_ArucoDetector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3299,6 +3331,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_ArucoDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ArucoDetector, crate::objdetect::ArucoDetectorTraitConst, as_raw_ArucoDetector, crate::objdetect::ArucoDetectorTrait, as_raw_mut_ArucoDetector }
+	
 	impl ArucoDetector {
 		/// Basic ArucoDetector constructor
 		/// 
@@ -3469,6 +3503,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Board(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Board, crate::objdetect::BoardTraitConst, as_raw_Board, crate::objdetect::BoardTrait, as_raw_mut_Board }
+	
 	impl Board {
 		/// Common Board constructor
 		/// 
@@ -3630,6 +3666,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Board(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CharucoBoard, crate::objdetect::BoardTraitConst, as_raw_Board, crate::objdetect::BoardTrait, as_raw_mut_Board }
+	
 	impl crate::objdetect::CharucoBoardTraitConst for CharucoBoard {
 		#[inline] fn as_raw_CharucoBoard(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3638,6 +3676,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_CharucoBoard(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CharucoBoard, crate::objdetect::CharucoBoardTraitConst, as_raw_CharucoBoard, crate::objdetect::CharucoBoardTrait, as_raw_mut_CharucoBoard }
+	
 	impl CharucoBoard {
 		/// CharucoBoard constructor
 		/// 
@@ -3872,6 +3912,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CharucoDetector, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::objdetect::CharucoDetectorTraitConst for CharucoDetector {
 		#[inline] fn as_raw_CharucoDetector(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3880,6 +3922,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_CharucoDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CharucoDetector, crate::objdetect::CharucoDetectorTraitConst, as_raw_CharucoDetector, crate::objdetect::CharucoDetectorTrait, as_raw_mut_CharucoDetector }
+	
 	impl CharucoDetector {
 		/// Basic CharucoDetector constructor
 		/// 
@@ -4001,6 +4045,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_CharucoParameters(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CharucoParameters, crate::objdetect::CharucoParametersTraitConst, as_raw_CharucoParameters, crate::objdetect::CharucoParametersTrait, as_raw_mut_CharucoParameters }
+	
 	impl CharucoParameters {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::CharucoParameters> {
@@ -4583,6 +4629,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_DetectorParameters(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DetectorParameters, crate::objdetect::DetectorParametersTraitConst, as_raw_DetectorParameters, crate::objdetect::DetectorParametersTrait, as_raw_mut_DetectorParameters }
+	
 	impl DetectorParameters {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::DetectorParameters> {
@@ -4765,6 +4813,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Dictionary(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Dictionary, crate::objdetect::DictionaryTraitConst, as_raw_Dictionary, crate::objdetect::DictionaryTrait, as_raw_mut_Dictionary }
+	
 	impl Dictionary {
 		#[inline]
 		pub fn default() -> Result<crate::objdetect::Dictionary> {
@@ -4887,6 +4937,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_Board(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GridBoard, crate::objdetect::BoardTraitConst, as_raw_Board, crate::objdetect::BoardTrait, as_raw_mut_Board }
+	
 	impl crate::objdetect::GridBoardTraitConst for GridBoard {
 		#[inline] fn as_raw_GridBoard(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4895,6 +4947,8 @@ pub mod objdetect {
 		#[inline] fn as_raw_mut_GridBoard(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GridBoard, crate::objdetect::GridBoardTraitConst, as_raw_GridBoard, crate::objdetect::GridBoardTrait, as_raw_mut_GridBoard }
+	
 	impl GridBoard {
 		/// GridBoard constructor
 		/// 
@@ -1015,6 +1015,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DenseRLOFOpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::video::DenseOpticalFlowTraitConst for DenseRLOFOpticalFlow {
 		#[inline] fn as_raw_DenseOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1023,6 +1025,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_DenseOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DenseRLOFOpticalFlow, crate::video::DenseOpticalFlowTraitConst, as_raw_DenseOpticalFlow, crate::video::DenseOpticalFlowTrait, as_raw_mut_DenseOpticalFlow }
+	
 	impl crate::optflow::DenseRLOFOpticalFlowTraitConst for DenseRLOFOpticalFlow {
 		#[inline] fn as_raw_DenseRLOFOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1031,6 +1035,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_DenseRLOFOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DenseRLOFOpticalFlow, crate::optflow::DenseRLOFOpticalFlowTraitConst, as_raw_DenseRLOFOpticalFlow, crate::optflow::DenseRLOFOpticalFlowTrait, as_raw_mut_DenseRLOFOpticalFlow }
+	
 	impl DenseRLOFOpticalFlow {
 		/// Creates instance of optflow::DenseRLOFOpticalFlow
 		/// 
@@ -1442,6 +1448,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DualTVL1OpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::video::DenseOpticalFlowTraitConst for DualTVL1OpticalFlow {
 		#[inline] fn as_raw_DenseOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1450,6 +1458,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_DenseOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DualTVL1OpticalFlow, crate::video::DenseOpticalFlowTraitConst, as_raw_DenseOpticalFlow, crate::video::DenseOpticalFlowTrait, as_raw_mut_DenseOpticalFlow }
+	
 	impl crate::optflow::DualTVL1OpticalFlowTraitConst for DualTVL1OpticalFlow {
 		#[inline] fn as_raw_DualTVL1OpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1458,6 +1468,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_DualTVL1OpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DualTVL1OpticalFlow, crate::optflow::DualTVL1OpticalFlowTraitConst, as_raw_DualTVL1OpticalFlow, crate::optflow::DualTVL1OpticalFlowTrait, as_raw_mut_DualTVL1OpticalFlow }
+	
 	impl DualTVL1OpticalFlow {
 		/// Creates instance of cv::DualTVL1OpticalFlow
 		/// 
@@ -1524,6 +1536,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_GPCDetails(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCDetails, crate::optflow::GPCDetailsTraitConst, as_raw_GPCDetails, crate::optflow::GPCDetailsTrait, as_raw_mut_GPCDetails }
+	
 	impl GPCDetails {
 		#[inline]
 		pub fn drop_outliers(corr: &mut core::Vector<core::Tuple<(core::Point2i, core::Point2i)>>) -> Result<()> {
@@ -1664,6 +1678,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_GPCPatchDescriptor(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCPatchDescriptor, crate::optflow::GPCPatchDescriptorTraitConst, as_raw_GPCPatchDescriptor, crate::optflow::GPCPatchDescriptorTrait, as_raw_mut_GPCPatchDescriptor }
+	
 	impl GPCPatchDescriptor {
 		/// number of features in a patch descriptor
 		pub const nFeatures: u32 = 18;
@@ -1753,6 +1769,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_GPCPatchSample(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCPatchSample, crate::optflow::GPCPatchSampleTraitConst, as_raw_GPCPatchSample, crate::optflow::GPCPatchSampleTrait, as_raw_mut_GPCPatchSample }
+	
 	impl GPCPatchSample {
 	}
 	
@@ -1853,6 +1871,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_GPCTrainingSamples(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCTrainingSamples, crate::optflow::GPCTrainingSamplesTraitConst, as_raw_GPCTrainingSamples, crate::optflow::GPCTrainingSamplesTrait, as_raw_mut_GPCTrainingSamples }
+	
 	impl GPCTrainingSamples {
 		/// This function can be used to extract samples from a pair of images and a ground truth flow.
 		/// Sizes of all the provided vectors must be equal.
@@ -1974,6 +1994,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCTree, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::optflow::GPCTreeTraitConst for GPCTree {
 		#[inline] fn as_raw_GPCTree(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1982,6 +2004,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_GPCTree(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GPCTree, crate::optflow::GPCTreeTraitConst, as_raw_GPCTree, crate::optflow::GPCTreeTrait, as_raw_mut_GPCTree }
+	
 	impl GPCTree {
 		#[inline]
 		pub fn create() -> Result<core::Ptr<crate::optflow::GPCTree>> {
@@ -2080,6 +2104,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OpticalFlowPCAFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::video::DenseOpticalFlowTraitConst for OpticalFlowPCAFlow {
 		#[inline] fn as_raw_DenseOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2088,6 +2114,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_DenseOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OpticalFlowPCAFlow, crate::video::DenseOpticalFlowTraitConst, as_raw_DenseOpticalFlow, crate::video::DenseOpticalFlowTrait, as_raw_mut_DenseOpticalFlow }
+	
 	impl crate::optflow::OpticalFlowPCAFlowTraitConst for OpticalFlowPCAFlow {
 		#[inline] fn as_raw_OpticalFlowPCAFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2096,6 +2124,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_OpticalFlowPCAFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OpticalFlowPCAFlow, crate::optflow::OpticalFlowPCAFlowTraitConst, as_raw_OpticalFlowPCAFlow, crate::optflow::OpticalFlowPCAFlowTrait, as_raw_mut_OpticalFlowPCAFlow }
+	
 	impl OpticalFlowPCAFlow {
 		/// Creates an instance of PCAFlow algorithm.
 		/// ## Parameters
@@ -2196,6 +2226,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_PCAPrior(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PCAPrior, crate::optflow::PCAPriorTraitConst, as_raw_PCAPrior, crate::optflow::PCAPriorTrait, as_raw_mut_PCAPrior }
+	
 	impl PCAPrior {
 		#[inline]
 		pub fn new(path_to_prior: &str) -> Result<crate::optflow::PCAPrior> {
@@ -2711,6 +2743,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_RLOFOpticalFlowParameter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RLOFOpticalFlowParameter, crate::optflow::RLOFOpticalFlowParameterTraitConst, as_raw_RLOFOpticalFlowParameter, crate::optflow::RLOFOpticalFlowParameterTrait, as_raw_mut_RLOFOpticalFlowParameter }
+	
 	impl RLOFOpticalFlowParameter {
 		#[inline]
 		pub fn default() -> Result<crate::optflow::RLOFOpticalFlowParameter> {
@@ -2841,6 +2875,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseRLOFOpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::video::SparseOpticalFlowTraitConst for SparseRLOFOpticalFlow {
 		#[inline] fn as_raw_SparseOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2849,6 +2885,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_SparseOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseRLOFOpticalFlow, crate::video::SparseOpticalFlowTraitConst, as_raw_SparseOpticalFlow, crate::video::SparseOpticalFlowTrait, as_raw_mut_SparseOpticalFlow }
+	
 	impl crate::optflow::SparseRLOFOpticalFlowTraitConst for SparseRLOFOpticalFlow {
 		#[inline] fn as_raw_SparseRLOFOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2857,6 +2895,8 @@ pub mod optflow {
 		#[inline] fn as_raw_mut_SparseRLOFOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SparseRLOFOpticalFlow, crate::optflow::SparseRLOFOpticalFlowTraitConst, as_raw_SparseRLOFOpticalFlow, crate::optflow::SparseRLOFOpticalFlowTrait, as_raw_mut_SparseRLOFOpticalFlow }
+	
 	impl SparseRLOFOpticalFlow {
 		/// Creates instance of SparseRLOFOpticalFlow
 		/// 
@@ -1001,6 +1001,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AlignExposures, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::AlignExposuresTraitConst for AlignExposures {
 		#[inline] fn as_raw_AlignExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1009,6 +1011,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_AlignExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AlignExposures, crate::photo::AlignExposuresTraitConst, as_raw_AlignExposures, crate::photo::AlignExposuresTrait, as_raw_mut_AlignExposures }
+	
 	impl AlignExposures {
 	}
 	
@@ -1191,6 +1195,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AlignMTB, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::AlignExposuresTraitConst for AlignMTB {
 		#[inline] fn as_raw_AlignExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1199,6 +1205,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_AlignExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AlignMTB, crate::photo::AlignExposuresTraitConst, as_raw_AlignExposures, crate::photo::AlignExposuresTrait, as_raw_mut_AlignExposures }
+	
 	impl crate::photo::AlignMTBTraitConst for AlignMTB {
 		#[inline] fn as_raw_AlignMTB(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1207,6 +1215,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_AlignMTB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { AlignMTB, crate::photo::AlignMTBTraitConst, as_raw_AlignMTB, crate::photo::AlignMTBTrait, as_raw_mut_AlignMTB }
+	
 	impl AlignMTB {
 	}
 	
@@ -1267,6 +1277,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateCRF, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::CalibrateCRFTraitConst for CalibrateCRF {
 		#[inline] fn as_raw_CalibrateCRF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1275,6 +1287,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_CalibrateCRF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateCRF, crate::photo::CalibrateCRFTraitConst, as_raw_CalibrateCRF, crate::photo::CalibrateCRFTrait, as_raw_mut_CalibrateCRF }
+	
 	impl CalibrateCRF {
 	}
 	
@@ -1375,6 +1389,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateDebevec, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::CalibrateCRFTraitConst for CalibrateDebevec {
 		#[inline] fn as_raw_CalibrateCRF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1383,6 +1399,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_CalibrateCRF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateDebevec, crate::photo::CalibrateCRFTraitConst, as_raw_CalibrateCRF, crate::photo::CalibrateCRFTrait, as_raw_mut_CalibrateCRF }
+	
 	impl crate::photo::CalibrateDebevecTraitConst for CalibrateDebevec {
 		#[inline] fn as_raw_CalibrateDebevec(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1391,6 +1409,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_CalibrateDebevec(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateDebevec, crate::photo::CalibrateDebevecTraitConst, as_raw_CalibrateDebevec, crate::photo::CalibrateDebevecTrait, as_raw_mut_CalibrateDebevec }
+	
 	impl CalibrateDebevec {
 	}
 	
@@ -1482,6 +1502,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateRobertson, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::CalibrateCRFTraitConst for CalibrateRobertson {
 		#[inline] fn as_raw_CalibrateCRF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1490,6 +1512,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_CalibrateCRF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateRobertson, crate::photo::CalibrateCRFTraitConst, as_raw_CalibrateCRF, crate::photo::CalibrateCRFTrait, as_raw_mut_CalibrateCRF }
+	
 	impl crate::photo::CalibrateRobertsonTraitConst for CalibrateRobertson {
 		#[inline] fn as_raw_CalibrateRobertson(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1498,6 +1522,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_CalibrateRobertson(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { CalibrateRobertson, crate::photo::CalibrateRobertsonTraitConst, as_raw_CalibrateRobertson, crate::photo::CalibrateRobertsonTrait, as_raw_mut_CalibrateRobertson }
+	
 	impl CalibrateRobertson {
 	}
 	
@@ -1568,6 +1594,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeDebevec, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::MergeExposuresTraitConst for MergeDebevec {
 		#[inline] fn as_raw_MergeExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1576,6 +1604,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeDebevec, crate::photo::MergeExposuresTraitConst, as_raw_MergeExposures, crate::photo::MergeExposuresTrait, as_raw_mut_MergeExposures }
+	
 	impl crate::photo::MergeDebevecTraitConst for MergeDebevec {
 		#[inline] fn as_raw_MergeDebevec(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1584,6 +1614,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeDebevec(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeDebevec, crate::photo::MergeDebevecTraitConst, as_raw_MergeDebevec, crate::photo::MergeDebevecTrait, as_raw_mut_MergeDebevec }
+	
 	impl MergeDebevec {
 	}
 	
@@ -1647,6 +1679,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeExposures, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::MergeExposuresTraitConst for MergeExposures {
 		#[inline] fn as_raw_MergeExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1655,6 +1689,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeExposures, crate::photo::MergeExposuresTraitConst, as_raw_MergeExposures, crate::photo::MergeExposuresTrait, as_raw_mut_MergeExposures }
+	
 	impl MergeExposures {
 	}
 	
@@ -1789,6 +1825,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeMertens, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::MergeExposuresTraitConst for MergeMertens {
 		#[inline] fn as_raw_MergeExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1797,6 +1835,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeMertens, crate::photo::MergeExposuresTraitConst, as_raw_MergeExposures, crate::photo::MergeExposuresTrait, as_raw_mut_MergeExposures }
+	
 	impl crate::photo::MergeMertensTraitConst for MergeMertens {
 		#[inline] fn as_raw_MergeMertens(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1805,6 +1845,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeMertens(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeMertens, crate::photo::MergeMertensTraitConst, as_raw_MergeMertens, crate::photo::MergeMertensTrait, as_raw_mut_MergeMertens }
+	
 	impl MergeMertens {
 	}
 	
@@ -1875,6 +1917,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeRobertson, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::MergeExposuresTraitConst for MergeRobertson {
 		#[inline] fn as_raw_MergeExposures(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1883,6 +1927,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeExposures(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeRobertson, crate::photo::MergeExposuresTraitConst, as_raw_MergeExposures, crate::photo::MergeExposuresTrait, as_raw_mut_MergeExposures }
+	
 	impl crate::photo::MergeRobertsonTraitConst for MergeRobertson {
 		#[inline] fn as_raw_MergeRobertson(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1891,6 +1937,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_MergeRobertson(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MergeRobertson, crate::photo::MergeRobertsonTraitConst, as_raw_MergeRobertson, crate::photo::MergeRobertsonTrait, as_raw_mut_MergeRobertson }
+	
 	impl MergeRobertson {
 	}
 	
@@ -1967,6 +2015,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Tonemap, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::TonemapTraitConst for Tonemap {
 		#[inline] fn as_raw_Tonemap(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1975,6 +2025,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Tonemap(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Tonemap, crate::photo::TonemapTraitConst, as_raw_Tonemap, crate::photo::TonemapTrait, as_raw_mut_Tonemap }
+	
 	impl Tonemap {
 	}
 	
@@ -2061,6 +2113,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDrago, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::TonemapTraitConst for TonemapDrago {
 		#[inline] fn as_raw_Tonemap(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2069,6 +2123,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Tonemap(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDrago, crate::photo::TonemapTraitConst, as_raw_Tonemap, crate::photo::TonemapTrait, as_raw_mut_Tonemap }
+	
 	impl crate::photo::TonemapDragoTraitConst for TonemapDrago {
 		#[inline] fn as_raw_TonemapDrago(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2077,6 +2133,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_TonemapDrago(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDrago, crate::photo::TonemapDragoTraitConst, as_raw_TonemapDrago, crate::photo::TonemapDragoTrait, as_raw_mut_TonemapDrago }
+	
 	impl TonemapDrago {
 	}
 	
@@ -2159,6 +2217,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapMantiuk, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::TonemapTraitConst for TonemapMantiuk {
 		#[inline] fn as_raw_Tonemap(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2167,6 +2227,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Tonemap(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapMantiuk, crate::photo::TonemapTraitConst, as_raw_Tonemap, crate::photo::TonemapTrait, as_raw_mut_Tonemap }
+	
 	impl crate::photo::TonemapMantiukTraitConst for TonemapMantiuk {
 		#[inline] fn as_raw_TonemapMantiuk(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2175,6 +2237,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_TonemapMantiuk(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapMantiuk, crate::photo::TonemapMantiukTraitConst, as_raw_TonemapMantiuk, crate::photo::TonemapMantiukTrait, as_raw_mut_TonemapMantiuk }
+	
 	impl TonemapMantiuk {
 	}
 	
@@ -2276,6 +2340,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapReinhard, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::TonemapTraitConst for TonemapReinhard {
 		#[inline] fn as_raw_Tonemap(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2284,6 +2350,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_Tonemap(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapReinhard, crate::photo::TonemapTraitConst, as_raw_Tonemap, crate::photo::TonemapTrait, as_raw_mut_Tonemap }
+	
 	impl crate::photo::TonemapReinhardTraitConst for TonemapReinhard {
 		#[inline] fn as_raw_TonemapReinhard(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2292,6 +2360,8 @@ pub mod photo {
 		#[inline] fn as_raw_mut_TonemapReinhard(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapReinhard, crate::photo::TonemapReinhardTraitConst, as_raw_TonemapReinhard, crate::photo::TonemapReinhardTrait, as_raw_mut_TonemapReinhard }
+	
 	impl TonemapReinhard {
 	}
 	
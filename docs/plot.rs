@@ -213,6 +213,8 @@ pub mod plot {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Plot2d, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::plot::Plot2dTraitConst for Plot2d {
 		#[inline] fn as_raw_Plot2d(&self) -> *const c_void { self.as_raw() }
 	}
@@ -221,6 +223,8 @@ pub mod plot {
 		#[inline] fn as_raw_mut_Plot2d(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Plot2d, crate::plot::Plot2dTraitConst, as_raw_Plot2d, crate::plot::Plot2dTrait, as_raw_mut_Plot2d }
+	
 	impl Plot2d {
 		/// Creates Plot2d object
 		/// 
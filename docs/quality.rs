@@ -63,6 +63,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityBRISQUE, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualityBRISQUE {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -71,6 +73,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityBRISQUE, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl crate::quality::QualityBRISQUETraitConst for QualityBRISQUE {
 		#[inline] fn as_raw_QualityBRISQUE(&self) -> *const c_void { self.as_raw() }
 	}
@@ -79,6 +83,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBRISQUE(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityBRISQUE, crate::quality::QualityBRISQUETraitConst, as_raw_QualityBRISQUE, crate::quality::QualityBRISQUETrait, as_raw_mut_QualityBRISQUE }
+	
 	impl QualityBRISQUE {
 		/// Create an object which calculates quality
 		/// ## Parameters
@@ -229,6 +235,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityBase, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualityBase {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -237,6 +245,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityBase, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl QualityBase {
 	}
 	
@@ -325,6 +335,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityGMSD, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualityGMSD {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -333,6 +345,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityGMSD, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl crate::quality::QualityGMSDTraitConst for QualityGMSD {
 		#[inline] fn as_raw_QualityGMSD(&self) -> *const c_void { self.as_raw() }
 	}
@@ -341,6 +355,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityGMSD(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityGMSD, crate::quality::QualityGMSDTraitConst, as_raw_QualityGMSD, crate::quality::QualityGMSDTrait, as_raw_mut_QualityGMSD }
+	
 	impl QualityGMSD {
 		/// Create an object which calculates image quality
 		/// ## Parameters
@@ -451,6 +467,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityMSE, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualityMSE {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -459,6 +477,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityMSE, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl crate::quality::QualityMSETraitConst for QualityMSE {
 		#[inline] fn as_raw_QualityMSE(&self) -> *const c_void { self.as_raw() }
 	}
@@ -467,6 +487,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityMSE(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityMSE, crate::quality::QualityMSETraitConst, as_raw_QualityMSE, crate::quality::QualityMSETrait, as_raw_mut_QualityMSE }
+	
 	impl QualityMSE {
 		/// Create an object which calculates quality
 		/// ## Parameters
@@ -599,6 +621,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityPSNR, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualityPSNR {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -607,6 +631,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityPSNR, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl crate::quality::QualityPSNRTraitConst for QualityPSNR {
 		#[inline] fn as_raw_QualityPSNR(&self) -> *const c_void { self.as_raw() }
 	}
@@ -615,6 +641,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityPSNR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualityPSNR, crate::quality::QualityPSNRTraitConst, as_raw_QualityPSNR, crate::quality::QualityPSNRTrait, as_raw_mut_QualityPSNR }
+	
 	impl QualityPSNR {
 		pub const MAX_PIXEL_VALUE_DEFAULT: f64 = 255.;
 		/// Create an object which calculates quality
@@ -734,6 +762,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualitySSIM, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::quality::QualityBaseTraitConst for QualitySSIM {
 		#[inline] fn as_raw_QualityBase(&self) -> *const c_void { self.as_raw() }
 	}
@@ -742,6 +772,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualityBase(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualitySSIM, crate::quality::QualityBaseTraitConst, as_raw_QualityBase, crate::quality::QualityBaseTrait, as_raw_mut_QualityBase }
+	
 	impl crate::quality::QualitySSIMTraitConst for QualitySSIM {
 		#[inline] fn as_raw_QualitySSIM(&self) -> *const c_void { self.as_raw() }
 	}
@@ -750,6 +782,8 @@ pub mod quality {
 		#[inline] fn as_raw_mut_QualitySSIM(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QualitySSIM, crate::quality::QualitySSIMTraitConst, as_raw_QualitySSIM, crate::quality::QualitySSIMTrait, as_raw_mut_QualitySSIM }
+	
 	impl QualitySSIM {
 		/// Create an object which calculates quality
 		/// ## Parameters
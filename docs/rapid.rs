@@ -239,6 +239,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GOSTracker, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rapid::TrackerTraitConst for GOSTracker {
 		#[inline] fn as_raw_Tracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -247,6 +249,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GOSTracker, crate::rapid::TrackerTraitConst, as_raw_Tracker, crate::rapid::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl crate::rapid::GOSTrackerTraitConst for GOSTracker {
 		#[inline] fn as_raw_GOSTracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -255,6 +259,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_GOSTracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GOSTracker, crate::rapid::GOSTrackerTraitConst, as_raw_GOSTracker, crate::rapid::GOSTrackerTrait, as_raw_mut_GOSTracker }
+	
 	impl GOSTracker {
 		/// ## C++ default parameters
 		/// * hist_bins: 4
@@ -313,6 +319,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OLSTracker, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rapid::TrackerTraitConst for OLSTracker {
 		#[inline] fn as_raw_Tracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -321,6 +329,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OLSTracker, crate::rapid::TrackerTraitConst, as_raw_Tracker, crate::rapid::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl crate::rapid::OLSTrackerTraitConst for OLSTracker {
 		#[inline] fn as_raw_OLSTracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -329,6 +339,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_OLSTracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OLSTracker, crate::rapid::OLSTrackerTraitConst, as_raw_OLSTracker, crate::rapid::OLSTrackerTrait, as_raw_mut_OLSTracker }
+	
 	impl OLSTracker {
 		/// ## C++ default parameters
 		/// * hist_bins: 8
@@ -386,6 +398,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Rapid, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rapid::TrackerTraitConst for Rapid {
 		#[inline] fn as_raw_Tracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -394,6 +408,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Rapid, crate::rapid::TrackerTraitConst, as_raw_Tracker, crate::rapid::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl crate::rapid::RapidTraitConst for Rapid {
 		#[inline] fn as_raw_Rapid(&self) -> *const c_void { self.as_raw() }
 	}
@@ -402,6 +418,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Rapid(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Rapid, crate::rapid::RapidTraitConst, as_raw_Rapid, crate::rapid::RapidTrait, as_raw_mut_Rapid }
+	
 	impl Rapid {
 		#[inline]
 		pub fn create(pts3d: &impl core::ToInputArray, tris: &impl core::ToInputArray) -> Result<core::Ptr<crate::rapid::Rapid>> {
@@ -480,6 +498,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Tracker, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rapid::TrackerTraitConst for Tracker {
 		#[inline] fn as_raw_Tracker(&self) -> *const c_void { self.as_raw() }
 	}
@@ -488,6 +508,8 @@ pub mod rapid {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Tracker, crate::rapid::TrackerTraitConst, as_raw_Tracker, crate::rapid::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl Tracker {
 	}
 	
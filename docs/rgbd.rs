@@ -518,6 +518,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_ColoredKinfu_ColoredKinFu(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColoredKinfu_ColoredKinFu, crate::rgbd::ColoredKinfu_ColoredKinFuTraitConst, as_raw_ColoredKinfu_ColoredKinFu, crate::rgbd::ColoredKinfu_ColoredKinFuTrait, as_raw_mut_ColoredKinfu_ColoredKinFu }
+	
 	impl ColoredKinfu_ColoredKinFu {
 		#[inline]
 		pub fn create(_params: &core::Ptr<crate::rgbd::ColoredKinfu_Params>) -> Result<core::Ptr<crate::rgbd::ColoredKinfu_ColoredKinFu>> {
@@ -951,6 +953,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_ColoredKinfu_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ColoredKinfu_Params, crate::rgbd::ColoredKinfu_ParamsTraitConst, as_raw_ColoredKinfu_Params, crate::rgbd::ColoredKinfu_ParamsTrait, as_raw_mut_ColoredKinfu_Params }
+	
 	impl ColoredKinfu_Params {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::ColoredKinfu_Params> {
@@ -1237,6 +1241,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Dynafu_DynaFu(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Dynafu_DynaFu, crate::rgbd::Dynafu_DynaFuTraitConst, as_raw_Dynafu_DynaFu, crate::rgbd::Dynafu_DynaFuTrait, as_raw_mut_Dynafu_DynaFu }
+	
 	impl Dynafu_DynaFu {
 		#[inline]
 		pub fn create(_params: &core::Ptr<crate::rgbd::Kinfu_Params>) -> Result<core::Ptr<crate::rgbd::Dynafu_DynaFu>> {
@@ -1582,6 +1588,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Kinfu_KinFu(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kinfu_KinFu, crate::rgbd::Kinfu_KinFuTraitConst, as_raw_Kinfu_KinFu, crate::rgbd::Kinfu_KinFuTrait, as_raw_mut_Kinfu_KinFu }
+	
 	impl Kinfu_KinFu {
 		#[inline]
 		pub fn create(_params: &core::Ptr<crate::rgbd::Kinfu_Params>) -> Result<core::Ptr<crate::rgbd::Kinfu_KinFu>> {
@@ -2001,6 +2009,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Kinfu_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kinfu_Params, crate::rgbd::Kinfu_ParamsTraitConst, as_raw_Kinfu_Params, crate::rgbd::Kinfu_ParamsTrait, as_raw_mut_Kinfu_Params }
+	
 	impl Kinfu_Params {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::Kinfu_Params> {
@@ -2245,6 +2255,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Kinfu_Volume(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kinfu_Volume, crate::rgbd::Kinfu_VolumeTraitConst, as_raw_Kinfu_Volume, crate::rgbd::Kinfu_VolumeTrait, as_raw_mut_Kinfu_Volume }
+	
 	impl Kinfu_Volume {
 	}
 	
@@ -2441,6 +2453,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Kinfu_VolumeParams(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kinfu_VolumeParams, crate::rgbd::Kinfu_VolumeParamsTraitConst, as_raw_Kinfu_VolumeParams, crate::rgbd::Kinfu_VolumeParamsTrait, as_raw_mut_Kinfu_VolumeParams }
+	
 	impl Kinfu_VolumeParams {
 		/// Default set of parameters that provide higher quality reconstruction
 		/// at the cost of slow performance.
@@ -2635,6 +2649,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Kinfu_Detail_PoseGraph(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Kinfu_Detail_PoseGraph, crate::rgbd::Kinfu_Detail_PoseGraphTraitConst, as_raw_Kinfu_Detail_PoseGraph, crate::rgbd::Kinfu_Detail_PoseGraphTrait, as_raw_mut_Kinfu_Detail_PoseGraph }
+	
 	impl Kinfu_Detail_PoseGraph {
 		#[inline]
 		pub fn create() -> Result<core::Ptr<crate::rgbd::Kinfu_Detail_PoseGraph>> {
@@ -2803,6 +2819,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_LargeKinfu(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LargeKinfu, crate::rgbd::LargeKinfuTraitConst, as_raw_LargeKinfu, crate::rgbd::LargeKinfuTrait, as_raw_mut_LargeKinfu }
+	
 	impl LargeKinfu {
 		#[inline]
 		pub fn create(_params: &core::Ptr<crate::rgbd::Params>) -> Result<core::Ptr<crate::rgbd::LargeKinfu>> {
@@ -3084,6 +3102,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Params, crate::rgbd::ParamsTraitConst, as_raw_Params, crate::rgbd::ParamsTrait, as_raw_mut_Params }
+	
 	impl Params {
 		/// Default parameters
 		/// A set of parameters which provides better model quality, can be very slow.
@@ -3225,6 +3245,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Modality(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_ColorGradient, crate::rgbd::Linemod_ModalityTraitConst, as_raw_Linemod_Modality, crate::rgbd::Linemod_ModalityTrait, as_raw_mut_Linemod_Modality }
+	
 	impl crate::rgbd::Linemod_ColorGradientTraitConst for Linemod_ColorGradient {
 		#[inline] fn as_raw_Linemod_ColorGradient(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3233,6 +3255,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_ColorGradient(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_ColorGradient, crate::rgbd::Linemod_ColorGradientTraitConst, as_raw_Linemod_ColorGradient, crate::rgbd::Linemod_ColorGradientTrait, as_raw_mut_Linemod_ColorGradient }
+	
 	impl Linemod_ColorGradient {
 		/// \brief Default constructor. Uses reasonable default parameter values.
 		#[inline]
@@ -3386,6 +3410,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Modality(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_DepthNormal, crate::rgbd::Linemod_ModalityTraitConst, as_raw_Linemod_Modality, crate::rgbd::Linemod_ModalityTrait, as_raw_mut_Linemod_Modality }
+	
 	impl crate::rgbd::Linemod_DepthNormalTraitConst for Linemod_DepthNormal {
 		#[inline] fn as_raw_Linemod_DepthNormal(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3394,6 +3420,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_DepthNormal(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_DepthNormal, crate::rgbd::Linemod_DepthNormalTraitConst, as_raw_Linemod_DepthNormal, crate::rgbd::Linemod_DepthNormalTrait, as_raw_mut_Linemod_DepthNormal }
+	
 	impl Linemod_DepthNormal {
 		/// \brief Default constructor. Uses reasonable default parameter values.
 		#[inline]
@@ -3686,6 +3714,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Detector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_Detector, crate::rgbd::Linemod_DetectorTraitConst, as_raw_Linemod_Detector, crate::rgbd::Linemod_DetectorTrait, as_raw_mut_Linemod_Detector }
+	
 	impl Linemod_Detector {
 		/// \brief Empty constructor, initialize with read().
 		#[inline]
@@ -3886,6 +3916,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Match(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_Match, crate::rgbd::Linemod_MatchTraitConst, as_raw_Linemod_Match, crate::rgbd::Linemod_MatchTrait, as_raw_mut_Linemod_Match }
+	
 	impl Linemod_Match {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::Linemod_Match> {
@@ -4003,6 +4035,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Modality(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_Modality, crate::rgbd::Linemod_ModalityTraitConst, as_raw_Linemod_Modality, crate::rgbd::Linemod_ModalityTrait, as_raw_mut_Linemod_Modality }
+	
 	impl Linemod_Modality {
 		/// \brief Create modality by name.
 		/// 
@@ -4111,6 +4145,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_QuantizedPyramid(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_QuantizedPyramid, crate::rgbd::Linemod_QuantizedPyramidTraitConst, as_raw_Linemod_QuantizedPyramid, crate::rgbd::Linemod_QuantizedPyramidTrait, as_raw_mut_Linemod_QuantizedPyramid }
+	
 	impl Linemod_QuantizedPyramid {
 	}
 	
@@ -4217,6 +4253,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Linemod_Template(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Linemod_Template, crate::rgbd::Linemod_TemplateTraitConst, as_raw_Linemod_Template, crate::rgbd::Linemod_TemplateTrait, as_raw_mut_Linemod_Template }
+	
 	impl Linemod_Template {
 		fn default() -> Self {
 			extern "C" { fn cv_Linemod_Template_default_new() -> extern_receive!(Linemod_Template: 'static); }
@@ -4358,6 +4396,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DepthCleaner, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::DepthCleanerTraitConst for DepthCleaner {
 		#[inline] fn as_raw_DepthCleaner(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4366,6 +4406,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_DepthCleaner(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { DepthCleaner, crate::rgbd::DepthCleanerTraitConst, as_raw_DepthCleaner, crate::rgbd::DepthCleanerTrait, as_raw_mut_DepthCleaner }
+	
 	impl DepthCleaner {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::DepthCleaner> {
@@ -4614,6 +4656,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FastICPOdometry, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::OdometryTraitConst for FastICPOdometry {
 		#[inline] fn as_raw_Odometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4622,6 +4666,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Odometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FastICPOdometry, crate::rgbd::OdometryTraitConst, as_raw_Odometry, crate::rgbd::OdometryTrait, as_raw_mut_Odometry }
+	
 	impl crate::rgbd::FastICPOdometryTraitConst for FastICPOdometry {
 		#[inline] fn as_raw_FastICPOdometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4630,6 +4676,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_FastICPOdometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { FastICPOdometry, crate::rgbd::FastICPOdometryTraitConst, as_raw_FastICPOdometry, crate::rgbd::FastICPOdometryTrait, as_raw_mut_FastICPOdometry }
+	
 	impl FastICPOdometry {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::FastICPOdometry> {
@@ -4912,6 +4960,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ICPOdometry, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::OdometryTraitConst for ICPOdometry {
 		#[inline] fn as_raw_Odometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4920,6 +4970,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Odometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ICPOdometry, crate::rgbd::OdometryTraitConst, as_raw_Odometry, crate::rgbd::OdometryTrait, as_raw_mut_Odometry }
+	
 	impl crate::rgbd::ICPOdometryTraitConst for ICPOdometry {
 		#[inline] fn as_raw_ICPOdometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4928,6 +4980,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_ICPOdometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ICPOdometry, crate::rgbd::ICPOdometryTraitConst, as_raw_ICPOdometry, crate::rgbd::ICPOdometryTrait, as_raw_mut_ICPOdometry }
+	
 	impl ICPOdometry {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::ICPOdometry> {
@@ -5132,6 +5186,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Odometry, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::OdometryTraitConst for Odometry {
 		#[inline] fn as_raw_Odometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5140,6 +5196,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Odometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Odometry, crate::rgbd::OdometryTraitConst, as_raw_Odometry, crate::rgbd::OdometryTrait, as_raw_mut_Odometry }
+	
 	impl Odometry {
 		#[inline]
 		pub fn default_min_depth() -> Result<f32> {
@@ -5392,6 +5450,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdFrame(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OdometryFrame, crate::rgbd::RgbdFrameTraitConst, as_raw_RgbdFrame, crate::rgbd::RgbdFrameTrait, as_raw_mut_RgbdFrame }
+	
 	impl crate::rgbd::OdometryFrameTraitConst for OdometryFrame {
 		#[inline] fn as_raw_OdometryFrame(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5400,6 +5460,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_OdometryFrame(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OdometryFrame, crate::rgbd::OdometryFrameTraitConst, as_raw_OdometryFrame, crate::rgbd::OdometryFrameTrait, as_raw_mut_OdometryFrame }
+	
 	impl OdometryFrame {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::OdometryFrame> {
@@ -5555,6 +5617,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdFrame(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdFrame, crate::rgbd::RgbdFrameTraitConst, as_raw_RgbdFrame, crate::rgbd::RgbdFrameTrait, as_raw_mut_RgbdFrame }
+	
 	impl RgbdFrame {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::RgbdFrame> {
@@ -5839,6 +5903,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdICPOdometry, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::OdometryTraitConst for RgbdICPOdometry {
 		#[inline] fn as_raw_Odometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5847,6 +5913,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Odometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdICPOdometry, crate::rgbd::OdometryTraitConst, as_raw_Odometry, crate::rgbd::OdometryTrait, as_raw_mut_Odometry }
+	
 	impl crate::rgbd::RgbdICPOdometryTraitConst for RgbdICPOdometry {
 		#[inline] fn as_raw_RgbdICPOdometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -5855,6 +5923,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdICPOdometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdICPOdometry, crate::rgbd::RgbdICPOdometryTraitConst, as_raw_RgbdICPOdometry, crate::rgbd::RgbdICPOdometryTrait, as_raw_mut_RgbdICPOdometry }
+	
 	impl RgbdICPOdometry {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::RgbdICPOdometry> {
@@ -6100,6 +6170,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdNormals, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::RgbdNormalsTraitConst for RgbdNormals {
 		#[inline] fn as_raw_RgbdNormals(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6108,6 +6180,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdNormals(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdNormals, crate::rgbd::RgbdNormalsTraitConst, as_raw_RgbdNormals, crate::rgbd::RgbdNormalsTrait, as_raw_mut_RgbdNormals }
+	
 	impl RgbdNormals {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::RgbdNormals> {
@@ -6390,6 +6464,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdOdometry, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::OdometryTraitConst for RgbdOdometry {
 		#[inline] fn as_raw_Odometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6398,6 +6474,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Odometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdOdometry, crate::rgbd::OdometryTraitConst, as_raw_Odometry, crate::rgbd::OdometryTrait, as_raw_mut_Odometry }
+	
 	impl crate::rgbd::RgbdOdometryTraitConst for RgbdOdometry {
 		#[inline] fn as_raw_RgbdOdometry(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6406,6 +6484,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdOdometry(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdOdometry, crate::rgbd::RgbdOdometryTraitConst, as_raw_RgbdOdometry, crate::rgbd::RgbdOdometryTrait, as_raw_mut_RgbdOdometry }
+	
 	impl RgbdOdometry {
 		#[inline]
 		pub fn default() -> Result<crate::rgbd::RgbdOdometry> {
@@ -6673,6 +6753,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdPlane, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::rgbd::RgbdPlaneTraitConst for RgbdPlane {
 		#[inline] fn as_raw_RgbdPlane(&self) -> *const c_void { self.as_raw() }
 	}
@@ -6681,6 +6763,8 @@ pub mod rgbd {
 		#[inline] fn as_raw_mut_RgbdPlane(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { RgbdPlane, crate::rgbd::RgbdPlaneTraitConst, as_raw_RgbdPlane, crate::rgbd::RgbdPlaneTrait, as_raw_mut_RgbdPlane }
+	
 	impl RgbdPlane {
 		/// ## C++ default parameters
 		/// * method: RgbdPlane::RGBD_PLANE_METHOD_DEFAULT
@@ -74,6 +74,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliency, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for MotionSaliency {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -82,6 +84,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliency, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::MotionSaliencyTraitConst for MotionSaliency {
 		#[inline] fn as_raw_MotionSaliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -90,6 +94,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_MotionSaliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliency, crate::saliency::MotionSaliencyTraitConst, as_raw_MotionSaliency, crate::saliency::MotionSaliencyTrait, as_raw_mut_MotionSaliency }
+	
 	impl MotionSaliency {
 	}
 	
@@ -213,6 +219,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliencyBinWangApr2014, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::MotionSaliencyTraitConst for MotionSaliencyBinWangApr2014 {
 		#[inline] fn as_raw_MotionSaliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -221,6 +229,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_MotionSaliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliencyBinWangApr2014, crate::saliency::MotionSaliencyTraitConst, as_raw_MotionSaliency, crate::saliency::MotionSaliencyTrait, as_raw_mut_MotionSaliency }
+	
 	impl crate::saliency::SaliencyTraitConst for MotionSaliencyBinWangApr2014 {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -229,6 +239,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliencyBinWangApr2014, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::MotionSaliencyBinWangApr2014TraitConst for MotionSaliencyBinWangApr2014 {
 		#[inline] fn as_raw_MotionSaliencyBinWangApr2014(&self) -> *const c_void { self.as_raw() }
 	}
@@ -237,6 +249,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_MotionSaliencyBinWangApr2014(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MotionSaliencyBinWangApr2014, crate::saliency::MotionSaliencyBinWangApr2014TraitConst, as_raw_MotionSaliencyBinWangApr2014, crate::saliency::MotionSaliencyBinWangApr2014Trait, as_raw_mut_MotionSaliencyBinWangApr2014 }
+	
 	impl MotionSaliencyBinWangApr2014 {
 		#[inline]
 		pub fn default() -> Result<crate::saliency::MotionSaliencyBinWangApr2014> {
@@ -299,6 +313,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Objectness, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for Objectness {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -307,6 +323,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Objectness, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::ObjectnessTraitConst for Objectness {
 		#[inline] fn as_raw_Objectness(&self) -> *const c_void { self.as_raw() }
 	}
@@ -315,6 +333,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Objectness(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Objectness, crate::saliency::ObjectnessTraitConst, as_raw_Objectness, crate::saliency::ObjectnessTrait, as_raw_mut_Objectness }
+	
 	impl Objectness {
 	}
 	
@@ -488,6 +508,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ObjectnessBING, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::ObjectnessTraitConst for ObjectnessBING {
 		#[inline] fn as_raw_Objectness(&self) -> *const c_void { self.as_raw() }
 	}
@@ -496,6 +518,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Objectness(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ObjectnessBING, crate::saliency::ObjectnessTraitConst, as_raw_Objectness, crate::saliency::ObjectnessTrait, as_raw_mut_Objectness }
+	
 	impl crate::saliency::SaliencyTraitConst for ObjectnessBING {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -504,6 +528,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ObjectnessBING, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::ObjectnessBINGTraitConst for ObjectnessBING {
 		#[inline] fn as_raw_ObjectnessBING(&self) -> *const c_void { self.as_raw() }
 	}
@@ -512,6 +538,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_ObjectnessBING(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ObjectnessBING, crate::saliency::ObjectnessBINGTraitConst, as_raw_ObjectnessBING, crate::saliency::ObjectnessBINGTrait, as_raw_mut_ObjectnessBING }
+	
 	impl ObjectnessBING {
 		#[inline]
 		pub fn default() -> Result<crate::saliency::ObjectnessBING> {
@@ -589,6 +617,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Saliency, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for Saliency {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -597,6 +627,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Saliency, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl Saliency {
 	}
 	
@@ -665,6 +697,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliency, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for StaticSaliency {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -673,6 +707,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliency, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::StaticSaliencyTraitConst for StaticSaliency {
 		#[inline] fn as_raw_StaticSaliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -681,6 +717,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_StaticSaliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliency, crate::saliency::StaticSaliencyTraitConst, as_raw_StaticSaliency, crate::saliency::StaticSaliencyTrait, as_raw_mut_StaticSaliency }
+	
 	impl StaticSaliency {
 	}
 	
@@ -741,6 +779,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencyFineGrained, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for StaticSaliencyFineGrained {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -749,6 +789,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencyFineGrained, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::StaticSaliencyTraitConst for StaticSaliencyFineGrained {
 		#[inline] fn as_raw_StaticSaliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -757,6 +799,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_StaticSaliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencyFineGrained, crate::saliency::StaticSaliencyTraitConst, as_raw_StaticSaliency, crate::saliency::StaticSaliencyTrait, as_raw_mut_StaticSaliency }
+	
 	impl crate::saliency::StaticSaliencyFineGrainedTraitConst for StaticSaliencyFineGrained {
 		#[inline] fn as_raw_StaticSaliencyFineGrained(&self) -> *const c_void { self.as_raw() }
 	}
@@ -765,6 +809,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_StaticSaliencyFineGrained(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencyFineGrained, crate::saliency::StaticSaliencyFineGrainedTraitConst, as_raw_StaticSaliencyFineGrained, crate::saliency::StaticSaliencyFineGrainedTrait, as_raw_mut_StaticSaliencyFineGrained }
+	
 	impl StaticSaliencyFineGrained {
 		#[inline]
 		pub fn default() -> Result<crate::saliency::StaticSaliencyFineGrained> {
@@ -897,6 +943,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencySpectralResidual, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::saliency::SaliencyTraitConst for StaticSaliencySpectralResidual {
 		#[inline] fn as_raw_Saliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -905,6 +953,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_Saliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencySpectralResidual, crate::saliency::SaliencyTraitConst, as_raw_Saliency, crate::saliency::SaliencyTrait, as_raw_mut_Saliency }
+	
 	impl crate::saliency::StaticSaliencyTraitConst for StaticSaliencySpectralResidual {
 		#[inline] fn as_raw_StaticSaliency(&self) -> *const c_void { self.as_raw() }
 	}
@@ -913,6 +963,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_StaticSaliency(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencySpectralResidual, crate::saliency::StaticSaliencyTraitConst, as_raw_StaticSaliency, crate::saliency::StaticSaliencyTrait, as_raw_mut_StaticSaliency }
+	
 	impl crate::saliency::StaticSaliencySpectralResidualTraitConst for StaticSaliencySpectralResidual {
 		#[inline] fn as_raw_StaticSaliencySpectralResidual(&self) -> *const c_void { self.as_raw() }
 	}
@@ -921,6 +973,8 @@ pub mod saliency {
 		#[inline] fn as_raw_mut_StaticSaliencySpectralResidual(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StaticSaliencySpectralResidual, crate::saliency::StaticSaliencySpectralResidualTraitConst, as_raw_StaticSaliencySpectralResidual, crate::saliency::StaticSaliencySpectralResidualTrait, as_raw_mut_StaticSaliencySpectralResidual }
+	
 	impl StaticSaliencySpectralResidual {
 		#[inline]
 		pub fn default() -> Result<crate::saliency::StaticSaliencySpectralResidual> {
@@ -374,6 +374,8 @@ pub mod stereo {
 		#[inline] fn as_raw_mut_QuasiDenseStereo(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { QuasiDenseStereo, crate::stereo::QuasiDenseStereoTraitConst, as_raw_QuasiDenseStereo, crate::stereo::QuasiDenseStereoTrait, as_raw_mut_QuasiDenseStereo }
+	
 	impl QuasiDenseStereo {
 		/// ## C++ default parameters
 		/// * param_filepath: cv::String()
@@ -158,6 +158,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayCodePattern, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::structured_light::StructuredLightPatternTraitConst for GrayCodePattern {
 		#[inline] fn as_raw_StructuredLightPattern(&self) -> *const c_void { self.as_raw() }
 	}
@@ -166,6 +168,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_StructuredLightPattern(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayCodePattern, crate::structured_light::StructuredLightPatternTraitConst, as_raw_StructuredLightPattern, crate::structured_light::StructuredLightPatternTrait, as_raw_mut_StructuredLightPattern }
+	
 	impl crate::structured_light::GrayCodePatternTraitConst for GrayCodePattern {
 		#[inline] fn as_raw_GrayCodePattern(&self) -> *const c_void { self.as_raw() }
 	}
@@ -174,6 +178,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_GrayCodePattern(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayCodePattern, crate::structured_light::GrayCodePatternTraitConst, as_raw_GrayCodePattern, crate::structured_light::GrayCodePatternTrait, as_raw_mut_GrayCodePattern }
+	
 	impl GrayCodePattern {
 		/// Constructor
 		/// ## Parameters
@@ -269,6 +275,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_GrayCodePattern_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayCodePattern_Params, crate::structured_light::GrayCodePattern_ParamsTraitConst, as_raw_GrayCodePattern_Params, crate::structured_light::GrayCodePattern_ParamsTrait, as_raw_mut_GrayCodePattern_Params }
+	
 	impl GrayCodePattern_Params {
 		#[inline]
 		pub fn default() -> Result<crate::structured_light::GrayCodePattern_Params> {
@@ -400,6 +408,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinusoidalPattern, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::structured_light::StructuredLightPatternTraitConst for SinusoidalPattern {
 		#[inline] fn as_raw_StructuredLightPattern(&self) -> *const c_void { self.as_raw() }
 	}
@@ -408,6 +418,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_StructuredLightPattern(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinusoidalPattern, crate::structured_light::StructuredLightPatternTraitConst, as_raw_StructuredLightPattern, crate::structured_light::StructuredLightPatternTrait, as_raw_mut_StructuredLightPattern }
+	
 	impl crate::structured_light::SinusoidalPatternTraitConst for SinusoidalPattern {
 		#[inline] fn as_raw_SinusoidalPattern(&self) -> *const c_void { self.as_raw() }
 	}
@@ -416,6 +428,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_SinusoidalPattern(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinusoidalPattern, crate::structured_light::SinusoidalPatternTraitConst, as_raw_SinusoidalPattern, crate::structured_light::SinusoidalPatternTrait, as_raw_mut_SinusoidalPattern }
+	
 	impl SinusoidalPattern {
 		/// Constructor.
 		/// ## Parameters
@@ -592,6 +606,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_SinusoidalPattern_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SinusoidalPattern_Params, crate::structured_light::SinusoidalPattern_ParamsTraitConst, as_raw_SinusoidalPattern_Params, crate::structured_light::SinusoidalPattern_ParamsTrait, as_raw_mut_SinusoidalPattern_Params }
+	
 	impl SinusoidalPattern_Params {
 		#[inline]
 		pub fn default() -> Result<crate::structured_light::SinusoidalPattern_Params> {
@@ -683,6 +699,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StructuredLightPattern, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::structured_light::StructuredLightPatternTraitConst for StructuredLightPattern {
 		#[inline] fn as_raw_StructuredLightPattern(&self) -> *const c_void { self.as_raw() }
 	}
@@ -691,6 +709,8 @@ pub mod structured_light {
 		#[inline] fn as_raw_mut_StructuredLightPattern(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { StructuredLightPattern, crate::structured_light::StructuredLightPatternTraitConst, as_raw_StructuredLightPattern, crate::structured_light::StructuredLightPatternTrait, as_raw_mut_StructuredLightPattern }
+	
 	impl StructuredLightPattern {
 	}
 	
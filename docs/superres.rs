@@ -331,6 +331,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_BroxOpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_DenseOpticalFlowExtTraitConst for Superres_BroxOpticalFlow {
 		#[inline] fn as_raw_Superres_DenseOpticalFlowExt(&self) -> *const c_void { self.as_raw() }
 	}
@@ -339,6 +341,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DenseOpticalFlowExt(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_BroxOpticalFlow, crate::superres::Superres_DenseOpticalFlowExtTraitConst, as_raw_Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTrait, as_raw_mut_Superres_DenseOpticalFlowExt }
+	
 	impl crate::superres::Superres_BroxOpticalFlowTraitConst for Superres_BroxOpticalFlow {
 		#[inline] fn as_raw_Superres_BroxOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -347,6 +351,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_BroxOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_BroxOpticalFlow, crate::superres::Superres_BroxOpticalFlowTraitConst, as_raw_Superres_BroxOpticalFlow, crate::superres::Superres_BroxOpticalFlowTrait, as_raw_mut_Superres_BroxOpticalFlow }
+	
 	impl Superres_BroxOpticalFlow {
 	}
 	
@@ -412,6 +418,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_DenseOpticalFlowExt, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_DenseOpticalFlowExtTraitConst for Superres_DenseOpticalFlowExt {
 		#[inline] fn as_raw_Superres_DenseOpticalFlowExt(&self) -> *const c_void { self.as_raw() }
 	}
@@ -420,6 +428,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DenseOpticalFlowExt(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTraitConst, as_raw_Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTrait, as_raw_mut_Superres_DenseOpticalFlowExt }
+	
 	impl Superres_DenseOpticalFlowExt {
 	}
 	
@@ -637,6 +647,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_DualTVL1OpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_DenseOpticalFlowExtTraitConst for Superres_DualTVL1OpticalFlow {
 		#[inline] fn as_raw_Superres_DenseOpticalFlowExt(&self) -> *const c_void { self.as_raw() }
 	}
@@ -645,6 +657,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DenseOpticalFlowExt(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_DualTVL1OpticalFlow, crate::superres::Superres_DenseOpticalFlowExtTraitConst, as_raw_Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTrait, as_raw_mut_Superres_DenseOpticalFlowExt }
+	
 	impl crate::superres::Superres_DualTVL1OpticalFlowTraitConst for Superres_DualTVL1OpticalFlow {
 		#[inline] fn as_raw_Superres_DualTVL1OpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -653,6 +667,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DualTVL1OpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_DualTVL1OpticalFlow, crate::superres::Superres_DualTVL1OpticalFlowTraitConst, as_raw_Superres_DualTVL1OpticalFlow, crate::superres::Superres_DualTVL1OpticalFlowTrait, as_raw_mut_Superres_DualTVL1OpticalFlow }
+	
 	impl Superres_DualTVL1OpticalFlow {
 	}
 	
@@ -848,6 +864,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_FarnebackOpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_DenseOpticalFlowExtTraitConst for Superres_FarnebackOpticalFlow {
 		#[inline] fn as_raw_Superres_DenseOpticalFlowExt(&self) -> *const c_void { self.as_raw() }
 	}
@@ -856,6 +874,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DenseOpticalFlowExt(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_FarnebackOpticalFlow, crate::superres::Superres_DenseOpticalFlowExtTraitConst, as_raw_Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTrait, as_raw_mut_Superres_DenseOpticalFlowExt }
+	
 	impl crate::superres::Superres_FarnebackOpticalFlowTraitConst for Superres_FarnebackOpticalFlow {
 		#[inline] fn as_raw_Superres_FarnebackOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -864,6 +884,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_FarnebackOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_FarnebackOpticalFlow, crate::superres::Superres_FarnebackOpticalFlowTraitConst, as_raw_Superres_FarnebackOpticalFlow, crate::superres::Superres_FarnebackOpticalFlowTrait, as_raw_mut_Superres_FarnebackOpticalFlow }
+	
 	impl Superres_FarnebackOpticalFlow {
 	}
 	
@@ -924,6 +946,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_FrameSource(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_FrameSource, crate::superres::Superres_FrameSourceTraitConst, as_raw_Superres_FrameSource, crate::superres::Superres_FrameSourceTrait, as_raw_mut_Superres_FrameSource }
+	
 	impl Superres_FrameSource {
 	}
 	
@@ -1029,6 +1053,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_PyrLKOpticalFlow, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_DenseOpticalFlowExtTraitConst for Superres_PyrLKOpticalFlow {
 		#[inline] fn as_raw_Superres_DenseOpticalFlowExt(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1037,6 +1063,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_DenseOpticalFlowExt(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_PyrLKOpticalFlow, crate::superres::Superres_DenseOpticalFlowExtTraitConst, as_raw_Superres_DenseOpticalFlowExt, crate::superres::Superres_DenseOpticalFlowExtTrait, as_raw_mut_Superres_DenseOpticalFlowExt }
+	
 	impl crate::superres::Superres_PyrLKOpticalFlowTraitConst for Superres_PyrLKOpticalFlow {
 		#[inline] fn as_raw_Superres_PyrLKOpticalFlow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1045,6 +1073,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_PyrLKOpticalFlow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_PyrLKOpticalFlow, crate::superres::Superres_PyrLKOpticalFlowTraitConst, as_raw_Superres_PyrLKOpticalFlow, crate::superres::Superres_PyrLKOpticalFlowTrait, as_raw_mut_Superres_PyrLKOpticalFlow }
+	
 	impl Superres_PyrLKOpticalFlow {
 	}
 	
@@ -1377,6 +1407,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_SuperResolution, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::superres::Superres_FrameSourceTraitConst for Superres_SuperResolution {
 		#[inline] fn as_raw_Superres_FrameSource(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1385,6 +1417,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_FrameSource(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_SuperResolution, crate::superres::Superres_FrameSourceTraitConst, as_raw_Superres_FrameSource, crate::superres::Superres_FrameSourceTrait, as_raw_mut_Superres_FrameSource }
+	
 	impl crate::superres::Superres_SuperResolutionTraitConst for Superres_SuperResolution {
 		#[inline] fn as_raw_Superres_SuperResolution(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1393,6 +1427,8 @@ pub mod superres {
 		#[inline] fn as_raw_mut_Superres_SuperResolution(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Superres_SuperResolution, crate::superres::Superres_SuperResolutionTraitConst, as_raw_Superres_SuperResolution, crate::superres::Superres_SuperResolutionTrait, as_raw_mut_Superres_SuperResolution }
+	
 	impl Superres_SuperResolution {
 	}
 	
@@ -454,6 +454,8 @@ pub mod surface_matching {
 		#[inline] fn as_raw_mut_ICP(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ICP, crate::surface_matching::ICPTraitConst, as_raw_ICP, crate::surface_matching::ICPTrait, as_raw_mut_ICP }
+	
 	impl ICP {
 		#[inline]
 		pub fn default() -> Result<crate::surface_matching::ICP> {
@@ -599,6 +601,8 @@ pub mod surface_matching {
 		#[inline] fn as_raw_mut_PPF3DDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PPF3DDetector, crate::surface_matching::PPF3DDetectorTraitConst, as_raw_PPF3DDetector, crate::surface_matching::PPF3DDetectorTrait, as_raw_mut_PPF3DDetector }
+	
 	impl PPF3DDetector {
 		/// \brief Empty constructor. Sets default arguments
 		#[inline]
@@ -854,6 +858,8 @@ pub mod surface_matching {
 		#[inline] fn as_raw_mut_Pose3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Pose3D, crate::surface_matching::Pose3DTraitConst, as_raw_Pose3D, crate::surface_matching::Pose3DTrait, as_raw_mut_Pose3D }
+	
 	impl Pose3D {
 		#[inline]
 		pub fn default() -> Result<crate::surface_matching::Pose3D> {
@@ -988,6 +994,8 @@ pub mod surface_matching {
 		#[inline] fn as_raw_mut_PoseCluster3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { PoseCluster3D, crate::surface_matching::PoseCluster3DTraitConst, as_raw_PoseCluster3D, crate::surface_matching::PoseCluster3DTrait, as_raw_mut_PoseCluster3D }
+	
 	impl PoseCluster3D {
 		#[inline]
 		pub fn default() -> Result<crate::surface_matching::PoseCluster3D> {
@@ -952,15 +952,10 @@ mod core_sys {
 		pub fn cv_LDA_eigenvalues_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_LDA_subspaceProject_const__InputArrayR_const__InputArrayR_const__InputArrayR(w: *const c_void, mean: *const c_void, src: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_LDA_subspaceReconstruct_const__InputArrayR_const__InputArrayR_const__InputArrayR(w: *const c_void, mean: *const c_void, src: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_setPropFlags_int(instance: *mut c_void, val: i32);
-		pub fn cv_Mat_getPropDims_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_setPropDims_int(instance: *mut c_void, val: i32);
-		pub fn cv_Mat_getPropRows_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_setPropRows_int(instance: *mut c_void, val: i32);
-		pub fn cv_Mat_getPropCols_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_setPropCols_int(instance: *mut c_void, val: i32);
-		pub fn cv_Mat_getPropData(instance: *mut c_void) -> *mut u8;
 		pub fn cv_Mat_setPropData_unsigned_charX(instance: *mut c_void, val: *mut u8);
 		pub fn cv_Mat_getPropDatastart_const(instance: *const c_void) -> *const u8;
 		pub fn cv_Mat_getPropDataend_const(instance: *const c_void) -> *const u8;
@@ -1037,16 +1032,10 @@ mod core_sys {
 		pub fn cv_Mat_operator___const_const_RectR(instance: *const c_void, roi: *const core::Rect, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_operator___const_const_RangeX(instance: *const c_void, ranges: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_operator___const_const_vectorLRangeGR(instance: *const c_void, ranges: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_isContinuous_const(instance: *const c_void) -> bool;
 		pub fn cv_Mat_isSubmatrix_const(instance: *const c_void) -> bool;
 		pub fn cv_Mat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
 		pub fn cv_Mat_elemSize1_const(instance: *const c_void) -> size_t;
-		pub fn cv_Mat_type_const(instance: *const c_void) -> i32;
-		pub fn cv_Mat_depth_const(instance: *const c_void) -> i32;
-		pub fn cv_Mat_channels_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_step1_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_Mat_empty_const(instance: *const c_void) -> bool;
-		pub fn cv_Mat_total_const(instance: *const c_void) -> size_t;
 		pub fn cv_Mat_total_const_int_int(instance: *const c_void, start_dim: i32, end_dim: i32, ocvrs_return: *mut Result<size_t>);
 		pub fn cv_Mat_checkVector_const_int_int_bool(instance: *const c_void, elem_channels: i32, depth: i32, require_continuous: bool, ocvrs_return: *mut Result<i32>);
 		pub fn cv_Mat_ptr_int(instance: *mut c_void, i0: i32, ocvrs_return: *mut Result<*mut u8>);
@@ -706,6 +706,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_BaseOCR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { BaseOCR, crate::text::BaseOCRTraitConst, as_raw_BaseOCR, crate::text::BaseOCRTrait, as_raw_mut_BaseOCR }
+	
 	impl BaseOCR {
 	}
 	
@@ -848,6 +850,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ERFilter, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::text::ERFilterTraitConst for ERFilter {
 		#[inline] fn as_raw_ERFilter(&self) -> *const c_void { self.as_raw() }
 	}
@@ -856,6 +860,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_ERFilter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ERFilter, crate::text::ERFilterTraitConst, as_raw_ERFilter, crate::text::ERFilterTrait, as_raw_mut_ERFilter }
+	
 	impl ERFilter {
 	}
 	
@@ -914,6 +920,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_ERFilter_Callback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ERFilter_Callback, crate::text::ERFilter_CallbackTraitConst, as_raw_ERFilter_Callback, crate::text::ERFilter_CallbackTrait, as_raw_mut_ERFilter_Callback }
+	
 	impl ERFilter_Callback {
 	}
 	
@@ -1214,6 +1222,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_ERStat(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { ERStat, crate::text::ERStatTraitConst, as_raw_ERStat, crate::text::ERStatTrait, as_raw_mut_ERStat }
+	
 	impl ERStat {
 		/// Constructor
 		/// 
@@ -1358,6 +1368,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_BaseOCR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRBeamSearchDecoder, crate::text::BaseOCRTraitConst, as_raw_BaseOCR, crate::text::BaseOCRTrait, as_raw_mut_BaseOCR }
+	
 	impl crate::text::OCRBeamSearchDecoderTraitConst for OCRBeamSearchDecoder {
 		#[inline] fn as_raw_OCRBeamSearchDecoder(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1366,6 +1378,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRBeamSearchDecoder(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRBeamSearchDecoder, crate::text::OCRBeamSearchDecoderTraitConst, as_raw_OCRBeamSearchDecoder, crate::text::OCRBeamSearchDecoderTrait, as_raw_mut_OCRBeamSearchDecoder }
+	
 	impl OCRBeamSearchDecoder {
 		/// Creates an instance of the OCRBeamSearchDecoder class. Initializes HMMDecoder.
 		/// 
@@ -1524,6 +1538,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRBeamSearchDecoder_ClassifierCallback, crate::text::OCRBeamSearchDecoder_ClassifierCallbackTraitConst, as_raw_OCRBeamSearchDecoder_ClassifierCallback, crate::text::OCRBeamSearchDecoder_ClassifierCallbackTrait, as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback }
+	
 	impl OCRBeamSearchDecoder_ClassifierCallback {
 	}
 	
@@ -1675,6 +1691,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_BaseOCR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRHMMDecoder, crate::text::BaseOCRTraitConst, as_raw_BaseOCR, crate::text::BaseOCRTrait, as_raw_mut_BaseOCR }
+	
 	impl crate::text::OCRHMMDecoderTraitConst for OCRHMMDecoder {
 		#[inline] fn as_raw_OCRHMMDecoder(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1683,6 +1701,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRHMMDecoder(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRHMMDecoder, crate::text::OCRHMMDecoderTraitConst, as_raw_OCRHMMDecoder, crate::text::OCRHMMDecoderTrait, as_raw_mut_OCRHMMDecoder }
+	
 	impl OCRHMMDecoder {
 		/// Creates an instance of the OCRHMMDecoder class. Initializes HMMDecoder.
 		/// 
@@ -1818,6 +1838,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRHMMDecoder_ClassifierCallback(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRHMMDecoder_ClassifierCallback, crate::text::OCRHMMDecoder_ClassifierCallbackTraitConst, as_raw_OCRHMMDecoder_ClassifierCallback, crate::text::OCRHMMDecoder_ClassifierCallbackTrait, as_raw_mut_OCRHMMDecoder_ClassifierCallback }
+	
 	impl OCRHMMDecoder_ClassifierCallback {
 	}
 	
@@ -1920,6 +1942,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_BaseOCR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRHolisticWordRecognizer, crate::text::BaseOCRTraitConst, as_raw_BaseOCR, crate::text::BaseOCRTrait, as_raw_mut_BaseOCR }
+	
 	impl crate::text::OCRHolisticWordRecognizerTraitConst for OCRHolisticWordRecognizer {
 		#[inline] fn as_raw_OCRHolisticWordRecognizer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1928,6 +1952,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRHolisticWordRecognizer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRHolisticWordRecognizer, crate::text::OCRHolisticWordRecognizerTraitConst, as_raw_OCRHolisticWordRecognizer, crate::text::OCRHolisticWordRecognizerTrait, as_raw_mut_OCRHolisticWordRecognizer }
+	
 	impl OCRHolisticWordRecognizer {
 		/// Creates an instance of the OCRHolisticWordRecognizer class.
 		#[inline]
@@ -2079,6 +2105,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_BaseOCR(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRTesseract, crate::text::BaseOCRTraitConst, as_raw_BaseOCR, crate::text::BaseOCRTrait, as_raw_mut_BaseOCR }
+	
 	impl crate::text::OCRTesseractTraitConst for OCRTesseract {
 		#[inline] fn as_raw_OCRTesseract(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2087,6 +2115,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_OCRTesseract(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { OCRTesseract, crate::text::OCRTesseractTraitConst, as_raw_OCRTesseract, crate::text::OCRTesseractTrait, as_raw_mut_OCRTesseract }
+	
 	impl OCRTesseract {
 		/// Creates an instance of the OCRTesseract class. Initializes Tesseract.
 		/// 
@@ -2177,6 +2207,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_TextDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetector, crate::text::TextDetectorTraitConst, as_raw_TextDetector, crate::text::TextDetectorTrait, as_raw_mut_TextDetector }
+	
 	impl TextDetector {
 	}
 	
@@ -2238,6 +2270,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_TextDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectorCNN, crate::text::TextDetectorTraitConst, as_raw_TextDetector, crate::text::TextDetectorTrait, as_raw_mut_TextDetector }
+	
 	impl crate::text::TextDetectorCNNTraitConst for TextDetectorCNN {
 		#[inline] fn as_raw_TextDetectorCNN(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2246,6 +2280,8 @@ pub mod text {
 		#[inline] fn as_raw_mut_TextDetectorCNN(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TextDetectorCNN, crate::text::TextDetectorCNNTraitConst, as_raw_TextDetectorCNN, crate::text::TextDetectorCNNTrait, as_raw_mut_TextDetectorCNN }
+	
 	impl TextDetectorCNN {
 		/// Creates an instance of the TextDetectorCNN class using the provided parameters.
 		/// 
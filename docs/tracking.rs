@@ -74,6 +74,8 @@ pub mod tracking {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrackerCSRT, crate::video::TrackerTraitConst, as_raw_Tracker, crate::video::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl crate::tracking::TrackerCSRTTraitConst for TrackerCSRT {
 		#[inline] fn as_raw_TrackerCSRT(&self) -> *const c_void { self.as_raw() }
 	}
@@ -82,6 +84,8 @@ pub mod tracking {
 		#[inline] fn as_raw_mut_TrackerCSRT(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrackerCSRT, crate::tracking::TrackerCSRTTraitConst, as_raw_TrackerCSRT, crate::tracking::TrackerCSRTTrait, as_raw_mut_TrackerCSRT }
+	
 	impl TrackerCSRT {
 		/// Create CSRT tracker instance
 		/// ## Parameters
@@ -467,6 +471,8 @@ pub mod tracking {
 		#[inline] fn as_raw_mut_TrackerCSRT_Params(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrackerCSRT_Params, crate::tracking::TrackerCSRT_ParamsTraitConst, as_raw_TrackerCSRT_Params, crate::tracking::TrackerCSRT_ParamsTrait, as_raw_mut_TrackerCSRT_Params }
+	
 	impl TrackerCSRT_Params {
 		#[inline]
 		pub fn default() -> Result<crate::tracking::TrackerCSRT_Params> {
@@ -542,6 +548,8 @@ pub mod tracking {
 		#[inline] fn as_raw_mut_Tracker(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrackerKCF, crate::video::TrackerTraitConst, as_raw_Tracker, crate::video::TrackerTrait, as_raw_mut_Tracker }
+	
 	impl crate::tracking::TrackerKCFTraitConst for TrackerKCF {
 		#[inline] fn as_raw_TrackerKCF(&self) -> *const c_void { self.as_raw() }
 	}
@@ -550,6 +558,8 @@ pub mod tracking {
 		#[inline] fn as_raw_mut_TrackerKCF(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TrackerKCF, crate::tracking::TrackerKCFTraitConst, as_raw_TrackerKCF, crate::tracking::TrackerKCFTrait, as_raw_mut_TrackerKCF }
+	
 	impl TrackerKCF {
 		/// Create KCF tracker instance
 		/// ## Parameters
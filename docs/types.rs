@@ -816,7 +816,7 @@ mod core_types {
 		cv_VectorOfGpuMat_get, cv_VectorOfGpuMat_set,
 		cv_VectorOfGpuMat_push, cv_VectorOfGpuMat_insert,
 	}
-	vector_non_copy_or_bool! { clone core::GpuMat }
+	vector_boxed! { clone core::GpuMat, cv_VectorOfGpuMat_get_ref, cv_VectorOfGpuMat_get_mut }
 	
 	pub type VectorOfKeyPoint = core::Vector<core::KeyPoint>;
 	
@@ -834,7 +834,7 @@ mod core_types {
 		cv_VectorOfKeyPoint_get, cv_VectorOfKeyPoint_set,
 		cv_VectorOfKeyPoint_push, cv_VectorOfKeyPoint_insert,
 	}
-	vector_non_copy_or_bool! { clone core::KeyPoint }
+	vector_boxed! { clone core::KeyPoint, cv_VectorOfKeyPoint_get_ref, cv_VectorOfKeyPoint_get_mut }
 	
 	pub type VectorOfMat = core::Vector<core::Mat>;
 	
@@ -852,7 +852,7 @@ mod core_types {
 		cv_VectorOfMat_get, cv_VectorOfMat_set,
 		cv_VectorOfMat_push, cv_VectorOfMat_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Mat }
+	vector_boxed! { clone core::Mat, cv_VectorOfMat_get_ref, cv_VectorOfMat_get_mut }
 	
 	pub type VectorOfPlatformInfo = core::Vector<core::PlatformInfo>;
 	
@@ -870,7 +870,7 @@ mod core_types {
 		cv_VectorOfPlatformInfo_get, cv_VectorOfPlatformInfo_set,
 		cv_VectorOfPlatformInfo_push, cv_VectorOfPlatformInfo_insert,
 	}
-	vector_non_copy_or_bool! { core::PlatformInfo }
+	vector_boxed! { core::PlatformInfo, cv_VectorOfPlatformInfo_get_ref, cv_VectorOfPlatformInfo_get_mut }
 	
 	pub type VectorOfPoint = core::Vector<core::Point>;
 	
@@ -1260,7 +1260,7 @@ mod core_types {
 		cv_VectorOfRange_get, cv_VectorOfRange_set,
 		cv_VectorOfRange_push, cv_VectorOfRange_insert,
 	}
-	vector_non_copy_or_bool! { core::Range }
+	vector_boxed! { core::Range, cv_VectorOfRange_get_ref, cv_VectorOfRange_get_mut }
 	
 	pub type VectorOfRect = core::Vector<core::Rect>;
 	
@@ -1402,7 +1402,7 @@ mod core_types {
 		cv_VectorOfRotatedRect_get, cv_VectorOfRotatedRect_set,
 		cv_VectorOfRotatedRect_push, cv_VectorOfRotatedRect_insert,
 	}
-	vector_non_copy_or_bool! { core::RotatedRect }
+	vector_boxed! { core::RotatedRect, cv_VectorOfRotatedRect_get_ref, cv_VectorOfRotatedRect_get_mut }
 	
 	pub type VectorOfScalar = core::Vector<core::Scalar>;
 	
@@ -1562,7 +1562,7 @@ mod core_types {
 		cv_VectorOfTupleOfPoint2i_Point2i_get, cv_VectorOfTupleOfPoint2i_Point2i_set,
 		cv_VectorOfTupleOfPoint2i_Point2i_push, cv_VectorOfTupleOfPoint2i_Point2i_insert,
 	}
-	vector_non_copy_or_bool! { core::Tuple<(core::Point2i, core::Point2i)> }
+	vector_boxed! { core::Tuple<(core::Point2i, core::Point2i)>, cv_VectorOfTupleOfPoint2i_Point2i_get_ref, cv_VectorOfTupleOfPoint2i_Point2i_get_mut }
 	
 	pub type VectorOfTupleOfUMat_u8 = core::Vector<core::Tuple<(core::UMat, u8)>>;
 	
@@ -1580,7 +1580,7 @@ mod core_types {
 		cv_VectorOfTupleOfUMat_u8_get, cv_VectorOfTupleOfUMat_u8_set,
 		cv_VectorOfTupleOfUMat_u8_push, cv_VectorOfTupleOfUMat_u8_insert,
 	}
-	vector_non_copy_or_bool! { core::Tuple<(core::UMat, u8)> }
+	vector_boxed! { core::Tuple<(core::UMat, u8)>, cv_VectorOfTupleOfUMat_u8_get_ref, cv_VectorOfTupleOfUMat_u8_get_mut }
 	
 	pub type VectorOfTupleOfi32_f64 = core::Vector<core::Tuple<(i32, f64)>>;
 	
@@ -1598,7 +1598,7 @@ mod core_types {
 		cv_VectorOfTupleOfi32_f64_get, cv_VectorOfTupleOfi32_f64_set,
 		cv_VectorOfTupleOfi32_f64_push, cv_VectorOfTupleOfi32_f64_insert,
 	}
-	vector_non_copy_or_bool! { core::Tuple<(i32, f64)> }
+	vector_boxed! { core::Tuple<(i32, f64)>, cv_VectorOfTupleOfi32_f64_get_ref, cv_VectorOfTupleOfi32_f64_get_mut }
 	
 	pub type VectorOfUMat = core::Vector<core::UMat>;
 	
@@ -1616,7 +1616,7 @@ mod core_types {
 		cv_VectorOfUMat_get, cv_VectorOfUMat_set,
 		cv_VectorOfUMat_push, cv_VectorOfUMat_insert,
 	}
-	vector_non_copy_or_bool! { clone core::UMat }
+	vector_boxed! { clone core::UMat, cv_VectorOfUMat_get_ref, cv_VectorOfUMat_get_mut }
 	
 	pub type VectorOfVec2d = core::Vector<core::Vec2d>;
 	
@@ -2192,7 +2192,7 @@ mod core_types {
 		cv_VectorOfVectorOfDMatch_get, cv_VectorOfVectorOfDMatch_set,
 		cv_VectorOfVectorOfDMatch_push, cv_VectorOfVectorOfDMatch_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::DMatch> }
+	vector_boxed! { clone core::Vector<core::DMatch>, cv_VectorOfVectorOfDMatch_get_ref, cv_VectorOfVectorOfDMatch_get_mut }
 	
 	pub type VectorOfVectorOfKeyPoint = core::Vector<core::Vector<core::KeyPoint>>;
 	
@@ -2210,7 +2210,7 @@ mod core_types {
 		cv_VectorOfVectorOfKeyPoint_get, cv_VectorOfVectorOfKeyPoint_set,
 		cv_VectorOfVectorOfKeyPoint_push, cv_VectorOfVectorOfKeyPoint_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::KeyPoint> }
+	vector_boxed! { clone core::Vector<core::KeyPoint>, cv_VectorOfVectorOfKeyPoint_get_ref, cv_VectorOfVectorOfKeyPoint_get_mut }
 	
 	pub type VectorOfVectorOfMat = core::Vector<core::Vector<core::Mat>>;
 	
@@ -2228,7 +2228,7 @@ mod core_types {
 		cv_VectorOfVectorOfMat_get, cv_VectorOfVectorOfMat_set,
 		cv_VectorOfVectorOfMat_push, cv_VectorOfVectorOfMat_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Mat> }
+	vector_boxed! { clone core::Vector<core::Mat>, cv_VectorOfVectorOfMat_get_ref, cv_VectorOfVectorOfMat_get_mut }
 	
 	pub type VectorOfVectorOfPoint = core::Vector<core::Vector<core::Point>>;
 	
@@ -2246,7 +2246,7 @@ mod core_types {
 		cv_VectorOfVectorOfPoint_get, cv_VectorOfVectorOfPoint_set,
 		cv_VectorOfVectorOfPoint_push, cv_VectorOfVectorOfPoint_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Point> }
+	vector_boxed! { clone core::Vector<core::Point>, cv_VectorOfVectorOfPoint_get_ref, cv_VectorOfVectorOfPoint_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfPoint_input_array(instance: extern_send!(core::Vector<core::Vector<core::Point>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2305,7 +2305,7 @@ mod core_types {
 		cv_VectorOfVectorOfPoint2f_get, cv_VectorOfVectorOfPoint2f_set,
 		cv_VectorOfVectorOfPoint2f_push, cv_VectorOfVectorOfPoint2f_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Point2f> }
+	vector_boxed! { clone core::Vector<core::Point2f>, cv_VectorOfVectorOfPoint2f_get_ref, cv_VectorOfVectorOfPoint2f_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfPoint2f_input_array(instance: extern_send!(core::Vector<core::Vector<core::Point2f>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2364,7 +2364,7 @@ mod core_types {
 		cv_VectorOfVectorOfPoint3d_get, cv_VectorOfVectorOfPoint3d_set,
 		cv_VectorOfVectorOfPoint3d_push, cv_VectorOfVectorOfPoint3d_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Point3d> }
+	vector_boxed! { clone core::Vector<core::Point3d>, cv_VectorOfVectorOfPoint3d_get_ref, cv_VectorOfVectorOfPoint3d_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfPoint3d_input_array(instance: extern_send!(core::Vector<core::Vector<core::Point3d>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2423,7 +2423,7 @@ mod core_types {
 		cv_VectorOfVectorOfPoint3f_get, cv_VectorOfVectorOfPoint3f_set,
 		cv_VectorOfVectorOfPoint3f_push, cv_VectorOfVectorOfPoint3f_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Point3f> }
+	vector_boxed! { clone core::Vector<core::Point3f>, cv_VectorOfVectorOfPoint3f_get_ref, cv_VectorOfVectorOfPoint3f_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfPoint3f_input_array(instance: extern_send!(core::Vector<core::Vector<core::Point3f>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2482,7 +2482,7 @@ mod core_types {
 		cv_VectorOfVectorOfPoint3i_get, cv_VectorOfVectorOfPoint3i_set,
 		cv_VectorOfVectorOfPoint3i_push, cv_VectorOfVectorOfPoint3i_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Point3i> }
+	vector_boxed! { clone core::Vector<core::Point3i>, cv_VectorOfVectorOfPoint3i_get_ref, cv_VectorOfVectorOfPoint3i_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfPoint3i_input_array(instance: extern_send!(core::Vector<core::Vector<core::Point3i>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2541,7 +2541,7 @@ mod core_types {
 		cv_VectorOfVectorOfRange_get, cv_VectorOfVectorOfRange_set,
 		cv_VectorOfVectorOfRange_push, cv_VectorOfVectorOfRange_insert,
 	}
-	vector_non_copy_or_bool! { core::Vector<core::Range> }
+	vector_boxed! { core::Vector<core::Range>, cv_VectorOfVectorOfRange_get_ref, cv_VectorOfVectorOfRange_get_mut }
 	
 	pub type VectorOfVectorOfVec2i = core::Vector<core::Vector<core::Vec2i>>;
 	
@@ -2559,7 +2559,7 @@ mod core_types {
 		cv_VectorOfVectorOfVec2i_get, cv_VectorOfVectorOfVec2i_set,
 		cv_VectorOfVectorOfVec2i_push, cv_VectorOfVectorOfVec2i_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Vec2i> }
+	vector_boxed! { clone core::Vector<core::Vec2i>, cv_VectorOfVectorOfVec2i_get_ref, cv_VectorOfVectorOfVec2i_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfVec2i_input_array(instance: extern_send!(core::Vector<core::Vector<core::Vec2i>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2618,7 +2618,7 @@ mod core_types {
 		cv_VectorOfVectorOfVec3f_get, cv_VectorOfVectorOfVec3f_set,
 		cv_VectorOfVectorOfVec3f_push, cv_VectorOfVectorOfVec3f_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<core::Vec3f> }
+	vector_boxed! { clone core::Vector<core::Vec3f>, cv_VectorOfVectorOfVec3f_get_ref, cv_VectorOfVectorOfVec3f_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfVec3f_input_array(instance: extern_send!(core::Vector<core::Vector<core::Vec3f>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2677,7 +2677,7 @@ mod core_types {
 		cv_VectorOfVectorOff32_get, cv_VectorOfVectorOff32_set,
 		cv_VectorOfVectorOff32_push, cv_VectorOfVectorOff32_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<f32> }
+	vector_boxed! { clone core::Vector<f32>, cv_VectorOfVectorOff32_get_ref, cv_VectorOfVectorOff32_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOff32_input_array(instance: extern_send!(core::Vector<core::Vector<f32>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2736,7 +2736,7 @@ mod core_types {
 		cv_VectorOfVectorOff64_get, cv_VectorOfVectorOff64_set,
 		cv_VectorOfVectorOff64_push, cv_VectorOfVectorOff64_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<f64> }
+	vector_boxed! { clone core::Vector<f64>, cv_VectorOfVectorOff64_get_ref, cv_VectorOfVectorOff64_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOff64_input_array(instance: extern_send!(core::Vector<core::Vector<f64>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2795,7 +2795,7 @@ mod core_types {
 		cv_VectorOfVectorOfi32_get, cv_VectorOfVectorOfi32_set,
 		cv_VectorOfVectorOfi32_push, cv_VectorOfVectorOfi32_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<i32> }
+	vector_boxed! { clone core::Vector<i32>, cv_VectorOfVectorOfi32_get_ref, cv_VectorOfVectorOfi32_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfi32_input_array(instance: extern_send!(core::Vector<core::Vector<i32>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2854,7 +2854,7 @@ mod core_types {
 		cv_VectorOfVectorOfi8_get, cv_VectorOfVectorOfi8_set,
 		cv_VectorOfVectorOfi8_push, cv_VectorOfVectorOfi8_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<i8> }
+	vector_boxed! { clone core::Vector<i8>, cv_VectorOfVectorOfi8_get_ref, cv_VectorOfVectorOfi8_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfi8_input_array(instance: extern_send!(core::Vector<core::Vector<i8>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -2913,7 +2913,7 @@ mod core_types {
 		cv_VectorOfVectorOfu8_get, cv_VectorOfVectorOfu8_set,
 		cv_VectorOfVectorOfu8_push, cv_VectorOfVectorOfu8_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<u8> }
+	vector_boxed! { clone core::Vector<u8>, cv_VectorOfVectorOfu8_get_ref, cv_VectorOfVectorOfu8_get_mut }
 	
 	extern "C" {
 		fn cv_VectorOfVectorOfu8_input_array(instance: extern_send!(core::Vector<core::Vector<u8>>), ocvrs_return: *mut sys::Result<extern_receive!(crate::core::_InputArray)>);
@@ -8289,7 +8289,7 @@ mod dnn_types {
 		cv_VectorOfPtrOfBackendNode_get, cv_VectorOfPtrOfBackendNode_set,
 		cv_VectorOfPtrOfBackendNode_push, cv_VectorOfPtrOfBackendNode_insert,
 	}
-	vector_non_copy_or_bool! { core::Ptr<crate::dnn::BackendNode> }
+	vector_boxed! { core::Ptr<crate::dnn::BackendNode>, cv_VectorOfPtrOfBackendNode_get_ref, cv_VectorOfPtrOfBackendNode_get_mut }
 	
 	pub type VectorOfPtrOfBackendWrapper = core::Vector<core::Ptr<crate::dnn::BackendWrapper>>;
 	
@@ -8307,7 +8307,7 @@ mod dnn_types {
 		cv_VectorOfPtrOfBackendWrapper_get, cv_VectorOfPtrOfBackendWrapper_set,
 		cv_VectorOfPtrOfBackendWrapper_push, cv_VectorOfPtrOfBackendWrapper_insert,
 	}
-	vector_non_copy_or_bool! { core::Ptr<crate::dnn::BackendWrapper> }
+	vector_boxed! { core::Ptr<crate::dnn::BackendWrapper>, cv_VectorOfPtrOfBackendWrapper_get_ref, cv_VectorOfPtrOfBackendWrapper_get_mut }
 	
 	pub type VectorOfPtrOfLayer = core::Vector<core::Ptr<crate::dnn::Layer>>;
 	
@@ -8325,7 +8325,7 @@ mod dnn_types {
 		cv_VectorOfPtrOfLayer_get, cv_VectorOfPtrOfLayer_set,
 		cv_VectorOfPtrOfLayer_push, cv_VectorOfPtrOfLayer_insert,
 	}
-	vector_non_copy_or_bool! { core::Ptr<crate::dnn::Layer> }
+	vector_boxed! { core::Ptr<crate::dnn::Layer>, cv_VectorOfPtrOfLayer_get_ref, cv_VectorOfPtrOfLayer_get_mut }
 	
 	pub type VectorOfTarget = core::Vector<crate::dnn::Target>;
 	
@@ -8364,7 +8364,7 @@ mod dnn_types {
 		cv_VectorOfTupleOfBackend_Target_get, cv_VectorOfTupleOfBackend_Target_set,
 		cv_VectorOfTupleOfBackend_Target_push, cv_VectorOfTupleOfBackend_Target_insert,
 	}
-	vector_non_copy_or_bool! { core::Tuple<(crate::dnn::Backend, crate::dnn::Target)> }
+	vector_boxed! { core::Tuple<(crate::dnn::Backend, crate::dnn::Target)>, cv_VectorOfTupleOfBackend_Target_get_ref, cv_VectorOfTupleOfBackend_Target_get_mut }
 	
 	pub type VectorOfVectorOfMatShape = core::Vector<core::Vector<crate::dnn::MatShape>>;
 	
@@ -8382,7 +8382,7 @@ mod dnn_types {
 		cv_VectorOfVectorOfMatShape_get, cv_VectorOfVectorOfMatShape_set,
 		cv_VectorOfVectorOfMatShape_push, cv_VectorOfVectorOfMatShape_insert,
 	}
-	vector_non_copy_or_bool! { core::Vector<crate::dnn::MatShape> }
+	vector_boxed! { core::Vector<crate::dnn::MatShape>, cv_VectorOfVectorOfMatShape_get_ref, cv_VectorOfVectorOfMatShape_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_dnn)]
@@ -8456,7 +8456,7 @@ mod dpm_types {
 		cv_VectorOfDPMDetector_ObjectDetection_get, cv_VectorOfDPMDetector_ObjectDetection_set,
 		cv_VectorOfDPMDetector_ObjectDetection_push, cv_VectorOfDPMDetector_ObjectDetection_insert,
 	}
-	vector_non_copy_or_bool! { crate::dpm::DPMDetector_ObjectDetection }
+	vector_boxed! { crate::dpm::DPMDetector_ObjectDetection, cv_VectorOfDPMDetector_ObjectDetection_get_ref, cv_VectorOfDPMDetector_ObjectDetection_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_dpm)]
@@ -8855,7 +8855,7 @@ mod face_types {
 		cv_VectorOfFacemarkAAM_Config_get, cv_VectorOfFacemarkAAM_Config_set,
 		cv_VectorOfFacemarkAAM_Config_push, cv_VectorOfFacemarkAAM_Config_insert,
 	}
-	vector_non_copy_or_bool! { crate::face::FacemarkAAM_Config }
+	vector_boxed! { crate::face::FacemarkAAM_Config, cv_VectorOfFacemarkAAM_Config_get_ref, cv_VectorOfFacemarkAAM_Config_get_mut }
 	
 	pub type VectorOfFacemarkAAM_Model_Texture = core::Vector<crate::face::FacemarkAAM_Model_Texture>;
 	
@@ -8873,7 +8873,7 @@ mod face_types {
 		cv_VectorOfFacemarkAAM_Model_Texture_get, cv_VectorOfFacemarkAAM_Model_Texture_set,
 		cv_VectorOfFacemarkAAM_Model_Texture_push, cv_VectorOfFacemarkAAM_Model_Texture_insert,
 	}
-	vector_non_copy_or_bool! { crate::face::FacemarkAAM_Model_Texture }
+	vector_boxed! { crate::face::FacemarkAAM_Model_Texture, cv_VectorOfFacemarkAAM_Model_Texture_get_ref, cv_VectorOfFacemarkAAM_Model_Texture_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_face)]
@@ -9626,7 +9626,7 @@ mod gapi_types {
 		cv_VectorOfGArg_get, cv_VectorOfGArg_set,
 		cv_VectorOfGArg_push, cv_VectorOfGArg_insert,
 	}
-	vector_non_copy_or_bool! { crate::gapi::GArg }
+	vector_boxed! { crate::gapi::GArg, cv_VectorOfGArg_get_ref, cv_VectorOfGArg_get_mut }
 	
 	pub type VectorOfGBackend = core::Vector<crate::gapi::GBackend>;
 	
@@ -9644,7 +9644,7 @@ mod gapi_types {
 		cv_VectorOfGBackend_get, cv_VectorOfGBackend_set,
 		cv_VectorOfGBackend_push, cv_VectorOfGBackend_insert,
 	}
-	vector_non_copy_or_bool! { crate::gapi::GBackend }
+	vector_boxed! { crate::gapi::GBackend, cv_VectorOfGBackend_get_ref, cv_VectorOfGBackend_get_mut }
 	
 	pub type VectorOfGCompileArg = core::Vector<crate::gapi::GCompileArg>;
 	
@@ -9662,7 +9662,7 @@ mod gapi_types {
 		cv_VectorOfGCompileArg_get, cv_VectorOfGCompileArg_set,
 		cv_VectorOfGCompileArg_push, cv_VectorOfGCompileArg_insert,
 	}
-	vector_non_copy_or_bool! { crate::gapi::GCompileArg }
+	vector_boxed! { crate::gapi::GCompileArg, cv_VectorOfGCompileArg_get_ref, cv_VectorOfGCompileArg_get_mut }
 	
 	pub type VectorOfGMat = core::Vector<crate::gapi::GMat>;
 	
@@ -9680,7 +9680,7 @@ mod gapi_types {
 		cv_VectorOfGMat_get, cv_VectorOfGMat_set,
 		cv_VectorOfGMat_push, cv_VectorOfGMat_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::gapi::GMat }
+	vector_boxed! { clone crate::gapi::GMat, cv_VectorOfGMat_get_ref, cv_VectorOfGMat_get_mut }
 	
 	pub type VectorOfGRunArg = core::Vector<crate::gapi::GRunArg>;
 	
@@ -9698,7 +9698,7 @@ mod gapi_types {
 		cv_VectorOfGRunArg_get, cv_VectorOfGRunArg_set,
 		cv_VectorOfGRunArg_push, cv_VectorOfGRunArg_insert,
 	}
-	vector_non_copy_or_bool! { crate::gapi::GRunArg }
+	vector_boxed! { crate::gapi::GRunArg, cv_VectorOfGRunArg_get_ref, cv_VectorOfGRunArg_get_mut }
 	
 	pub type VectorOfGShape = core::Vector<crate::gapi::GShape>;
 	
@@ -9737,7 +9737,7 @@ mod gapi_types {
 		cv_VectorOfGTransform_get, cv_VectorOfGTransform_set,
 		cv_VectorOfGTransform_push, cv_VectorOfGTransform_insert,
 	}
-	vector_non_copy_or_bool! { crate::gapi::GTransform }
+	vector_boxed! { crate::gapi::GTransform, cv_VectorOfGTransform_get_ref, cv_VectorOfGTransform_get_mut }
 	
 	pub type VectorOfGTypeInfo = core::Vector<crate::gapi::GTypeInfo>;
 	
@@ -9755,7 +9755,7 @@ mod gapi_types {
 		cv_VectorOfGTypeInfo_get, cv_VectorOfGTypeInfo_set,
 		cv_VectorOfGTypeInfo_push, cv_VectorOfGTypeInfo_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::gapi::GTypeInfo }
+	vector_boxed! { clone crate::gapi::GTypeInfo, cv_VectorOfGTypeInfo_get_ref, cv_VectorOfGTypeInfo_get_mut }
 	
 	pub type VectorOfOpaqueKind = core::Vector<crate::gapi::OpaqueKind>;
 	
@@ -10334,7 +10334,7 @@ mod line_descriptor_types {
 		cv_VectorOfVectorOfKeyLine_get, cv_VectorOfVectorOfKeyLine_set,
 		cv_VectorOfVectorOfKeyLine_push, cv_VectorOfVectorOfKeyLine_insert,
 	}
-	vector_non_copy_or_bool! { clone core::Vector<crate::line_descriptor::KeyLine> }
+	vector_boxed! { clone core::Vector<crate::line_descriptor::KeyLine>, cv_VectorOfVectorOfKeyLine_get_ref, cv_VectorOfVectorOfKeyLine_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_line_descriptor)]
@@ -10446,7 +10446,7 @@ mod mcc_types {
 		cv_VectorOfPtrOfMCC_CChecker_get, cv_VectorOfPtrOfMCC_CChecker_set,
 		cv_VectorOfPtrOfMCC_CChecker_push, cv_VectorOfPtrOfMCC_CChecker_insert,
 	}
-	vector_non_copy_or_bool! { core::Ptr<crate::mcc::MCC_CChecker> }
+	vector_boxed! { core::Ptr<crate::mcc::MCC_CChecker>, cv_VectorOfPtrOfMCC_CChecker_get_ref, cv_VectorOfPtrOfMCC_CChecker_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_mcc)]
@@ -10905,7 +10905,7 @@ mod ml_types {
 		cv_VectorOfDTrees_Node_get, cv_VectorOfDTrees_Node_set,
 		cv_VectorOfDTrees_Node_push, cv_VectorOfDTrees_Node_insert,
 	}
-	vector_non_copy_or_bool! { crate::ml::DTrees_Node }
+	vector_boxed! { crate::ml::DTrees_Node, cv_VectorOfDTrees_Node_get_ref, cv_VectorOfDTrees_Node_get_mut }
 	
 	pub type VectorOfDTrees_Split = core::Vector<crate::ml::DTrees_Split>;
 	
@@ -10923,7 +10923,7 @@ mod ml_types {
 		cv_VectorOfDTrees_Split_get, cv_VectorOfDTrees_Split_set,
 		cv_VectorOfDTrees_Split_push, cv_VectorOfDTrees_Split_insert,
 	}
-	vector_non_copy_or_bool! { crate::ml::DTrees_Split }
+	vector_boxed! { crate::ml::DTrees_Split, cv_VectorOfDTrees_Split_get_ref, cv_VectorOfDTrees_Split_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_ml)]
@@ -11163,7 +11163,7 @@ mod objdetect_types {
 		cv_VectorOfDetectionBasedTracker_ExtObject_get, cv_VectorOfDetectionBasedTracker_ExtObject_set,
 		cv_VectorOfDetectionBasedTracker_ExtObject_push, cv_VectorOfDetectionBasedTracker_ExtObject_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::objdetect::DetectionBasedTracker_ExtObject }
+	vector_boxed! { clone crate::objdetect::DetectionBasedTracker_ExtObject, cv_VectorOfDetectionBasedTracker_ExtObject_get_ref, cv_VectorOfDetectionBasedTracker_ExtObject_get_mut }
 	
 	pub type VectorOfDetectionBasedTracker_Object = core::Vector<crate::objdetect::DetectionBasedTracker_Object>;
 	
@@ -11181,7 +11181,7 @@ mod objdetect_types {
 		cv_VectorOfDetectionBasedTracker_Object_get, cv_VectorOfDetectionBasedTracker_Object_set,
 		cv_VectorOfDetectionBasedTracker_Object_push, cv_VectorOfDetectionBasedTracker_Object_insert,
 	}
-	vector_non_copy_or_bool! { crate::objdetect::DetectionBasedTracker_Object }
+	vector_boxed! { crate::objdetect::DetectionBasedTracker_Object, cv_VectorOfDetectionBasedTracker_Object_get_ref, cv_VectorOfDetectionBasedTracker_Object_get_mut }
 	
 	pub type VectorOfDetectionROI = core::Vector<crate::objdetect::DetectionROI>;
 	
@@ -11199,7 +11199,7 @@ mod objdetect_types {
 		cv_VectorOfDetectionROI_get, cv_VectorOfDetectionROI_set,
 		cv_VectorOfDetectionROI_push, cv_VectorOfDetectionROI_insert,
 	}
-	vector_non_copy_or_bool! { crate::objdetect::DetectionROI }
+	vector_boxed! { crate::objdetect::DetectionROI, cv_VectorOfDetectionROI_get_ref, cv_VectorOfDetectionROI_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_objdetect)]
@@ -11422,7 +11422,7 @@ mod optflow_types {
 		cv_VectorOfGPCPatchDescriptor_get, cv_VectorOfGPCPatchDescriptor_set,
 		cv_VectorOfGPCPatchDescriptor_push, cv_VectorOfGPCPatchDescriptor_insert,
 	}
-	vector_non_copy_or_bool! { crate::optflow::GPCPatchDescriptor }
+	vector_boxed! { crate::optflow::GPCPatchDescriptor, cv_VectorOfGPCPatchDescriptor_get_ref, cv_VectorOfGPCPatchDescriptor_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_optflow)]
@@ -12822,7 +12822,7 @@ mod rgbd_types {
 		cv_VectorOfLinemod_Match_get, cv_VectorOfLinemod_Match_set,
 		cv_VectorOfLinemod_Match_push, cv_VectorOfLinemod_Match_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::rgbd::Linemod_Match }
+	vector_boxed! { clone crate::rgbd::Linemod_Match, cv_VectorOfLinemod_Match_get_ref, cv_VectorOfLinemod_Match_get_mut }
 	
 	pub type VectorOfLinemod_Template = core::Vector<crate::rgbd::Linemod_Template>;
 	
@@ -12840,7 +12840,7 @@ mod rgbd_types {
 		cv_VectorOfLinemod_Template_get, cv_VectorOfLinemod_Template_set,
 		cv_VectorOfLinemod_Template_push, cv_VectorOfLinemod_Template_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::rgbd::Linemod_Template }
+	vector_boxed! { clone crate::rgbd::Linemod_Template, cv_VectorOfLinemod_Template_get_ref, cv_VectorOfLinemod_Template_get_mut }
 	
 	pub type VectorOfPtrOfLinemod_Modality = core::Vector<core::Ptr<crate::rgbd::Linemod_Modality>>;
 	
@@ -12858,7 +12858,7 @@ mod rgbd_types {
 		cv_VectorOfPtrOfLinemod_Modality_get, cv_VectorOfPtrOfLinemod_Modality_set,
 		cv_VectorOfPtrOfLinemod_Modality_push, cv_VectorOfPtrOfLinemod_Modality_insert,
 	}
-	vector_non_copy_or_bool! { core::Ptr<crate::rgbd::Linemod_Modality> }
+	vector_boxed! { core::Ptr<crate::rgbd::Linemod_Modality>, cv_VectorOfPtrOfLinemod_Modality_get_ref, cv_VectorOfPtrOfLinemod_Modality_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_rgbd)]
@@ -15025,7 +15025,7 @@ mod stitching_types {
 		cv_VectorOfDetail_CameraParams_get, cv_VectorOfDetail_CameraParams_set,
 		cv_VectorOfDetail_CameraParams_push, cv_VectorOfDetail_CameraParams_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::stitching::Detail_CameraParams }
+	vector_boxed! { clone crate::stitching::Detail_CameraParams, cv_VectorOfDetail_CameraParams_get_ref, cv_VectorOfDetail_CameraParams_get_mut }
 	
 	pub type VectorOfDetail_ImageFeatures = core::Vector<crate::stitching::Detail_ImageFeatures>;
 	
@@ -15043,7 +15043,7 @@ mod stitching_types {
 		cv_VectorOfDetail_ImageFeatures_get, cv_VectorOfDetail_ImageFeatures_set,
 		cv_VectorOfDetail_ImageFeatures_push, cv_VectorOfDetail_ImageFeatures_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::stitching::Detail_ImageFeatures }
+	vector_boxed! { clone crate::stitching::Detail_ImageFeatures, cv_VectorOfDetail_ImageFeatures_get_ref, cv_VectorOfDetail_ImageFeatures_get_mut }
 	
 	pub type VectorOfDetail_MatchesInfo = core::Vector<crate::stitching::Detail_MatchesInfo>;
 	
@@ -15061,7 +15061,7 @@ mod stitching_types {
 		cv_VectorOfDetail_MatchesInfo_get, cv_VectorOfDetail_MatchesInfo_set,
 		cv_VectorOfDetail_MatchesInfo_push, cv_VectorOfDetail_MatchesInfo_insert,
 	}
-	vector_non_copy_or_bool! { clone crate::stitching::Detail_MatchesInfo }
+	vector_boxed! { clone crate::stitching::Detail_MatchesInfo, cv_VectorOfDetail_MatchesInfo_get_ref, cv_VectorOfDetail_MatchesInfo_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_stitching)]
@@ -15457,7 +15457,7 @@ mod surface_matching_types {
 		cv_VectorOfPose3DPtr_get, cv_VectorOfPose3DPtr_set,
 		cv_VectorOfPose3DPtr_push, cv_VectorOfPose3DPtr_insert,
 	}
-	vector_non_copy_or_bool! { crate::surface_matching::Pose3DPtr }
+	vector_boxed! { crate::surface_matching::Pose3DPtr, cv_VectorOfPose3DPtr_get_ref, cv_VectorOfPose3DPtr_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_surface_matching)]
@@ -15710,7 +15710,7 @@ mod text_types {
 		cv_VectorOfERStat_get, cv_VectorOfERStat_set,
 		cv_VectorOfERStat_push, cv_VectorOfERStat_insert,
 	}
-	vector_non_copy_or_bool! { crate::text::ERStat }
+	vector_boxed! { crate::text::ERStat, cv_VectorOfERStat_get_ref, cv_VectorOfERStat_get_mut }
 	
 	pub type VectorOfVectorOfERStat = core::Vector<core::Vector<crate::text::ERStat>>;
 	
@@ -15728,7 +15728,7 @@ mod text_types {
 		cv_VectorOfVectorOfERStat_get, cv_VectorOfVectorOfERStat_set,
 		cv_VectorOfVectorOfERStat_push, cv_VectorOfVectorOfERStat_insert,
 	}
-	vector_non_copy_or_bool! { core::Vector<crate::text::ERStat> }
+	vector_boxed! { core::Vector<crate::text::ERStat>, cv_VectorOfVectorOfERStat_get_ref, cv_VectorOfVectorOfERStat_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_text)]
@@ -16196,7 +16196,7 @@ mod videoio_types {
 		cv_VectorOfVideoCapture_get, cv_VectorOfVideoCapture_set,
 		cv_VectorOfVideoCapture_push, cv_VectorOfVideoCapture_insert,
 	}
-	vector_non_copy_or_bool! { crate::videoio::VideoCapture }
+	vector_boxed! { crate::videoio::VideoCapture, cv_VectorOfVideoCapture_get_ref, cv_VectorOfVideoCapture_get_mut }
 	
 	pub type VectorOfVideoCaptureAPIs = core::Vector<crate::videoio::VideoCaptureAPIs>;
 	
@@ -18220,7 +18220,7 @@ mod xfeatures2d_types {
 		cv_VectorOfElliptic_KeyPoint_get, cv_VectorOfElliptic_KeyPoint_set,
 		cv_VectorOfElliptic_KeyPoint_push, cv_VectorOfElliptic_KeyPoint_insert,
 	}
-	vector_non_copy_or_bool! { crate::xfeatures2d::Elliptic_KeyPoint }
+	vector_boxed! { crate::xfeatures2d::Elliptic_KeyPoint, cv_VectorOfElliptic_KeyPoint_get_ref, cv_VectorOfElliptic_KeyPoint_get_mut }
 	
 }
 #[cfg(ocvrs_has_module_xfeatures2d)]
@@ -1496,6 +1496,8 @@ pub mod videoio {
 		#[inline] fn as_raw_mut_VideoCapture(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { VideoCapture, crate::videoio::VideoCaptureTraitConst, as_raw_VideoCapture, crate::videoio::VideoCaptureTrait, as_raw_mut_VideoCapture }
+	
 	impl VideoCapture {
 		/// Default constructor
 		/// 
@@ -1865,6 +1867,8 @@ pub mod videoio {
 		#[inline] fn as_raw_mut_VideoWriter(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { VideoWriter, crate::videoio::VideoWriterTraitConst, as_raw_VideoWriter, crate::videoio::VideoWriterTrait, as_raw_mut_VideoWriter }
+	
 	impl VideoWriter {
 		/// Default constructors
 		/// 
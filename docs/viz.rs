@@ -527,6 +527,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Camera(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Camera, crate::viz::CameraTraitConst, as_raw_Camera, crate::viz::CameraTrait, as_raw_mut_Camera }
+	
 	impl Camera {
 		/// Constructs a Camera.
 		/// 
@@ -691,6 +693,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Color(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Color, crate::viz::ColorTraitConst, as_raw_Color, crate::viz::ColorTrait, as_raw_mut_Color }
+	
 	impl Color {
 		/// ///////////////////////////////////////////////////////////////////////////////////////////////////
 		/// cv::viz::Color
@@ -1176,6 +1180,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_KeyboardEvent(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { KeyboardEvent, crate::viz::KeyboardEventTraitConst, as_raw_KeyboardEvent, crate::viz::KeyboardEventTrait, as_raw_mut_KeyboardEvent }
+	
 	impl KeyboardEvent {
 		/// Constructs a KeyboardEvent.
 		/// 
@@ -1322,6 +1328,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Mesh(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Mesh, crate::viz::MeshTraitConst, as_raw_Mesh, crate::viz::MeshTrait, as_raw_mut_Mesh }
+	
 	impl Mesh {
 		#[inline]
 		pub fn default() -> Result<crate::viz::Mesh> {
@@ -1457,6 +1465,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_MouseEvent(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { MouseEvent, crate::viz::MouseEventTraitConst, as_raw_MouseEvent, crate::viz::MouseEventTrait, as_raw_mut_MouseEvent }
+	
 	impl MouseEvent {
 		/// Constructs a MouseEvent.
 		/// 
@@ -2089,6 +2099,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Viz3d(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Viz3d, crate::viz::Viz3dTraitConst, as_raw_Viz3d, crate::viz::Viz3dTrait, as_raw_mut_Viz3d }
+	
 	impl Viz3d {
 		/// The constructors.
 		/// 
@@ -2157,6 +2169,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WArrow, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WArrow {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2165,6 +2179,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WArrow, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WArrowTraitConst for WArrow {
 		#[inline] fn as_raw_WArrow(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2173,6 +2189,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WArrow(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WArrow, crate::viz::WArrowTraitConst, as_raw_WArrow, crate::viz::WArrowTrait, as_raw_mut_WArrow }
+	
 	impl WArrow {
 		/// Constructs an WArrow.
 		/// 
@@ -2241,6 +2259,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCameraPosition, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCameraPosition {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2249,6 +2269,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCameraPosition, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCameraPositionTraitConst for WCameraPosition {
 		#[inline] fn as_raw_WCameraPosition(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2257,6 +2279,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCameraPosition(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCameraPosition, crate::viz::WCameraPositionTraitConst, as_raw_WCameraPosition, crate::viz::WCameraPositionTrait, as_raw_mut_WCameraPosition }
+	
 	impl WCameraPosition {
 		/// Creates camera coordinate frame at the origin.
 		/// 
@@ -2417,6 +2441,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCircle, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCircle {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2425,6 +2451,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCircle, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCircleTraitConst for WCircle {
 		#[inline] fn as_raw_WCircle(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2433,6 +2461,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCircle(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCircle, crate::viz::WCircleTraitConst, as_raw_WCircle, crate::viz::WCircleTrait, as_raw_mut_WCircle }
+	
 	impl WCircle {
 		/// Constructs default planar circle centered at origin with plane normal along z-axis
 		/// 
@@ -2522,6 +2552,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloud, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCloud {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2530,6 +2562,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloud, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCloudTraitConst for WCloud {
 		#[inline] fn as_raw_WCloud(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2538,6 +2572,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCloud(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloud, crate::viz::WCloudTraitConst, as_raw_WCloud, crate::viz::WCloudTrait, as_raw_mut_WCloud }
+	
 	impl WCloud {
 		/// Constructs a WCloud.
 		/// 
@@ -2715,6 +2751,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudCollection, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCloudCollection {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2723,6 +2761,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudCollection, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCloudCollectionTraitConst for WCloudCollection {
 		#[inline] fn as_raw_WCloudCollection(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2731,6 +2771,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCloudCollection(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudCollection, crate::viz::WCloudCollectionTraitConst, as_raw_WCloudCollection, crate::viz::WCloudCollectionTrait, as_raw_mut_WCloudCollection }
+	
 	impl WCloudCollection {
 		#[inline]
 		pub fn default() -> Result<crate::viz::WCloudCollection> {
@@ -2785,6 +2827,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudNormals, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCloudNormals {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2793,6 +2837,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudNormals, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCloudNormalsTraitConst for WCloudNormals {
 		#[inline] fn as_raw_WCloudNormals(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2801,6 +2847,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCloudNormals(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCloudNormals, crate::viz::WCloudNormalsTraitConst, as_raw_WCloudNormals, crate::viz::WCloudNormalsTrait, as_raw_mut_WCloudNormals }
+	
 	impl WCloudNormals {
 		/// Constructs a WCloudNormals.
 		/// 
@@ -2873,6 +2921,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCone, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCone {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2881,6 +2931,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCone, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WConeTraitConst for WCone {
 		#[inline] fn as_raw_WCone(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2889,6 +2941,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCone(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCone, crate::viz::WConeTraitConst, as_raw_WCone, crate::viz::WConeTrait, as_raw_mut_WCone }
+	
 	impl WCone {
 		/// Constructs default cone oriented along x-axis with center of its base located at origin
 		/// 
@@ -2976,6 +3030,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCoordinateSystem, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCoordinateSystem {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2984,6 +3040,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCoordinateSystem, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCoordinateSystemTraitConst for WCoordinateSystem {
 		#[inline] fn as_raw_WCoordinateSystem(&self) -> *const c_void { self.as_raw() }
 	}
@@ -2992,6 +3050,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCoordinateSystem(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCoordinateSystem, crate::viz::WCoordinateSystemTraitConst, as_raw_WCoordinateSystem, crate::viz::WCoordinateSystemTrait, as_raw_mut_WCoordinateSystem }
+	
 	impl WCoordinateSystem {
 		/// Constructs a WCoordinateSystem.
 		/// 
@@ -3053,6 +3113,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCube, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCube {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3061,6 +3123,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCube, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCubeTraitConst for WCube {
 		#[inline] fn as_raw_WCube(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3069,6 +3133,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCube(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCube, crate::viz::WCubeTraitConst, as_raw_WCube, crate::viz::WCubeTrait, as_raw_mut_WCube }
+	
 	impl WCube {
 		/// Constructs a WCube.
 		/// 
@@ -3138,6 +3204,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCylinder, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WCylinder {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3146,6 +3214,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCylinder, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WCylinderTraitConst for WCylinder {
 		#[inline] fn as_raw_WCylinder(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3154,6 +3224,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WCylinder(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WCylinder, crate::viz::WCylinderTraitConst, as_raw_WCylinder, crate::viz::WCylinderTrait, as_raw_mut_WCylinder }
+	
 	impl WCylinder {
 		/// Constructs a WCylinder.
 		/// 
@@ -3220,6 +3292,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WGrid, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WGrid {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3228,6 +3302,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WGrid, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WGridTraitConst for WGrid {
 		#[inline] fn as_raw_WGrid(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3236,6 +3312,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WGrid(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WGrid, crate::viz::WGridTraitConst, as_raw_WGrid, crate::viz::WGridTrait, as_raw_mut_WGrid }
+	
 	impl WGrid {
 		/// Constructs a WGrid.
 		/// 
@@ -3344,6 +3422,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImage3D, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WImage3D {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3352,6 +3432,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImage3D, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WImage3DTraitConst for WImage3D {
 		#[inline] fn as_raw_WImage3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3360,6 +3442,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WImage3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImage3D, crate::viz::WImage3DTraitConst, as_raw_WImage3D, crate::viz::WImage3DTrait, as_raw_mut_WImage3D }
+	
 	impl WImage3D {
 		/// Constructs an WImage3D.
 		/// 
@@ -3453,6 +3537,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImageOverlay, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget2DTraitConst for WImageOverlay {
 		#[inline] fn as_raw_Widget2D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3461,6 +3547,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImageOverlay, crate::viz::Widget2DTraitConst, as_raw_Widget2D, crate::viz::Widget2DTrait, as_raw_mut_Widget2D }
+	
 	impl crate::viz::WImageOverlayTraitConst for WImageOverlay {
 		#[inline] fn as_raw_WImageOverlay(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3469,6 +3557,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WImageOverlay(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WImageOverlay, crate::viz::WImageOverlayTraitConst, as_raw_WImageOverlay, crate::viz::WImageOverlayTrait, as_raw_mut_WImageOverlay }
+	
 	impl WImageOverlay {
 		/// Constructs an WImageOverlay.
 		/// 
@@ -3529,6 +3619,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WLine, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WLine {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3537,6 +3629,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WLine, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WLineTraitConst for WLine {
 		#[inline] fn as_raw_WLine(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3545,6 +3639,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WLine(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WLine, crate::viz::WLineTraitConst, as_raw_WLine, crate::viz::WLineTrait, as_raw_mut_WLine }
+	
 	impl WLine {
 		/// Constructs a WLine.
 		/// 
@@ -3615,6 +3711,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WMesh, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WMesh {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3623,6 +3721,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WMesh, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WMeshTraitConst for WMesh {
 		#[inline] fn as_raw_WMesh(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3631,6 +3731,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WMesh(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WMesh, crate::viz::WMeshTraitConst, as_raw_WMesh, crate::viz::WMeshTrait, as_raw_mut_WMesh }
+	
 	impl WMesh {
 		#[inline]
 		pub fn new(mesh: &crate::viz::Mesh) -> Result<crate::viz::WMesh> {
@@ -3701,6 +3803,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPaintedCloud, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WPaintedCloud {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3709,6 +3813,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPaintedCloud, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WPaintedCloudTraitConst for WPaintedCloud {
 		#[inline] fn as_raw_WPaintedCloud(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3717,6 +3823,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WPaintedCloud(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPaintedCloud, crate::viz::WPaintedCloudTraitConst, as_raw_WPaintedCloud, crate::viz::WPaintedCloudTrait, as_raw_mut_WPaintedCloud }
+	
 	impl WPaintedCloud {
 		/// Paint cloud with default gradient between cloud bounds points
 		#[inline]
@@ -3797,6 +3905,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPlane, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WPlane {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3805,6 +3915,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPlane, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WPlaneTraitConst for WPlane {
 		#[inline] fn as_raw_WPlane(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3813,6 +3925,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WPlane(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPlane, crate::viz::WPlaneTraitConst, as_raw_WPlane, crate::viz::WPlaneTrait, as_raw_mut_WPlane }
+	
 	impl WPlane {
 		/// Constructs a default plane with center point at origin and normal oriented along z-axis.
 		/// 
@@ -3898,6 +4012,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPolyLine, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WPolyLine {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3906,6 +4022,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPolyLine, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WPolyLineTraitConst for WPolyLine {
 		#[inline] fn as_raw_WPolyLine(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3914,6 +4032,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WPolyLine(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WPolyLine, crate::viz::WPolyLineTraitConst, as_raw_WPolyLine, crate::viz::WPolyLineTrait, as_raw_mut_WPolyLine }
+	
 	impl WPolyLine {
 		#[inline]
 		pub fn new(points: &impl core::ToInputArray, colors: &impl core::ToInputArray) -> Result<crate::viz::WPolyLine> {
@@ -3989,6 +4109,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WSphere, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WSphere {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -3997,6 +4119,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WSphere, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WSphereTraitConst for WSphere {
 		#[inline] fn as_raw_WSphere(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4005,6 +4129,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WSphere(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WSphere, crate::viz::WSphereTraitConst, as_raw_WSphere, crate::viz::WSphereTrait, as_raw_mut_WSphere }
+	
 	impl WSphere {
 		/// Constructs a WSphere.
 		/// 
@@ -4095,6 +4221,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget2DTraitConst for WText {
 		#[inline] fn as_raw_Widget2D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4103,6 +4231,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText, crate::viz::Widget2DTraitConst, as_raw_Widget2D, crate::viz::Widget2DTrait, as_raw_mut_Widget2D }
+	
 	impl crate::viz::WTextTraitConst for WText {
 		#[inline] fn as_raw_WText(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4111,6 +4241,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WText(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText, crate::viz::WTextTraitConst, as_raw_WText, crate::viz::WTextTrait, as_raw_mut_WText }
+	
 	impl WText {
 		/// Constructs a WText.
 		/// 
@@ -4202,6 +4334,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText3D, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WText3D {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4210,6 +4344,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText3D, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WText3DTraitConst for WText3D {
 		#[inline] fn as_raw_WText3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4218,6 +4354,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WText3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WText3D, crate::viz::WText3DTraitConst, as_raw_WText3D, crate::viz::WText3DTrait, as_raw_mut_WText3D }
+	
 	impl WText3D {
 		/// Constructs a WText3D.
 		/// 
@@ -4286,6 +4424,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectory, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WTrajectory {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4294,6 +4434,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectory, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WTrajectoryTraitConst for WTrajectory {
 		#[inline] fn as_raw_WTrajectory(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4302,6 +4444,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WTrajectory(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectory, crate::viz::WTrajectoryTraitConst, as_raw_WTrajectory, crate::viz::WTrajectoryTrait, as_raw_mut_WTrajectory }
+	
 	impl WTrajectory {
 		/// Constructs a WTrajectory.
 		/// 
@@ -4375,6 +4519,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectoryFrustums, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WTrajectoryFrustums {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4383,6 +4529,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectoryFrustums, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WTrajectoryFrustumsTraitConst for WTrajectoryFrustums {
 		#[inline] fn as_raw_WTrajectoryFrustums(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4391,6 +4539,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WTrajectoryFrustums(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectoryFrustums, crate::viz::WTrajectoryFrustumsTraitConst, as_raw_WTrajectoryFrustums, crate::viz::WTrajectoryFrustumsTrait, as_raw_mut_WTrajectoryFrustums }
+	
 	impl WTrajectoryFrustums {
 		/// Constructs a WTrajectoryFrustums.
 		/// 
@@ -4486,6 +4636,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectorySpheres, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WTrajectorySpheres {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4494,6 +4646,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectorySpheres, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WTrajectorySpheresTraitConst for WTrajectorySpheres {
 		#[inline] fn as_raw_WTrajectorySpheres(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4502,6 +4656,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WTrajectorySpheres(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WTrajectorySpheres, crate::viz::WTrajectorySpheresTraitConst, as_raw_WTrajectorySpheres, crate::viz::WTrajectorySpheresTrait, as_raw_mut_WTrajectorySpheres }
+	
 	impl WTrajectorySpheres {
 		/// Constructs a WTrajectorySpheres.
 		/// 
@@ -4599,6 +4755,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WWidgetMerger, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for WWidgetMerger {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4607,6 +4765,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WWidgetMerger, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl crate::viz::WWidgetMergerTraitConst for WWidgetMerger {
 		#[inline] fn as_raw_WWidgetMerger(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4615,6 +4775,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_WWidgetMerger(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WWidgetMerger, crate::viz::WWidgetMergerTraitConst, as_raw_WWidgetMerger, crate::viz::WWidgetMergerTrait, as_raw_mut_WWidgetMerger }
+	
 	impl WWidgetMerger {
 		#[inline]
 		pub fn default() -> Result<crate::viz::WWidgetMerger> {
@@ -4737,6 +4899,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Widget, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl Widget {
 		#[inline]
 		pub fn default() -> Result<crate::viz::Widget> {
@@ -4825,6 +4989,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Widget2D, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget2DTraitConst for Widget2D {
 		#[inline] fn as_raw_Widget2D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4833,6 +4999,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget2D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Widget2D, crate::viz::Widget2DTraitConst, as_raw_Widget2D, crate::viz::Widget2DTrait, as_raw_mut_Widget2D }
+	
 	impl Widget2D {
 		#[inline]
 		pub fn default() -> Result<crate::viz::Widget2D> {
@@ -4947,6 +5115,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Widget3D, crate::viz::WidgetTraitConst, as_raw_Widget, crate::viz::WidgetTrait, as_raw_mut_Widget }
+	
 	impl crate::viz::Widget3DTraitConst for Widget3D {
 		#[inline] fn as_raw_Widget3D(&self) -> *const c_void { self.as_raw() }
 	}
@@ -4955,6 +5125,8 @@ pub mod viz {
 		#[inline] fn as_raw_mut_Widget3D(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { Widget3D, crate::viz::Widget3DTraitConst, as_raw_Widget3D, crate::viz::Widget3DTrait, as_raw_mut_Widget3D }
+	
 	impl Widget3D {
 		#[inline]
 		pub fn default() -> Result<crate::viz::Widget3D> {
@@ -98,6 +98,8 @@ pub mod wechat_qrcode {
 		#[inline] fn as_raw_mut_WeChatQRCode(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WeChatQRCode, crate::wechat_qrcode::WeChatQRCodeTraitConst, as_raw_WeChatQRCode, crate::wechat_qrcode::WeChatQRCodeTrait, as_raw_mut_WeChatQRCode }
+	
 	impl WeChatQRCode {
 		/// Initialize the WeChatQRCode.
 		/// It includes two models, which are packaged with caffe format.
@@ -95,6 +95,8 @@ pub mod xobjdetect {
 		#[inline] fn as_raw_mut_WBDetector(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WBDetector, crate::xobjdetect::WBDetectorTraitConst, as_raw_WBDetector, crate::xobjdetect::WBDetectorTrait, as_raw_mut_WBDetector }
+	
 	impl WBDetector {
 		/// Create instance of WBDetector
 		#[inline]
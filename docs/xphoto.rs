@@ -471,6 +471,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayworldWB, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::xphoto::WhiteBalancerTraitConst for GrayworldWB {
 		#[inline] fn as_raw_WhiteBalancer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -479,6 +481,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_WhiteBalancer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayworldWB, crate::xphoto::WhiteBalancerTraitConst, as_raw_WhiteBalancer, crate::xphoto::WhiteBalancerTrait, as_raw_mut_WhiteBalancer }
+	
 	impl crate::xphoto::GrayworldWBTraitConst for GrayworldWB {
 		#[inline] fn as_raw_GrayworldWB(&self) -> *const c_void { self.as_raw() }
 	}
@@ -487,6 +491,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_GrayworldWB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { GrayworldWB, crate::xphoto::GrayworldWBTraitConst, as_raw_GrayworldWB, crate::xphoto::GrayworldWBTrait, as_raw_mut_GrayworldWB }
+	
 	impl GrayworldWB {
 	}
 	
@@ -646,6 +652,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LearningBasedWB, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::xphoto::WhiteBalancerTraitConst for LearningBasedWB {
 		#[inline] fn as_raw_WhiteBalancer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -654,6 +662,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_WhiteBalancer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LearningBasedWB, crate::xphoto::WhiteBalancerTraitConst, as_raw_WhiteBalancer, crate::xphoto::WhiteBalancerTrait, as_raw_mut_WhiteBalancer }
+	
 	impl crate::xphoto::LearningBasedWBTraitConst for LearningBasedWB {
 		#[inline] fn as_raw_LearningBasedWB(&self) -> *const c_void { self.as_raw() }
 	}
@@ -662,6 +672,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_LearningBasedWB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { LearningBasedWB, crate::xphoto::LearningBasedWBTraitConst, as_raw_LearningBasedWB, crate::xphoto::LearningBasedWBTrait, as_raw_mut_LearningBasedWB }
+	
 	impl LearningBasedWB {
 	}
 	
@@ -826,6 +838,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SimpleWB, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::xphoto::WhiteBalancerTraitConst for SimpleWB {
 		#[inline] fn as_raw_WhiteBalancer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -834,6 +848,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_WhiteBalancer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SimpleWB, crate::xphoto::WhiteBalancerTraitConst, as_raw_WhiteBalancer, crate::xphoto::WhiteBalancerTrait, as_raw_mut_WhiteBalancer }
+	
 	impl crate::xphoto::SimpleWBTraitConst for SimpleWB {
 		#[inline] fn as_raw_SimpleWB(&self) -> *const c_void { self.as_raw() }
 	}
@@ -842,6 +858,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_SimpleWB(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { SimpleWB, crate::xphoto::SimpleWBTraitConst, as_raw_SimpleWB, crate::xphoto::SimpleWBTrait, as_raw_mut_SimpleWB }
+	
 	impl SimpleWB {
 	}
 	
@@ -963,6 +981,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDurand, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::photo::TonemapTraitConst for TonemapDurand {
 		#[inline] fn as_raw_Tonemap(&self) -> *const c_void { self.as_raw() }
 	}
@@ -971,6 +991,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Tonemap(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDurand, crate::photo::TonemapTraitConst, as_raw_Tonemap, crate::photo::TonemapTrait, as_raw_mut_Tonemap }
+	
 	impl crate::xphoto::TonemapDurandTraitConst for TonemapDurand {
 		#[inline] fn as_raw_TonemapDurand(&self) -> *const c_void { self.as_raw() }
 	}
@@ -979,6 +1001,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_TonemapDurand(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { TonemapDurand, crate::xphoto::TonemapDurandTraitConst, as_raw_TonemapDurand, crate::xphoto::TonemapDurandTrait, as_raw_mut_TonemapDurand }
+	
 	impl TonemapDurand {
 	}
 	
@@ -1039,6 +1063,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_Algorithm(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WhiteBalancer, core::AlgorithmTraitConst, as_raw_Algorithm, core::AlgorithmTrait, as_raw_mut_Algorithm }
+	
 	impl crate::xphoto::WhiteBalancerTraitConst for WhiteBalancer {
 		#[inline] fn as_raw_WhiteBalancer(&self) -> *const c_void { self.as_raw() }
 	}
@@ -1047,6 +1073,8 @@ pub mod xphoto {
 		#[inline] fn as_raw_mut_WhiteBalancer(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	boxed_ref! { WhiteBalancer, crate::xphoto::WhiteBalancerTraitConst, as_raw_WhiteBalancer, crate::xphoto::WhiteBalancerTrait, as_raw_mut_WhiteBalancer }
+	
 	impl WhiteBalancer {
 	}
 	
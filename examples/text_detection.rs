@@ -82,7 +82,7 @@ fn main() -> Result<()> {
 				None
 			};
 			let mut contours = VectorOfVectorOfPoint::new();
-			for quadrangle in &det_results {
+			for quadrangle in det_results {
 				let mut quadrangle_2f = VectorOfPoint2f::new();
 				for pt in &quadrangle {
 					quadrangle_2f.push(Point2f::new(pt.x as f32, pt.y as f32))
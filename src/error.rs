@@ -2,10 +2,115 @@ use std::{char::TryFromCharError, ffi::NulError, fmt};
 
 use crate::core;
 
+macro_rules! error_codes {
+	($($name: ident => $code: path, $doc: literal;)+) => {
+		/// Typed representation of the `cv::Error::Code` values
+		///
+		/// Use `Error::error_code()` to get it from the `Error`, it's convertible from and to the raw `i32` code.
+		#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+		pub enum ErrorCode {
+			$(
+				#[doc = $doc]
+				$name,
+			)+
+			/// Code that doesn't correspond to any known `cv::Error::Code` value
+			Unknown(i32),
+		}
+
+		impl From<i32> for ErrorCode {
+			#[inline]
+			fn from(code: i32) -> Self {
+				match code {
+					$(
+						$code => Self::$name,
+					)+
+					code => Self::Unknown(code),
+				}
+			}
+		}
+
+		impl From<ErrorCode> for i32 {
+			#[inline]
+			fn from(code: ErrorCode) -> Self {
+				match code {
+					$(
+						ErrorCode::$name => $code,
+					)+
+					ErrorCode::Unknown(code) => code,
+				}
+			}
+		}
+	};
+}
+
+error_codes! {
+	StsOk => core::StsOk, "everything is ok";
+	StsBackTrace => core::StsBackTrace, "pseudo error for back trace";
+	StsError => core::StsError, "unknown /unspecified error";
+	StsInternal => core::StsInternal, "internal error (bad state)";
+	StsNoMem => core::StsNoMem, "insufficient memory";
+	StsBadArg => core::StsBadArg, "function arg/param is bad";
+	StsBadFunc => core::StsBadFunc, "unsupported function";
+	StsNoConv => core::StsNoConv, "iteration didn't converge";
+	StsAutoTrace => core::StsAutoTrace, "tracing";
+	HeaderIsNull => core::HeaderIsNull, "image header is NULL";
+	BadImageSize => core::BadImageSize, "image size is invalid";
+	BadOffset => core::BadOffset, "offset is invalid";
+	BadDataPtr => core::BadDataPtr, "bad data pointer";
+	BadStep => core::BadStep, "image step is wrong, this may happen for a non-continuous matrix";
+	BadModelOrChSeq => core::BadModelOrChSeq, "bad model or channel sequence";
+	BadNumChannels => core::BadNumChannels, "bad number of channels";
+	BadNumChannel1U => core::BadNumChannel1U, "bad number of channels";
+	BadDepth => core::BadDepth, "input image depth is not supported by the function";
+	BadAlphaChannel => core::BadAlphaChannel, "bad alpha channel";
+	BadOrder => core::BadOrder, "number of dimensions is out of range";
+	BadOrigin => core::BadOrigin, "incorrect input origin";
+	BadAlign => core::BadAlign, "incorrect input align";
+	BadCallBack => core::BadCallBack, "bad callback";
+	BadTileSize => core::BadTileSize, "bad tile size";
+	BadCOI => core::BadCOI, "input COI is not supported";
+	BadROISize => core::BadROISize, "incorrect input roi";
+	MaskIsTiled => core::MaskIsTiled, "mask is tiled";
+	StsNullPtr => core::StsNullPtr, "null pointer";
+	StsVecLengthErr => core::StsVecLengthErr, "incorrect vector length";
+	StsFilterStructContentErr => core::StsFilterStructContentErr, "incorrect filter structure content";
+	StsKernelStructContentErr => core::StsKernelStructContentErr, "incorrect transform kernel content";
+	StsFilterOffsetErr => core::StsFilterOffsetErr, "incorrect filter offset value";
+	StsBadSize => core::StsBadSize, "the input/output structure size is incorrect";
+	StsDivByZero => core::StsDivByZero, "division by zero";
+	StsInplaceNotSupported => core::StsInplaceNotSupported, "in-place operation is not supported";
+	StsObjectNotFound => core::StsObjectNotFound, "request can't be completed";
+	StsUnmatchedFormats => core::StsUnmatchedFormats, "formats of input/output arrays differ";
+	StsBadFlag => core::StsBadFlag, "flag is wrong or not supported";
+	StsBadPoint => core::StsBadPoint, "bad CvPoint";
+	StsBadMask => core::StsBadMask, "bad format of mask (neither 8uC1 nor 8sC1)";
+	StsUnmatchedSizes => core::StsUnmatchedSizes, "sizes of input/output structures do not match";
+	StsUnsupportedFormat => core::StsUnsupportedFormat, "the data format/type is not supported by the function";
+	StsOutOfRange => core::StsOutOfRange, "some of parameters are out of range";
+	StsParseError => core::StsParseError, "invalid syntax/structure of the parsed file";
+	StsNotImplemented => core::StsNotImplemented, "the requested function/feature is not implemented";
+	StsBadMemBlock => core::StsBadMemBlock, "an allocated block has been corrupted";
+	StsAssert => core::StsAssert, "assertion failed";
+	GpuNotSupported => core::GpuNotSupported, "no CUDA support";
+	GpuApiCallError => core::GpuApiCallError, "GPU API call error";
+	OpenGlNotSupported => core::OpenGlNotSupported, "no OpenGL support";
+	OpenGlApiCallError => core::OpenGlApiCallError, "OpenGL API call error";
+	OpenCLApiCallError => core::OpenCLApiCallError, "OpenCL API call error";
+	OpenCLDoubleNotSupported => core::OpenCLDoubleNotSupported, "OpenCL double is not supported";
+	OpenCLInitError => core::OpenCLInitError, "OpenCL initialization error";
+	OpenCLNoAMDBlasFft => core::OpenCLNoAMDBlasFft, "OpenCL AMD BLAS/FFT library is not available";
+}
+
 #[derive(Debug)]
 pub struct Error {
+	/// Raw error code, usually one of the `cv::Error::Code` values, see `Error::error_code()` for the typed variant
 	pub code: i32,
+	/// Full error message, for errors coming from `cv::Exception` it's the result of `what()`
 	pub message: String,
+	err: String,
+	func: String,
+	file: String,
+	line: i32,
 }
 
 impl Error {
@@ -14,8 +119,54 @@ impl Error {
 		Self {
 			code,
 			message: message.into(),
+			err: String::new(),
+			func: String::new(),
+			file: String::new(),
+			line: 0,
+		}
+	}
+
+	#[inline]
+	pub(crate) fn with_context(code: i32, message: String, err: String, func: String, file: String, line: i32) -> Self {
+		Self {
+			code,
+			message,
+			err,
+			func,
+			file,
+			line,
 		}
 	}
+
+	/// Typed error code
+	#[inline]
+	pub fn error_code(&self) -> ErrorCode {
+		ErrorCode::from(self.code)
+	}
+
+	/// Short error description (`cv::Exception::err`), empty if the error didn't originate from `cv::Exception`
+	#[inline]
+	pub fn err(&self) -> &str {
+		&self.err
+	}
+
+	/// Name of the C++ function where the error occurred (`cv::Exception::func`), empty if unknown
+	#[inline]
+	pub fn func(&self) -> &str {
+		&self.func
+	}
+
+	/// Source file where the error occurred (`cv::Exception::file`), empty if unknown
+	#[inline]
+	pub fn file(&self) -> &str {
+		&self.file
+	}
+
+	/// Source file line where the error occurred (`cv::Exception::line`), 0 if unknown
+	#[inline]
+	pub fn line(&self) -> i32 {
+		self.line
+	}
 }
 
 impl fmt::Display for Error {
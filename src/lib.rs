@@ -1,7 +1,7 @@
 #![allow(broken_intra_doc_links)]
 
 pub use cond_macros::*;
-pub use error::{Error, Result};
+pub use error::{Error, ErrorCode, Result};
 
 pub use crate::opencv::hub::*;
 
@@ -37,14 +37,14 @@ pub(crate) mod mod_prelude_sys {
 /// Prelude for generated modules and types
 pub(crate) mod mod_prelude {
 	pub use crate::{
-		boxed_cast_base, boxed_cast_descendant,
+		boxed_cast_base, boxed_cast_descendant, boxed_ref,
 		core::{CV_MAKETYPE, CV_MAKE_TYPE},
 		extern_arg_send, extern_container_send, extern_receive, extern_send,
 		hub_prelude::*,
 		input_output_array_ref_forward,
 		mod_prelude_sys::*,
 		opencv_type_boxed, opencv_type_enum, opencv_type_simple, ptr_cast_base, ptr_extern, ptr_extern_ctor, tuple_extern,
-		vector_copy_non_bool, vector_extern, vector_non_copy_or_bool, Error, Result,
+		vector_boxed, vector_copy_non_bool, vector_extern, vector_non_copy_or_bool, Error, Result,
 	};
 	pub use std::convert::TryFrom;
 }
@@ -5,9 +5,11 @@ pub use input_output_array::*;
 pub use mat::*;
 pub use mat_ops::*;
 pub use matx::*;
+pub use pixel::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
+pub use quat::*;
 pub use rect::*;
 pub use scalar::*;
 pub use size::*;
@@ -17,6 +19,8 @@ pub use vec::*;
 pub use vector::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
 
+pub(crate) use mat::MatHeader;
+
 mod affine3;
 mod data_type;
 mod gpumat;
@@ -24,11 +28,15 @@ mod input_output_array;
 mod mat;
 mod mat_ops;
 mod matx;
+mod pixel;
 mod point;
 mod point3;
 pub(crate) mod ptr;
+mod quat;
 mod rect;
 mod scalar;
+#[cfg(feature = "serde")]
+mod serde;
 mod size;
 mod sized;
 mod tuple;
@@ -1,11 +1,13 @@
-use num_traits::{One, Zero};
+use std::ops::{Mul, MulAssign};
 
-use crate::core::{Matx44, MatxTrait};
+use num_traits::{Float, One, Zero};
+
+use crate::core::{Matx33, Matx44, MatxTrait, Point3_, Quat, VecN};
 use crate::opencv_type_simple_generic;
 
 /// [docs.opencv.org](https://docs.opencv.org/master/dd/d99/classcv_1_1Affine3.html)
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Affine3<T: Copy> {
 	pub matrix: Matx44<T>,
 }
@@ -16,4 +18,252 @@ impl<T: Copy + Zero + One> Default for Affine3<T> {
 	}
 }
 
+impl<T: Copy + Zero + One> Affine3<T> {
+	/// Identity transform
+	#[inline]
+	pub fn identity() -> Self {
+		Self::default()
+	}
+
+	/// Creates the transform from the full 4x4 `matrix`
+	#[inline]
+	pub fn from_matrix(matrix: Matx44<T>) -> Self {
+		Self { matrix }
+	}
+
+	/// Creates the transform from the 3x3 `rotation` (or any other linear transformation) matrix and the `translation`
+	#[inline]
+	pub fn from_rotation(rotation: Matx33<T>, translation: VecN<T, 3>) -> Self {
+		let mut out = Self::default();
+		out.set_linear(rotation);
+		out.set_translation(translation);
+		out
+	}
+
+	/// Left 3x3 part of the transform matrix
+	#[inline]
+	pub fn linear(&self) -> Matx33<T> {
+		let mut out = Matx33::zeros();
+		for row in 0..3 {
+			for col in 0..3 {
+				out[(row, col)] = self.matrix[(row, col)];
+			}
+		}
+		out
+	}
+
+	#[inline]
+	pub fn set_linear(&mut self, linear: Matx33<T>) {
+		for row in 0..3 {
+			for col in 0..3 {
+				self.matrix[(row, col)] = linear[(row, col)];
+			}
+		}
+	}
+
+	/// Translation part of the transform
+	#[inline]
+	pub fn translation(&self) -> VecN<T, 3> {
+		VecN([self.matrix[(0, 3)], self.matrix[(1, 3)], self.matrix[(2, 3)]])
+	}
+
+	#[inline]
+	pub fn set_translation(&mut self, translation: VecN<T, 3>) {
+		for (row, t) in translation.into_iter().enumerate() {
+			self.matrix[(row, 3)] = t;
+		}
+	}
+}
+
+impl<T: Float> Affine3<T> {
+	/// Creates the transform from the rotation specified as Rodrigues vector and the `translation`
+	#[inline]
+	pub fn from_rvec(rvec: VecN<T, 3>, translation: VecN<T, 3>) -> Self {
+		Self::from_rotation(rodrigues_to_rotation(rvec), translation)
+	}
+
+	/// Creates the transform from the rotation specified as quaternion (`Quat` or `[w, x, y, z]` vector) and the
+	/// `translation`, the quaternion doesn't need to be normalized
+	#[inline]
+	pub fn from_quat(quat: impl Into<Quat<T>>, translation: VecN<T, 3>) -> Self {
+		Self::from_rotation(quat.into().to_rot_mat3(), translation)
+	}
+
+	/// Rotation part of the transform
+	///
+	/// Same as `linear()` like in `cv::Affine3::rotation()`, so it's not orthonormal if the transform includes scaling or
+	/// shear. `rvec()` takes care of that by using the closest orthonormal matrix.
+	#[inline]
+	pub fn rotation(&self) -> Matx33<T> {
+		self.linear()
+	}
+
+	/// Sets the rotation part of the transform, same as `set_linear()`
+	#[inline]
+	pub fn set_rotation(&mut self, rotation: Matx33<T>) {
+		self.set_linear(rotation)
+	}
+
+	/// Rotation part of the transform as Rodrigues vector
+	#[inline]
+	pub fn rvec(&self) -> VecN<T, 3> {
+		rotation_to_rodrigues(&orthonormalize(self.rotation()))
+	}
+
+	/// Sets the rotation part of the transform from the Rodrigues vector
+	#[inline]
+	pub fn set_rvec(&mut self, rvec: VecN<T, 3>) {
+		self.set_linear(rodrigues_to_rotation(rvec))
+	}
+
+	/// Inverse transform, returns `None` if the linear part of the transform is singular
+	#[inline]
+	pub fn inv(&self) -> Option<Self> {
+		let linear = self.linear().inv()?;
+		let translation = -(linear * self.translation());
+		Some(Self::from_rotation(linear, translation))
+	}
+
+	/// Transform rotated by `rotation` after applying `self`, i.e. `Affine3::from_rotation(rotation, 0) * self`
+	#[inline]
+	pub fn rotate(&self, rotation: Matx33<T>) -> Self {
+		Self::from_rotation(rotation * self.linear(), rotation * self.translation())
+	}
+
+	/// Same as `rotate()` but with the rotation specified as Rodrigues vector
+	#[inline]
+	pub fn rotate_rvec(&self, rvec: VecN<T, 3>) -> Self {
+		self.rotate(rodrigues_to_rotation(rvec))
+	}
+
+	/// Transform translated by `translation` after applying `self`
+	#[inline]
+	pub fn translate(&self, translation: VecN<T, 3>) -> Self {
+		let mut out = *self;
+		out.set_translation(self.translation() + translation);
+		out
+	}
+
+	/// Transform that applies `self` followed by `affine`, i.e. `affine * self`
+	#[inline]
+	pub fn concatenate(&self, affine: &Self) -> Self {
+		*affine * *self
+	}
+}
+
 opencv_type_simple_generic! { Affine3<Copy> }
+
+/// Composition of the transforms, `rhs` is applied first
+impl<T: Float> Mul for Affine3<T> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		Self::from_rotation(
+			self.linear() * rhs.linear(),
+			self.linear() * rhs.translation() + self.translation(),
+		)
+	}
+}
+
+impl<T: Float> MulAssign for Affine3<T> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+impl<T: Float> Mul<VecN<T, 3>> for Affine3<T> {
+	type Output = VecN<T, 3>;
+
+	#[inline]
+	fn mul(self, rhs: VecN<T, 3>) -> Self::Output {
+		self.linear() * rhs + self.translation()
+	}
+}
+
+impl<T: Float> Mul<Point3_<T>> for Affine3<T> {
+	type Output = Point3_<T>;
+
+	#[inline]
+	fn mul(self, rhs: Point3_<T>) -> Self::Output {
+		Point3_::from_vec3(self * VecN([rhs.x, rhs.y, rhs.z]))
+	}
+}
+
+/// Rotation matrix from the Rodrigues vector, see `calib3d::rodrigues()`
+fn rodrigues_to_rotation<T: Float>(rvec: VecN<T, 3>) -> Matx33<T> {
+	let theta = rvec.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+	if theta < T::epsilon() {
+		return Matx33::eye();
+	}
+	let [x, y, z] = (rvec / theta).0;
+	let (s, c) = theta.sin_cos();
+	let c1 = T::one() - c;
+	Matx33::from_array([
+		c + c1 * x * x,
+		c1 * x * y - s * z,
+		c1 * x * z + s * y,
+		c1 * x * y + s * z,
+		c + c1 * y * y,
+		c1 * y * z - s * x,
+		c1 * x * z - s * y,
+		c1 * y * z + s * x,
+		c + c1 * z * z,
+	])
+}
+
+/// Rodrigues vector from the rotation matrix, see `calib3d::rodrigues()`
+fn rotation_to_rodrigues<T: Float>(r: &Matx33<T>) -> VecN<T, 3> {
+	let half = T::from(0.5).unwrap_or_else(T::one);
+	let mut out = VecN([r[(2, 1)] - r[(1, 2)], r[(0, 2)] - r[(2, 0)], r[(1, 0)] - r[(0, 1)]]);
+	let s = (out.iter().fold(T::zero(), |acc, &x| acc + x * x) * half * half).sqrt();
+	let c = ((r.trace() - T::one()) * half).max(-T::one()).min(T::one());
+	let theta = c.acos();
+	if s < T::from(1e-5).unwrap_or_else(T::epsilon) {
+		if c > T::zero() {
+			return VecN::all(T::zero());
+		}
+		// rotation by pi, the axis is restored from the diagonal
+		let x = ((r[(0, 0)] + T::one()) * half).max(T::zero()).sqrt();
+		let mut y = ((r[(1, 1)] + T::one()) * half).max(T::zero()).sqrt();
+		let mut z = ((r[(2, 2)] + T::one()) * half).max(T::zero()).sqrt();
+		if r[(0, 1)] < T::zero() {
+			y = -y;
+		}
+		if r[(0, 2)] < T::zero() {
+			z = -z;
+		}
+		if x.abs() < y.abs() && x.abs() < z.abs() && (r[(1, 2)] > T::zero()) != (y * z > T::zero()) {
+			z = -z;
+		}
+		out = VecN([x, y, z]);
+		let norm = out.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+		out * (theta / norm)
+	} else {
+		out * (theta / (s + s))
+	}
+}
+
+/// Closest orthonormal matrix to `m` computed with the Newton iteration for the polar decomposition
+fn orthonormalize<T: Float>(m: Matx33<T>) -> Matx33<T> {
+	let half = T::from(0.5).unwrap_or_else(T::one);
+	let eps = T::epsilon() * T::from(10).unwrap_or_else(T::one);
+	let mut out = m;
+	for _ in 0..100 {
+		let inv_t = match out.inv() {
+			Some(inv) => inv.t(),
+			None => break,
+		};
+		let mut diff = T::zero();
+		out.val_mut().iter_mut().zip(inv_t.val()).for_each(|(x, &x_inv_t)| {
+			let next = (*x + x_inv_t) * half;
+			diff = diff.max((next - *x).abs());
+			*x = next;
+		});
+		if diff <= eps {
+			break;
+		}
+	}
+	out
+}
@@ -1,5 +1,5 @@
 use crate::core;
-use crate::core::{Point3_, Point_, Rect_, Size_, VecN};
+use crate::core::{Bgr, Bgra, Gray, Hls, Hsv, Lab, Luv, Point3_, Point_, Quat, Rect_, Rgb, Rgba, Size_, VecN, Xyz, YCrCb};
 
 #[inline]
 pub const fn CV_MAT_DEPTH(flags: i32) -> i32 {
@@ -81,6 +81,10 @@ data_type!(i32, core::CV_32S, 1);
 data_type!(f32, core::CV_32F, 1);
 data_type!(f64, core::CV_64F, 1);
 
+// half-precision float, OpenCV has no depth matching `half::bf16`
+#[cfg(all(feature = "half", not(ocvrs_opencv_branch_32)))]
+data_type!(half::f16, core::CV_16F, 1);
+
 #[cfg(feature = "rgb")]
 data_type!(rgb::RGB8, core::CV_8U, 3);
 #[cfg(feature = "rgb")]
@@ -98,6 +102,32 @@ data_type!(rgb::alt::BGRA8, core::CV_8U, 4);
 #[cfg(feature = "rgb")]
 data_type!(rgb::alt::ABGR8, core::CV_8U, 4);
 
+#[cfg(feature = "image")]
+macro_rules! data_type_image {
+	($pixel: ident, $channels: expr) => {
+		unsafe impl<T: DataType + image::Primitive> DataType for image::$pixel<T> {
+			#[inline]
+			fn opencv_depth() -> i32 {
+				T::opencv_depth()
+			}
+
+			#[inline]
+			fn opencv_channels() -> i32 {
+				$channels
+			}
+		}
+	};
+}
+
+#[cfg(feature = "image")]
+data_type_image!(Luma, 1);
+#[cfg(feature = "image")]
+data_type_image!(LumaA, 2);
+#[cfg(feature = "image")]
+data_type_image!(Rgb, 3);
+#[cfg(feature = "image")]
+data_type_image!(Rgba, 4);
+
 unsafe impl<T: DataType, const N: usize> DataType for VecN<T, N> {
 	#[inline]
 	fn opencv_depth() -> i32 {
@@ -110,6 +140,21 @@ unsafe impl<T: DataType, const N: usize> DataType for VecN<T, N> {
 	}
 }
 
+/// Complex number is stored as 2-channel element with the real part in the first channel, the layout that `core::dft()`
+/// uses for the complex output
+#[cfg(feature = "num-complex")]
+unsafe impl<T: DataType> DataType for num_complex::Complex<T> {
+	#[inline]
+	fn opencv_depth() -> i32 {
+		T::opencv_depth()
+	}
+
+	#[inline]
+	fn opencv_channels() -> i32 {
+		2
+	}
+}
+
 unsafe impl<T: DataType> DataType for Point_<T> {
 	#[inline]
 	fn opencv_depth() -> i32 {
@@ -157,3 +202,43 @@ unsafe impl<T: DataType> DataType for Rect_<T> {
 		4
 	}
 }
+
+unsafe impl<T: DataType> DataType for Quat<T> {
+	#[inline]
+	fn opencv_depth() -> i32 {
+		T::opencv_depth()
+	}
+
+	#[inline]
+	fn opencv_channels() -> i32 {
+		4
+	}
+}
+
+macro_rules! data_type_pixel {
+	($pixel: ident, $channels: expr) => {
+		unsafe impl<T: DataType> DataType for $pixel<T> {
+			#[inline]
+			fn opencv_depth() -> i32 {
+				T::opencv_depth()
+			}
+
+			#[inline]
+			fn opencv_channels() -> i32 {
+				$channels
+			}
+		}
+	};
+}
+
+data_type_pixel!(Gray, 1);
+data_type_pixel!(Bgr, 3);
+data_type_pixel!(Bgra, 4);
+data_type_pixel!(Rgb, 3);
+data_type_pixel!(Rgba, 4);
+data_type_pixel!(Hsv, 3);
+data_type_pixel!(Hls, 3);
+data_type_pixel!(Lab, 3);
+data_type_pixel!(Luv, 3);
+data_type_pixel!(Xyz, 3);
+data_type_pixel!(YCrCb, 3);
@@ -1,8 +1,12 @@
+use std::mem;
 use std::os::raw::c_void;
 
-use crate::core::{_InputArray, _InputArrayTrait, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, _OutputArrayTrait};
+use crate::core::{
+	_InputArray, _InputArrayTrait, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, _OutputArrayTrait, BoxedRef, DataType,
+	Mat, MatTrait, MatTraitConst, MatTraitConstManual,
+};
 use crate::traits::Boxed;
-use crate::{input_output_array, sys, Result};
+use crate::{core, input_array_ref_forward, input_output_array, output_array_ref_forward, sys, Error, Result};
 
 /// Trait to serve as a replacement for `InputArray` in C++ OpenCV
 ///
@@ -16,6 +20,43 @@ pub trait ToInputArray {
 
 input_output_array! { f64, from_f64 }
 
+impl<T: DataType> ToInputArray for [T] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		// the returned array has the same lifetime contract as the one of `Mat::input_array()`
+		unsafe { _InputArray::from_slice(self) }
+	}
+}
+
+input_array_ref_forward! { [T: DataType] [T] }
+
+impl<T: DataType, const N: usize> ToInputArray for [T; N] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.as_slice().input_array()
+	}
+}
+
+input_array_ref_forward! { [T: DataType, const N: usize] [T; N] }
+
+impl<T: DataType> ToInputArray for Vec<T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.as_slice().input_array()
+	}
+}
+
+input_array_ref_forward! { [T: DataType] Vec<T> }
+
+impl<T: Boxed + ToInputArray> ToInputArray for BoxedRef<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(**self).input_array()
+	}
+}
+
+input_array_ref_forward! { [T: Boxed + ToInputArray] BoxedRef<'_, T> }
+
 /// Trait to serve as a replacement for `OutputArray` in C++ OpenCV
 ///
 /// You can pass reference to the type implementing this trait everywhere where OpenCV API expects
@@ -75,6 +116,158 @@ impl<T: _InputOutputArrayTrait> ToInputOutputArray for T {
 	}
 }
 
+#[inline]
+fn len_i32(len: usize) -> Result<i32> {
+	i32::try_from(len).map_err(|_| Error::new(core::StsBadArg, format!("Length: {len} is too high")))
+}
+
+impl _InputArray {
+	/// Creates `_InputArray` referencing the elements of `s` as a single row matrix without copying
+	///
+	/// # Safety
+	/// The returned array doesn't borrow `s`, caller must make sure that it doesn't outlive `s`.
+	pub unsafe fn from_slice<T: DataType>(s: &[T]) -> Result<Self> {
+		extern "C" {
+			fn cv_manual_InputArray_from_slice(typ: i32, data: *const c_void, len: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		let len = len_i32(s.len())?;
+		return_send!(via ocvrs_return);
+		cv_manual_InputArray_from_slice(T::opencv_type(), s.as_ptr().cast::<c_void>(), len, ocvrs_return.as_mut_ptr());
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| Self::from_raw(ptr))
+	}
+}
+
+/// Output array over the elements of a `Vec`, OpenCV can reallocate it to any size
+///
+/// Pass it where OpenCV API expects `OutputArray` or `InputOutputArray`. The output is written directly to the `Vec` if it
+/// already has the final length, otherwise the elements of the reallocated output replace the contents of the `Vec` in
+/// `finish()`. Dropping `VecArray` does the same, but any error is ignored, so call `finish()` to handle it.
+///
+/// `Vec<T>` itself doesn't implement `ToOutputArray`: there is no hook that runs after the OpenCV function call to copy
+/// the reallocated output back, and the `_OutputArray` returned by `ToOutputArray::output_array()` doesn't borrow its
+/// source, so it can't safely write to the `Vec` when it's dropped. `VecArray` holds the mutable borrow of the `Vec`
+/// instead and does the copy while it's still alive.
+pub struct VecArray<'v, T: DataType> {
+	vec: &'v mut Vec<T>,
+	header: Mat,
+	finished: bool,
+}
+
+impl<'v, T: DataType> VecArray<'v, T> {
+	/// Creates a `Mat` header over the elements of `vec`, the `Vec` stays borrowed until `VecArray` is dropped
+	pub fn new(vec: &'v mut Vec<T>) -> Result<Self> {
+		let len = len_i32(vec.len())?;
+		let header = if len > 0 {
+			unsafe {
+				Mat::new_rows_cols_with_data(
+					1,
+					len,
+					T::opencv_type(),
+					vec.as_mut_ptr().cast::<c_void>(),
+					core::Mat_AUTO_STEP,
+				)
+			}?
+		} else {
+			// empty, but with the type that OpenCV checks against the FIXED_TYPE flag
+			unsafe { Mat::new_rows_cols(0, 0, T::opencv_type()) }?
+		};
+		Ok(Self {
+			vec,
+			header,
+			finished: false,
+		})
+	}
+
+	/// Copies the final elements to the `Vec` if OpenCV has reallocated the output
+	///
+	/// Returns an error if the channel count of the output doesn't match the `Vec` element type, the `Vec` is left unchanged
+	/// in that case.
+	pub fn finish(mut self) -> Result<()> {
+		self.finished = true;
+		self.reflect()
+	}
+
+	fn reflect(&mut self) -> Result<()> {
+		let header = mem::take(&mut self.header);
+		if header.data() == self.vec.as_ptr().cast::<u8>()
+			&& header.typ() == T::opencv_type()
+			&& header.is_continuous()
+			&& header.total() == self.vec.len()
+		{
+			return Ok(());
+		}
+		if header.channels() != T::opencv_channels() {
+			return Err(Error::new(
+				core::StsUnmatchedFormats,
+				format!(
+					"Output channel count: {} doesn't match the Vec element channel count: {}",
+					header.channels(),
+					T::opencv_channels()
+				),
+			));
+		}
+		let mut out = header;
+		if out.typ() != T::opencv_type() {
+			let mut converted = Mat::default();
+			out.convert_to(&mut converted, T::opencv_type(), 1., 0.)?;
+			out = converted;
+		}
+		if !out.is_continuous() {
+			out = out.try_clone()?;
+		}
+		if out.total() == 0 {
+			self.vec.clear();
+		} else {
+			*self.vec = out.data_typed::<T>()?.to_vec();
+		}
+		Ok(())
+	}
+}
+
+impl<T: DataType> Drop for VecArray<'_, T> {
+	fn drop(&mut self) {
+		if !self.finished {
+			// there is no way to report the failure from drop, see `finish()`
+			let _ = self.reflect();
+		}
+	}
+}
+
+impl<T: DataType> ToOutputArray for VecArray<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_output_array_fixed_type(instance: *mut c_void, typ: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_Mat_output_array_fixed_type(self.header.as_raw_mut_Mat(), T::opencv_type(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for VecArray<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_input_output_array_fixed_type(
+				instance: *mut c_void,
+				typ: i32,
+				ocvrs_return: *mut sys::Result<*mut c_void>,
+			);
+		}
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_Mat_input_output_array_fixed_type(self.header.as_raw_mut_Mat(), T::opencv_type(), ocvrs_return.as_mut_ptr())
+		}
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+	}
+}
+
+output_array_ref_forward! { ['v, T: DataType] VecArray<'v, T> }
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! input_output_array {
@@ -113,6 +306,15 @@ macro_rules! input_output_array {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! input_array_ref_forward {
+	([$($gen: tt)*] $type: ty) => {
+		impl<$($gen)*> $crate::core::ToInputArray for &$type {
+			#[inline]
+			fn input_array(&self) -> $crate::Result<$crate::core::_InputArray> {
+				(*self).input_array()
+			}
+		}
+	};
+
 	($type: ty) => {
 		impl $crate::core::ToInputArray for &$type {
 			#[inline]
@@ -126,6 +328,22 @@ macro_rules! input_array_ref_forward {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! output_array_ref_forward {
+	([$($gen: tt)*] $type: ty) => {
+		impl<$($gen)*> $crate::core::ToOutputArray for &mut $type {
+			#[inline]
+			fn output_array(&mut self) -> $crate::Result<$crate::core::_OutputArray> {
+				(*self).output_array()
+			}
+		}
+
+		impl<$($gen)*> $crate::core::ToInputOutputArray for &mut $type {
+			#[inline]
+			fn input_output_array(&mut self) -> $crate::Result<$crate::core::_InputOutputArray> {
+				(*self).input_output_array()
+			}
+		}
+	};
+
 	($type: ty) => {
 		impl $crate::core::ToOutputArray for &mut $type {
 			#[inline]
@@ -1,17 +1,49 @@
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::ffi::c_void;
-use std::marker::PhantomData;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::ops::Deref;
-use std::{fmt, slice};
+use std::path::Path;
+use std::{fmt, ptr, slice};
 
+pub use allocator::{GlobalMatAllocator, MatAllocator, MatAllocatorImpl, MAT_ALLOC_ALIGN};
+pub use borrowed_mat::*;
+#[cfg(feature = "image")]
+pub use self::image::ChannelOrder;
 pub use mat_::*;
-
-use crate::core::{MatConstIterator, MatExpr, MatSize, MatStep, Point, Scalar, UMat};
+pub use mat_iter::{MatIter, MatIterMut, MatIterNd, MatIterNdMut};
+pub use mat_view::{MatView, MatViewMut};
+pub use npy::{NpzReader, NpzWriter};
+#[cfg(feature = "rayon")]
+pub use par_iter::{MatParChunksMut, MatParRowsMut};
+pub use row_iter::{MatRowIter, MatRowIterMut};
+pub use uninit_mat::UninitMat;
+
+use crate::core::{BoxedRefMut, MatExpr, MatSize, MatStep, Point, Rect, Scalar, UMat};
 use crate::platform_types::size_t;
 use crate::prelude::*;
-use crate::{core, input_output_array, sys, Error, Result};
-
+use crate::{core, input_output_array, input_output_array_ref_forward, sys, Error, Result};
+pub(crate) use header::MatHeader;
+use header::{MatSizeHeader, MatStepHeader};
+
+mod allocator;
+mod borrowed_mat;
+mod header;
+#[cfg(feature = "image")]
+mod image;
 mod mat_;
+mod mat_iter;
+mod mat_view;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod npy;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod row_iter;
+#[cfg(feature = "serde")]
+mod serde;
+mod uninit_mat;
+mod vec_storage;
 
 #[inline(always)]
 unsafe fn convert_ptr<'r, T>(r: *const u8) -> &'r T {
@@ -178,25 +210,7 @@ impl Mat {
 	/// Create a new `Mat` by copying the data from a single-dimensional slice with custom shape
 	#[inline]
 	pub fn from_slice_rows_cols<T: DataType>(s: &[T], row_count: usize, col_count: usize) -> Result<Self> {
-		if row_count * col_count != s.len() {
-			return Err(Error::new(
-				core::StsUnmatchedSizes,
-				format!(
-					"The length of the slice: {} must match the passed row count: {row_count} and column count: {col_count} exactly",
-					s.len()
-				),
-			));
-		}
-		unsafe {
-			Self::new_rows_cols_with_data(
-				row_count_i32(row_count)?,
-				col_count_i32(col_count)?,
-				T::opencv_type(),
-				s.as_ptr() as *mut c_void,
-				core::Mat_AUTO_STEP,
-			)
-		}?
-		.try_clone()
+		Self::borrow_slice(s, row_count, col_count)?.try_clone()
 	}
 
 	#[inline]
@@ -208,42 +222,47 @@ impl Mat {
 	}
 
 	/// Returns iterator over Mat elements and their positions
+	///
+	/// Element addresses are calculated on the Rust side so the iteration doesn't involve any FFI calls, non-continuous
+	/// `Mat`s are supported. Empty `Mat` yields no elements.
 	#[inline]
 	pub fn iter<T: DataType>(&self) -> Result<MatIter<T>> {
-		match_format::<T>(self.typ())?;
-		Ok(if self.empty() {
-			MatIter {
-				iter: None,
-				_d: PhantomData,
-			}
-		} else {
-			MatIter {
-				iter: Some(MatConstIterator::over(self)?),
-				_d: PhantomData,
-			}
-		})
+		MatIter::new(self)
 	}
-}
 
-pub struct MatIter<'m, T> {
-	iter: Option<MatConstIterator>,
-	_d: PhantomData<&'m T>,
-}
+	/// Returns iterator over mutable references to Mat elements and their positions, see `iter()`
+	#[inline]
+	pub fn iter_mut<T: DataType>(&mut self) -> Result<MatIterMut<T>> {
+		MatIterMut::new(self)
+	}
 
-impl<T: DataType> Iterator for MatIter<'_, T> {
-	type Item = (Point, T);
+	/// Returns iterator over Mat elements and their `N`-dimensional indices, `N` must match the number of Mat dimensions
+	#[inline]
+	pub fn iter_nd<T: DataType, const N: usize>(&self) -> Result<MatIterNd<T, N>> {
+		MatIterNd::new(self)
+	}
 
-	fn next(&mut self) -> Option<Self::Item> {
-		self.iter.as_mut().and_then(|iter| {
-			if iter.has_elements() {
-				let cur = iter.current().ok()?;
-				let out = (iter.pos().ok()?, *cur);
-				iter.seek(1, true).ok()?;
-				Some(out)
-			} else {
-				None
-			}
-		})
+	/// Returns iterator over mutable references to Mat elements and their `N`-dimensional indices, see `iter_nd()`
+	#[inline]
+	pub fn iter_nd_mut<T: DataType, const N: usize>(&mut self) -> Result<MatIterNdMut<T, N>> {
+		MatIterNdMut::new(self)
+	}
+
+	/// Reads the NumPy `.npy` file
+	///
+	/// Supported dtypes are `u1`, `i1`, `u2`, `i2`, `i4`, `f4`, `f8` (and `f2` with OpenCV 4), `b1` is read as `CV_8U`.
+	/// Both byte orders and both C and Fortran array orders are supported. 0- and 1-dimensional arrays produce a single
+	/// column `Mat` and the last dimension of a 3-dimensional array becomes the channels if it doesn't exceed `CV_CN_MAX`,
+	/// like in the OpenCV Python bindings. Use `NpzReader` to read `.npz` archives.
+	#[inline]
+	pub fn read_npy(path: impl AsRef<Path>) -> Result<Self> {
+		Self::read_npy_from(&mut BufReader::new(File::open(path).map_err(npy::io_error)?))
+	}
+
+	/// Reads the NumPy `.npy` array from `reader`, see `read_npy()`
+	#[inline]
+	pub fn read_npy_from(reader: &mut impl Read) -> Result<Self> {
+		npy::read_npy(reader)
 	}
 }
 
@@ -314,6 +333,61 @@ pub(crate) mod mat_forward {
 		match_format::<T>(mat.typ()).and_then(|_| match_indices(mat, idx))?;
 		unsafe { mat.at_nd_unchecked_mut(idx) }
 	}
+
+	#[inline]
+	pub fn flags(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).flags
+	}
+
+	#[inline]
+	pub fn dims(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).dims
+	}
+
+	#[inline]
+	pub fn rows(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).rows
+	}
+
+	#[inline]
+	pub fn cols(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).cols
+	}
+
+	#[inline]
+	pub fn data_mut(mat: &mut (impl MatTrait + ?Sized)) -> *mut u8 {
+		MatHeader::of(mat).data
+	}
+
+	#[inline]
+	pub fn typ(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).typ()
+	}
+
+	#[inline]
+	pub fn depth(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).depth()
+	}
+
+	#[inline]
+	pub fn channels(mat: &(impl MatTraitConst + ?Sized)) -> i32 {
+		MatHeader::of(mat).channels()
+	}
+
+	#[inline]
+	pub fn is_continuous(mat: &(impl MatTraitConst + ?Sized)) -> bool {
+		MatHeader::of(mat).is_continuous()
+	}
+
+	#[inline]
+	pub fn empty(mat: &(impl MatTraitConst + ?Sized)) -> bool {
+		MatHeader::of(mat).empty()
+	}
+
+	#[inline]
+	pub fn total(mat: &(impl MatTraitConst + ?Sized)) -> size_t {
+		MatHeader::of(mat).total()
+	}
 }
 
 pub trait MatTraitConstManual: MatTraitConst {
@@ -386,13 +460,7 @@ pub trait MatTraitConstManual: MatTraitConst {
 
 	#[inline]
 	fn size(&self) -> Result<core::Size> {
-		extern "C" {
-			fn cv_manual_Mat_size(instance: *const c_void, ocvrs_return: *mut sys::Result<core::Size>);
-		}
-		return_send!(via ocvrs_return);
-		unsafe { cv_manual_Mat_size(self.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
-		return_receive!(unsafe ocvrs_return => ret);
-		ret.into_result()
+		Ok(MatHeader::of(self).size())
 	}
 
 	#[inline]
@@ -403,10 +471,7 @@ pub trait MatTraitConstManual: MatTraitConst {
 	/// Raw pointer to the underlying data array, can be NULL
 	#[inline]
 	fn data(&self) -> *const u8 {
-		extern "C" {
-			fn cv_manual_Mat_data(instance: *const c_void) -> *const u8;
-		}
-		unsafe { cv_manual_Mat_data(self.as_raw_Mat()) }
+		MatHeader::of(self).data
 	}
 
 	/// Returns underlying data array as byte slice, Mat must be continuous.
@@ -468,6 +533,81 @@ pub trait MatTraitConstManual: MatTraitConst {
 			}
 		})
 	}
+
+	/// Returns an iterator over the rows of the 2-dimensional `Mat` as typed slices, non-continuous `Mat`s are supported
+	///
+	/// Type and dimensions are checked once when creating the iterator, iteration itself doesn't involve any FFI calls. Empty
+	/// `Mat` yields no rows.
+	#[inline]
+	fn row_iter<T: DataType>(&self) -> Result<MatRowIter<'_, T>> {
+		MatRowIter::new(self)
+	}
+
+	/// Returns an immutable view of the whole `Mat`, see `MatView`
+	#[inline]
+	fn view<T: DataType>(&self) -> Result<MatView<'_, T>> {
+		self.view_rows(0, self.rows())
+	}
+
+	/// Returns an immutable view of the rows `start..end`
+	#[inline]
+	fn view_rows<T: DataType>(&self, start: i32, end: i32) -> Result<MatView<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self.row_bounds(start, end).map(|mat| unsafe { MatView::new(mat) })
+	}
+
+	/// Returns an immutable view of the columns `start..end`
+	#[inline]
+	fn view_cols<T: DataType>(&self, start: i32, end: i32) -> Result<MatView<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self.col_bounds(start, end).map(|mat| unsafe { MatView::new(mat) })
+	}
+
+	/// Returns an immutable view of the intersection of `row_range` and `col_range`
+	#[inline]
+	fn view_rows_cols<T: DataType>(&self, row_range: &core::Range, col_range: &core::Range) -> Result<MatView<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self
+			.row_range(row_range)?
+			.col_range(col_range)
+			.map(|mat| unsafe { MatView::new(mat) })
+	}
+
+	/// Returns an immutable view of the region of interest `rect`
+	#[inline]
+	fn view_rect<T: DataType>(&self, rect: Rect) -> Result<MatView<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self
+			.row_bounds(rect.y, rect.y + rect.height)?
+			.col_bounds(rect.x, rect.x + rect.width)
+			.map(|mat| unsafe { MatView::new(mat) })
+	}
+
+	/// Returns an immutable view of the channels `start..end` of the 2-dimensional `Mat`
+	///
+	/// The view is a 3-dimensional single-channel `Mat` of size rows × cols × (end - start), `T` must be the single-channel
+	/// type of the `Mat` depth, e.g. `u8` for `CV_8UC3`.
+	#[inline]
+	fn view_channels<T: DataType>(&self, start: i32, end: i32) -> Result<MatView<'_, T>> {
+		mat_view::channels_header::<T, _>(self, start, end).map(|mat| unsafe { MatView::new(mat) })
+	}
+
+	/// Writes the `Mat` to the NumPy `.npy` file
+	///
+	/// The array is stored in the C order using the native byte order, multichannel `Mat`s get the channels as the last
+	/// dimension. Non-continuous `Mat`s are supported. Use `NpzWriter` to write `.npz` archives.
+	#[inline]
+	fn write_npy(&self, path: impl AsRef<Path>) -> Result<()> {
+		let mut writer = BufWriter::new(File::create(path).map_err(npy::io_error)?);
+		self.write_npy_to(&mut writer)?;
+		writer.flush().map_err(npy::io_error)
+	}
+
+	/// Writes the `Mat` in the NumPy `.npy` format to `writer`, see `write_npy()`
+	#[inline]
+	fn write_npy_to(&self, writer: &mut impl Write) -> Result<()> {
+		npy::write_npy(self, writer)
+	}
 }
 
 pub trait MatTraitManual: MatTraitConstManual + MatTrait {
@@ -545,6 +685,14 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 		ret.into_result()
 	}
 
+	/// Sets the allocator used for the element buffer by the subsequent `create_*()` calls, `None` to use the default one
+	///
+	/// The currently allocated buffer is not affected.
+	#[inline]
+	fn set_allocator(&mut self, allocator: Option<&MatAllocator>) {
+		MatHeader::of_mut(self).allocator = allocator.map_or(ptr::null_mut(), |a| a.as_raw_MatAllocator());
+	}
+
 	/// Returns underlying data array as mutable byte slice, Mat must be continuous.
 	#[inline]
 	fn data_bytes_mut(&mut self) -> Result<&mut [u8]> {
@@ -565,6 +713,86 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 		let total = self.total();
 		Ok(slice::from_raw_parts_mut(self.data_mut() as *mut T, total))
 	}
+
+	/// Returns an iterator over the rows of the 2-dimensional `Mat` as mutable typed slices, see `row_iter()`
+	#[inline]
+	fn row_iter_mut<T: DataType>(&mut self) -> Result<MatRowIterMut<'_, T>> {
+		MatRowIterMut::new(self)
+	}
+
+	/// Returns a parallel iterator over the rows of the 2-dimensional `Mat` as mutable typed slices, see `row_iter()`
+	#[cfg(feature = "rayon")]
+	#[inline]
+	fn par_rows_mut<T: DataType + Send>(&mut self) -> Result<MatParRowsMut<'_, T>> {
+		MatParRowsMut::new(self)
+	}
+
+	/// Returns a parallel iterator over the non-overlapping mutable views of `chunk_rows` rows each, the last chunk can be
+	/// shorter
+	#[cfg(feature = "rayon")]
+	#[inline]
+	fn par_chunks_mut<T: DataType + Send>(&mut self, chunk_rows: i32) -> Result<MatParChunksMut<'_, T>> {
+		MatParChunksMut::new(self, chunk_rows)
+	}
+
+	/// Returns a mutable view of the whole `Mat`, see `MatViewMut`
+	#[inline]
+	fn view_mut<T: DataType>(&mut self) -> Result<MatViewMut<'_, T>> {
+		let rows = self.rows();
+		self.view_rows_mut(0, rows)
+	}
+
+	/// Returns a mutable view of the rows `start..end`
+	#[inline]
+	fn view_rows_mut<T: DataType>(&mut self, start: i32, end: i32) -> Result<MatViewMut<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self.row_bounds(start, end).map(|mat| unsafe { MatViewMut::new(mat) })
+	}
+
+	/// Returns a mutable view of the columns `start..end`
+	#[inline]
+	fn view_cols_mut<T: DataType>(&mut self, start: i32, end: i32) -> Result<MatViewMut<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self.col_bounds(start, end).map(|mat| unsafe { MatViewMut::new(mat) })
+	}
+
+	/// Returns a mutable view of the intersection of `row_range` and `col_range`
+	#[inline]
+	fn view_rows_cols_mut<T: DataType>(&mut self, row_range: &core::Range, col_range: &core::Range) -> Result<MatViewMut<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self
+			.row_range(row_range)?
+			.col_range(col_range)
+			.map(|mat| unsafe { MatViewMut::new(mat) })
+	}
+
+	/// Returns a mutable view of the region of interest `rect`
+	#[inline]
+	fn view_rect_mut<T: DataType>(&mut self, rect: Rect) -> Result<MatViewMut<'_, T>> {
+		match_format::<T>(self.typ())?;
+		self
+			.row_bounds(rect.y, rect.y + rect.height)?
+			.col_bounds(rect.x, rect.x + rect.width)
+			.map(|mat| unsafe { MatViewMut::new(mat) })
+	}
+
+	/// Returns a mutable view of the channels `start..end` of the 2-dimensional `Mat`, see `view_channels()`
+	#[inline]
+	fn view_channels_mut<T: DataType>(&mut self, start: i32, end: i32) -> Result<MatViewMut<'_, T>> {
+		mat_view::channels_header::<T, _>(self, start, end).map(|mat| unsafe { MatViewMut::new(mat) })
+	}
+
+	/// Splits the `Mat` into 2 non-overlapping mutable views, rows `0..mid` and `mid..rows`
+	#[inline]
+	fn split_rows_mut<T: DataType>(&mut self, mid: i32) -> Result<(MatViewMut<'_, T>, MatViewMut<'_, T>)> {
+		mat_view::split_rows::<T, _>(self, mid).map(|(top, bottom)| unsafe { (MatViewMut::new(top), MatViewMut::new(bottom)) })
+	}
+
+	/// Splits the `Mat` into 2 non-overlapping mutable views, columns `0..mid` and `mid..cols`
+	#[inline]
+	fn split_cols_mut<T: DataType>(&mut self, mid: i32) -> Result<(MatViewMut<'_, T>, MatViewMut<'_, T>)> {
+		mat_view::split_cols::<T, _>(self, mid).map(|(left, right)| unsafe { (MatViewMut::new(left), MatViewMut::new(right)) })
+	}
 }
 
 impl<T: MatTraitConst + ?Sized> MatTraitConstManual for T {}
@@ -573,6 +801,31 @@ impl<T: MatTrait + ?Sized> MatTraitManual for T {}
 
 input_output_array! { Mat, from_mat, from_mat_mut }
 
+impl core::ToInputArray for BoxedRefMut<'_, Mat> {
+	#[inline]
+	fn input_array(&self) -> Result<core::_InputArray> {
+		core::_InputArray::from_mat(self)
+	}
+}
+
+impl core::ToOutputArray for BoxedRefMut<'_, Mat> {
+	#[inline]
+	fn output_array(&mut self) -> Result<core::_OutputArray> {
+		// safe because the constructor only stores the pointer to the referenced Mat
+		core::_OutputArray::from_mat_mut(unsafe { self.as_inner_mut() })
+	}
+}
+
+impl core::ToInputOutputArray for BoxedRefMut<'_, Mat> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<core::_InputOutputArray> {
+		// safe because the constructor only stores the pointer to the referenced Mat
+		core::_InputOutputArray::from_mat_mut(unsafe { self.as_inner_mut() })
+	}
+}
+
+input_output_array_ref_forward! { BoxedRefMut<'_, Mat> }
+
 impl fmt::Debug for Mat {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		let typ = self.typ();
@@ -620,10 +873,8 @@ input_output_array! { UMat, from_umat, from_umat_mut }
 pub trait MatSizeTraitConstManual: MatSizeTraitConst {
 	#[inline]
 	fn dims(&self) -> i32 {
-		extern "C" {
-			fn cv_manual_MatSize_dims(instance: *const c_void) -> i32;
-		}
-		unsafe { cv_manual_MatSize_dims(self.as_raw_MatSize()) }
+		// safe because `MatSize` is a C++ `cv::MatSize` that has the same layout as `MatSizeHeader`
+		unsafe { &*(self.as_raw_MatSize() as *const MatSizeHeader) }.dims()
 	}
 }
 
@@ -635,7 +886,8 @@ impl Deref for MatSize {
 
 	#[inline]
 	fn deref(&self) -> &Self::Target {
-		unsafe { slice::from_raw_parts(self.to_xconst_i32(), self.dims() as usize) }
+		// safe because `MatSize` is a C++ `cv::MatSize` that has the same layout as `MatSizeHeader`
+		unsafe { &*(self.as_raw_MatSize() as *const MatSizeHeader) }.as_slice()
 	}
 }
 
@@ -650,11 +902,8 @@ impl Deref for MatStep {
 
 	#[inline]
 	fn deref(&self) -> &Self::Target {
-		extern "C" {
-			fn cv_manual_MatStep_deref(instance: *const c_void) -> *const size_t;
-		}
-		let ptr = unsafe { cv_manual_MatStep_deref(self.as_raw_MatStep()) };
-		unsafe { slice::from_raw_parts(ptr, 2) }
+		// safe because `MatStep` is a C++ `cv::MatStep` that has the same layout as `MatStepHeader`
+		unsafe { &*(self.as_raw_MatStep() as *const MatStepHeader) }.as_slice()
 	}
 }
 
@@ -697,4 +946,40 @@ pub trait MatConstIteratorTraitManual: MatConstIteratorTrait {
 
 impl<T: MatConstIteratorTrait> MatConstIteratorTraitManual for T {}
 
+impl MatExpr {
+	/// Evaluates the expression into a new `Mat`, same as `to_mat()`
+	#[inline]
+	pub fn eval(&self) -> Result<Mat> {
+		self.to_mat()
+	}
+
+	/// Evaluates the expression into `dst`, the buffer of `dst` is reused when its size and type match the result
+	#[inline]
+	pub fn eval_into(&self, dst: &mut Mat) -> Result<()> {
+		self.assign_to(dst, -1)
+	}
+
+	/// Evaluates the expression into `m` converting it to the depth of `typ` (or keeping the expression type if it's -1),
+	/// the same way as C++ `Mat::operator=(const MatExpr&)` does, so the buffer of `m` is reused when possible
+	#[inline]
+	pub fn assign_to(&self, m: &mut Mat, typ: i32) -> Result<()> {
+		extern "C" {
+			fn cv_manual_MatExpr_assign_to(instance: *const c_void, m: *mut c_void, typ: i32, ocvrs_return: *mut sys::Result_void);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_MatExpr_assign_to(self.as_raw_MatExpr(), m.as_raw_mut_Mat(), typ, ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()
+	}
+}
+
+impl TryFrom<MatExpr> for Mat {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(expr: MatExpr) -> Result<Self> {
+		expr.to_mat()
+	}
+}
+
 input_output_array! { MatExpr, from_matexpr }
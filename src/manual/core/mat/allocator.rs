@@ -0,0 +1,193 @@
+//! Custom allocation of the `Mat` element buffers from Rust
+//!
+//! OpenCV requests the memory for the `Mat` elements through `cv::MatAllocator`. The C++ side of this module implements it
+//! by forwarding every allocation and deallocation to a `MatAllocatorImpl` through the trampolines below.
+
+use std::alloc::{self, Layout};
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::core::{Mat, MatTraitManual, Scalar};
+use crate::platform_types::size_t;
+use crate::{sys, Result};
+
+/// Alignment of the buffers requested from `MatAllocatorImpl`, the same as the one OpenCV uses for its own allocations
+pub const MAT_ALLOC_ALIGN: usize = 64;
+
+/// Source of the memory for the `Mat` element buffers, wrap it in `MatAllocator` to use it
+///
+/// Implement it to allocate from a pool, an arena or page-aligned memory. Allocations can happen in any thread
+/// OpenCV uses internally so the implementation must be `Send` and `Sync`.
+pub trait MatAllocatorImpl: Send + Sync {
+	/// Allocates a buffer described by `layout`, returns a null pointer if the allocation fails
+	///
+	/// `layout` always has non-zero size and the alignment of `MAT_ALLOC_ALIGN`.
+	fn allocate(&self, layout: Layout) -> *mut u8;
+
+	/// Frees the buffer previously returned by `allocate()`
+	/// # Safety
+	/// `ptr` must be returned by `allocate()` of the same allocator with the same `layout`
+	unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout);
+}
+
+/// `MatAllocatorImpl` that uses the Rust global allocator
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GlobalMatAllocator;
+
+impl MatAllocatorImpl for GlobalMatAllocator {
+	#[inline]
+	fn allocate(&self, layout: Layout) -> *mut u8 {
+		// safe because the layout size is never zero
+		unsafe { alloc::alloc(layout) }
+	}
+
+	#[inline]
+	unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout) {
+		alloc::dealloc(ptr, layout)
+	}
+}
+
+/// State shared between the Rust handle and the C++ allocator object, passed to the trampolines as userdata
+struct Shared {
+	imp: Box<dyn MatAllocatorImpl>,
+	bytes_in_use: AtomicUsize,
+	peak: AtomicUsize,
+}
+
+impl Shared {
+	fn allocate(&self, size: usize) -> *mut u8 {
+		if size == 0 {
+			// dangling, but properly aligned pointer, it's never dereferenced
+			return MAT_ALLOC_ALIGN as *mut u8;
+		}
+		let layout = match Layout::from_size_align(size, MAT_ALLOC_ALIGN) {
+			Ok(layout) => layout,
+			Err(_) => return ptr::null_mut(),
+		};
+		let out = self.imp.allocate(layout);
+		if !out.is_null() {
+			let in_use = self.bytes_in_use.fetch_add(size, Ordering::Relaxed) + size;
+			self.peak.fetch_max(in_use, Ordering::Relaxed);
+		}
+		out
+	}
+
+	unsafe fn deallocate(&self, ptr: *mut u8, size: usize) {
+		if size == 0 {
+			return;
+		}
+		// safe because the same layout was successfully created during the allocation
+		let layout = Layout::from_size_align_unchecked(size, MAT_ALLOC_ALIGN);
+		self.imp.deallocate(ptr, layout);
+		self.bytes_in_use.fetch_sub(size, Ordering::Relaxed);
+	}
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_mat_allocator_allocate(userdata: *const c_void, size: size_t) -> *mut c_void {
+	let shared = &*(userdata as *const Shared);
+	// unwinding into C++ is not allowed, panic is reported to OpenCV as allocation failure
+	panic::catch_unwind(AssertUnwindSafe(|| shared.allocate(size))).unwrap_or(ptr::null_mut()) as *mut c_void
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_mat_allocator_deallocate(userdata: *const c_void, ptr: *mut c_void, size: size_t) {
+	let shared = &*(userdata as *const Shared);
+	// unwinding into C++ is not allowed and there is no way to report the failure so the buffer is leaked
+	let _ = panic::catch_unwind(AssertUnwindSafe(|| shared.deallocate(ptr as *mut u8, size)));
+}
+
+/// `cv::MatAllocator` backed by a `MatAllocatorImpl`
+///
+/// Install it globally with `set_default()` or for a single `Mat` with `MatTraitManual::set_allocator()` or
+/// `Mat::new_rows_cols_with_allocator()`. Every `Mat` buffer keeps a reference to the allocator that created it and can
+/// outlive any Rust value so the allocator is never freed, create it once and copy the handle around.
+#[derive(Copy, Clone)]
+pub struct MatAllocator {
+	ptr: *mut c_void,
+	shared: &'static Shared,
+}
+
+unsafe impl Send for MatAllocator {}
+
+unsafe impl Sync for MatAllocator {}
+
+impl MatAllocator {
+	/// Creates a new allocator forwarding all of the allocations to `imp`, it's never freed
+	pub fn new(imp: impl MatAllocatorImpl + 'static) -> Result<Self> {
+		extern "C" {
+			fn cv_manual_MatAllocator_new(userdata: *const c_void, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		let shared: &'static Shared = Box::leak(Box::new(Shared {
+			imp: Box::new(imp),
+			bytes_in_use: AtomicUsize::new(0),
+			peak: AtomicUsize::new(0),
+		}));
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_MatAllocator_new(shared as *const Shared as *const c_void, ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| Self { ptr, shared })
+	}
+
+	/// Makes this allocator the default one for all `Mat`s created afterwards
+	#[inline]
+	pub fn set_default(&self) {
+		set_default_allocator(self.ptr)
+	}
+
+	/// Restores the standard OpenCV allocator as the default one
+	#[inline]
+	pub fn reset_default() {
+		set_default_allocator(ptr::null_mut())
+	}
+
+	/// Number of bytes currently allocated through this allocator
+	#[inline]
+	pub fn bytes_in_use(&self) -> usize {
+		self.shared.bytes_in_use.load(Ordering::Relaxed)
+	}
+
+	/// Highest value of `bytes_in_use()` so far
+	#[inline]
+	pub fn peak(&self) -> usize {
+		self.shared.peak.load(Ordering::Relaxed)
+	}
+
+	#[inline]
+	pub fn as_raw_MatAllocator(&self) -> *mut c_void {
+		#![allow(non_snake_case)]
+		self.ptr
+	}
+}
+
+impl fmt::Debug for MatAllocator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("MatAllocator")
+			.field("bytes_in_use", &self.bytes_in_use())
+			.field("peak", &self.peak())
+			.finish()
+	}
+}
+
+#[inline]
+fn set_default_allocator(allocator: *mut c_void) {
+	extern "C" {
+		fn cv_manual_Mat_setDefaultAllocator(allocator: *mut c_void);
+	}
+	unsafe { cv_manual_Mat_setDefaultAllocator(allocator) }
+}
+
+impl Mat {
+	/// Allocates new `Mat` using `allocator` with every element set to `s`, see `Mat::new_rows_cols_with_default()`
+	pub fn new_rows_cols_with_allocator(rows: i32, cols: i32, typ: i32, s: Scalar, allocator: &MatAllocator) -> Result<Self> {
+		let mut out = Self::default();
+		out.set_allocator(Some(allocator));
+		// safe because all of the elements are initialized right after the allocation
+		unsafe { out.create_rows_cols(rows, cols, typ) }?;
+		out.set(s)?;
+		Ok(out)
+	}
+}
@@ -0,0 +1,380 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::core::{
+	_InputArray, _InputOutputArray, _OutputArray, Mat, MatTrait, MatTraitConst, MatTraitConstManual, MatTraitManual, Point, Rect,
+	Size, ToInputArray, ToInputOutputArray, ToOutputArray,
+};
+use crate::platform_types::size_t;
+use crate::traits::Boxed;
+use crate::{core, input_array_ref_forward, output_array_ref_forward, sys, Error, Result};
+
+use super::{col_count_i32, row_count_i32, DataType};
+
+#[inline]
+fn match_slice_len(len: usize, row_count: usize, col_count: usize) -> Result<()> {
+	if row_count.checked_mul(col_count) == Some(len) {
+		Ok(())
+	} else {
+		Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"The length of the slice: {len} must match the passed row count: {row_count} and column count: {col_count} exactly"
+			),
+		))
+	}
+}
+
+impl Mat {
+	/// Create a `Mat` header over the data of the slice without copying it
+	///
+	/// The length of the slice must match `row_count * col_count` exactly.
+	pub fn borrow_slice<T: DataType>(s: &[T], row_count: usize, col_count: usize) -> Result<BorrowedMat> {
+		match_slice_len(s.len(), row_count, col_count)?;
+		unsafe {
+			Self::new_rows_cols_with_data(
+				row_count_i32(row_count)?,
+				col_count_i32(col_count)?,
+				T::opencv_type(),
+				s.as_ptr() as *mut c_void,
+				core::Mat_AUTO_STEP,
+			)
+		}
+		.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Create a `Mat` header over the data of the mutable slice without copying it, it can be used as an output array
+	///
+	/// The length of the slice must match `row_count * col_count` exactly. See `BorrowedMatMut` for the details on using
+	/// it as an output.
+	pub fn borrow_slice_mut<T: DataType>(s: &mut [T], row_count: usize, col_count: usize) -> Result<BorrowedMatMut> {
+		match_slice_len(s.len(), row_count, col_count)?;
+		unsafe {
+			Self::new_rows_cols_with_data(
+				row_count_i32(row_count)?,
+				col_count_i32(col_count)?,
+				T::opencv_type(),
+				s.as_mut_ptr() as *mut c_void,
+				core::Mat_AUTO_STEP,
+			)
+		}
+		.map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+}
+
+/// Read-only accessors shared by `BorrowedMat` and `BorrowedMatMut`
+///
+/// Only the methods that return plain values or references bound to `&self` are forwarded, the ones that create new `Mat`
+/// headers (`row()`, `col()`, `reshape()`, etc.) would produce owned `Mat`s pointing to the borrowed data without any lifetime.
+macro_rules! borrowed_mat_const_methods {
+	($type: ty) => {
+		impl $type {
+			/// See [MatTraitConst::typ]
+			#[inline]
+			pub fn typ(&self) -> i32 {
+				self.mat.typ()
+			}
+
+			/// See [MatTraitConst::depth]
+			#[inline]
+			pub fn depth(&self) -> i32 {
+				self.mat.depth()
+			}
+
+			/// See [MatTraitConst::channels]
+			#[inline]
+			pub fn channels(&self) -> i32 {
+				self.mat.channels()
+			}
+
+			/// See [MatTraitConst::elem_size]
+			#[inline]
+			pub fn elem_size(&self) -> Result<size_t> {
+				self.mat.elem_size()
+			}
+
+			/// See [MatTraitConst::dims]
+			#[inline]
+			pub fn dims(&self) -> i32 {
+				self.mat.dims()
+			}
+
+			/// See [MatTraitConst::rows]
+			#[inline]
+			pub fn rows(&self) -> i32 {
+				self.mat.rows()
+			}
+
+			/// See [MatTraitConst::cols]
+			#[inline]
+			pub fn cols(&self) -> i32 {
+				self.mat.cols()
+			}
+
+			/// See [MatTraitConstManual::size]
+			#[inline]
+			pub fn size(&self) -> Result<Size> {
+				MatTraitConstManual::size(&self.mat)
+			}
+
+			/// See [MatTraitConst::total]
+			#[inline]
+			pub fn total(&self) -> size_t {
+				self.mat.total()
+			}
+
+			/// See [MatTraitConst::empty]
+			#[inline]
+			pub fn empty(&self) -> bool {
+				self.mat.empty()
+			}
+
+			/// See [MatTraitConst::is_continuous]
+			#[inline]
+			pub fn is_continuous(&self) -> bool {
+				self.mat.is_continuous()
+			}
+
+			/// See [MatTraitConstManual::data]
+			#[inline]
+			pub fn data(&self) -> *const u8 {
+				self.mat.data()
+			}
+
+			/// See [MatTraitConst::at]
+			#[inline]
+			pub fn at<T: DataType>(&self, i0: i32) -> Result<&T> {
+				self.mat.at(i0)
+			}
+
+			/// See [MatTraitConst::at_2d]
+			#[inline]
+			pub fn at_2d<T: DataType>(&self, row: i32, col: i32) -> Result<&T> {
+				self.mat.at_2d(row, col)
+			}
+
+			/// See [MatTraitConst::at_3d]
+			#[inline]
+			pub fn at_3d<T: DataType>(&self, i0: i32, i1: i32, i2: i32) -> Result<&T> {
+				self.mat.at_3d(i0, i1, i2)
+			}
+
+			/// See [MatTraitConst::at_nd]
+			#[inline]
+			pub fn at_nd<T: DataType>(&self, idx: &[i32]) -> Result<&T> {
+				self.mat.at_nd(idx)
+			}
+
+			/// See [MatTraitConst::at_pt]
+			#[inline]
+			pub fn at_pt<T: DataType>(&self, pt: Point) -> Result<&T> {
+				self.mat.at_pt(pt)
+			}
+
+			/// See [MatTraitConstManual::at_row]
+			#[inline]
+			pub fn at_row<T: DataType>(&self, row: i32) -> Result<&[T]> {
+				self.mat.at_row(row)
+			}
+
+			/// See [MatTraitConstManual::data_bytes]
+			#[inline]
+			pub fn data_bytes(&self) -> Result<&[u8]> {
+				self.mat.data_bytes()
+			}
+
+			/// See [MatTraitConstManual::data_typed]
+			#[inline]
+			pub fn data_typed<T: DataType>(&self) -> Result<&[T]> {
+				self.mat.data_typed()
+			}
+
+			/// See [MatTraitConstManual::to_vec_2d]
+			#[inline]
+			pub fn to_vec_2d<T: DataType>(&self) -> Result<Vec<Vec<T>>> {
+				self.mat.to_vec_2d()
+			}
+
+			/// Deep copy of the borrowed data into a new `Mat` that owns it, see [MatTraitConst::try_clone]
+			#[inline]
+			pub fn try_clone(&self) -> Result<Mat> {
+				self.mat.try_clone()
+			}
+		}
+	};
+}
+
+/// `Mat` header over the data borrowed from the external buffer for the lifetime `'a`.
+///
+/// It can be read through the accessors below and passed to OpenCV functions as an input array, but it can't outlive the
+/// buffer it points to and doesn't allow mutation of the underlying data. It doesn't implement `MatTraitConst` or dereference
+/// to `Mat` because both would allow creating owned `Mat` headers (e.g. with `row()` or `Mat::roi()`) sharing the borrowed
+/// buffer without its lifetime. Sub-matrices are available through `roi()` which keeps the borrow.
+pub struct BorrowedMat<'a> {
+	mat: Mat,
+	_d: PhantomData<&'a ()>,
+}
+
+impl<'a> BorrowedMat<'a> {
+	/// Wrap the `Mat` header pointing to the borrowed data
+	///
+	/// # Safety
+	/// Caller must ensure that the data referenced by `mat` lives at least for the lifetime of the returned object and is not
+	/// mutated during that time.
+	#[inline]
+	pub unsafe fn new(mat: Mat) -> Self {
+		Self { mat, _d: PhantomData }
+	}
+
+	/// Header over the rectangular region of the borrowed data, see [Mat::roi]
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<BorrowedMat<'a>> {
+		Mat::roi(&self.mat, roi).map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+}
+
+borrowed_mat_const_methods! { BorrowedMat<'_> }
+
+impl ToInputArray for BorrowedMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.mat.input_array()
+	}
+}
+
+input_array_ref_forward! { BorrowedMat<'_> }
+
+impl fmt::Debug for BorrowedMat<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.mat.fmt(f)
+	}
+}
+
+/// `Mat` header over the data mutably borrowed from the external buffer for the lifetime `'a`.
+///
+/// Same as `BorrowedMat`, but allows modification of the underlying data so it can be used as an output array. Mutable access
+/// is limited to the element accessors below, operations that can reallocate or detach the header are not exposed and, like
+/// with `BorrowedMat`, neither is `MatTraitConst`. When passed
+/// as an output array its size and type are fixed: if the OpenCV function tries to reallocate the buffer to a different size
+/// or type it fails with an error instead of silently detaching the header from the borrowed data.
+pub struct BorrowedMatMut<'a> {
+	mat: Mat,
+	_d: PhantomData<&'a mut ()>,
+}
+
+impl BorrowedMatMut<'_> {
+	/// Wrap the `Mat` header pointing to the mutably borrowed data
+	///
+	/// # Safety
+	/// Caller must ensure that the data referenced by `mat` lives at least for the lifetime of the returned object and is not
+	/// accessed by anything else during that time.
+	#[inline]
+	pub unsafe fn new(mat: Mat) -> Self {
+		Self { mat, _d: PhantomData }
+	}
+
+	/// See [MatTrait::at_mut]
+	#[inline]
+	pub fn at_mut<T: DataType>(&mut self, i0: i32) -> Result<&mut T> {
+		self.mat.at_mut(i0)
+	}
+
+	/// See [MatTrait::at_2d_mut]
+	#[inline]
+	pub fn at_2d_mut<T: DataType>(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		self.mat.at_2d_mut(row, col)
+	}
+
+	/// See [MatTrait::at_3d_mut]
+	#[inline]
+	pub fn at_3d_mut<T: DataType>(&mut self, i0: i32, i1: i32, i2: i32) -> Result<&mut T> {
+		self.mat.at_3d_mut(i0, i1, i2)
+	}
+
+	/// See [MatTrait::at_nd_mut]
+	#[inline]
+	pub fn at_nd_mut<T: DataType>(&mut self, idx: &[i32]) -> Result<&mut T> {
+		self.mat.at_nd_mut(idx)
+	}
+
+	/// See [MatTrait::at_pt_mut]
+	#[inline]
+	pub fn at_pt_mut<T: DataType>(&mut self, pt: Point) -> Result<&mut T> {
+		self.mat.at_pt_mut(pt)
+	}
+
+	/// See [MatTraitManual::at_row_mut]
+	#[inline]
+	pub fn at_row_mut<T: DataType>(&mut self, row: i32) -> Result<&mut [T]> {
+		self.mat.at_row_mut(row)
+	}
+
+	/// See [MatTraitManual::data_bytes_mut]
+	#[inline]
+	pub fn data_bytes_mut(&mut self) -> Result<&mut [u8]> {
+		self.mat.data_bytes_mut()
+	}
+
+	/// See [MatTraitManual::data_typed_mut]
+	#[inline]
+	pub fn data_typed_mut<T: DataType>(&mut self) -> Result<&mut [T]> {
+		self.mat.data_typed_mut()
+	}
+
+	/// Read-only header over the rectangular region of the borrowed data, see [Mat::roi]
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<BorrowedMat<'_>> {
+		Mat::roi(&self.mat, roi).map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Mutable header over the rectangular region of the borrowed data, see [Mat::roi]
+	#[inline]
+	pub fn roi_mut(&mut self, roi: Rect) -> Result<BorrowedMatMut<'_>> {
+		Mat::roi(&self.mat, roi).map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+}
+
+borrowed_mat_const_methods! { BorrowedMatMut<'_> }
+
+impl ToInputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.mat.input_array()
+	}
+}
+
+impl ToOutputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_output_array_fixed(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_Mat_output_array_fixed(self.mat.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+	}
+}
+
+impl ToInputOutputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_input_output_array_fixed(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_Mat_input_output_array_fixed(self.mat.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+	}
+}
+
+input_array_ref_forward! { BorrowedMatMut<'_> }
+output_array_ref_forward! { BorrowedMatMut<'_> }
+
+impl fmt::Debug for BorrowedMatMut<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.mat.fmt(f)
+	}
+}
@@ -0,0 +1,127 @@
+//! Rust mirrors of the `cv::Mat`, `cv::MatSize` and `cv::MatStep` memory layout
+//!
+//! They allow reading the frequently used header fields without calling into C++. `manual-core.cpp` asserts the sizes and
+//! the field offsets of the C++ classes at compile time, the size of `MatHeader` is checked against the `sizeof(cv::Mat)`
+//! reported by C++ in the `mat_header_layout` test.
+
+use std::ffi::c_void;
+use std::mem;
+
+use crate::core::{self, MatTrait, MatTraitConst, Size};
+use crate::platform_types::size_t;
+
+/// Mirror of `cv::MatSize`
+#[repr(C)]
+pub(crate) struct MatSizeHeader {
+	/// Points to the `rows` field of the parent `Mat`, the number of dimensions is stored right before it
+	pub p: *const i32,
+}
+
+impl MatSizeHeader {
+	#[inline(always)]
+	pub fn dims(&self) -> i32 {
+		// safe because `p` always points at least one element after the beginning of the Mat header
+		unsafe { *self.p.offset(-1) }
+	}
+
+	#[inline(always)]
+	pub fn as_slice(&self) -> &[i32] {
+		// safe because `p` points to the array of the `dims()` dimension sizes
+		unsafe { std::slice::from_raw_parts(self.p, self.dims() as usize) }
+	}
+}
+
+/// Mirror of `cv::MatStep`
+#[repr(C)]
+#[allow(dead_code)]
+pub(crate) struct MatStepHeader {
+	pub p: *const size_t,
+	pub buf: [size_t; 2],
+}
+
+impl MatStepHeader {
+	#[inline(always)]
+	pub fn as_slice(&self) -> &[size_t] {
+		// safe because `p` points either to `buf` or to the heap allocated steps of an N-dimensional Mat, both contain at
+		// least 2 elements
+		unsafe { std::slice::from_raw_parts(self.p, 2) }
+	}
+}
+
+/// Mirror of `cv::Mat`, some fields are not read from Rust and are only here to preserve the layout
+#[repr(C)]
+#[allow(dead_code)]
+pub(crate) struct MatHeader {
+	pub flags: i32,
+	pub dims: i32,
+	pub rows: i32,
+	pub cols: i32,
+	pub data: *mut u8,
+	pub datastart: *const u8,
+	pub dataend: *const u8,
+	pub datalimit: *const u8,
+	pub allocator: *mut c_void,
+	pub u: *mut c_void,
+	pub size: MatSizeHeader,
+	pub step: MatStepHeader,
+}
+
+const _: () = assert!(mem::size_of::<MatSizeHeader>() == mem::size_of::<usize>());
+const _: () = assert!(mem::size_of::<MatStepHeader>() == 3 * mem::size_of::<usize>());
+
+impl MatHeader {
+	/// Header of the `Mat` behind `mat`, the returned reference is only valid while `mat` is not modified
+	#[inline(always)]
+	pub fn of(mat: &(impl MatTraitConst + ?Sized)) -> &Self {
+		// safe because `as_raw_Mat()` always returns a valid pointer to `cv::Mat` that lives at least as long as `mat`
+		unsafe { &*(mat.as_raw_Mat() as *const Self) }
+	}
+
+	/// Mutable header of the `Mat` behind `mat`
+	#[inline(always)]
+	pub fn of_mut(mat: &mut (impl MatTrait + ?Sized)) -> &mut Self {
+		// safe because `as_raw_mut_Mat()` always returns a valid pointer to `cv::Mat` that lives at least as long as `mat`
+		unsafe { &mut *(mat.as_raw_mut_Mat() as *mut Self) }
+	}
+
+	#[inline(always)]
+	pub fn typ(&self) -> i32 {
+		self.flags & core::Mat_TYPE_MASK
+	}
+
+	#[inline(always)]
+	pub fn depth(&self) -> i32 {
+		self.flags & core::Mat_DEPTH_MASK
+	}
+
+	#[inline(always)]
+	pub fn channels(&self) -> i32 {
+		(self.typ() >> core::CV_CN_SHIFT) + 1
+	}
+
+	#[inline(always)]
+	pub fn is_continuous(&self) -> bool {
+		self.flags & core::Mat_CONTINUOUS_FLAG != 0
+	}
+
+	/// Same as `cv::Mat::size()`, for matrices with more than 2 dimensions the sizes along the first 2 of them are returned
+	#[inline(always)]
+	pub fn size(&self) -> Size {
+		// safe because the size array always contains at least 2 elements, for the empty Mat they are `rows` and `cols`
+		unsafe { Size::new(*self.size.p.add(1), *self.size.p) }
+	}
+
+	#[inline(always)]
+	pub fn total(&self) -> size_t {
+		if self.dims <= 2 {
+			self.rows as size_t * self.cols as size_t
+		} else {
+			self.size.as_slice().iter().map(|&s| s as size_t).product()
+		}
+	}
+
+	#[inline(always)]
+	pub fn empty(&self) -> bool {
+		self.data.is_null() || self.dims == 0 || self.total() == 0
+	}
+}
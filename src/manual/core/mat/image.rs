@@ -0,0 +1,175 @@
+use std::convert::TryFrom;
+use std::ffi::c_void;
+use std::ops::{Deref, DerefMut};
+
+use image::{ImageBuffer, Pixel};
+
+use crate::core::{BorrowedMat, BorrowedMatMut, Mat, MatTraitConst, MatTraitConstManual, MatTraitManual};
+use crate::{core, Error, Result};
+
+use super::{match_dims, match_format, DataType};
+
+/// Order of the color channels inside the `Mat` when converting from or to the [image](https://crates.io/crates/image)
+/// crate types
+///
+/// `image` crate always stores color pixels in RGB(A) order, but OpenCV functions normally expect BGR(A). Grayscale pixels
+/// (`Luma` and `LumaA`) are never reordered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelOrder {
+	/// Keep the channels as is, the `Mat` will contain RGB(A) data
+	Rgb,
+	/// Swap red and blue channels, the `Mat` will contain BGR(A) data, this is what OpenCV expects by default
+	Bgr,
+}
+
+impl Default for ChannelOrder {
+	#[inline]
+	fn default() -> Self {
+		Self::Bgr
+	}
+}
+
+#[inline]
+fn convert_pixel<P: Pixel>(mut px: P, order: ChannelOrder) -> P {
+	if order == ChannelOrder::Bgr && P::CHANNEL_COUNT >= 3 {
+		px.channels_mut().swap(0, 2);
+	}
+	px
+}
+
+#[inline]
+fn image_size_i32(width: u32, height: u32) -> Result<(i32, i32)> {
+	match (i32::try_from(width), i32::try_from(height)) {
+		(Ok(width), Ok(height)) => Ok((width, height)),
+		_ => Err(Error::new(
+			core::StsUnmatchedFormats,
+			format!("Image size: {width}x{height} is too large for Mat"),
+		)),
+	}
+}
+
+/// Creates a `Mat` header over the image data without copying, the channel order is always kept as is
+#[inline]
+unsafe fn mat_over_image<P: Pixel + DataType>(width: u32, height: u32, data: *mut P::Subpixel) -> Result<Mat> {
+	let (width, height) = image_size_i32(width, height)?;
+	Mat::new_rows_cols_with_data(height, width, P::opencv_type(), data as *mut c_void, core::Mat_AUTO_STEP)
+}
+
+/// Conversions from and to [image](https://crates.io/crates/image) crate types, available with the `image` feature
+impl Mat {
+	/// Creates a new `Mat` by copying the pixels of the `ImageBuffer` and reordering the color channels according to `order`
+	pub fn from_image_buffer<P, C>(img: &ImageBuffer<P, C>, order: ChannelOrder) -> Result<Self>
+	where
+		P: Pixel + DataType,
+		C: Deref<Target = [P::Subpixel]>,
+	{
+		let (width, height) = image_size_i32(img.width(), img.height())?;
+		let mut out = unsafe { Self::new_rows_cols(height, width, P::opencv_type()) }?;
+		if !out.empty() {
+			out.data_typed_mut::<P>()?
+				.iter_mut()
+				.zip(img.pixels())
+				.for_each(|(dst, src)| *dst = convert_pixel(*src, order));
+		}
+		Ok(out)
+	}
+
+	/// Creates a new `ImageBuffer` by copying the `Mat` elements and reordering the color channels according to `order`
+	///
+	/// `Mat` must be 2-dimensional and its type must match the pixel type `P` exactly, non-continuous matrices are
+	/// supported.
+	pub fn to_image_buffer<P>(&self, order: ChannelOrder) -> Result<ImageBuffer<P, Vec<P::Subpixel>>>
+	where
+		P: Pixel + DataType,
+	{
+		match_format::<P>(self.typ())?;
+		let mut out = ImageBuffer::new(self.cols().max(0) as u32, self.rows().max(0) as u32);
+		if !self.empty() {
+			match_dims(self, 2)?;
+			for (row_n, dst_row) in out.rows_mut().enumerate() {
+				// safe because the number of image rows came from `i32`
+				let src_row = self.at_row::<P>(row_n as i32)?;
+				dst_row.zip(src_row).for_each(|(dst, src)| *dst = convert_pixel(*src, order));
+			}
+		}
+		Ok(out)
+	}
+}
+
+/// Copies the image data converting it to BGR(A), see `Mat::from_image_buffer()`
+impl<P, C> TryFrom<&ImageBuffer<P, C>> for Mat
+where
+	P: Pixel + DataType,
+	C: Deref<Target = [P::Subpixel]>,
+{
+	type Error = Error;
+
+	#[inline]
+	fn try_from(img: &ImageBuffer<P, C>) -> Result<Self> {
+		Self::from_image_buffer(img, ChannelOrder::Bgr)
+	}
+}
+
+/// Copies the image data converting it to BGR(A), see `Mat::from_image_buffer()`
+impl<P, C> TryFrom<ImageBuffer<P, C>> for Mat
+where
+	P: Pixel + DataType,
+	C: Deref<Target = [P::Subpixel]>,
+{
+	type Error = Error;
+
+	#[inline]
+	fn try_from(img: ImageBuffer<P, C>) -> Result<Self> {
+		Self::from_image_buffer(&img, ChannelOrder::Bgr)
+	}
+}
+
+/// Copies the `Mat` data converting it from BGR(A), see `Mat::to_image_buffer()`
+impl<P: Pixel + DataType> TryFrom<&Mat> for ImageBuffer<P, Vec<P::Subpixel>> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: &Mat) -> Result<Self> {
+		mat.to_image_buffer(ChannelOrder::Bgr)
+	}
+}
+
+/// Copies the `Mat` data converting it from BGR(A), see `Mat::to_image_buffer()`
+impl<P: Pixel + DataType> TryFrom<Mat> for ImageBuffer<P, Vec<P::Subpixel>> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: Mat) -> Result<Self> {
+		mat.to_image_buffer(ChannelOrder::Bgr)
+	}
+}
+
+/// Borrows the image data without copying, so the channels stay in RGB(A) order
+impl<'i, P, C> TryFrom<&'i ImageBuffer<P, C>> for BorrowedMat<'i>
+where
+	P: Pixel + DataType,
+	C: Deref<Target = [P::Subpixel]>,
+{
+	type Error = Error;
+
+	#[inline]
+	fn try_from(img: &'i ImageBuffer<P, C>) -> Result<Self> {
+		unsafe { mat_over_image::<P>(img.width(), img.height(), img.as_ptr() as *mut P::Subpixel) }
+			.map(|mat| unsafe { Self::new(mat) })
+	}
+}
+
+/// Mutably borrows the image data without copying, so the channels stay in RGB(A) order
+impl<'i, P, C> TryFrom<&'i mut ImageBuffer<P, C>> for BorrowedMatMut<'i>
+where
+	P: Pixel + DataType,
+	C: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+	type Error = Error;
+
+	#[inline]
+	fn try_from(img: &'i mut ImageBuffer<P, C>) -> Result<Self> {
+		let (width, height) = img.dimensions();
+		unsafe { mat_over_image::<P>(width, height, img.as_mut_ptr()) }.map(|mat| unsafe { Self::new(mat) })
+	}
+}
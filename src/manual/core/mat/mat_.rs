@@ -2,21 +2,24 @@ use std::convert::TryFrom;
 use std::ffi::c_void;
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
 
 use crate::core::{
-	Mat, MatTrait, MatTraitConst, MatTraitConstManual, MatTraitManual, ToInputArray, ToInputOutputArray, ToOutputArray,
-	_InputArray, _InputOutputArray, _OutputArray,
+	Mat, MatExprTraitConst, MatIter, MatIterMut, MatRowIter, MatRowIterMut, MatTrait, MatTraitConst, MatTraitConstManual,
+	MatTraitManual, Point, Rect, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray, UninitMat, _InputArray,
+	_InputOutputArray, _OutputArray,
 };
 use crate::traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer, OpenCVTypeExternContainerMove};
 use crate::{Error, Result};
 
-use super::{match_dims, match_format, match_is_continuous, match_total, DataType};
+use super::{match_dims, match_format, match_indices, match_is_continuous, match_total, DataType};
 
 /// [docs.opencv.org](https://docs.opencv.org/master/df/dfc/classcv_1_1Mat__.html)
 ///
 /// This struct is freely convertible into and from `Mat` using `into` and `try_from` methods. You might want
-/// to convert `Mat` to `Mat_` before calling typed methods (like `at`, `data_typed`) when more performance is
-/// required because this way you will skip the data type checks (still WIP, not all methods are covered).
+/// to convert `Mat` to `Mat_` before calling typed methods (like `at_2d`, `data_typed`, `row_iter`) when more performance
+/// is required because this way you will skip the data type checks. The element type is checked only once during the
+/// conversion, all the methods producing new matrices (like `roi`, `row`, `try_clone`) return `Mat_` of the same type.
 pub struct Mat_<T> {
 	inner: Mat,
 	_type: PhantomData<T>,
@@ -42,6 +45,83 @@ impl<T: DataType> From<Mat_<T>> for Mat {
 }
 
 impl<T: DataType> Mat_<T> {
+	/// Wraps `mat` without checking its element type
+	/// # Safety
+	/// Caller must ensure that `T` matches the `mat` element type
+	#[inline]
+	pub(crate) unsafe fn from_untyped_unchecked(mat: Mat) -> Self {
+		Self {
+			inner: mat,
+			_type: PhantomData,
+		}
+	}
+
+	/// Allocates new uninitialized `Mat_` of the specified size, see `Mat::new_rows_cols()`
+	/// # Safety
+	/// Matrix elements are left uninitialized, caller must write them before reading
+	#[inline]
+	pub unsafe fn new_rows_cols(rows: i32, cols: i32) -> Result<Self> {
+		Mat::new_rows_cols(rows, cols, T::opencv_type()).map(|mat| Self::from_untyped_unchecked(mat))
+	}
+
+	/// Allocates new `Mat_` of the specified size without initializing the elements
+	///
+	/// The result only allows writing the elements, see `UninitMat` for the ways to convert it to `Mat_`.
+	///
+	/// ```no_run
+	/// # use opencv::core::Mat_;
+	/// # fn main() -> opencv::Result<()> {
+	/// let mat = Mat_::<f32>::new_uninit(3, 4)?.init_from_fn(|row, col| (row * 4 + col) as f32);
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[inline]
+	pub fn new_uninit(rows: i32, cols: i32) -> Result<UninitMat<T>> {
+		UninitMat::new(rows, cols)
+	}
+
+	/// Allocates new `Mat_` of the specified size with every element set to `s`
+	#[inline]
+	pub fn new_rows_cols_with_default(rows: i32, cols: i32, s: Scalar) -> Result<Self> {
+		Mat::new_rows_cols_with_default(rows, cols, T::opencv_type(), s).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Matrix of the specified size filled with zeros
+	#[inline]
+	pub fn zeros(rows: i32, cols: i32) -> Result<Self> {
+		Mat::zeros(rows, cols, T::opencv_type())?
+			.to_mat()
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Matrix of the specified size filled with ones, for multichannel types only the first channel is set to 1
+	#[inline]
+	pub fn ones(rows: i32, cols: i32) -> Result<Self> {
+		Mat::ones(rows, cols, T::opencv_type())?
+			.to_mat()
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Identity matrix of the specified size
+	#[inline]
+	pub fn eye(rows: i32, cols: i32) -> Result<Self> {
+		Mat::eye(rows, cols, T::opencv_type())?
+			.to_mat()
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new `Mat_` by copying the data from a single-dimensional slice, see `Mat::from_slice()`
+	#[inline]
+	pub fn from_slice(s: &[T]) -> Result<Self> {
+		Mat::from_slice(s).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new `Mat_` by copying the data from a 2-dimensional slice (slice of slices), see `Mat::from_slice_2d()`
+	#[inline]
+	pub fn from_slice_2d(s: &[impl AsRef<[T]>]) -> Result<Self> {
+		Mat::from_slice_2d(s).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
 	#[inline]
 	pub fn into_untyped(self) -> Mat {
 		self.into()
@@ -77,6 +157,40 @@ impl<T: DataType> Mat_<T> {
 		unsafe { self.at_unchecked_mut(i0) }
 	}
 
+	#[inline]
+	pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+		match_indices(self, &[row, col]).and_then(|_| unsafe { self.at_2d_unchecked(row, col) })
+	}
+
+	#[inline]
+	pub fn at_2d_mut(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		match_indices(self, &[row, col])?;
+		unsafe { self.at_2d_unchecked_mut(row, col) }
+	}
+
+	#[inline]
+	pub fn at_pt(&self, pt: Point) -> Result<&T> {
+		self.at_2d(pt.y, pt.x)
+	}
+
+	#[inline]
+	pub fn at_pt_mut(&mut self, pt: Point) -> Result<&mut T> {
+		self.at_2d_mut(pt.y, pt.x)
+	}
+
+	/// Return a complete read-only row
+	#[inline]
+	pub fn at_row(&self, row: i32) -> Result<&[T]> {
+		match_indices(self, &[row, 0]).and_then(|_| unsafe { self.at_row_unchecked(row) })
+	}
+
+	/// Return a complete writeable row
+	#[inline]
+	pub fn at_row_mut(&mut self, row: i32) -> Result<&mut [T]> {
+		match_indices(self, &[row, 0])?;
+		unsafe { self.at_row_unchecked_mut(row) }
+	}
+
 	#[inline]
 	pub fn data_typed(&self) -> Result<&[T]> {
 		match_is_continuous(self).and_then(|_| unsafe { self.data_typed_unchecked() })
@@ -87,6 +201,81 @@ impl<T: DataType> Mat_<T> {
 		match_is_continuous(self)?;
 		unsafe { self.data_typed_unchecked_mut() }
 	}
+
+	/// Matrix header for the specified rectangular region, the data is shared with `self`, see `Mat::roi()`
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<Self> {
+		Mat::roi(&self.inner, roi).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Matrix header for the specified row, the data is shared with `self`
+	#[inline]
+	pub fn row(&self, y: i32) -> Result<Self> {
+		self.inner.row(y).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Matrix header for the specified column, the data is shared with `self`
+	#[inline]
+	pub fn col(&self, x: i32) -> Result<Self> {
+		self.inner.col(x).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Full copy of the matrix and its data
+	#[inline]
+	pub fn try_clone(&self) -> Result<Self> {
+		self.inner.try_clone().map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Returns iterator over the matrix elements and their positions, see `Mat::iter()`
+	#[inline]
+	pub fn iter(&self) -> Result<MatIter<T>> {
+		// safe because the element type was checked during the construction
+		unsafe { MatIter::new_unchecked(self) }
+	}
+
+	/// Returns iterator over mutable references to the matrix elements and their positions, see `Mat::iter_mut()`
+	#[inline]
+	pub fn iter_mut(&mut self) -> Result<MatIterMut<T>> {
+		unsafe { MatIterMut::new_unchecked(self) }
+	}
+
+	/// Returns an iterator over the matrix rows as typed slices, see `MatTraitConstManual::row_iter()`
+	#[inline]
+	pub fn row_iter(&self) -> Result<MatRowIter<'_, T>> {
+		unsafe { MatRowIter::new_unchecked(self) }
+	}
+
+	/// Returns an iterator over the matrix rows as mutable typed slices, see `MatTraitManual::row_iter_mut()`
+	#[inline]
+	pub fn row_iter_mut(&mut self) -> Result<MatRowIterMut<'_, T>> {
+		unsafe { MatRowIterMut::new_unchecked(self) }
+	}
+}
+
+impl<T: DataType> Clone for Mat_<T> {
+	#[inline]
+	/// Calls try_clone() and panics if that fails
+	fn clone(&self) -> Self {
+		self.try_clone().expect("Cannot clone Mat_")
+	}
+}
+
+/// Panics if the index is out of bounds, use `at_2d()` for the fallible access
+impl<T: DataType> Index<(i32, i32)> for Mat_<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, (row, col): (i32, i32)) -> &Self::Output {
+		self.at_2d(row, col).unwrap_or_else(|e| panic!("{}", e.message))
+	}
+}
+
+/// Panics if the index is out of bounds, use `at_2d_mut()` for the fallible access
+impl<T: DataType> IndexMut<(i32, i32)> for Mat_<T> {
+	#[inline]
+	fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut Self::Output {
+		self.at_2d_mut(row, col).unwrap_or_else(|e| panic!("{}", e.message))
+	}
 }
 
 impl<T> MatTraitConst for Mat_<T> {
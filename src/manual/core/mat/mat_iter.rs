@@ -0,0 +1,299 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::core::{MatTraitConst, MatTraitConstManual, MatTraitManual, Point};
+use crate::{core, Error, Result};
+
+use super::{match_format, DataType};
+
+/// Walks over the element addresses of a `Mat` with arbitrary number of dimensions in the row-major order
+///
+/// Sizes and steps are queried only once during the construction, the addresses are then calculated without any FFI calls.
+struct ElemWalker {
+	data: *mut u8,
+	ptr: *mut u8,
+	sizes: Vec<i32>,
+	steps: Vec<usize>,
+	idx: Vec<i32>,
+	/// Position of the current element as `Point`, `y` is the index along the first dimension and `x` is the flat index
+	/// along all the other dimensions
+	pos: Point,
+	/// Number of elements along all the dimensions except the first one
+	inner_total: i32,
+	remaining: usize,
+}
+
+impl ElemWalker {
+	fn new(mat: &(impl MatTraitConst + ?Sized), data: *mut u8) -> Result<Self> {
+		let remaining = if mat.empty() {
+			0
+		} else {
+			mat.total()
+		};
+		let sizes = mat.mat_size().to_vec();
+		let elem_size1 = mat.elem_size1();
+		let steps = (0..sizes.len())
+			.map(|dim| mat.step1(dim as i32).map(|step1| step1 * elem_size1))
+			.collect::<Result<Vec<_>>>()?;
+		let inner_total = sizes.iter().skip(1).product();
+		Ok(Self {
+			data,
+			ptr: data,
+			idx: vec![0; sizes.len()],
+			sizes,
+			steps,
+			pos: Point::new(0, 0),
+			inner_total,
+			remaining,
+		})
+	}
+
+	/// N-D index of the element that will be returned by the next call to `next()`
+	#[inline(always)]
+	fn idx(&self) -> &[i32] {
+		&self.idx
+	}
+
+	/// Returns the address and position of the current element and advances to the next one
+	#[inline]
+	fn next(&mut self) -> Option<(*mut u8, Point)> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let out = (self.ptr, self.pos);
+		if self.remaining > 0 {
+			self.advance();
+		}
+		Some(out)
+	}
+
+	#[inline(always)]
+	fn advance(&mut self) {
+		self.pos.x += 1;
+		if self.pos.x == self.inner_total {
+			self.pos.x = 0;
+			self.pos.y += 1;
+		}
+		let last = self.idx.len() - 1;
+		self.idx[last] += 1;
+		if self.idx[last] < self.sizes[last] {
+			// safe because the index is still within the Mat bounds
+			self.ptr = unsafe { self.ptr.add(self.steps[last]) };
+		} else {
+			let mut dim = last;
+			while self.idx[dim] == self.sizes[dim] && dim > 0 {
+				self.idx[dim] = 0;
+				dim -= 1;
+				self.idx[dim] += 1;
+			}
+			let offset = self
+				.idx
+				.iter()
+				.zip(&self.steps)
+				.map(|(&i, &step)| i as usize * step)
+				.sum::<usize>();
+			self.ptr = unsafe { self.data.add(offset) };
+		}
+	}
+}
+
+#[inline]
+fn match_idx_len<M: MatTraitConst + ?Sized, const N: usize>(mat: &M) -> Result<()> {
+	let mat_dims = mat.dims() as usize;
+	if mat.empty() || mat_dims == N {
+		Ok(())
+	} else {
+		Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!("Mat dims is: {mat_dims}, but requested index length is: {N}"),
+		))
+	}
+}
+
+#[inline(always)]
+fn to_array<const N: usize>(idx: &[i32]) -> [i32; N] {
+	let mut out = [0; N];
+	out.copy_from_slice(idx);
+	out
+}
+
+/// Iterator over `Mat` elements and their positions, see `Mat::iter()`
+///
+/// Position is returned as `Point` where `y` is the index along the first dimension and `x` is the index along the
+/// second one, for `Mat`s with more than 2 dimensions `x` is the flat index along all the dimensions except the first
+/// one. Use `Mat::iter_nd()` to get the full N-D indices.
+pub struct MatIter<'m, T> {
+	walker: ElemWalker,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> MatIter<'m, T> {
+	pub(crate) fn new(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		// safe because the element type was checked above
+		unsafe { Self::new_unchecked(mat) }
+	}
+
+	/// Like `new()` but doesn't check the `Mat` element type
+	/// # Safety
+	/// Caller must ensure that `T` matches the `Mat` element type
+	pub(crate) unsafe fn new_unchecked(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<Self> {
+		Ok(Self {
+			walker: ElemWalker::new(mat, mat.data() as *mut u8)?,
+			_d: PhantomData,
+		})
+	}
+}
+
+impl<T: DataType> Iterator for MatIter<'_, T> {
+	type Item = (Point, T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// safe because the walker only yields the addresses within the Mat bounds
+		self.walker.next().map(|(ptr, pos)| (pos, unsafe { *(ptr as *const T) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.walker.remaining, Some(self.walker.remaining))
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatIter<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatIter<'_, T> {}
+
+unsafe impl<T: Sync> Send for MatIter<'_, T> {}
+
+/// Iterator over mutable references to `Mat` elements and their positions, see `Mat::iter_mut()` and `MatIter`
+pub struct MatIterMut<'m, T> {
+	walker: ElemWalker,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType> MatIterMut<'m, T> {
+	pub(crate) fn new(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		// safe because the element type was checked above
+		unsafe { Self::new_unchecked(mat) }
+	}
+
+	/// Like `new()` but doesn't check the `Mat` element type
+	/// # Safety
+	/// Caller must ensure that `T` matches the `Mat` element type
+	pub(crate) unsafe fn new_unchecked(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		let data = mat.data_mut();
+		Ok(Self {
+			walker: ElemWalker::new(mat, data)?,
+			_d: PhantomData,
+		})
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatIterMut<'m, T> {
+	type Item = (Point, &'m mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// safe because the walker yields every address only once and within the Mat bounds
+		self.walker.next().map(|(ptr, pos)| (pos, unsafe { &mut *(ptr as *mut T) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.walker.remaining, Some(self.walker.remaining))
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatIterMut<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatIterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for MatIterMut<'_, T> {}
+
+/// Iterator over `Mat` elements and their `N`-dimensional indices, see `Mat::iter_nd()`
+pub struct MatIterNd<'m, T, const N: usize> {
+	walker: ElemWalker,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType, const N: usize> MatIterNd<'m, T, N> {
+	pub(crate) fn new(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		match_idx_len::<_, N>(mat)?;
+		Ok(Self {
+			walker: ElemWalker::new(mat, mat.data() as *mut u8)?,
+			_d: PhantomData,
+		})
+	}
+}
+
+impl<T: DataType, const N: usize> Iterator for MatIterNd<'_, T, N> {
+	type Item = ([i32; N], T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.walker.remaining == 0 {
+			return None;
+		}
+		let idx = to_array(self.walker.idx());
+		// safe because the walker only yields the addresses within the Mat bounds
+		self.walker.next().map(|(ptr, _)| (idx, unsafe { *(ptr as *const T) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.walker.remaining, Some(self.walker.remaining))
+	}
+}
+
+impl<T: DataType, const N: usize> ExactSizeIterator for MatIterNd<'_, T, N> {}
+
+impl<T: DataType, const N: usize> FusedIterator for MatIterNd<'_, T, N> {}
+
+unsafe impl<T: Sync, const N: usize> Send for MatIterNd<'_, T, N> {}
+
+/// Iterator over mutable references to `Mat` elements and their `N`-dimensional indices, see `Mat::iter_nd_mut()`
+pub struct MatIterNdMut<'m, T, const N: usize> {
+	walker: ElemWalker,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType, const N: usize> MatIterNdMut<'m, T, N> {
+	pub(crate) fn new(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		match_idx_len::<_, N>(mat)?;
+		let data = mat.data_mut();
+		Ok(Self {
+			walker: ElemWalker::new(mat, data)?,
+			_d: PhantomData,
+		})
+	}
+}
+
+impl<'m, T: DataType, const N: usize> Iterator for MatIterNdMut<'m, T, N> {
+	type Item = ([i32; N], &'m mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.walker.remaining == 0 {
+			return None;
+		}
+		let idx = to_array(self.walker.idx());
+		// safe because the walker yields every address only once and within the Mat bounds
+		self.walker.next().map(|(ptr, _)| (idx, unsafe { &mut *(ptr as *mut T) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.walker.remaining, Some(self.walker.remaining))
+	}
+}
+
+impl<T: DataType, const N: usize> ExactSizeIterator for MatIterNdMut<'_, T, N> {}
+
+impl<T: DataType, const N: usize> FusedIterator for MatIterNdMut<'_, T, N> {}
+
+unsafe impl<T: Send, const N: usize> Send for MatIterNdMut<'_, T, N> {}
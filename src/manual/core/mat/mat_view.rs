@@ -0,0 +1,404 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::core::{
+	Mat, MatRowIter, MatRowIterMut, MatSize, MatTrait, MatTraitConst, MatTraitConstManual, MatTraitManual, Point, Range, Rect,
+	Scalar, Size, ToInputArray, ToInputOutputArray, ToOutputArray, _InputArray, _InputOutputArray, _OutputArray, CV_MAKETYPE,
+};
+use crate::platform_types::size_t;
+use crate::traits::Boxed;
+use crate::{core, sys, Error, Result};
+
+use super::{match_dims, match_format, DataType};
+
+#[inline]
+fn match_split(at: i32, len: i32, what: &str) -> Result<()> {
+	if 0 <= at && at <= len {
+		Ok(())
+	} else {
+		Err(Error::new(
+			core::StsOutOfRange,
+			format!("Split {what} index: {at} out of bounds 0..={len}"),
+		))
+	}
+}
+
+/// Creates a 3-dimensional single-channel header (rows × cols × channels) over the channels `start..end` of `mat`
+pub(crate) fn channels_header<T: DataType, M: MatTraitConst + ?Sized>(mat: &M, start: i32, end: i32) -> Result<Mat> {
+	match_format::<T>(CV_MAKETYPE(mat.depth(), 1))?;
+	match_dims(mat, 2)?;
+	let channels = mat.channels();
+	if start < 0 || start >= end || end > channels {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Channel range: {start}..{end} out of bounds 0..{channels}"),
+		));
+	}
+	let steps = [mat.mat_step()[0], mat.elem_size()?];
+	let data = unsafe { mat.data().add(start as usize * mat.elem_size1()) };
+	unsafe {
+		Mat::new_nd_with_data(
+			&[mat.rows(), mat.cols(), end - start],
+			T::opencv_type(),
+			data as *mut c_void,
+			Some(&steps),
+		)
+	}
+}
+
+/// Read-only accessors shared by `MatView` and `MatViewMut`
+///
+/// The methods of `MatTraitConst` that create new `Mat` headers (`row()`, `col()`, `Mat::roi()`, etc.) are not exposed because
+/// the resulting owned `Mat` would alias the parent without borrowing it, sub-views are returned as `MatView` instead.
+macro_rules! mat_view_const_methods {
+	($type: ident) => {
+		impl<T: DataType> $type<'_, T> {
+			/// See [MatTraitConst::typ]
+			#[inline]
+			pub fn typ(&self) -> i32 {
+				self.mat.typ()
+			}
+
+			/// See [MatTraitConst::depth]
+			#[inline]
+			pub fn depth(&self) -> i32 {
+				self.mat.depth()
+			}
+
+			/// See [MatTraitConst::channels]
+			#[inline]
+			pub fn channels(&self) -> i32 {
+				self.mat.channels()
+			}
+
+			/// See [MatTraitConst::dims]
+			#[inline]
+			pub fn dims(&self) -> i32 {
+				self.mat.dims()
+			}
+
+			/// See [MatTraitConst::rows]
+			#[inline]
+			pub fn rows(&self) -> i32 {
+				self.mat.rows()
+			}
+
+			/// See [MatTraitConst::cols]
+			#[inline]
+			pub fn cols(&self) -> i32 {
+				self.mat.cols()
+			}
+
+			/// See [MatTraitConstManual::size]
+			#[inline]
+			pub fn size(&self) -> Result<Size> {
+				MatTraitConstManual::size(&self.mat)
+			}
+
+			/// See [MatTraitConst::mat_size]
+			#[inline]
+			pub fn mat_size(&self) -> MatSize {
+				self.mat.mat_size()
+			}
+
+			/// See [MatTraitConst::total]
+			#[inline]
+			pub fn total(&self) -> size_t {
+				self.mat.total()
+			}
+
+			/// See [MatTraitConst::empty]
+			#[inline]
+			pub fn empty(&self) -> bool {
+				self.mat.empty()
+			}
+
+			/// See [MatTraitConst::is_continuous]
+			#[inline]
+			pub fn is_continuous(&self) -> bool {
+				self.mat.is_continuous()
+			}
+
+			/// See [MatTraitConstManual::data]
+			#[inline]
+			pub fn data(&self) -> *const u8 {
+				self.mat.data()
+			}
+
+			/// Returns a reference to the element at the specified position, see [MatTraitConst::at]
+			#[inline]
+			pub fn at(&self, i0: i32) -> Result<&T> {
+				self.mat.at(i0)
+			}
+
+			/// Returns a reference to the element at the specified position, see [MatTraitConst::at_2d]
+			#[inline]
+			pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+				self.mat.at_2d(row, col)
+			}
+
+			/// Returns a reference to the element at the specified position, see [MatTraitConst::at_3d]
+			#[inline]
+			pub fn at_3d(&self, i0: i32, i1: i32, i2: i32) -> Result<&T> {
+				self.mat.at_3d(i0, i1, i2)
+			}
+
+			/// Returns a reference to the element at the specified position, see [MatTraitConst::at_nd]
+			#[inline]
+			pub fn at_nd(&self, idx: &[i32]) -> Result<&T> {
+				self.mat.at_nd(idx)
+			}
+
+			/// Returns a reference to the element at the specified position, see [MatTraitConst::at_pt]
+			#[inline]
+			pub fn at_pt(&self, pt: Point) -> Result<&T> {
+				self.mat.at_pt(pt)
+			}
+
+			/// Return a complete read-only row of the view
+			#[inline]
+			pub fn at_row(&self, row: i32) -> Result<&[T]> {
+				self.mat.at_row(row)
+			}
+
+			/// Returns the elements of the view as a slice, view must be continuous
+			#[inline]
+			pub fn data_typed(&self) -> Result<&[T]> {
+				self.mat.data_typed()
+			}
+
+			/// See [MatTraitConstManual::to_vec_2d]
+			#[inline]
+			pub fn to_vec_2d(&self) -> Result<Vec<Vec<T>>> {
+				self.mat.to_vec_2d()
+			}
+
+			/// Deep copy of the view elements into a new `Mat`, see [MatTraitConst::try_clone]
+			#[inline]
+			pub fn try_clone(&self) -> Result<Mat> {
+				self.mat.try_clone()
+			}
+
+			/// Returns an iterator over the rows of the view, view must be 2-dimensional
+			#[inline]
+			pub fn row_iter(&self) -> Result<MatRowIter<'_, T>> {
+				self.mat.row_iter()
+			}
+
+			/// Returns an immutable sub-view of the rows `start..end`
+			#[inline]
+			pub fn view_rows(&self, start: i32, end: i32) -> Result<MatView<'_, T>> {
+				self.mat.view_rows(start, end)
+			}
+
+			/// Returns an immutable sub-view of the columns `start..end`
+			#[inline]
+			pub fn view_cols(&self, start: i32, end: i32) -> Result<MatView<'_, T>> {
+				self.mat.view_cols(start, end)
+			}
+
+			/// Returns an immutable sub-view of the intersection of `row_range` and `col_range`
+			#[inline]
+			pub fn view_rows_cols(&self, row_range: &Range, col_range: &Range) -> Result<MatView<'_, T>> {
+				self.mat.view_rows_cols(row_range, col_range)
+			}
+
+			/// Returns an immutable sub-view of the region of interest `rect`
+			#[inline]
+			pub fn view_rect(&self, rect: Rect) -> Result<MatView<'_, T>> {
+				self.mat.view_rect(rect)
+			}
+		}
+	};
+}
+
+/// Immutable view into a part of the `Mat` with elements of type `T`, borrowing the parent for the lifetime `'a`
+///
+/// Create it with `view()`, `view_rows()`, `view_cols()`, `view_rows_cols()`, `view_rect()` or `view_channels()`. While the
+/// view is alive the parent can't be mutated. The view can be passed to OpenCV functions as an input array and sliced further
+/// with the `view_*()` methods. Rows are accessible with `at_row()` and `row_iter()`. It intentionally doesn't implement
+/// `MatTraitConst` or dereference to `Mat`, that would allow creating owned `Mat` headers aliasing the parent.
+pub struct MatView<'a, T> {
+	mat: Mat,
+	_d: PhantomData<&'a T>,
+}
+
+impl<'a, T: DataType> MatView<'a, T> {
+	/// # Safety
+	/// Caller must ensure that `mat` is a header over the data immutably borrowed for `'a` and that its type is `T`
+	#[inline]
+	pub(crate) unsafe fn new(mat: Mat) -> Self {
+		Self { mat, _d: PhantomData }
+	}
+}
+
+mat_view_const_methods! { MatView }
+
+impl<T> ToInputArray for MatView<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.mat.input_array()
+	}
+}
+
+impl<T> fmt::Debug for MatView<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.mat.fmt(f)
+	}
+}
+
+/// Mutable view into a part of the `Mat` with elements of type `T`, mutably borrowing the parent for the lifetime `'a`
+///
+/// Create it with `view_mut()`, `view_rows_mut()`, `view_cols_mut()`, `view_rows_cols_mut()`, `view_rect_mut()` or
+/// `view_channels_mut()`. Non-overlapping mutable views can be obtained at the same time with `split_rows_mut()` and
+/// `split_cols_mut()`, e.g. to process the parts of the image in separate threads. Rows are accessible with `at_row_mut()`
+/// and `row_iter_mut()`. The view can't be reallocated: mutable access is limited to its elements and when passed as an
+/// output array its size and type are fixed. Same as `MatView` it doesn't implement `MatTraitConst` or dereference to `Mat`.
+pub struct MatViewMut<'a, T> {
+	mat: Mat,
+	_d: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: DataType> MatViewMut<'a, T> {
+	/// # Safety
+	/// Caller must ensure that `mat` is a header over the data mutably borrowed for `'a`, that the data is not accessed by
+	/// anything else during that time and that its type is `T`
+	#[inline]
+	pub(crate) unsafe fn new(mat: Mat) -> Self {
+		Self { mat, _d: PhantomData }
+	}
+
+	/// Returns a reference to the element at the specified position, see [MatTrait::at_mut]
+	#[inline]
+	pub fn at_mut(&mut self, i0: i32) -> Result<&mut T> {
+		self.mat.at_mut(i0)
+	}
+
+	/// Returns a reference to the element at the specified position, see [MatTrait::at_2d_mut]
+	#[inline]
+	pub fn at_2d_mut(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		self.mat.at_2d_mut(row, col)
+	}
+
+	/// Returns a reference to the element at the specified position, see [MatTrait::at_3d_mut]
+	#[inline]
+	pub fn at_3d_mut(&mut self, i0: i32, i1: i32, i2: i32) -> Result<&mut T> {
+		self.mat.at_3d_mut(i0, i1, i2)
+	}
+
+	/// Returns a reference to the element at the specified position, see [MatTrait::at_nd_mut]
+	#[inline]
+	pub fn at_nd_mut(&mut self, idx: &[i32]) -> Result<&mut T> {
+		self.mat.at_nd_mut(idx)
+	}
+
+	/// Returns a reference to the element at the specified position, see [MatTrait::at_pt_mut]
+	#[inline]
+	pub fn at_pt_mut(&mut self, pt: Point) -> Result<&mut T> {
+		self.mat.at_pt_mut(pt)
+	}
+
+	/// Return a complete writeable row of the view
+	#[inline]
+	pub fn at_row_mut(&mut self, row: i32) -> Result<&mut [T]> {
+		self.mat.at_row_mut(row)
+	}
+
+	/// Sets all the elements of the view to the specified value, see [MatTraitManual::set]
+	#[inline]
+	pub fn set(&mut self, s: Scalar) -> Result<()> {
+		self.mat.set(s)
+	}
+
+	/// Returns an iterator over the writeable rows of the view, view must be 2-dimensional
+	#[inline]
+	pub fn row_iter_mut(&mut self) -> Result<MatRowIterMut<'_, T>> {
+		self.mat.row_iter_mut()
+	}
+
+	/// Returns a mutable sub-view of the rows `start..end`
+	#[inline]
+	pub fn view_rows_mut(&mut self, start: i32, end: i32) -> Result<MatViewMut<'_, T>> {
+		self.mat.view_rows_mut(start, end)
+	}
+
+	/// Returns a mutable sub-view of the columns `start..end`
+	#[inline]
+	pub fn view_cols_mut(&mut self, start: i32, end: i32) -> Result<MatViewMut<'_, T>> {
+		self.mat.view_cols_mut(start, end)
+	}
+
+	/// Returns a mutable sub-view of the region of interest `rect`
+	#[inline]
+	pub fn view_rect_mut(&mut self, rect: Rect) -> Result<MatViewMut<'_, T>> {
+		self.mat.view_rect_mut(rect)
+	}
+
+	/// Splits the view into 2 non-overlapping mutable views, rows `0..mid` and `mid..rows`
+	#[inline]
+	pub fn split_rows_mut(&mut self, mid: i32) -> Result<(MatViewMut<'_, T>, MatViewMut<'_, T>)> {
+		self.mat.split_rows_mut(mid)
+	}
+
+	/// Splits the view into 2 non-overlapping mutable views, columns `0..mid` and `mid..cols`
+	#[inline]
+	pub fn split_cols_mut(&mut self, mid: i32) -> Result<(MatViewMut<'_, T>, MatViewMut<'_, T>)> {
+		self.mat.split_cols_mut(mid)
+	}
+}
+
+mat_view_const_methods! { MatViewMut }
+
+impl<T> ToInputArray for MatViewMut<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.mat.input_array()
+	}
+}
+
+impl<T> ToOutputArray for MatViewMut<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_output_array_fixed(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_Mat_output_array_fixed(self.mat.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+	}
+}
+
+impl<T> ToInputOutputArray for MatViewMut<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		extern "C" {
+			fn cv_manual_Mat_input_output_array_fixed(instance: *mut c_void, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_Mat_input_output_array_fixed(self.mat.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+	}
+}
+
+impl<T> fmt::Debug for MatViewMut<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.mat.fmt(f)
+	}
+}
+
+pub(crate) fn split_rows<T: DataType, M: MatTraitConst + ?Sized>(mat: &M, mid: i32) -> Result<(Mat, Mat)> {
+	match_format::<T>(mat.typ())?;
+	let rows = mat.rows();
+	match_split(mid, rows, "row")?;
+	Ok((mat.row_bounds(0, mid)?, mat.row_bounds(mid, rows)?))
+}
+
+pub(crate) fn split_cols<T: DataType, M: MatTraitConst + ?Sized>(mat: &M, mid: i32) -> Result<(Mat, Mat)> {
+	match_format::<T>(mat.typ())?;
+	let cols = mat.cols();
+	match_split(mid, cols, "column")?;
+	Ok((mat.col_bounds(0, mid)?, mat.col_bounds(mid, cols)?))
+}
@@ -0,0 +1,313 @@
+use std::convert::TryFrom;
+use std::ffi::c_void;
+use std::mem;
+use std::ptr::NonNull;
+
+use ndarray::{ArrayView, ArrayView2, ArrayViewD, ArrayViewMut, ArrayViewMut2, ArrayViewMutD, Dimension, IxDyn, ShapeBuilder};
+
+use crate::core::{BorrowedMat, BorrowedMatMut, Mat, MatTrait, MatTraitConst, Mat_, Scalar};
+use crate::platform_types::size_t;
+use crate::{core, Error, Result};
+
+use super::{match_dims, match_format, DataType};
+
+/// Shape and strides (in elements of `T`) of the `Mat` data as seen by `ndarray`
+///
+/// When `channels_axis` is true the channels of the `Mat` are exposed as an additional trailing axis.
+fn mat_layout<T: DataType, M: MatTraitConst + ?Sized>(mat: &M, channels_axis: bool) -> Result<(Vec<usize>, Vec<usize>)> {
+	let mut shape = mat.mat_size().iter().map(|&x| x as usize).collect::<Vec<_>>();
+	if mat.empty() {
+		let dims = shape.len().max(2) + usize::from(channels_axis);
+		return Ok((vec![0; dims], vec![0; dims]));
+	}
+	let elem_size = mem::size_of::<T>();
+	let step_bytes = if shape.len() == 2 {
+		mat.mat_step().to_vec()
+	} else {
+		let elem_size1 = mat.elem_size1();
+		(0..mat.dims())
+			.map(|i| mat.step1(i).map(|x| x * elem_size1))
+			.collect::<Result<_>>()?
+	};
+	let mut strides = step_bytes
+		.into_iter()
+		.map(|step| {
+			if step % elem_size == 0 {
+				Ok(step / elem_size)
+			} else {
+				Err(Error::new(
+					core::StsUnmatchedSizes,
+					format!("Mat step: {step} is not a multiple of the element size: {elem_size}"),
+				))
+			}
+		})
+		.collect::<Result<Vec<_>>>()?;
+	if channels_axis {
+		shape.push(mat.channels() as usize);
+		strides.push(1);
+	}
+	Ok((shape, strides))
+}
+
+/// Checks that `T` is suitable for viewing the `Mat` data, returns whether the channels need to be a separate axis
+#[inline]
+fn match_format_channels<T: DataType, M: MatTraitConst + ?Sized>(mat: &M) -> Result<bool> {
+	let mat_type = mat.typ();
+	if mat_type != T::opencv_type() && mat.channels() > 1 && T::opencv_type() == mat.depth() {
+		Ok(true)
+	} else {
+		match_format::<T>(mat_type).map(|_| false)
+	}
+}
+
+#[inline]
+fn match_dims_2d(mat: &(impl MatTraitConst + ?Sized)) -> Result<()> {
+	if mat.empty() {
+		Ok(())
+	} else {
+		match_dims(mat, 2)
+	}
+}
+
+#[inline]
+fn data_ptr<T>(mat: &(impl MatTraitConst + ?Sized)) -> *const T {
+	if mat.empty() {
+		NonNull::dangling().as_ptr()
+	} else {
+		mat.data() as *const T
+	}
+}
+
+#[inline]
+fn data_ptr_mut<T>(mat: &mut (impl MatTrait + ?Sized)) -> *mut T {
+	if mat.empty() {
+		NonNull::dangling().as_ptr()
+	} else {
+		mat.data_mut() as *mut T
+	}
+}
+
+unsafe fn view_2d<'m, T: DataType>(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<ArrayView2<'m, T>> {
+	let (shape, strides) = mat_layout::<T, _>(mat, false)?;
+	Ok(ArrayView2::from_shape_ptr(
+		(shape[0], shape[1]).strides((strides[0], strides[1])),
+		data_ptr(mat),
+	))
+}
+
+unsafe fn view_2d_mut<'m, T: DataType>(mat: &'m mut (impl MatTrait + ?Sized)) -> Result<ArrayViewMut2<'m, T>> {
+	let (shape, strides) = mat_layout::<T, _>(mat, false)?;
+	Ok(ArrayViewMut2::from_shape_ptr(
+		(shape[0], shape[1]).strides((strides[0], strides[1])),
+		data_ptr_mut(mat),
+	))
+}
+
+unsafe fn view_nd<'m, T: DataType>(mat: &'m (impl MatTraitConst + ?Sized), channels_axis: bool) -> Result<ArrayViewD<'m, T>> {
+	let (shape, strides) = mat_layout::<T, _>(mat, channels_axis)?;
+	Ok(ArrayViewD::from_shape_ptr(
+		IxDyn(&shape).strides(IxDyn(&strides)),
+		data_ptr(mat),
+	))
+}
+
+unsafe fn view_nd_mut<'m, T: DataType>(
+	mat: &'m mut (impl MatTrait + ?Sized),
+	channels_axis: bool,
+) -> Result<ArrayViewMutD<'m, T>> {
+	let (shape, strides) = mat_layout::<T, _>(mat, channels_axis)?;
+	Ok(ArrayViewMutD::from_shape_ptr(
+		IxDyn(&shape).strides(IxDyn(&strides)),
+		data_ptr_mut(mat),
+	))
+}
+
+/// Converts the `ndarray` shape and strides to the `Mat` sizes and steps (in bytes)
+///
+/// OpenCV requires the last dimension to be contiguous and the dimensions to not overlap in memory, so only arrays in
+/// the standard (row-major) layout, possibly sliced along the outer axes, are accepted.
+fn array_layout(shape: &[usize], strides: &[isize], elem_size: usize) -> Result<(Vec<i32>, Vec<size_t>)> {
+	if shape.is_empty() {
+		return Err(Error::new(core::StsBadArg, "Array must have at least 1 dimension"));
+	}
+	let sizes = shape
+		.iter()
+		.map(|&x| i32::try_from(x).map_err(|_| Error::new(core::StsOutOfRange, format!("Array dimension: {x} is too large"))))
+		.collect::<Result<Vec<_>>>()?;
+	let mut steps = vec![elem_size; shape.len()];
+	for i in (0..shape.len()).rev() {
+		let min_step = if i == shape.len() - 1 {
+			elem_size
+		} else {
+			steps[i + 1] * shape[i + 1]
+		};
+		if shape[i] > 1 {
+			let stride = strides[i];
+			let step = usize::try_from(stride).ok().map(|x| x * elem_size);
+			let valid_step = match step {
+				Some(step) if i == shape.len() - 1 => step == elem_size,
+				Some(step) => step >= min_step,
+				None => false,
+			};
+			if !valid_step {
+				return Err(Error::new(
+					core::StsBadArg,
+					format!(
+						"Array stride: {stride} along axis: {i} is not supported, array must be in the standard layout (try `as_standard_layout()`)"
+					),
+				));
+			}
+			steps[i] = step.unwrap_or(min_step);
+		} else {
+			steps[i] = min_step;
+		}
+	}
+	Ok((sizes, steps))
+}
+
+/// Creates the `Mat` header over the array data, `channels` is the length of the trailing axis that becomes `Mat` channels
+unsafe fn mat_over_array<T: DataType>(shape: &[usize], strides: &[isize], channels: Option<usize>, data: *mut T) -> Result<Mat> {
+	let (typ, shape, strides, elem_size) = if let Some(channels) = channels {
+		if T::opencv_channels() != 1 {
+			return Err(Error::new(
+				core::StsUnmatchedFormats,
+				"Array element type must have a single channel to map the trailing axis to channels",
+			));
+		}
+		let (channels_stride, strides) = strides.split_last().expect("Array must be at least 2-dimensional");
+		if channels > core::CV_CN_MAX as usize || (channels > 1 && *channels_stride != 1) {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Trailing array axis of length: {channels} can't be mapped to Mat channels"),
+			));
+		}
+		(
+			core::CV_MAKETYPE(T::opencv_depth(), channels as i32),
+			&shape[..shape.len() - 1],
+			strides,
+			mem::size_of::<T>() * channels,
+		)
+	} else {
+		(T::opencv_type(), shape, strides, mem::size_of::<T>())
+	};
+	let (sizes, steps) = array_layout(shape, strides, elem_size)?;
+	if shape.iter().any(|&x| x == 0) {
+		Mat::new_nd_with_default(&sizes, typ, Scalar::default())
+	} else {
+		Mat::new_nd_with_data(&sizes, typ, data as *mut c_void, Some(&steps))
+	}
+}
+
+#[inline]
+fn channels_axis_len(shape: &[usize]) -> Result<usize> {
+	if shape.len() < 2 {
+		Err(Error::new(
+			core::StsBadArg,
+			"Array must have at least 2 dimensions to map the trailing axis to channels",
+		))
+	} else {
+		Ok(shape[shape.len() - 1])
+	}
+}
+
+/// Interoperability with [ndarray](https://crates.io/crates/ndarray), available with the `ndarray` feature
+impl Mat {
+	/// Returns a 2-dimensional `ndarray` view over the `Mat` elements without copying the data
+	///
+	/// `Mat` must be 2-dimensional and its type must match `T` exactly. Steps of non-continuous matrices (e.g. results of
+	/// `Mat::roi()`) are reflected in the strides of the view.
+	#[inline]
+	pub fn as_array_view_2d<T: DataType>(&self) -> Result<ArrayView2<T>> {
+		match_format::<T>(self.typ()).and_then(|_| match_dims_2d(self))?;
+		unsafe { view_2d(self) }
+	}
+
+	/// Mutable version of `as_array_view_2d()`
+	#[inline]
+	pub fn as_array_view_2d_mut<T: DataType>(&mut self) -> Result<ArrayViewMut2<T>> {
+		match_format::<T>(self.typ()).and_then(|_| match_dims_2d(self))?;
+		unsafe { view_2d_mut(self) }
+	}
+
+	/// Returns an N-dimensional `ndarray` view over the `Mat` elements without copying the data
+	///
+	/// If `T` matches the `Mat` type then the view has the same number of dimensions as the `Mat`. If `T` is a single
+	/// channel type with the same depth as a multichannel `Mat` then channels are exposed as an additional trailing axis,
+	/// e.g. an 8-bit BGR image can be viewed as a `rows x cols x 3` array of `u8`.
+	#[inline]
+	pub fn as_array_view<T: DataType>(&self) -> Result<ArrayViewD<T>> {
+		let channels_axis = match_format_channels::<T, _>(self)?;
+		unsafe { view_nd(self, channels_axis) }
+	}
+
+	/// Mutable version of `as_array_view()`
+	#[inline]
+	pub fn as_array_view_mut<T: DataType>(&mut self) -> Result<ArrayViewMutD<T>> {
+		let channels_axis = match_format_channels::<T, _>(self)?;
+		unsafe { view_nd_mut(self, channels_axis) }
+	}
+
+	/// Creates a `Mat` header over the data of the `ndarray` view without copying it
+	///
+	/// The resulting `Mat` has the same number of dimensions and elements of type `T`. The array must have the last
+	/// axis contiguous and outer axes must not overlap, which is the case for arrays in standard layout and their slices.
+	#[inline]
+	pub fn from_array_view<T: DataType, D: Dimension>(arr: ArrayView<T, D>) -> Result<BorrowedMat> {
+		unsafe { mat_over_array(arr.shape(), arr.strides(), None, arr.as_ptr() as *mut T) }
+			.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Same as `from_array_view()`, but the trailing axis of the array becomes `Mat` channels
+	///
+	/// `T` must be a single channel type, e.g. a `rows x cols x 3` array of `u8` becomes a 2-dimensional `CV_8UC3` `Mat`.
+	#[inline]
+	pub fn from_array_view_with_channels<T: DataType, D: Dimension>(arr: ArrayView<T, D>) -> Result<BorrowedMat> {
+		let channels = channels_axis_len(arr.shape())?;
+		unsafe { mat_over_array(arr.shape(), arr.strides(), Some(channels), arr.as_ptr() as *mut T) }
+			.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Mutable version of `from_array_view()`, the resulting `Mat` can be used as an output array
+	#[inline]
+	pub fn from_array_view_mut<T: DataType, D: Dimension>(mut arr: ArrayViewMut<T, D>) -> Result<BorrowedMatMut> {
+		let data = arr.as_mut_ptr();
+		unsafe { mat_over_array(arr.shape(), arr.strides(), None, data) }.map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+
+	/// Mutable version of `from_array_view_with_channels()`, the resulting `Mat` can be used as an output array
+	#[inline]
+	pub fn from_array_view_with_channels_mut<T: DataType, D: Dimension>(mut arr: ArrayViewMut<T, D>) -> Result<BorrowedMatMut> {
+		let channels = channels_axis_len(arr.shape())?;
+		let data = arr.as_mut_ptr();
+		unsafe { mat_over_array(arr.shape(), arr.strides(), Some(channels), data) }.map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+}
+
+/// Interoperability with [ndarray](https://crates.io/crates/ndarray), available with the `ndarray` feature
+impl<T: DataType> Mat_<T> {
+	/// Same as `Mat::as_array_view_2d()`, but skips the type check
+	#[inline]
+	pub fn as_array_view_2d(&self) -> Result<ArrayView2<T>> {
+		match_dims_2d(self)?;
+		unsafe { view_2d(self) }
+	}
+
+	/// Same as `Mat::as_array_view_2d_mut()`, but skips the type check
+	#[inline]
+	pub fn as_array_view_2d_mut(&mut self) -> Result<ArrayViewMut2<T>> {
+		match_dims_2d(self)?;
+		unsafe { view_2d_mut(self) }
+	}
+
+	/// Same as `Mat::as_array_view()`, but skips the type check, the view never has a separate channels axis
+	#[inline]
+	pub fn as_array_view(&self) -> Result<ArrayViewD<T>> {
+		unsafe { view_nd(self, false) }
+	}
+
+	/// Same as `Mat::as_array_view_mut()`, but skips the type check, the view never has a separate channels axis
+	#[inline]
+	pub fn as_array_view_mut(&mut self) -> Result<ArrayViewMutD<T>> {
+		unsafe { view_nd_mut(self, false) }
+	}
+}
@@ -0,0 +1,687 @@
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::core::{Mat, MatTraitConst, MatTraitConstManual, MatTraitManual, Scalar, CV_MAKETYPE};
+use crate::{core, Error, Result};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+/// Written headers are padded so that the array data starts at the multiple of this value
+const NPY_ALIGN: usize = 64;
+const NPY_EXT: &str = ".npy";
+
+const ZIP_LOCAL_HEADER_SIG: u32 = 0x04034b50;
+const ZIP_CENTRAL_HEADER_SIG: u32 = 0x02014b50;
+const ZIP_EOCD_SIG: u32 = 0x06054b50;
+const ZIP64_EOCD_LOCATOR_SIG: u32 = 0x07064b50;
+const ZIP64_EOCD_SIG: u32 = 0x06064b50;
+const ZIP_LOCAL_HEADER_LEN: usize = 30;
+const ZIP_CENTRAL_HEADER_LEN: usize = 46;
+const ZIP_EOCD_LEN: usize = 22;
+const ZIP_VERSION: u16 = 20;
+/// 1980-01-01 in MS-DOS format, the earliest representable date
+const ZIP_DATE: u16 = 0x0021;
+
+#[inline]
+pub(crate) fn io_error(e: io::Error) -> Error {
+	Error::new(core::StsError, format!("I/O error: {e}"))
+}
+
+#[inline]
+fn parse_error(msg: impl Into<String>) -> Error {
+	Error::new(core::StsParseError, msg.into())
+}
+
+/// Reads exactly `len` bytes from `r`
+///
+/// `len` usually comes from the untrusted file header, so the buffer grows only as the data is actually read instead of
+/// being allocated upfront.
+fn read_vec(r: &mut impl Read, len: usize) -> Result<Vec<u8>> {
+	let mut out = Vec::new();
+	let len_u64 = u64::try_from(len).map_err(|_| Error::new(core::StsOutOfRange, "Data length is too large"))?;
+	r.by_ref().take(len_u64).read_to_end(&mut out).map_err(io_error)?;
+	if out.len() == len {
+		Ok(out)
+	} else {
+		Err(io_error(io::ErrorKind::UnexpectedEof.into()))
+	}
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ByteOrder {
+	Little,
+	Big,
+}
+
+impl ByteOrder {
+	#[cfg(target_endian = "little")]
+	const NATIVE: Self = Self::Little;
+	#[cfg(target_endian = "big")]
+	const NATIVE: Self = Self::Big;
+}
+
+/// Supported subset of the numpy dtypes, `depth` is the corresponding OpenCV depth
+#[derive(Copy, Clone, Debug)]
+struct Dtype {
+	depth: i32,
+	item_size: usize,
+	order: ByteOrder,
+}
+
+impl Dtype {
+	fn from_depth(depth: i32) -> Result<Self> {
+		let item_size = match depth {
+			core::CV_8U | core::CV_8S => 1,
+			core::CV_16U | core::CV_16S => 2,
+			// same condition as the `half::f16` DataType impl
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			core::CV_16F => 2,
+			core::CV_32S | core::CV_32F => 4,
+			core::CV_64F => 8,
+			_ => {
+				return Err(Error::new(
+					core::StsUnsupportedFormat,
+					format!("Mat depth: {depth} can't be stored in npy format"),
+				))
+			}
+		};
+		Ok(Self {
+			depth,
+			item_size,
+			order: ByteOrder::NATIVE,
+		})
+	}
+
+	/// Parses numpy dtype descriptor like `<f4` or `|u1`
+	fn parse(descr: &str) -> Result<Self> {
+		let unsupported = || Error::new(core::StsUnsupportedFormat, format!("Unsupported npy dtype: {descr}"));
+		let (order, type_str) = match descr.chars().next() {
+			Some('<') => (ByteOrder::Little, &descr[1..]),
+			Some('>') => (ByteOrder::Big, &descr[1..]),
+			Some('|' | '=') => (ByteOrder::NATIVE, &descr[1..]),
+			_ => (ByteOrder::NATIVE, descr),
+		};
+		let mut chars = type_str.chars();
+		let kind = chars.next().ok_or_else(unsupported)?;
+		let item_size = chars.as_str().parse::<usize>().map_err(|_| unsupported())?;
+		let depth = match (kind, item_size) {
+			('u' | 'b', 1) => core::CV_8U,
+			('i', 1) => core::CV_8S,
+			('u', 2) => core::CV_16U,
+			('i', 2) => core::CV_16S,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			('f', 2) => core::CV_16F,
+			('i', 4) => core::CV_32S,
+			('f', 4) => core::CV_32F,
+			('f', 8) => core::CV_64F,
+			_ => return Err(unsupported()),
+		};
+		Ok(Self { depth, item_size, order })
+	}
+
+	fn descr(&self) -> String {
+		let kind = match self.depth {
+			core::CV_8S | core::CV_16S | core::CV_32S => 'i',
+			core::CV_8U | core::CV_16U => 'u',
+			_ => 'f',
+		};
+		let order = match (self.item_size, self.order) {
+			(1, _) => '|',
+			(_, ByteOrder::Little) => '<',
+			(_, ByteOrder::Big) => '>',
+		};
+		format!("{order}{kind}{}", self.item_size)
+	}
+}
+
+struct NpyHeader {
+	dtype: Dtype,
+	fortran_order: bool,
+	shape: Vec<usize>,
+}
+
+/// Returns the text following `key` and the colon in the Python dict literal
+fn dict_value<'h>(header: &'h str, key: &str) -> Result<&'h str> {
+	let key_pos = header
+		.find(&format!("'{key}'"))
+		.or_else(|| header.find(&format!("\"{key}\"")))
+		.ok_or_else(|| parse_error(format!("Missing key: {key} in npy header: {header}")))?;
+	let rest = header[key_pos + key.len() + 2..].trim_start();
+	rest
+		.strip_prefix(':')
+		.map(str::trim_start)
+		.ok_or_else(|| parse_error(format!("Invalid npy header: {header}")))
+}
+
+impl NpyHeader {
+	fn parse(header: &str) -> Result<Self> {
+		let invalid = || parse_error(format!("Invalid npy header: {header}"));
+		let descr = dict_value(header, "descr")?;
+		let quote = descr.chars().next().filter(|&c| c == '\'' || c == '"').ok_or_else(|| {
+			Error::new(
+				core::StsUnsupportedFormat,
+				format!("Structured npy dtypes are not supported: {header}"),
+			)
+		})?;
+		let descr = descr[1..].split(quote).next().ok_or_else(invalid)?;
+		let fortran_order = dict_value(header, "fortran_order")?;
+		let fortran_order = if fortran_order.starts_with("True") {
+			true
+		} else if fortran_order.starts_with("False") {
+			false
+		} else {
+			return Err(invalid());
+		};
+		let shape = dict_value(header, "shape")?.strip_prefix('(').ok_or_else(invalid)?;
+		let shape = shape[..shape.find(')').ok_or_else(invalid)?]
+			.split(',')
+			.map(str::trim)
+			.filter(|dim| !dim.is_empty())
+			.map(|dim| dim.trim_end_matches('L').parse::<usize>().map_err(|_| invalid()))
+			.collect::<Result<Vec<_>>>()?;
+		Ok(Self {
+			dtype: Dtype::parse(descr)?,
+			fortran_order,
+			shape,
+		})
+	}
+
+	fn read(r: &mut impl Read) -> Result<Self> {
+		let mut preamble = [0; 8];
+		r.read_exact(&mut preamble).map_err(io_error)?;
+		if &preamble[..NPY_MAGIC.len()] != NPY_MAGIC {
+			return Err(parse_error("Invalid npy magic string"));
+		}
+		let header_len = match preamble[6] {
+			1 => {
+				let mut len = [0; 2];
+				r.read_exact(&mut len).map_err(io_error)?;
+				usize::from(u16::from_le_bytes(len))
+			}
+			2 | 3 => {
+				let mut len = [0; 4];
+				r.read_exact(&mut len).map_err(io_error)?;
+				u32::from_le_bytes(len) as usize
+			}
+			major => {
+				return Err(parse_error(format!(
+					"Unsupported npy format version: {major}.{}",
+					preamble[7]
+				)))
+			}
+		};
+		let header = read_vec(r, header_len)?;
+		Self::parse(&String::from_utf8_lossy(&header))
+	}
+
+	fn write(&self, w: &mut impl Write) -> Result<()> {
+		let mut shape = self.shape.iter().map(|dim| dim.to_string()).collect::<Vec<_>>().join(", ");
+		if self.shape.len() == 1 {
+			shape.push(',');
+		}
+		let mut header = format!(
+			"{{'descr': '{}', 'fortran_order': {}, 'shape': ({shape}), }}",
+			self.dtype.descr(),
+			if self.fortran_order {
+				"True"
+			} else {
+				"False"
+			},
+		);
+		// version 1.0 uses 2 bytes for the header length, 2.0 uses 4 bytes
+		let mut prefix_len = NPY_MAGIC.len() + 2 + 2;
+		if prefix_len + header.len() + 1 > usize::from(u16::MAX) {
+			prefix_len += 2;
+		}
+		let padded_len = (prefix_len + header.len() + 1 + NPY_ALIGN - 1) / NPY_ALIGN * NPY_ALIGN;
+		header.extend(std::iter::repeat(' ').take(padded_len - prefix_len - header.len() - 1));
+		header.push('\n');
+		w.write_all(NPY_MAGIC).map_err(io_error)?;
+		if prefix_len == NPY_MAGIC.len() + 4 {
+			w.write_all(&[1, 0]).map_err(io_error)?;
+			w.write_all(&(header.len() as u16).to_le_bytes()).map_err(io_error)?;
+		} else {
+			let header_len = u32::try_from(header.len()).map_err(|_| Error::new(core::StsOutOfRange, "npy header is too long"))?;
+			w.write_all(&[2, 0]).map_err(io_error)?;
+			w.write_all(&header_len.to_le_bytes()).map_err(io_error)?;
+		}
+		w.write_all(header.as_bytes()).map_err(io_error)
+	}
+}
+
+/// Reorders the items stored in the Fortran (column-major) order into the C (row-major) order
+fn fortran_to_c(data: &[u8], shape: &[usize], item_size: usize) -> Vec<u8> {
+	let mut out = Vec::with_capacity(data.len());
+	// strides of the source data in items
+	let mut strides = vec![1; shape.len()];
+	for dim in 1..shape.len() {
+		strides[dim] = strides[dim - 1] * shape[dim - 1];
+	}
+	let mut idx = vec![0; shape.len()];
+	for _ in 0..shape.iter().product::<usize>() {
+		let src = idx.iter().zip(&strides).map(|(i, stride)| i * stride).sum::<usize>() * item_size;
+		out.extend_from_slice(&data[src..src + item_size]);
+		for dim in (0..shape.len()).rev() {
+			idx[dim] += 1;
+			if idx[dim] < shape[dim] {
+				break;
+			}
+			idx[dim] = 0;
+		}
+	}
+	out
+}
+
+/// Returns the `Mat` sizes and the channel count for the array `shape`
+///
+/// Follows the conventions of the OpenCV Python bindings: 0- and 1-dimensional arrays become single column `Mat`s and
+/// the last dimension of 3-dimensional arrays is treated as channels if it doesn't exceed `CV_CN_MAX`.
+fn mat_layout(shape: &[usize]) -> Result<(Vec<i32>, i32)> {
+	let to_i32 = |dim: &usize| {
+		i32::try_from(*dim).map_err(|_| Error::new(core::StsOutOfRange, format!("npy array dimension: {dim} is too large")))
+	};
+	match shape {
+		[] => Ok((vec![1, 1], 1)),
+		[len] => Ok((vec![to_i32(len)?, 1], 1)),
+		[rows, cols, channels] if (1..=core::CV_CN_MAX as usize).contains(channels) => {
+			Ok((vec![to_i32(rows)?, to_i32(cols)?], to_i32(channels)?))
+		}
+		shape => Ok((shape.iter().map(to_i32).collect::<Result<_>>()?, 1)),
+	}
+}
+
+pub(crate) fn read_npy(r: &mut impl Read) -> Result<Mat> {
+	let header = NpyHeader::read(r)?;
+	let dtype = header.dtype;
+	let data_len = header
+		.shape
+		.iter()
+		.try_fold(dtype.item_size, |acc, &dim| acc.checked_mul(dim))
+		.ok_or_else(|| Error::new(core::StsOutOfRange, "npy array is too large"))?;
+	let mut data = read_vec(r, data_len)?;
+	if dtype.order != ByteOrder::NATIVE {
+		data.chunks_exact_mut(dtype.item_size).for_each(|item| item.reverse());
+	}
+	if header.fortran_order && header.shape.len() > 1 {
+		data = fortran_to_c(&data, &header.shape, dtype.item_size);
+	}
+	let (sizes, channels) = mat_layout(&header.shape)?;
+	let mut out = Mat::new_nd_with_default(&sizes, CV_MAKETYPE(dtype.depth, channels), Scalar::all(0.))?;
+	if !data.is_empty() {
+		out.data_bytes_mut()?.copy_from_slice(&data);
+	}
+	Ok(out)
+}
+
+pub(crate) fn write_npy(mat: &(impl MatTraitConst + ?Sized), w: &mut impl Write) -> Result<()> {
+	let dtype = Dtype::from_depth(mat.depth())?;
+	let mut shape = mat.mat_size().iter().map(|&dim| dim as usize).collect::<Vec<_>>();
+	if shape.is_empty() {
+		shape = vec![0, 0];
+	}
+	let channels = mat.channels();
+	if channels > 1 {
+		shape.push(channels as usize);
+	}
+	NpyHeader {
+		dtype,
+		fortran_order: false,
+		shape,
+	}
+	.write(w)?;
+	if mat.empty() {
+		Ok(())
+	} else if mat.is_continuous() {
+		w.write_all(mat.data_bytes()?).map_err(io_error)
+	} else {
+		w.write_all(mat.try_clone()?.data_bytes()?).map_err(io_error)
+	}
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+	let mut table = [0; 256];
+	let mut i = 0;
+	while i < table.len() {
+		let mut crc = i as u32;
+		let mut bit = 0;
+		while bit < 8 {
+			crc = if crc & 1 == 1 {
+				0xedb88320 ^ (crc >> 1)
+			} else {
+				crc >> 1
+			};
+			bit += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+}
+
+fn crc32(data: &[u8]) -> u32 {
+	!data.iter().fold(!0, |crc, &byte| {
+		CRC32_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+	})
+}
+
+#[inline]
+fn le_bytes<const N: usize>(buf: &[u8], pos: usize) -> Result<[u8; N]> {
+	let mut out = [0; N];
+	out.copy_from_slice(
+		buf.get(pos..pos + N)
+			.ok_or_else(|| parse_error("Unexpected end of the npz archive record"))?,
+	);
+	Ok(out)
+}
+
+#[inline]
+fn le_u16(buf: &[u8], pos: usize) -> Result<u16> {
+	le_bytes(buf, pos).map(u16::from_le_bytes)
+}
+
+#[inline]
+fn le_u32(buf: &[u8], pos: usize) -> Result<u32> {
+	le_bytes(buf, pos).map(u32::from_le_bytes)
+}
+
+#[inline]
+fn le_u64(buf: &[u8], pos: usize) -> Result<u64> {
+	le_bytes(buf, pos).map(u64::from_le_bytes)
+}
+
+fn read_at(r: &mut (impl Read + Seek), pos: u64, len: usize) -> Result<Vec<u8>> {
+	r.seek(SeekFrom::Start(pos)).map_err(io_error)?;
+	read_vec(r, len)
+}
+
+#[derive(Clone, Debug)]
+struct NpzEntry {
+	file_name: String,
+	method: u16,
+	crc: u32,
+	compressed_size: u64,
+	size: u64,
+	header_offset: u64,
+}
+
+impl NpzEntry {
+	/// Array name without the `.npy` extension
+	fn name(&self) -> &str {
+		self.file_name.strip_suffix(NPY_EXT).unwrap_or(&self.file_name)
+	}
+}
+
+/// Reader for `.npz` archives as written by `numpy.savez()`
+///
+/// Only uncompressed archives are supported, reading the arrays from `numpy.savez_compressed()` fails with
+/// `StsNotImplemented`. Arrays are converted to `Mat`s the same way as by `Mat::read_npy()`.
+pub struct NpzReader<R> {
+	reader: R,
+	entries: Vec<NpzEntry>,
+}
+
+impl NpzReader<BufReader<File>> {
+	/// Opens the archive at `path`
+	pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+		Self::new(BufReader::new(File::open(path).map_err(io_error)?))
+	}
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+	/// Reads the archive directory from `reader`
+	pub fn new(mut reader: R) -> Result<Self> {
+		let file_len = reader.seek(SeekFrom::End(0)).map_err(io_error)?;
+		let tail_len = file_len.min((ZIP_EOCD_LEN + usize::from(u16::MAX)) as u64);
+		let tail = read_at(&mut reader, file_len - tail_len, tail_len as usize)?;
+		let eocd_pos = (0..=tail.len().saturating_sub(ZIP_EOCD_LEN))
+			.rev()
+			.find(|&pos| le_u32(&tail, pos).ok() == Some(ZIP_EOCD_SIG))
+			.ok_or_else(|| parse_error("Not an npz archive: end of central directory record is not found"))?;
+		let mut entry_count = u64::from(le_u16(&tail, eocd_pos + 10)?);
+		let mut dir_len = u64::from(le_u32(&tail, eocd_pos + 12)?);
+		let mut dir_offset = u64::from(le_u32(&tail, eocd_pos + 16)?);
+		if entry_count == u64::from(u16::MAX) || dir_len == u64::from(u32::MAX) || dir_offset == u64::from(u32::MAX) {
+			let locator_pos = eocd_pos
+				.checked_sub(20)
+				.ok_or_else(|| parse_error("Missing zip64 end of central directory locator"))?;
+			if le_u32(&tail, locator_pos)? != ZIP64_EOCD_LOCATOR_SIG {
+				return Err(parse_error("Missing zip64 end of central directory locator"));
+			}
+			let eocd64 = read_at(&mut reader, le_u64(&tail, locator_pos + 8)?, 56)?;
+			if le_u32(&eocd64, 0)? != ZIP64_EOCD_SIG {
+				return Err(parse_error("Invalid zip64 end of central directory record"));
+			}
+			entry_count = le_u64(&eocd64, 32)?;
+			dir_len = le_u64(&eocd64, 40)?;
+			dir_offset = le_u64(&eocd64, 48)?;
+		}
+		let dir_len = usize::try_from(dir_len).map_err(|_| parse_error("npz central directory is too large"))?;
+		let dir = read_at(&mut reader, dir_offset, dir_len)?;
+		let mut entries = Vec::new();
+		let mut pos = 0;
+		for _ in 0..entry_count {
+			if le_u32(&dir, pos)? != ZIP_CENTRAL_HEADER_SIG {
+				return Err(parse_error("Invalid npz central directory entry"));
+			}
+			let name_len = usize::from(le_u16(&dir, pos + 28)?);
+			let extra_len = usize::from(le_u16(&dir, pos + 30)?);
+			let comment_len = usize::from(le_u16(&dir, pos + 32)?);
+			let name_start = pos + ZIP_CENTRAL_HEADER_LEN;
+			let file_name = dir
+				.get(name_start..name_start + name_len)
+				.map(|name| String::from_utf8_lossy(name).into_owned())
+				.ok_or_else(|| parse_error("Unexpected end of the npz central directory"))?;
+			let mut entry = NpzEntry {
+				file_name,
+				method: le_u16(&dir, pos + 10)?,
+				crc: le_u32(&dir, pos + 16)?,
+				compressed_size: u64::from(le_u32(&dir, pos + 20)?),
+				size: u64::from(le_u32(&dir, pos + 24)?),
+				header_offset: u64::from(le_u32(&dir, pos + 42)?),
+			};
+			// zip64 extended information, only the fields that overflow in the main record are present
+			let mut extra_pos = name_start + name_len;
+			let extra_end = extra_pos + extra_len;
+			while extra_pos + 4 <= extra_end {
+				let id = le_u16(&dir, extra_pos)?;
+				let len = usize::from(le_u16(&dir, extra_pos + 2)?);
+				if id == 0x0001 {
+					let mut field_pos = extra_pos + 4;
+					for field in [&mut entry.size, &mut entry.compressed_size, &mut entry.header_offset] {
+						if *field == u64::from(u32::MAX) {
+							*field = le_u64(&dir, field_pos)?;
+							field_pos += 8;
+						}
+					}
+				}
+				extra_pos += 4 + len;
+			}
+			entries.push(entry);
+			pos = extra_end + comment_len;
+		}
+		Ok(Self { reader, entries })
+	}
+
+	/// Returns the names of the arrays stored in the archive without the `.npy` extension
+	pub fn names(&self) -> Vec<&str> {
+		self.entries.iter().map(NpzEntry::name).collect()
+	}
+
+	/// Returns the number of arrays in the archive
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Reads the array with the specified `name`, the `.npy` extension is optional
+	pub fn by_name(&mut self, name: &str) -> Result<Mat> {
+		let entry = self
+			.entries
+			.iter()
+			.find(|entry| entry.name() == name || entry.file_name == name)
+			.cloned()
+			.ok_or_else(|| {
+				Error::new(
+					core::StsObjectNotFound,
+					format!("Array: {name} is not found in the npz archive"),
+				)
+			})?;
+		self.read_entry(&entry)
+	}
+
+	/// Reads all the arrays from the archive together with their names
+	pub fn read_all(&mut self) -> Result<Vec<(String, Mat)>> {
+		let entries = self.entries.clone();
+		entries
+			.iter()
+			.map(|entry| self.read_entry(entry).map(|mat| (entry.name().to_string(), mat)))
+			.collect()
+	}
+
+	fn read_entry(&mut self, entry: &NpzEntry) -> Result<Mat> {
+		if entry.method != 0 {
+			return Err(Error::new(
+				core::StsNotImplemented,
+				format!(
+					"Array: {} is compressed using method: {}, only uncompressed npz archives are supported",
+					entry.name(),
+					entry.method
+				),
+			));
+		}
+		let header = read_at(&mut self.reader, entry.header_offset, ZIP_LOCAL_HEADER_LEN)?;
+		if le_u32(&header, 0)? != ZIP_LOCAL_HEADER_SIG {
+			return Err(parse_error(format!("Invalid npz local header for array: {}", entry.name())));
+		}
+		let data_offset =
+			entry.header_offset + ZIP_LOCAL_HEADER_LEN as u64 + u64::from(le_u16(&header, 26)?) + u64::from(le_u16(&header, 28)?);
+		let size = usize::try_from(entry.size).map_err(|_| Error::new(core::StsOutOfRange, "npz array is too large"))?;
+		let data = read_at(&mut self.reader, data_offset, size)?;
+		if crc32(&data) != entry.crc {
+			return Err(parse_error(format!("CRC mismatch for array: {}", entry.name())));
+		}
+		read_npy(&mut data.as_slice())
+	}
+}
+
+/// Writer for `.npz` archives readable by `numpy.load()`
+///
+/// Arrays are stored uncompressed like `numpy.savez()` does, the archive size is limited to 4 GiB. Call `finish()` after
+/// adding all the arrays to write the archive directory.
+pub struct NpzWriter<W: Write> {
+	writer: W,
+	offset: u64,
+	entries: Vec<NpzEntry>,
+}
+
+impl NpzWriter<BufWriter<File>> {
+	/// Creates the archive at `path`, truncating the existing file
+	pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+		Ok(Self::new(BufWriter::new(File::create(path).map_err(io_error)?)))
+	}
+}
+
+impl<W: Write> NpzWriter<W> {
+	#[inline]
+	pub fn new(writer: W) -> Self {
+		Self {
+			writer,
+			offset: 0,
+			entries: vec![],
+		}
+	}
+
+	/// Adds `mat` to the archive under the specified `name`, see `MatTraitConstManual::write_npy()` for the format details
+	pub fn add(&mut self, name: &str, mat: &(impl MatTraitConst + ?Sized)) -> Result<()> {
+		if self.entries.iter().any(|entry| entry.name() == name) {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Array: {name} is already present in the npz archive"),
+			));
+		}
+		let mut data = vec![];
+		write_npy(mat, &mut data)?;
+		let file_name = format!("{name}{NPY_EXT}");
+		let entry = NpzEntry {
+			method: 0,
+			crc: crc32(&data),
+			compressed_size: data.len() as u64,
+			size: data.len() as u64,
+			header_offset: self.offset,
+			file_name,
+		};
+		let mut header = Vec::with_capacity(ZIP_LOCAL_HEADER_LEN + entry.file_name.len());
+		header.extend_from_slice(&ZIP_LOCAL_HEADER_SIG.to_le_bytes());
+		header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+		write_entry_fields(&mut header, &entry)?;
+		header.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+		header.extend_from_slice(entry.file_name.as_bytes());
+		self.write(&header)?;
+		self.write(&data)?;
+		self.entries.push(entry);
+		Ok(())
+	}
+
+	/// Writes the archive directory and returns the underlying writer
+	pub fn finish(mut self) -> Result<W> {
+		let dir_offset = self.offset;
+		let mut dir = vec![];
+		for entry in &self.entries {
+			dir.extend_from_slice(&ZIP_CENTRAL_HEADER_SIG.to_le_bytes());
+			dir.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // version made by
+			dir.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // version needed to extract
+			write_entry_fields(&mut dir, entry)?;
+			dir.extend_from_slice(&[0; 12]); // extra field and comment lengths, disk number, attributes
+			dir.extend_from_slice(&zip_u32(entry.header_offset)?.to_le_bytes());
+			dir.extend_from_slice(entry.file_name.as_bytes());
+		}
+		let entry_count =
+			u16::try_from(self.entries.len()).map_err(|_| Error::new(core::StsOutOfRange, "Too many arrays for the npz archive"))?;
+		let mut eocd = Vec::with_capacity(ZIP_EOCD_LEN);
+		eocd.extend_from_slice(&ZIP_EOCD_SIG.to_le_bytes());
+		eocd.extend_from_slice(&[0; 4]); // disk numbers
+		eocd.extend_from_slice(&entry_count.to_le_bytes());
+		eocd.extend_from_slice(&entry_count.to_le_bytes());
+		eocd.extend_from_slice(&zip_u32(dir.len() as u64)?.to_le_bytes());
+		eocd.extend_from_slice(&zip_u32(dir_offset)?.to_le_bytes());
+		eocd.extend_from_slice(&0u16.to_le_bytes()); // comment length
+		self.write(&dir)?;
+		self.write(&eocd)?;
+		self.writer.flush().map_err(io_error)?;
+		Ok(self.writer)
+	}
+
+	fn write(&mut self, buf: &[u8]) -> Result<()> {
+		self.writer.write_all(buf).map_err(io_error)?;
+		self.offset += buf.len() as u64;
+		Ok(())
+	}
+}
+
+#[inline]
+fn zip_u32(val: u64) -> Result<u32> {
+	u32::try_from(val).map_err(|_| Error::new(core::StsOutOfRange, "npz archives larger than 4 GiB are not supported"))
+}
+
+/// Writes the part of the record shared by the local and central headers: flags, method, time, date, CRC, sizes and
+/// the file name length
+fn write_entry_fields(out: &mut Vec<u8>, entry: &NpzEntry) -> Result<()> {
+	out.extend_from_slice(&0u16.to_le_bytes()); // flags
+	out.extend_from_slice(&entry.method.to_le_bytes());
+	out.extend_from_slice(&0u16.to_le_bytes()); // time
+	out.extend_from_slice(&ZIP_DATE.to_le_bytes());
+	out.extend_from_slice(&entry.crc.to_le_bytes());
+	out.extend_from_slice(&zip_u32(entry.compressed_size)?.to_le_bytes());
+	out.extend_from_slice(&zip_u32(entry.size)?.to_le_bytes());
+	let name_len = u16::try_from(entry.file_name.len()).map_err(|_| Error::new(core::StsOutOfRange, "Array name is too long"))?;
+	out.extend_from_slice(&name_len.to_le_bytes());
+	Ok(())
+}
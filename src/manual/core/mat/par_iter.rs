@@ -0,0 +1,221 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::core::{Mat, MatTraitManual, MatViewMut};
+use crate::{core, Error, Result};
+
+use super::row_iter::row_layout;
+use super::{match_format, DataType, MatRowIterMut};
+
+/// Parallel iterator over the rows of a 2-dimensional `Mat` as mutable typed slices, see `MatTraitManual::par_rows_mut()`
+///
+/// The rows are split between the threads without collecting them first, non-continuous matrices are supported.
+pub struct MatParRowsMut<'m, T> {
+	iter: MatRowIterMut<'m, T>,
+}
+
+impl<'m, T: DataType> MatParRowsMut<'m, T> {
+	pub(crate) fn new(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		MatRowIterMut::new(mat).map(|iter| Self { iter })
+	}
+}
+
+impl<'m, T: DataType + Send> ParallelIterator for MatParRowsMut<'m, T> {
+	type Item = &'m mut [T];
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.iter.len())
+	}
+}
+
+impl<T: DataType + Send> IndexedParallelIterator for MatParRowsMut<'_, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.iter.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(self.iter)
+	}
+}
+
+impl<'m, T: DataType + Send> Producer for MatRowIterMut<'m, T> {
+	type Item = &'m mut [T];
+	type IntoIter = Self;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		self.split_at_row(index)
+	}
+}
+
+/// Parallel iterator over the non-overlapping mutable views of a 2-dimensional `Mat`, see
+/// `MatTraitManual::par_chunks_mut()`
+///
+/// The view headers are created lazily by the worker threads, nothing is collected upfront.
+pub struct MatParChunksMut<'m, T> {
+	producer: ChunksProducer<'m, T>,
+}
+
+impl<'m, T: DataType> MatParChunksMut<'m, T> {
+	pub(crate) fn new(mat: &'m mut (impl MatTraitManual + ?Sized), chunk_rows: i32) -> Result<Self> {
+		if chunk_rows <= 0 {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Chunk row count: {chunk_rows} must be positive"),
+			));
+		}
+		match_format::<T>(mat.typ())?;
+		let (step, rows, cols) = row_layout(mat)?;
+		let chunk_rows = chunk_rows as usize;
+		Ok(Self {
+			producer: ChunksProducer {
+				data: mat.data_mut(),
+				step,
+				rows,
+				cols,
+				chunk_rows,
+				chunks: 0..(0..rows).step_by(chunk_rows).len(),
+				_d: PhantomData,
+			},
+		})
+	}
+}
+
+impl<'m, T: DataType + Send> ParallelIterator for MatParChunksMut<'m, T> {
+	type Item = MatViewMut<'m, T>;
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.producer.len())
+	}
+}
+
+impl<T: DataType + Send> IndexedParallelIterator for MatParChunksMut<'_, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.producer.len()
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(self.producer)
+	}
+}
+
+struct ChunksProducer<'m, T> {
+	data: *mut u8,
+	step: usize,
+	rows: usize,
+	cols: usize,
+	chunk_rows: usize,
+	chunks: Range<usize>,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType> ChunksProducer<'m, T> {
+	/// # Safety
+	/// Caller must ensure that every chunk index is used only once so that the returned views don't alias
+	unsafe fn chunk(&self, chunk: usize) -> MatViewMut<'m, T> {
+		let start = chunk * self.chunk_rows;
+		let rows = self.chunk_rows.min(self.rows - start);
+		Mat::new_rows_cols_with_data(
+			rows as i32,
+			self.cols as i32,
+			T::opencv_type(),
+			self.data.add(start * self.step) as *mut c_void,
+			self.step,
+		)
+		.map(|mat| MatViewMut::new(mat))
+		.expect("Cannot create Mat header for the chunk")
+	}
+
+	#[inline]
+	fn with_chunks(&self, chunks: Range<usize>) -> Self {
+		Self {
+			data: self.data,
+			step: self.step,
+			rows: self.rows,
+			cols: self.cols,
+			chunk_rows: self.chunk_rows,
+			chunks,
+			_d: PhantomData,
+		}
+	}
+}
+
+impl<'m, T: DataType> Iterator for ChunksProducer<'m, T> {
+	type Item = MatViewMut<'m, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// safe because every chunk index is yielded only once
+		self.chunks.next().map(|chunk| unsafe { self.chunk(chunk) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chunks.size_hint()
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for ChunksProducer<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.chunks.next_back().map(|chunk| unsafe { self.chunk(chunk) })
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for ChunksProducer<'_, T> {}
+
+impl<'m, T: DataType + Send> Producer for ChunksProducer<'m, T> {
+	type Item = MatViewMut<'m, T>;
+	type IntoIter = Self;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = self.chunks.start + index;
+		(
+			self.with_chunks(self.chunks.start..mid),
+			self.with_chunks(mid..self.chunks.end),
+		)
+	}
+}
+
+unsafe impl<T: Send> Send for ChunksProducer<'_, T> {}
@@ -0,0 +1,175 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::slice;
+
+use crate::core::{MatTraitConst, MatTraitConstManual, MatTraitManual};
+use crate::Result;
+
+use super::{match_dims, match_format, DataType};
+
+/// Returns the row step in bytes, row count and column count of the 2-dimensional `mat`
+///
+/// Empty `Mat` is reported as having no rows.
+pub(super) fn row_layout(mat: &(impl MatTraitConst + ?Sized)) -> Result<(usize, usize, usize)> {
+	if mat.empty() {
+		return Ok((0, 0, 0));
+	}
+	match_dims(mat, 2)?;
+	Ok((mat.mat_step()[0], mat.rows() as usize, mat.cols() as usize))
+}
+
+/// Iterator over the rows of a 2-dimensional `Mat` as typed slices, see `MatTraitConstManual::row_iter()`
+///
+/// The row addresses are calculated using the `Mat` step so non-continuous matrices are supported, no FFI calls are
+/// performed during the iteration.
+pub struct MatRowIter<'m, T> {
+	data: *const u8,
+	step: usize,
+	cols: usize,
+	rows: Range<usize>,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> MatRowIter<'m, T> {
+	pub(crate) fn new(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		// safe because the element type was checked above
+		unsafe { Self::new_unchecked(mat) }
+	}
+
+	/// Like `new()` but doesn't check the `Mat` element type
+	/// # Safety
+	/// Caller must ensure that `T` matches the `Mat` element type
+	pub(crate) unsafe fn new_unchecked(mat: &'m (impl MatTraitConst + ?Sized)) -> Result<Self> {
+		let (step, rows, cols) = row_layout(mat)?;
+		Ok(Self {
+			data: mat.data(),
+			step,
+			cols,
+			rows: 0..rows,
+			_d: PhantomData,
+		})
+	}
+
+	#[inline(always)]
+	unsafe fn row(&self, row: usize) -> &'m [T] {
+		slice::from_raw_parts(self.data.add(row * self.step) as *const T, self.cols)
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatRowIter<'m, T> {
+	type Item = &'m [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// safe because the row index comes from the range checked during the construction
+		self.rows.next().map(|row| unsafe { self.row(row) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.rows.size_hint()
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for MatRowIter<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.rows.next_back().map(|row| unsafe { self.row(row) })
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatRowIter<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatRowIter<'_, T> {}
+
+unsafe impl<T: Sync> Send for MatRowIter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for MatRowIter<'_, T> {}
+
+/// Iterator over the rows of a 2-dimensional `Mat` as mutable typed slices, see `MatTraitManual::row_iter_mut()`
+///
+/// Yielded rows never overlap, so they can be processed concurrently.
+pub struct MatRowIterMut<'m, T> {
+	data: *mut u8,
+	step: usize,
+	cols: usize,
+	rows: Range<usize>,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType> MatRowIterMut<'m, T> {
+	pub(crate) fn new(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ())?;
+		// safe because the element type was checked above
+		unsafe { Self::new_unchecked(mat) }
+	}
+
+	/// Like `new()` but doesn't check the `Mat` element type
+	/// # Safety
+	/// Caller must ensure that `T` matches the `Mat` element type
+	pub(crate) unsafe fn new_unchecked(mat: &'m mut (impl MatTraitManual + ?Sized)) -> Result<Self> {
+		let (step, rows, cols) = row_layout(mat)?;
+		Ok(Self {
+			data: mat.data_mut(),
+			step,
+			cols,
+			rows: 0..rows,
+			_d: PhantomData,
+		})
+	}
+
+	/// Splits the remaining rows into 2 iterators, the first one yields `index` rows
+	#[cfg(feature = "rayon")]
+	pub(super) fn split_at_row(self, index: usize) -> (Self, Self) {
+		let mid = self.rows.start + index;
+		let left = Self {
+			data: self.data,
+			step: self.step,
+			cols: self.cols,
+			rows: self.rows.start..mid,
+			_d: PhantomData,
+		};
+		let right = Self {
+			rows: mid..self.rows.end,
+			..self
+		};
+		(left, right)
+	}
+
+	#[inline(always)]
+	unsafe fn row(&mut self, row: usize) -> &'m mut [T] {
+		slice::from_raw_parts_mut(self.data.add(row * self.step) as *mut T, self.cols)
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatRowIterMut<'m, T> {
+	type Item = &'m mut [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// safe because every row index is yielded only once so the returned slices don't alias
+		self.rows.next().map(|row| unsafe { self.row(row) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.rows.size_hint()
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for MatRowIterMut<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.rows.next_back().map(|row| unsafe { self.row(row) })
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatRowIterMut<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatRowIterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for MatRowIterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for MatRowIterMut<'_, T> {}
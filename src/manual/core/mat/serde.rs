@@ -0,0 +1,189 @@
+use std::ffi::c_void;
+use std::fmt;
+
+use serde::de::{Error as _, SeqAccess, Visitor};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core::{Mat, MatTraitConst, MatTraitConstManual, Scalar};
+use crate::{core, Error, Result};
+
+/// Byte slice that is serialized using `serialize_bytes()` to allow compact representation in binary formats
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+/// Owned counterpart of `Bytes`, accepts both byte buffers and sequences of integers (e.g. from JSON)
+struct ByteBuf(Vec<u8>);
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+	type Value = ByteBuf;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a byte array")
+	}
+
+	fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		Ok(ByteBuf(v.to_vec()))
+	}
+
+	fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		Ok(ByteBuf(v))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		// untrusted length, the buffer grows as the bytes actually arrive
+		let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+		while let Some(byte) = seq.next_element()? {
+			out.push(byte);
+		}
+		Ok(ByteBuf(out))
+	}
+}
+
+impl<'de> Deserialize<'de> for ByteBuf {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_byte_buf(ByteBufVisitor)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Mat")]
+struct MatRef<'a> {
+	typ: i32,
+	sizes: Vec<i32>,
+	steps: Vec<usize>,
+	data: Bytes<'a>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Mat")]
+struct MatRepr {
+	typ: i32,
+	sizes: Vec<i32>,
+	steps: Vec<usize>,
+	data: ByteBuf,
+}
+
+/// Steps in bytes of the continuous `Mat` with the specified `sizes`
+fn continuous_steps(sizes: &[i32], elem_size: usize) -> Vec<usize> {
+	let mut steps = vec![elem_size; sizes.len()];
+	for dim in (0..sizes.len().saturating_sub(1)).rev() {
+		steps[dim] = steps[dim + 1] * sizes[dim + 1] as usize;
+	}
+	steps
+}
+
+impl MatRepr {
+	fn into_mat(self) -> Result<Mat> {
+		let Self { typ, sizes, steps, data } = self;
+		if sizes.is_empty() {
+			return Ok(Mat::default());
+		}
+		if sizes.iter().any(|&size| size < 0) {
+			return Err(Error::new(core::StsBadSize, format!("Negative Mat size: {sizes:?}")));
+		}
+		if sizes.contains(&0) {
+			return Mat::new_nd_with_default(&sizes, typ, Scalar::default());
+		}
+		if steps.len() != sizes.len() {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Mat steps count: {} doesn't match the dimensions count: {}",
+					steps.len(),
+					sizes.len()
+				),
+			));
+		}
+		let mut data = data.0;
+		// the header is not accessed until the data length is validated
+		let header =
+			unsafe { Mat::new_nd_with_data(&sizes, typ, data.as_mut_ptr() as *mut c_void, Some(&steps[..steps.len() - 1])) }?;
+		let elem_size = header.elem_size()?;
+		let last_step = steps[steps.len() - 1];
+		if last_step != elem_size {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Mat last step: {last_step} doesn't match the element size: {elem_size}"),
+			));
+		}
+		let overflow = || {
+			Error::new(
+				core::StsOutOfRange,
+				format!("Mat data length for sizes: {sizes:?} and steps: {steps:?} overflows"),
+			)
+		};
+		for (dim, (dim_steps, &inner_size)) in steps.windows(2).zip(&sizes[1..]).enumerate() {
+			let step = dim_steps[0];
+			let min_step = dim_steps[1].checked_mul(inner_size as usize).ok_or_else(overflow)?;
+			if step < min_step || step % elem_size != 0 {
+				return Err(Error::new(
+					core::StsBadArg,
+					format!(
+						"Mat step: {step} for dimension: {dim} must be a multiple of the element size: {elem_size} and not less than: {min_step}"
+					),
+				));
+			}
+		}
+		let required_len = sizes
+			.iter()
+			.zip(&steps)
+			.try_fold(elem_size, |len, (&size, &step)| {
+				(size as usize - 1)
+					.checked_mul(step)
+					.and_then(|offset| len.checked_add(offset))
+			})
+			.ok_or_else(overflow)?;
+		if data.len() < required_len {
+			return Err(Error::new(
+				core::StsBadSize,
+				format!("Mat data length: {} is less than required: {required_len}", data.len()),
+			));
+		}
+		header.try_clone()
+	}
+}
+
+/// `Mat` is serialized as a struct with its `typ`, `sizes`, `steps` (in bytes) and `data`
+///
+/// Non-continuous `Mat`s are serialized as continuous ones so `data` contains only the elements without any padding.
+/// Element values are stored using the native byte order. Deserialization accepts arbitrary steps (e.g. to skip row
+/// padding in the data coming from elsewhere) and always produces a continuous `Mat` owning its data.
+impl Serialize for Mat {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let continuous;
+		let mat = if self.is_continuous() {
+			self
+		} else {
+			continuous = self.try_clone().map_err(S::Error::custom)?;
+			&continuous
+		};
+		let sizes = mat.mat_size().to_vec();
+		let elem_size = mat.elem_size().map_err(S::Error::custom)?;
+		let data = if mat.empty() {
+			&[]
+		} else {
+			mat.data_bytes().map_err(S::Error::custom)?
+		};
+		MatRef {
+			typ: mat.typ(),
+			steps: continuous_steps(&sizes, elem_size),
+			sizes,
+			data: Bytes(data),
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Mat {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		MatRepr::deserialize(deserializer)?.into_mat().map_err(D::Error::custom)
+	}
+}
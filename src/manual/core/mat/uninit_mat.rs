@@ -0,0 +1,109 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::slice;
+
+use crate::core::{Mat, MatTrait, MatTraitConst, MatTraitConstManual, Mat_, Size};
+use crate::{core, Error, Result};
+
+use super::DataType;
+
+/// Freshly allocated `Mat_` with uninitialized elements, create it with `Mat_::new_uninit()`
+///
+/// The elements can only be written to, not read. Once every element is written convert it to `Mat_` using `assume_init()`
+/// or use `init_from_fn()` to fill all of the elements and convert it in one go without any `unsafe`. The underlying
+/// matrix is always continuous.
+pub struct UninitMat<T> {
+	mat: Mat,
+	_type: PhantomData<T>,
+}
+
+impl<T: DataType> UninitMat<T> {
+	#[inline]
+	pub(crate) fn new(rows: i32, cols: i32) -> Result<Self> {
+		// safe because the elements are only exposed as `MaybeUninit`
+		unsafe { Mat::new_rows_cols(rows, cols, T::opencv_type()) }.map(|mat| Self { mat, _type: PhantomData })
+	}
+
+	#[inline]
+	pub fn rows(&self) -> i32 {
+		self.mat.rows()
+	}
+
+	#[inline]
+	pub fn cols(&self) -> i32 {
+		self.mat.cols()
+	}
+
+	#[inline]
+	pub fn size(&self) -> Result<Size> {
+		self.mat.size()
+	}
+
+	/// All of the matrix elements in row-major order
+	#[inline]
+	pub fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+		let total = self.mat.total();
+		if total == 0 {
+			return &mut [];
+		}
+		// safe because the matrix was allocated by `Mat::new_rows_cols()` so it's continuous and holds `total` elements of `T`
+		unsafe { slice::from_raw_parts_mut(self.mat.data_mut().cast::<MaybeUninit<T>>(), total) }
+	}
+
+	/// Initializes the whole `row` by copying the elements from `data`, its length must be equal to the number of columns
+	pub fn write_row(&mut self, row: i32, data: &[T]) -> Result<()> {
+		let (rows, cols) = (self.rows(), self.cols());
+		if row < 0 || row >= rows {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Row index: {row} out of bounds 0..{rows}"),
+			));
+		}
+		if data.len() != cols as usize {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!("Row length is: {}, but Mat has: {cols} columns", data.len()),
+			));
+		}
+		let cols = cols as usize;
+		let dst = &mut self.as_uninit_slice_mut()[row as usize * cols..][..cols];
+		dst.iter_mut().zip(data).for_each(|(dst, src)| {
+			dst.write(*src);
+		});
+		Ok(())
+	}
+
+	/// Converts to `Mat_` assuming that every element has been written
+	/// # Safety
+	/// Caller must ensure that all elements are initialized, e.g. with `write_row()` or `as_uninit_slice_mut()`
+	#[inline]
+	pub unsafe fn assume_init(self) -> Mat_<T> {
+		Mat_::from_untyped_unchecked(self.mat)
+	}
+
+	/// Initializes every element with the result of calling `f(row, col)` and converts to `Mat_`
+	pub fn init_from_fn(mut self, mut f: impl FnMut(i32, i32) -> T) -> Mat_<T> {
+		let cols = self.cols() as usize;
+		if cols > 0 {
+			for (row, row_data) in self.as_uninit_slice_mut().chunks_exact_mut(cols).enumerate() {
+				for (col, dst) in row_data.iter_mut().enumerate() {
+					// casts are safe because both row and column counts fit `i32`
+					dst.write(f(row as i32, col as i32));
+				}
+			}
+		}
+		// safe because every element has been written in the loop above
+		unsafe { self.assume_init() }
+	}
+}
+
+impl<T: DataType> fmt::Debug for UninitMat<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("UninitMat")
+			.field("rows", &self.rows())
+			.field("cols", &self.cols())
+			.field("type", &T::opencv_type())
+			.finish()
+	}
+}
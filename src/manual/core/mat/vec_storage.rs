@@ -0,0 +1,156 @@
+//! `Mat` element buffers owned by a Rust `Vec`
+//!
+//! `Mat::from_vec()` moves the `Vec` into a `VecStorage` that is attached to the `cv::UMatData` of the new `Mat`. When the
+//! last reference to the buffer is dropped the C++ side calls `ocvrs_vec_storage_drop()` to free it with the Rust
+//! allocator.
+
+use std::ffi::c_void;
+use std::mem::{self, ManuallyDrop};
+
+use crate::core::{Mat, MatTrait, MatTraitConst, MatTraitConstManual, Mat_};
+use crate::platform_types::size_t;
+use crate::traits::Boxed;
+use crate::{core, sys, Error, Result};
+
+use super::{col_count_i32, row_count_i32, DataType};
+
+/// Decomposed `Vec<T>` with the type erased
+struct VecStorage {
+	ptr: *mut u8,
+	len: usize,
+	capacity: usize,
+	elem_size: usize,
+	elem_align: usize,
+	drop_vec: unsafe fn(&VecStorage),
+}
+
+impl VecStorage {
+	fn new<T>(v: Vec<T>) -> Self {
+		let mut v = ManuallyDrop::new(v);
+		Self {
+			ptr: v.as_mut_ptr().cast::<u8>(),
+			len: v.len(),
+			capacity: v.capacity(),
+			elem_size: mem::size_of::<T>(),
+			elem_align: mem::align_of::<T>(),
+			drop_vec: Self::drop_vec::<T>,
+		}
+	}
+
+	unsafe fn drop_vec<T>(&self) {
+		drop(Vec::from_raw_parts(self.ptr.cast::<T>(), self.len, self.capacity))
+	}
+
+	/// Reassembles the `Vec<T>` if `T` has the same layout as the original element type
+	fn into_vec<T>(self) -> Result<Vec<T>, Self> {
+		if self.elem_size == mem::size_of::<T>() && self.elem_align == mem::align_of::<T>() {
+			let s = ManuallyDrop::new(self);
+			// safe because the allocation was made by `Vec` with the element type of the same layout
+			Ok(unsafe { Vec::from_raw_parts(s.ptr.cast::<T>(), s.len, s.capacity) })
+		} else {
+			Err(self)
+		}
+	}
+}
+
+impl Drop for VecStorage {
+	fn drop(&mut self) {
+		// safe because `drop_vec` was created for the original element type
+		unsafe { (self.drop_vec)(self) }
+	}
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_vec_storage_drop(storage: *mut c_void) {
+	drop(Box::from_raw(storage as *mut VecStorage))
+}
+
+impl Mat {
+	/// Create a new `Mat` that takes ownership of the `Vec` data without copying it
+	///
+	/// The buffer is freed with the Rust allocator when the last `Mat` referencing it is dropped. The length of `v` must
+	/// match `row_count * col_count` exactly.
+	pub fn from_vec<T: DataType>(v: Vec<T>, row_count: usize, col_count: usize) -> Result<Self> {
+		extern "C" {
+			fn cv_manual_Mat_from_vec(
+				rows: i32,
+				cols: i32,
+				typ: i32,
+				data: *mut c_void,
+				size: size_t,
+				storage: *mut c_void,
+				ocvrs_return: *mut sys::Result<*mut c_void>,
+			);
+		}
+		if row_count.checked_mul(col_count) != Some(v.len()) {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"The length of the vec: {} must match the passed row count: {row_count} and column count: {col_count} exactly",
+					v.len()
+				),
+			));
+		}
+		let (rows, cols) = (row_count_i32(row_count)?, col_count_i32(col_count)?);
+		let size = v.len() * mem::size_of::<T>();
+		let storage = Box::into_raw(Box::new(VecStorage::new(v)));
+		// safe because `storage` was just created and is not shared yet
+		let data = unsafe { (*storage).ptr };
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_Mat_from_vec(
+				rows,
+				cols,
+				T::opencv_type(),
+				data.cast::<c_void>(),
+				size,
+				storage.cast::<c_void>(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { Self::from_raw(ptr) }).map_err(|e| {
+			// safe because the ownership of `storage` is not taken on error
+			drop(unsafe { Box::from_raw(storage) });
+			e
+		})
+	}
+}
+
+impl<T: DataType> Mat_<T> {
+	/// Create a new `Mat_` that takes ownership of the `Vec` data without copying it, see `Mat::from_vec()`
+	#[inline]
+	pub fn from_vec(v: Vec<T>, row_count: usize, col_count: usize) -> Result<Self> {
+		Mat::from_vec(v, row_count, col_count).map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Converts the matrix into a `Vec` of its elements in row-major order
+	///
+	/// The data is not copied if this `Mat_` is the only owner of the continuous buffer created by `from_vec()` and it
+	/// spans the whole buffer, otherwise the elements are copied to a new `Vec`.
+	pub fn into_vec(self) -> Result<Vec<T>> {
+		extern "C" {
+			fn cv_manual_Mat_detach_vec_storage(instance: *mut c_void) -> *mut c_void;
+		}
+		let mut mat = self.into_untyped();
+		let storage = unsafe { cv_manual_Mat_detach_vec_storage(mat.as_raw_mut_Mat()) };
+		if storage.is_null() {
+			return if mat.is_continuous() {
+				mat.data_typed::<T>().map(|data| data.to_vec())
+			} else {
+				mat.try_clone()?.data_typed::<T>().map(|data| data.to_vec())
+			};
+		}
+		// safe because the ownership of the storage was passed from the `Mat` by the call above
+		let storage = *unsafe { Box::from_raw(storage as *mut VecStorage) };
+		match storage.into_vec() {
+			Ok(out) => Ok(out),
+			Err(storage) => {
+				let out = mat.data_typed::<T>().map(|data| data.to_vec());
+				drop(mat);
+				drop(storage);
+				out
+			}
+		}
+	}
+}
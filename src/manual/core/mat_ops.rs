@@ -1,5 +1,6 @@
 use crate::core::*;
 use crate::Result;
+use std::convert::TryFrom;
 use std::ops::*;
 
 pub enum MatExprResult<T> {
@@ -16,6 +17,12 @@ impl<T> From<Result<T, crate::Error>> for MatExprResult<T> {
 	}
 }
 
+impl<T> From<MatExprResult<T>> for Result<T, crate::Error> {
+	fn from(r: MatExprResult<T>) -> Self {
+		r.into_result()
+	}
+}
+
 impl<T> MatExprResult<T> {
 	pub fn into_result(self) -> Result<T, crate::Error> {
 		match self {
@@ -25,12 +32,64 @@ impl<T> MatExprResult<T> {
 	}
 }
 
+impl MatExprResult<MatExpr> {
+	/// Evaluates the expression into a new `Mat`, use it to get a `Result` at the end of the operator chain:
+	/// `let sum = (&a + &b).eval()?;`
+	pub fn eval(self) -> Result<Mat> {
+		self.into_result()?.to_mat()
+	}
+
+	/// Evaluates the expression into `dst`, the buffer of `dst` is reused when its size and type match the result, see
+	/// `MatExpr::eval_into()`
+	pub fn eval_into(self, dst: &mut Mat) -> Result<()> {
+		self.into_result()?.eval_into(dst)
+	}
+}
+
+impl TryFrom<MatExprResult<MatExpr>> for Mat {
+	type Error = crate::Error;
+
+	fn try_from(r: MatExprResult<MatExpr>) -> Result<Self> {
+		r.eval()
+	}
+}
+
 /// elementwise multiplication
 pub trait ElemMul<Rhs = Self> {
 	type Output;
 	fn elem_mul(self, rhs: Rhs) -> Self::Output;
 }
 
+/// elementwise minimum
+pub trait ElemMin<Rhs = Self> {
+	type Output;
+	fn elem_min(self, rhs: Rhs) -> Self::Output;
+}
+
+/// elementwise maximum
+pub trait ElemMax<Rhs = Self> {
+	type Output;
+	fn elem_max(self, rhs: Rhs) -> Self::Output;
+}
+
+/// elementwise absolute value
+pub trait ElemAbs {
+	type Output;
+	fn elem_abs(self) -> Self::Output;
+}
+
+/// elementwise comparison producing a mask, the elements of the resulting 8-bit matrix are 255 where the comparison
+/// holds and 0 otherwise
+pub trait ElemCmp<Rhs = Self> {
+	type Output;
+	fn lt_mask(self, rhs: Rhs) -> Self::Output;
+	fn le_mask(self, rhs: Rhs) -> Self::Output;
+	fn gt_mask(self, rhs: Rhs) -> Self::Output;
+	fn ge_mask(self, rhs: Rhs) -> Self::Output;
+	fn eq_mask(self, rhs: Rhs) -> Self::Output;
+	fn ne_mask(self, rhs: Rhs) -> Self::Output;
+}
+
 // only for internal usage
 trait ToUnderlyingArg<'a, T: 'a> {
 	fn to_underlying_arg(&'a self) -> T;
@@ -186,6 +245,209 @@ impl_ops!(elemmul_mat_matexpr, ElemMul, Mat, MatExpr, elem_mul);
 impl_ops!(elemmul_matexpr_mat, ElemMul, MatExpr, Mat, elem_mul);
 impl_ops!(elemmul_matexpr_matexpr, ElemMul, MatExpr, MatExpr, elem_mul);
 
-// not implemented yet, but can use `0 - mat`
-// fn sub_mat(Mat);
-// fn sub_matexpr(MatExpr);
+impl_ops!(and_mat_mat, BitAnd, Mat, Mat, bitand);
+impl_ops!(and_mat_scalar, BitAnd, Mat, Scalar, bitand);
+impl_ops!(and_scalar_mat, BitAnd, Scalar, Mat, bitand);
+
+impl_ops!(or_mat_mat, BitOr, Mat, Mat, bitor);
+impl_ops!(or_mat_scalar, BitOr, Mat, Scalar, bitor);
+impl_ops!(or_scalar_mat, BitOr, Scalar, Mat, bitor);
+
+impl_ops!(xor_mat_mat, BitXor, Mat, Mat, bitxor);
+impl_ops!(xor_mat_scalar, BitXor, Mat, Scalar, bitxor);
+impl_ops!(xor_scalar_mat, BitXor, Scalar, Mat, bitxor);
+
+impl_ops!(min_mat, ElemMin, Mat, Mat, elem_min);
+impl_ops!(min_mat_f64, ElemMin, Mat, f64, elem_min);
+impl_ops!(min_f64_mat, ElemMin, f64, Mat, elem_min);
+
+impl_ops!(max_mat, ElemMax, Mat, Mat, elem_max);
+impl_ops!(max_mat_f64, ElemMax, Mat, f64, elem_max);
+impl_ops!(max_f64_mat, ElemMax, f64, Mat, elem_max);
+
+macro_rules! impl_unary_ops_core {
+	($func_name:ident, $op_type:ident, $type:ty, $op_func:ident) => {
+		// op Operand
+		impl $op_type for $type {
+			type Output = MatExprResult<MatExpr>;
+
+			fn $op_func(self) -> Self::Output {
+				$func_name(self.to_underlying_arg()).into()
+			}
+		}
+
+		// op MatExprResult<Operand>
+		impl $op_type for MatExprResult<$type> {
+			type Output = MatExprResult<MatExpr>;
+
+			fn $op_func(self) -> Self::Output {
+				match self {
+					MatExprResult::Ok(val) => $func_name(val.to_underlying_arg()).into(),
+					MatExprResult::Err(e) => MatExprResult::Err(e),
+				}
+			}
+		}
+	};
+}
+
+macro_rules! impl_unary_ops {
+	($func_name:ident, $op_type:ident, $type:ty, $op_func:ident) => {
+		impl_unary_ops_core!($func_name, $op_type, $type, $op_func);
+		impl_unary_ops_core!($func_name, $op_type, &$type, $op_func);
+	};
+}
+
+impl_unary_ops!(sub_mat, Neg, Mat, neg);
+impl_unary_ops!(sub_matexpr, Neg, MatExpr, neg);
+
+impl_unary_ops!(negate, Not, Mat, not);
+
+impl_unary_ops!(abs, ElemAbs, Mat, elem_abs);
+impl_unary_ops!(abs_matexpr, ElemAbs, MatExpr, elem_abs);
+
+macro_rules! impl_cmp_ops_core {
+	($lhs_type:ty, $rhs_type:ty, $lt:ident, $le:ident, $gt:ident, $ge:ident, $eq:ident, $ne:ident) => {
+		impl ElemCmp<$rhs_type> for $lhs_type {
+			type Output = MatExprResult<MatExpr>;
+
+			fn lt_mask(self, rhs: $rhs_type) -> Self::Output {
+				$lt(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+
+			fn le_mask(self, rhs: $rhs_type) -> Self::Output {
+				$le(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+
+			fn gt_mask(self, rhs: $rhs_type) -> Self::Output {
+				$gt(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+
+			fn ge_mask(self, rhs: $rhs_type) -> Self::Output {
+				$ge(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+
+			fn eq_mask(self, rhs: $rhs_type) -> Self::Output {
+				$eq(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+
+			fn ne_mask(self, rhs: $rhs_type) -> Self::Output {
+				$ne(self.to_underlying_arg(), rhs.to_underlying_arg()).into()
+			}
+		}
+	};
+}
+
+macro_rules! impl_cmp_ops {
+	($lhs_type:ty, $rhs_type:ty, $lt:ident, $le:ident, $gt:ident, $ge:ident, $eq:ident, $ne:ident) => {
+		impl_cmp_ops_core!($lhs_type, $rhs_type, $lt, $le, $gt, $ge, $eq, $ne);
+		impl_cmp_ops_core!($lhs_type, &$rhs_type, $lt, $le, $gt, $ge, $eq, $ne);
+		impl_cmp_ops_core!(&$lhs_type, $rhs_type, $lt, $le, $gt, $ge, $eq, $ne);
+		impl_cmp_ops_core!(&$lhs_type, &$rhs_type, $lt, $le, $gt, $ge, $eq, $ne);
+	};
+}
+
+impl_cmp_ops!(
+	Mat,
+	Mat,
+	less_than_mat_mat,
+	less_than_or_equal_mat_mat,
+	greater_than_mat_mat,
+	greater_than_or_equal_mat_mat,
+	equals_mat_mat,
+	not_equals_mat_mat
+);
+impl_cmp_ops!(
+	Mat,
+	f64,
+	less_than_mat_f64,
+	less_than_or_equal_mat_f64,
+	greater_than_mat_f64,
+	greater_than_or_equal_mat_f64,
+	equals_mat_f64,
+	not_equals_mat_f64
+);
+impl_cmp_ops!(
+	f64,
+	Mat,
+	less_than_f64_mat,
+	less_than_or_equal_f64_mat,
+	greater_than_f64_mat,
+	greater_than_or_equal_f64_mat,
+	equals_f64_mat,
+	not_equals_f64_mat
+);
+
+/// Calls `op` passing `mat` both as the source and as the destination, OpenCV arithmetic functions support such in-place
+/// operation and reuse the destination buffer
+fn in_place(mat: &mut Mat, op: impl FnOnce(&_InputArray, &mut Mat) -> Result<()>) -> Result<()> {
+	let src = mat.input_array()?;
+	op(&src, mat)
+}
+
+/// Evaluates `self + rhs` in place, `rhs` can be a matrix, a matrix expression, `Scalar` or `f64`
+///
+/// Panics if the operation fails, use `core::add()` to handle the error.
+impl<R: ToInputArray> AddAssign<R> for Mat {
+	fn add_assign(&mut self, rhs: R) {
+		in_place(self, |src, dst| crate::core::add(src, &rhs, dst, &no_array(), -1)).expect("Cannot add to Mat in place")
+	}
+}
+
+/// Evaluates `self - rhs` in place, `rhs` can be a matrix, a matrix expression, `Scalar` or `f64`
+///
+/// Panics if the operation fails, use `core::subtract()` to handle the error.
+impl<R: ToInputArray> SubAssign<R> for Mat {
+	fn sub_assign(&mut self, rhs: R) {
+		in_place(self, |src, dst| subtract(src, &rhs, dst, &no_array(), -1)).expect("Cannot subtract from Mat in place")
+	}
+}
+
+/// Scales all the elements of the matrix in place
+///
+/// Panics if the operation fails, use `Mat::convert_to()` to handle the error.
+impl MulAssign<f64> for Mat {
+	fn mul_assign(&mut self, rhs: f64) {
+		in_place(self, |src, dst| src.get_mat(-1)?.convert_to(dst, -1, rhs, 0.)).expect("Cannot scale Mat in place")
+	}
+}
+
+/// Scales all the elements of the matrix in place
+///
+/// Panics if the operation fails, use `Mat::convert_to()` to handle the error.
+impl DivAssign<f64> for Mat {
+	fn div_assign(&mut self, rhs: f64) {
+		in_place(self, |src, dst| src.get_mat(-1)?.convert_to(dst, -1, 1. / rhs, 0.)).expect("Cannot scale Mat in place")
+	}
+}
+
+macro_rules! impl_assign_ops {
+	($op_type:ident, $op_func:ident, $rhs_type:ty, $func_name:ident, $msg:literal) => {
+		impl $op_type<$rhs_type> for Mat {
+			fn $op_func(&mut self, rhs: $rhs_type) {
+				in_place(self, |src, dst| $func_name(src, &rhs, dst)).expect($msg)
+			}
+		}
+	};
+}
+
+fn matmul_to(src: &_InputArray, rhs: &impl ToInputArray, dst: &mut Mat) -> Result<()> {
+	gemm(src, rhs, 1., &no_array(), 0., dst, 0)
+}
+
+fn div_to(src: &_InputArray, rhs: &impl ToInputArray, dst: &mut Mat) -> Result<()> {
+	divide2(src, rhs, dst, 1., -1)
+}
+
+// matrix multiplication like `Mul`, panics if the operation fails, use `core::gemm()` to handle the error; the product
+// can't be computed over its own operand so `gemm()` uses a temporary buffer internally, but the destination buffer is
+// still reused when the size and type of the result don't change
+impl_assign_ops!(MulAssign, mul_assign, Mat, matmul_to, "Cannot multiply Mat in place");
+impl_assign_ops!(MulAssign, mul_assign, &Mat, matmul_to, "Cannot multiply Mat in place");
+impl_assign_ops!(MulAssign, mul_assign, MatExpr, matmul_to, "Cannot multiply Mat in place");
+impl_assign_ops!(MulAssign, mul_assign, &MatExpr, matmul_to, "Cannot multiply Mat in place");
+
+// elementwise division like `Div`, panics if the operation fails, use `core::divide2()` to handle the error
+impl_assign_ops!(DivAssign, div_assign, Mat, div_to, "Cannot divide Mat in place");
+impl_assign_ops!(DivAssign, div_assign, &Mat, div_to, "Cannot divide Mat in place");
+impl_assign_ops!(DivAssign, div_assign, MatExpr, div_to, "Cannot divide Mat in place");
+impl_assign_ops!(DivAssign, div_assign, &MatExpr, div_to, "Cannot divide Mat in place");
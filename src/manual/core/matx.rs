@@ -7,6 +7,8 @@ use crate::manual::core::sized::*;
 use crate::traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer};
 use crate::{core, extern_receive, extern_send, sys, Error, Result};
 
+mod operations;
+
 fn index_check(idx: (usize, usize), rows: usize, cols: usize) -> Result<()> {
 	if idx.0 >= rows {
 		Err(Error::new(
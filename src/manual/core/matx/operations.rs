@@ -0,0 +1,368 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{Float, Num, Zero};
+
+use crate::core::{
+	randn, randu, MatxExtern, MatxTrait, Scalar, SizedArray, SizedArray12, SizedArray13, SizedArray14, SizedArray16, SizedArray21,
+	SizedArray22, SizedArray23, SizedArray31, SizedArray32, SizedArray33, SizedArray34, SizedArray41, SizedArray43, SizedArray44,
+	SizedArray61, SizedArray66, SizedArrayMul, SizedArraySquare, SizedArrayTranspose, VecN,
+};
+use crate::Result;
+
+use super::Matx;
+
+/// Multiplies row-major `rows`×`inner` matrix `a` by `inner`×`cols` matrix `b` storing the result in `out`
+#[inline]
+fn mat_mul<T: Num + Copy>(a: &[T], b: &[T], out: &mut [T], rows: usize, inner: usize, cols: usize) {
+	for row in 0..rows {
+		for col in 0..cols {
+			out[row * cols + col] = (0..inner).fold(T::zero(), |acc, k| acc + a[row * inner + k] * b[k * cols + col]);
+		}
+	}
+}
+
+/// LU decomposition with partial pivoting of the `n`×`n` row-major matrix `a`, the same row operations are applied to
+/// the `n`×`m` matrix `b` which then gets replaced with the solution of `a * x = b`
+///
+/// Returns the sign of the row permutation or `None` if the matrix is singular.
+fn lu<T: Float>(a: &mut [T], n: usize, b: &mut [T], m: usize) -> Option<T> {
+	let eps = T::epsilon() * T::from(100).unwrap_or_else(T::one);
+	let mut sign = T::one();
+	for i in 0..n {
+		let pivot = (i..n)
+			.max_by(|&r1, &r2| {
+				a[r1 * n + i]
+					.abs()
+					.partial_cmp(&a[r2 * n + i].abs())
+					.unwrap_or(std::cmp::Ordering::Equal)
+			})
+			.unwrap_or(i);
+		if a[pivot * n + i].is_nan() || a[pivot * n + i].abs() < eps {
+			return None;
+		}
+		if pivot != i {
+			(0..n).for_each(|col| a.swap(i * n + col, pivot * n + col));
+			(0..m).for_each(|col| b.swap(i * m + col, pivot * m + col));
+			sign = -sign;
+		}
+		let d = -a[i * n + i].recip();
+		for row in i + 1..n {
+			let alpha = a[row * n + i] * d;
+			for col in i + 1..n {
+				a[row * n + col] = a[row * n + col] + alpha * a[i * n + col];
+			}
+			for col in 0..m {
+				b[row * m + col] = b[row * m + col] + alpha * b[i * m + col];
+			}
+		}
+	}
+	for i in (0..n).rev() {
+		for col in 0..m {
+			let s = (i + 1..n).fold(b[i * m + col], |s, k| s - a[i * n + k] * b[k * m + col]);
+			b[i * m + col] = s / a[i * n + i];
+		}
+	}
+	Some(sign)
+}
+
+impl<T, A: SizedArray<T>> Matx<T, A> {
+	/// Per-element multiplication
+	#[inline]
+	pub fn mul(&self, a: Self) -> Self
+	where
+		T: MulAssign + Copy,
+	{
+		let mut out = *self;
+		out.val_mut().iter_mut().zip(a.val()).for_each(|(dest, &m)| *dest *= m);
+		out
+	}
+
+	/// Per-element division
+	#[inline]
+	pub fn div(&self, a: Self) -> Self
+	where
+		T: DivAssign + Copy,
+	{
+		let mut out = *self;
+		out.val_mut().iter_mut().zip(a.val()).for_each(|(dest, &d)| *dest /= d);
+		out
+	}
+
+	/// Transposed matrix
+	#[inline]
+	pub fn t(&self) -> Matx<T, A::Output>
+	where
+		T: Copy,
+		A: SizedArrayTranspose<T>,
+	{
+		let val = self.val();
+		let mut out = Matx::<T, A::Output>::all(val[0]);
+		for row in 0..A::ROWS {
+			for col in 0..A::COLS {
+				out.val_mut()[col * A::ROWS + row] = val[row * A::COLS + col];
+			}
+		}
+		out
+	}
+
+	/// Dot product computed with the default precision of `T`
+	#[inline]
+	pub fn dot(&self, m: &Self) -> T
+	where
+		T: Num + Copy,
+	{
+		self.val().iter().zip(m.val()).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+	}
+
+	/// Dot product computed in double precision
+	#[inline]
+	pub fn ddot(&self, m: &Self) -> f64
+	where
+		T: Copy,
+		f64: From<T>,
+	{
+		self
+			.val()
+			.iter()
+			.zip(m.val())
+			.fold(0., |acc, (&a, &b)| acc + f64::from(a) * f64::from(b))
+	}
+
+	/// Sum of the elements on the main diagonal
+	#[inline]
+	pub fn trace(&self) -> T
+	where
+		T: Num + Copy,
+	{
+		(0..A::ROWS.min(A::COLS)).fold(T::zero(), |acc, i| acc + self.val()[i * A::COLS + i])
+	}
+
+	/// Solves the linear system `self * x = rhs` using LU decomposition, returns `None` if the matrix is singular
+	#[inline]
+	pub fn solve<B: SizedArray<T>>(&self, rhs: &Matx<T, B>) -> Option<Matx<T, B>>
+	where
+		T: Float,
+		A: SizedArraySquare<T> + SizedArrayMul<T, B, Output = B>,
+	{
+		let mut a = *self;
+		let mut out = *rhs;
+		lu(a.val_mut(), A::ROWS, out.val_mut(), B::COLS).map(|_| out)
+	}
+
+	/// Solves the linear system `self * x = rhs` for the vector `rhs`, see `solve()`
+	#[inline]
+	pub fn solve_vec<const N: usize>(&self, rhs: VecN<T, N>) -> Option<VecN<T, N>>
+	where
+		T: Float,
+		A: SizedArraySquare<T>,
+		Self: Mul<VecN<T, N>, Output = VecN<T, N>>,
+	{
+		let mut a = *self;
+		let mut out = rhs;
+		lu(a.val_mut(), A::ROWS, out.as_mut_slice(), 1).map(|_| out)
+	}
+
+	/// Determinant of the square matrix
+	#[inline]
+	pub fn det(&self) -> T
+	where
+		T: Float,
+		A: SizedArraySquare<T>,
+	{
+		let mut a = *self;
+		lu(a.val_mut(), A::ROWS, &mut [], 0)
+			.map(|sign| (0..A::ROWS).fold(sign, |acc, i| acc * a.val()[i * A::COLS + i]))
+			.unwrap_or_else(T::zero)
+	}
+
+	/// Inverse of the square matrix computed using LU decomposition, returns `None` if the matrix is singular
+	#[inline]
+	pub fn inv(&self) -> Option<Self>
+	where
+		T: Float,
+		A: SizedArraySquare<T>,
+	{
+		let mut a = *self;
+		let mut out = Self::eye();
+		lu(a.val_mut(), A::ROWS, out.val_mut(), A::COLS).map(|_| out)
+	}
+
+	/// Matrix with the elements drawn from the uniform distribution in the range `[a, b)`, see `core::randu()`
+	#[inline]
+	pub fn randu(a: f64, b: f64) -> Result<Self>
+	where
+		T: Zero + Copy,
+		Self: MatxExtern,
+	{
+		let mut out = Self::zeros();
+		randu(&mut out, &Scalar::all(a), &Scalar::all(b))?;
+		Ok(out)
+	}
+
+	/// Matrix with the elements drawn from the normal distribution with the specified mean and standard deviation, see
+	/// `core::randn()`
+	#[inline]
+	pub fn randn(mean: f64, stddev: f64) -> Result<Self>
+	where
+		T: Zero + Copy,
+		Self: MatxExtern,
+	{
+		let mut out = Self::zeros();
+		randn(&mut out, &Scalar::all(mean), &Scalar::all(stddev))?;
+		Ok(out)
+	}
+}
+
+impl<T: AddAssign + Copy, A: SizedArray<T>> AddAssign for Matx<T, A> {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(out, &v)| *out += v)
+	}
+}
+
+impl<T: AddAssign + Copy, A: SizedArray<T>> Add for Matx<T, A> {
+	type Output = Self;
+
+	#[inline]
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<T: SubAssign + Copy, A: SizedArray<T>> SubAssign for Matx<T, A> {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(out, &v)| *out -= v)
+	}
+}
+
+impl<T: SubAssign + Copy, A: SizedArray<T>> Sub for Matx<T, A> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<T: Num + MulAssign + Copy, A: SizedArray<T>> MulAssign<T> for Matx<T, A> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: T) {
+		self.val_mut().iter_mut().for_each(|out| *out *= rhs)
+	}
+}
+
+impl<T: Num + MulAssign + Copy, A: SizedArray<T>> Mul<T> for Matx<T, A> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(mut self, rhs: T) -> Self::Output {
+		self *= rhs;
+		self
+	}
+}
+
+impl<T: Num + DivAssign + Copy, A: SizedArray<T>> DivAssign<T> for Matx<T, A> {
+	#[inline]
+	fn div_assign(&mut self, rhs: T) {
+		self.val_mut().iter_mut().for_each(|out| *out /= rhs)
+	}
+}
+
+impl<T: Num + DivAssign + Copy, A: SizedArray<T>> Div<T> for Matx<T, A> {
+	type Output = Self;
+
+	#[inline]
+	fn div(mut self, rhs: T) -> Self::Output {
+		self /= rhs;
+		self
+	}
+}
+
+impl<T: Neg<Output = T> + Copy, A: SizedArray<T>> Neg for Matx<T, A> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(mut self) -> Self::Output {
+		self.val_mut().iter_mut().for_each(|out| *out = -*out);
+		self
+	}
+}
+
+/// Matrix product, the dimensions are checked at compile time
+impl<T: Num + Copy, A: SizedArrayMul<T, B>, B: SizedArray<T>> Mul<Matx<T, B>> for Matx<T, A> {
+	type Output = Matx<T, A::Output>;
+
+	#[inline]
+	fn mul(self, rhs: Matx<T, B>) -> Self::Output {
+		let mut out = Matx::<T, A::Output>::all(T::zero());
+		mat_mul(self.val(), rhs.val(), out.val_mut(), A::ROWS, A::COLS, B::COLS);
+		out
+	}
+}
+
+macro_rules! matx_shape_impl {
+	($array: ident, $rows: expr, $cols: expr, $shortdim: expr) => {
+		/// Product of the matrix and the column vector
+		impl<T: Num + Copy> Mul<VecN<T, $cols>> for Matx<T, $array> {
+			type Output = VecN<T, $rows>;
+
+			#[inline]
+			fn mul(self, rhs: VecN<T, $cols>) -> Self::Output {
+				let mut out = VecN::all(T::zero());
+				mat_mul(self.val(), rhs.as_slice(), out.as_mut_slice(), $rows, $cols, 1);
+				out
+			}
+		}
+
+		impl<T: Copy> Matx<T, $array> {
+			/// Elements of the main diagonal
+			#[inline]
+			pub fn diag(&self) -> VecN<T, $shortdim> {
+				let val = self.val();
+				let mut out = VecN::all(val[0]);
+				out.iter_mut()
+					.enumerate()
+					.for_each(|(i, out)| *out = val[i * $cols + i]);
+				out
+			}
+
+			/// Creates a matrix with the specified main diagonal and zeros elsewhere
+			#[inline]
+			pub fn from_diag(d: VecN<T, $shortdim>) -> Self
+			where
+				T: Zero,
+			{
+				let mut out = Self::all(T::zero());
+				d.into_iter()
+					.enumerate()
+					.for_each(|(i, v)| out.val_mut()[i * $cols + i] = v);
+				out
+			}
+		}
+	};
+}
+
+matx_shape_impl!(SizedArray12, 1, 2, 1);
+matx_shape_impl!(SizedArray13, 1, 3, 1);
+matx_shape_impl!(SizedArray14, 1, 4, 1);
+matx_shape_impl!(SizedArray16, 1, 6, 1);
+
+matx_shape_impl!(SizedArray21, 2, 1, 1);
+matx_shape_impl!(SizedArray31, 3, 1, 1);
+matx_shape_impl!(SizedArray41, 4, 1, 1);
+matx_shape_impl!(SizedArray61, 6, 1, 1);
+
+matx_shape_impl!(SizedArray22, 2, 2, 2);
+matx_shape_impl!(SizedArray23, 2, 3, 2);
+matx_shape_impl!(SizedArray32, 3, 2, 2);
+
+matx_shape_impl!(SizedArray33, 3, 3, 3);
+
+matx_shape_impl!(SizedArray34, 3, 4, 3);
+matx_shape_impl!(SizedArray43, 4, 3, 3);
+
+matx_shape_impl!(SizedArray44, 4, 4, 4);
+matx_shape_impl!(SizedArray66, 6, 6, 6);
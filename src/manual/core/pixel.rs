@@ -0,0 +1,135 @@
+//! Strongly typed pixel formats
+//!
+//! OpenCV itself only knows the depth and the number of channels of the `Mat` elements so the meaning of the channels is
+//! up to the caller. The types in this module attach that meaning to the element type, e.g. `Mat_<Bgr8>` and
+//! `Mat_<Rgb8>` have the same memory layout, but can't be accidentally mixed up. Use `imgproc::cvt_color_typed()` to
+//! convert between them.
+//!
+//! Value ranges follow the OpenCV conventions of `cvtColor()`:
+//! * 8-bit: `Hsv` and `Hls` store hue as `0..180`, `Lab` and `Luv` are scaled to fit `0..=255`
+//! * floating point `Gray`, `Bgr`, `Rgb` and their alpha variants: `0..=1`
+//! * floating point `Xyz` and `YCrCb`: about `0..=1` (`z` slightly exceeds 1 for white), `cr` and `cb` are centered at `0.5`
+//! * floating point `Hsv` and `Hls`: hue is `0..360`, the other channels are `0..=1`
+//! * floating point `Lab`: `l` is `0..=100`, `a` and `b` are `-127..=127`
+//! * floating point `Luv`: `l` is `0..=100`, `u` is `-134..=220`, `v` is `-140..=122`
+
+use crate::core::VecN;
+
+macro_rules! pixel {
+	($(#[$attr: meta])* $name: ident, $channels: literal, [$($field: ident),+]) => {
+		$(#[$attr])*
+		#[repr(C)]
+		#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+		pub struct $name<T> {
+			$(pub $field: T,)+
+		}
+
+		impl<T> $name<T> {
+			#[inline]
+			pub const fn new($($field: T),+) -> Self {
+				Self { $($field),+ }
+			}
+		}
+
+		impl<T> From<VecN<T, $channels>> for $name<T> {
+			#[inline]
+			fn from(s: VecN<T, $channels>) -> Self {
+				let [$($field),+] = s.0;
+				Self { $($field),+ }
+			}
+		}
+
+		impl<T> From<$name<T>> for VecN<T, $channels> {
+			#[inline]
+			fn from(s: $name<T>) -> Self {
+				VecN([$(s.$field),+])
+			}
+		}
+	};
+}
+
+pixel! {
+	/// Single channel grayscale pixel
+	Gray, 1, [v]
+}
+pixel! {
+	/// Color pixel in the OpenCV default BGR channel order
+	Bgr, 3, [b, g, r]
+}
+pixel! {
+	/// Color pixel with alpha channel in the OpenCV default BGRA channel order
+	Bgra, 4, [b, g, r, a]
+}
+pixel! {
+	/// Color pixel in RGB channel order
+	Rgb, 3, [r, g, b]
+}
+pixel! {
+	/// Color pixel with alpha channel in RGBA channel order
+	Rgba, 4, [r, g, b, a]
+}
+pixel! {
+	/// Pixel in HSV (hue, saturation, value) color space
+	Hsv, 3, [h, s, v]
+}
+pixel! {
+	/// Pixel in HLS (hue, lightness, saturation) color space
+	Hls, 3, [h, l, s]
+}
+pixel! {
+	/// Pixel in CIE L\*a\*b\* color space
+	Lab, 3, [l, a, b]
+}
+pixel! {
+	/// Pixel in CIE L\*u\*v\* color space
+	Luv, 3, [l, u, v]
+}
+pixel! {
+	/// Pixel in CIE XYZ color space
+	Xyz, 3, [x, y, z]
+}
+pixel! {
+	/// Pixel in YCrCb (luma, red-difference and blue-difference chroma) color space
+	YCrCb, 3, [y, cr, cb]
+}
+
+pub type Gray8 = Gray<u8>;
+pub type Gray16 = Gray<u16>;
+pub type Gray32f = Gray<f32>;
+
+pub type Bgr8 = Bgr<u8>;
+pub type Bgr16 = Bgr<u16>;
+pub type Bgr32f = Bgr<f32>;
+
+pub type Bgra8 = Bgra<u8>;
+pub type Bgra16 = Bgra<u16>;
+pub type Bgra32f = Bgra<f32>;
+
+pub type Rgb8 = Rgb<u8>;
+pub type Rgb16 = Rgb<u16>;
+pub type Rgb32f = Rgb<f32>;
+
+pub type Rgba8 = Rgba<u8>;
+pub type Rgba16 = Rgba<u16>;
+pub type Rgba32f = Rgba<f32>;
+
+pub type Hsv8 = Hsv<u8>;
+pub type Hsv32f = Hsv<f32>;
+
+pub type Hls8 = Hls<u8>;
+pub type Hls32f = Hls<f32>;
+
+pub type Lab8 = Lab<u8>;
+pub type Lab32f = Lab<f32>;
+
+pub type Luv8 = Luv<u8>;
+pub type Luv32f = Luv<f32>;
+
+pub type Xyz8 = Xyz<u8>;
+pub type Xyz16 = Xyz<u16>;
+pub type Xyz32f = Xyz<f32>;
+
+pub type YCrCb8 = YCrCb<u8>;
+pub type YCrCb16 = YCrCb<u16>;
+pub type YCrCb32f = YCrCb<f32>;
@@ -9,6 +9,7 @@ use crate::{
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/db/d4e/classcv_1_1Point__.html)
 pub struct Point_<T> {
 	pub x: T,
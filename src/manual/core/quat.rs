@@ -0,0 +1,493 @@
+use num_traits::Float;
+
+use crate::core::{Matx33, Matx44, MatxTrait, VecN};
+use crate::{core, opencv_type_enum, opencv_type_simple_generic, Error, Result};
+
+mod operations;
+
+/// Default tolerance used by the `Quat` methods, mirrors `cv::CV_QUAT_EPS`
+pub const CV_QUAT_EPS: f64 = 1e-6;
+
+/// Order of the rotations for the Euler angles conversions of `Quat`, mirrors `cv::QuatEnum::EulerAnglesType`
+///
+/// `INT_*` variants are the intrinsic rotations (around the axes of the rotating coordinate system) and `EXT_*` variants are
+/// the extrinsic rotations (around the axes of the fixed coordinate system). The angles are always specified in the order
+/// of the axes in the variant name.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum QuatEnum_EulerAnglesType {
+	INT_XYZ = 0,
+	INT_XZY = 1,
+	INT_YXZ = 2,
+	INT_YZX = 3,
+	INT_ZXY = 4,
+	INT_ZYX = 5,
+	INT_XYX = 6,
+	INT_XZX = 7,
+	INT_YXY = 8,
+	INT_YZY = 9,
+	INT_ZXZ = 10,
+	INT_ZYZ = 11,
+	EXT_XYZ = 12,
+	EXT_XZY = 13,
+	EXT_YXZ = 14,
+	EXT_YZX = 15,
+	EXT_ZXY = 16,
+	EXT_ZYX = 17,
+	EXT_XYX = 18,
+	EXT_XZX = 19,
+	EXT_YXY = 20,
+	EXT_YZY = 21,
+	EXT_ZXZ = 22,
+	EXT_ZYZ = 23,
+}
+
+opencv_type_enum! { core::QuatEnum_EulerAnglesType }
+
+impl QuatEnum_EulerAnglesType {
+	/// Indices of the rotation axes and whether the rotations are extrinsic
+	fn axes(self) -> ([usize; 3], bool) {
+		const AXES: [[usize; 3]; 12] = [
+			[0, 1, 2],
+			[0, 2, 1],
+			[1, 0, 2],
+			[1, 2, 0],
+			[2, 0, 1],
+			[2, 1, 0],
+			[0, 1, 0],
+			[0, 2, 0],
+			[1, 0, 1],
+			[1, 2, 1],
+			[2, 0, 2],
+			[2, 1, 2],
+		];
+		let idx = self as usize;
+		(AXES[idx % 12], idx >= 12)
+	}
+}
+
+/// [docs.opencv.org](https://docs.opencv.org/master/df/d57/classcv_1_1Quat.html)
+///
+/// Quaternion `w + x*i + y*j + z*k`, for the rotations the unit quaternions are used.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quat<T> {
+	pub w: T,
+	pub x: T,
+	pub y: T,
+	pub z: T,
+}
+
+opencv_type_simple_generic! { Quat<Copy> }
+
+pub type Quatd = Quat<f64>;
+pub type Quatf = Quat<f32>;
+
+impl<T> Quat<T> {
+	#[inline]
+	pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+		Self { w, x, y, z }
+	}
+
+	/// Creates the quaternion from the vector `[w, x, y, z]`
+	#[inline]
+	pub fn from_vec(vec: VecN<T, 4>) -> Self {
+		let [w, x, y, z] = vec.0;
+		Self::new(w, x, y, z)
+	}
+
+	/// Quaternion as the vector `[w, x, y, z]`
+	#[inline]
+	pub fn to_vec(self) -> VecN<T, 4> {
+		VecN([self.w, self.x, self.y, self.z])
+	}
+}
+
+impl<T> From<VecN<T, 4>> for Quat<T> {
+	#[inline]
+	fn from(vec: VecN<T, 4>) -> Self {
+		Self::from_vec(vec)
+	}
+}
+
+impl<T> From<Quat<T>> for VecN<T, 4> {
+	#[inline]
+	fn from(quat: Quat<T>) -> Self {
+		quat.to_vec()
+	}
+}
+
+impl<T: Float> Quat<T> {
+	#[inline]
+	fn eps() -> T {
+		T::from(CV_QUAT_EPS).unwrap_or_else(T::epsilon)
+	}
+
+	#[inline]
+	fn half() -> T {
+		T::from(0.5).unwrap_or_else(T::one)
+	}
+
+	/// Quaternion of the rotation by `angle` (in radians) around `axis`, returns `None` if `axis` is a zero vector
+	#[inline]
+	pub fn create_from_angle_axis(angle: T, axis: VecN<T, 3>) -> Option<Self> {
+		let norm = axis.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+		if norm < Self::eps() {
+			return None;
+		}
+		let (s, c) = (angle * Self::half()).sin_cos();
+		let [x, y, z] = (axis * (s / norm)).0;
+		Some(Self::new(c, x, y, z))
+	}
+
+	/// Quaternion of the rotation specified by the Rodrigues vector
+	#[inline]
+	pub fn create_from_rvec(rvec: VecN<T, 3>) -> Self {
+		let theta = rvec.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+		Self::create_from_angle_axis(theta, rvec).unwrap_or_else(|| Self::new(T::one(), T::zero(), T::zero(), T::zero()))
+	}
+
+	/// Quaternion of the rotation specified by the rotation matrix
+	pub fn create_from_rot_mat(r: &Matx33<T>) -> Self {
+		let one = T::one();
+		let quarter = Self::half() * Self::half();
+		let trace = r.trace();
+		if trace > T::zero() {
+			let s = (trace + one).sqrt() * (one + one);
+			Self::new(
+				quarter * s,
+				(r[(2, 1)] - r[(1, 2)]) / s,
+				(r[(0, 2)] - r[(2, 0)]) / s,
+				(r[(1, 0)] - r[(0, 1)]) / s,
+			)
+		} else if r[(0, 0)] > r[(1, 1)] && r[(0, 0)] > r[(2, 2)] {
+			let s = (one + r[(0, 0)] - r[(1, 1)] - r[(2, 2)]).sqrt() * (one + one);
+			Self::new(
+				(r[(2, 1)] - r[(1, 2)]) / s,
+				quarter * s,
+				(r[(0, 1)] + r[(1, 0)]) / s,
+				(r[(0, 2)] + r[(2, 0)]) / s,
+			)
+		} else if r[(1, 1)] > r[(2, 2)] {
+			let s = (one + r[(1, 1)] - r[(0, 0)] - r[(2, 2)]).sqrt() * (one + one);
+			Self::new(
+				(r[(0, 2)] - r[(2, 0)]) / s,
+				(r[(0, 1)] + r[(1, 0)]) / s,
+				quarter * s,
+				(r[(1, 2)] + r[(2, 1)]) / s,
+			)
+		} else {
+			let s = (one + r[(2, 2)] - r[(0, 0)] - r[(1, 1)]).sqrt() * (one + one);
+			Self::new(
+				(r[(1, 0)] - r[(0, 1)]) / s,
+				(r[(0, 2)] + r[(2, 0)]) / s,
+				(r[(1, 2)] + r[(2, 1)]) / s,
+				quarter * s,
+			)
+		}
+	}
+
+	/// Quaternion of the rotation by `theta` around the axis with the index `axis`
+	#[inline]
+	fn create_from_axis_rot(theta: T, axis: usize) -> Self {
+		let (s, c) = (theta * Self::half()).sin_cos();
+		let mut out = Self::new(c, T::zero(), T::zero(), T::zero());
+		match axis {
+			0 => out.x = s,
+			1 => out.y = s,
+			_ => out.z = s,
+		}
+		out
+	}
+
+	/// Quaternion of the rotation by `theta` around X axis
+	#[inline]
+	pub fn create_from_x_rot(theta: T) -> Self {
+		Self::create_from_axis_rot(theta, 0)
+	}
+
+	/// Quaternion of the rotation by `theta` around Y axis
+	#[inline]
+	pub fn create_from_y_rot(theta: T) -> Self {
+		Self::create_from_axis_rot(theta, 1)
+	}
+
+	/// Quaternion of the rotation by `theta` around Z axis
+	#[inline]
+	pub fn create_from_z_rot(theta: T) -> Self {
+		Self::create_from_axis_rot(theta, 2)
+	}
+
+	/// Quaternion of the rotation specified by the Euler `angles` (in radians) in the order described by `typ`
+	pub fn create_from_euler_angles(angles: VecN<T, 3>, typ: QuatEnum_EulerAnglesType) -> Self {
+		let (axes, extrinsic) = typ.axes();
+		let q1 = Self::create_from_axis_rot(angles[0], axes[0]);
+		let q2 = Self::create_from_axis_rot(angles[1], axes[1]);
+		let q3 = Self::create_from_axis_rot(angles[2], axes[2]);
+		if extrinsic {
+			q3 * q2 * q1
+		} else {
+			q1 * q2 * q3
+		}
+	}
+
+	#[inline]
+	pub fn norm(&self) -> T {
+		self.dot(self).sqrt()
+	}
+
+	#[inline]
+	pub fn dot(&self, q: &Self) -> T {
+		self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z
+	}
+
+	/// Cross product of the imaginary parts of the quaternions
+	#[inline]
+	pub fn cross(&self, q: &Self) -> Self {
+		Self::new(
+			T::zero(),
+			self.y * q.z - self.z * q.y,
+			self.z * q.x - self.x * q.z,
+			self.x * q.y - self.y * q.x,
+		)
+	}
+
+	#[inline]
+	pub fn conjugate(&self) -> Self {
+		Self::new(self.w, -self.x, -self.y, -self.z)
+	}
+
+	/// Quaternion with the unit norm, returns `None` for the zero quaternion
+	#[inline]
+	pub fn normalize(&self) -> Option<Self> {
+		let norm = self.norm();
+		if norm < Self::eps() {
+			None
+		} else {
+			Some(*self / norm)
+		}
+	}
+
+	/// Inverse quaternion, returns `None` for the zero quaternion
+	#[inline]
+	pub fn inv(&self) -> Option<Self> {
+		let norm2 = self.dot(self);
+		if norm2 < Self::eps() {
+			None
+		} else {
+			Some(self.conjugate() / norm2)
+		}
+	}
+
+	/// Checks whether the norm of the quaternion differs from 1 by less than `eps`
+	#[inline]
+	pub fn is_normal(&self, eps: T) -> bool {
+		(self.norm() - T::one()).abs() < eps
+	}
+
+	/// Returns an error if the quaternion is not normal, see `is_normal()`
+	#[inline]
+	pub fn assert_normal(&self, eps: T) -> Result<()> {
+		if self.is_normal(eps) {
+			Ok(())
+		} else {
+			Err(Error::new(core::StsBadArg, "Quaternion should be normalized"))
+		}
+	}
+
+	/// Angle of the rotation represented by the quaternion in the range `[0, 2*pi]`
+	#[inline]
+	pub fn get_angle(&self) -> T {
+		let two = T::one() + T::one();
+		two * (self.w / self.norm()).max(-T::one()).min(T::one()).acos()
+	}
+
+	/// Unit axis of the rotation represented by the quaternion, the result is undefined for the zero rotation
+	#[inline]
+	pub fn get_axis(&self) -> VecN<T, 3> {
+		let s = self.norm() * (self.get_angle() * Self::half()).sin();
+		VecN([self.x / s, self.y / s, self.z / s])
+	}
+
+	/// Rotation matrix, the quaternion is normalized first
+	pub fn to_rot_mat3(&self) -> Matx33<T> {
+		let Self { w, x, y, z } = *self / self.norm();
+		let one = T::one();
+		let two = one + one;
+		Matx33::from_array([
+			one - two * (y * y + z * z),
+			two * (x * y - w * z),
+			two * (x * z + w * y),
+			two * (x * y + w * z),
+			one - two * (x * x + z * z),
+			two * (y * z - w * x),
+			two * (x * z - w * y),
+			two * (y * z + w * x),
+			one - two * (x * x + y * y),
+		])
+	}
+
+	/// Rotation matrix in homogeneous coordinates, see `to_rot_mat3()`
+	pub fn to_rot_mat4(&self) -> Matx44<T> {
+		let r = self.to_rot_mat3();
+		let mut out = Matx44::eye();
+		for row in 0..3 {
+			for col in 0..3 {
+				out[(row, col)] = r[(row, col)];
+			}
+		}
+		out
+	}
+
+	/// Rotation as Rodrigues vector, i.e. the rotation axis scaled by the angle
+	#[inline]
+	pub fn to_rot_vec(&self) -> VecN<T, 3> {
+		let v = VecN([self.x, self.y, self.z]);
+		let v_norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+		if v_norm < Self::eps() {
+			VecN::all(T::zero())
+		} else {
+			v * (self.get_angle() / v_norm)
+		}
+	}
+
+	/// Euler angles (in radians) of the rotation in the order described by `typ`
+	///
+	/// In case of the gimbal lock the angles are not unique and the third angle (for the intrinsic rotations) or the first
+	/// angle (for the extrinsic rotations) is set to 0.
+	pub fn to_euler_angles(&self, typ: QuatEnum_EulerAnglesType) -> VecN<T, 3> {
+		let r = self.to_rot_mat3();
+		let (axes, extrinsic) = typ.axes();
+		// extrinsic rotations are the intrinsic rotations around the same axes in the reverse order
+		let (i, j) = if extrinsic {
+			(axes[2], axes[1])
+		} else {
+			(axes[0], axes[1])
+		};
+		let k = 3 - i - j;
+		let one = T::one();
+		let sign = if j == (i + 1) % 3 {
+			one
+		} else {
+			-one
+		};
+		let gimbal_lock = one - Self::eps();
+		let (a, b, c) = if axes[0] == axes[2] {
+			let b = r[(i, i)].max(-one).min(one).acos();
+			if r[(i, i)].abs() > gimbal_lock {
+				((sign * r[(k, j)]).atan2(r[(j, j)]), b, T::zero())
+			} else {
+				(r[(j, i)].atan2(-sign * r[(k, i)]), b, r[(i, j)].atan2(sign * r[(i, k)]))
+			}
+		} else {
+			let b = (sign * r[(i, k)]).max(-one).min(one).asin();
+			if r[(i, k)].abs() > gimbal_lock {
+				((sign * r[(k, j)]).atan2(r[(j, j)]), b, T::zero())
+			} else {
+				((-sign * r[(j, k)]).atan2(r[(k, k)]), b, (-sign * r[(i, j)]).atan2(r[(i, i)]))
+			}
+		};
+		if extrinsic {
+			VecN([c, b, a])
+		} else {
+			VecN([a, b, c])
+		}
+	}
+
+	/// Exponential function of the quaternion
+	#[inline]
+	pub fn exp(&self) -> Self {
+		let v_norm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+		let k = if v_norm < Self::eps() {
+			T::one()
+		} else {
+			v_norm.sin() / v_norm
+		};
+		Self::new(v_norm.cos(), self.x * k, self.y * k, self.z * k) * self.w.exp()
+	}
+
+	/// Natural logarithm of the quaternion
+	#[inline]
+	pub fn log(&self) -> Self {
+		let norm = self.norm();
+		let v_norm = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+		let k = if v_norm < Self::eps() {
+			T::one()
+		} else {
+			(self.w / norm).max(-T::one()).min(T::one()).acos() / v_norm
+		};
+		Self::new(norm.ln(), self.x * k, self.y * k, self.z * k)
+	}
+
+	/// Quaternion raised to the power `x`, for the unit quaternions that's the rotation by the angle scaled by `x`
+	#[inline]
+	pub fn pow(&self, x: T) -> Self {
+		(self.log() * x).exp()
+	}
+
+	/// Square root of the quaternion, see `pow()`
+	#[inline]
+	pub fn sqrt(&self) -> Self {
+		self.pow(Self::half())
+	}
+
+	/// Linear interpolation between `q0` and `q1`, `t` is in the range `[0, 1]`
+	#[inline]
+	pub fn lerp(q0: Self, q1: Self, t: T) -> Self {
+		q0 * (T::one() - t) + q1 * t
+	}
+
+	/// Normalized linear interpolation between the rotations `q0` and `q1` along the shortest path, the inputs are normalized
+	/// first
+	#[inline]
+	pub fn nlerp(q0: Self, q1: Self, t: T) -> Self {
+		let q0 = q0 / q0.norm();
+		let mut q1 = q1 / q1.norm();
+		if q0.dot(&q1) < T::zero() {
+			q1 = -q1;
+		}
+		let out = Self::lerp(q0, q1, t);
+		out / out.norm()
+	}
+
+	/// Spherical linear interpolation between the rotations `q0` and `q1` along the shortest path, the inputs are normalized
+	/// first
+	///
+	/// Falls back to `nlerp()` for the close rotations.
+	pub fn slerp(q0: Self, q1: Self, t: T) -> Self {
+		let q0 = q0 / q0.norm();
+		let mut q1 = q1 / q1.norm();
+		let mut cos_theta = q0.dot(&q1);
+		if cos_theta < T::zero() {
+			q1 = -q1;
+			cos_theta = -cos_theta;
+		}
+		if cos_theta > T::from(0.995).unwrap_or_else(T::one) {
+			return Self::nlerp(q0, q1, t);
+		}
+		let sin_theta = (T::one() - cos_theta * cos_theta).sqrt();
+		let theta = sin_theta.atan2(cos_theta);
+		q0 * (((T::one() - t) * theta).sin() / sin_theta) + q1 * ((t * theta).sin() / sin_theta)
+	}
+
+	/// Spherical and quadrangle interpolation between the rotations `q0` and `q1` with the control points `s0` and `s1`, see
+	/// `inter_point()` for calculating the control points
+	#[inline]
+	pub fn squad(q0: Self, s0: Self, s1: Self, q1: Self, t: T) -> Self {
+		let two = T::one() + T::one();
+		Self::slerp(Self::slerp(q0, q1, t), Self::slerp(s0, s1, t), two * t * (T::one() - t))
+	}
+
+	/// Control point for `squad()` at the rotation `q1` given the neighbouring rotations `q0` and `q2`
+	#[inline]
+	pub fn inter_point(q0: Self, q1: Self, q2: Self) -> Self {
+		let q0 = q0 / q0.norm();
+		let q1 = q1 / q1.norm();
+		let q2 = q2 / q2.norm();
+		let q1_conj = q1.conjugate();
+		let four = T::from(4).unwrap_or_else(T::one);
+		q1 * (-((q1_conj * q0).log() + (q1_conj * q2).log()) / four).exp()
+	}
+}
@@ -0,0 +1,129 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{Float, Num};
+
+use super::Quat;
+
+impl<T: AddAssign> AddAssign for Quat<T> {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		self.w += rhs.w;
+		self.x += rhs.x;
+		self.y += rhs.y;
+		self.z += rhs.z;
+	}
+}
+
+impl<T: Add<Output = T>> Add for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, rhs: Self) -> Self::Output {
+		Self::new(self.w + rhs.w, self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+	}
+}
+
+impl<T: SubAssign> SubAssign for Quat<T> {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		self.w -= rhs.w;
+		self.x -= rhs.x;
+		self.y -= rhs.y;
+		self.z -= rhs.z;
+	}
+}
+
+impl<T: Sub<Output = T>> Sub for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self::new(self.w - rhs.w, self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+	}
+}
+
+impl<T: Num + MulAssign + Copy> MulAssign<T> for Quat<T> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: T) {
+		self.w *= rhs;
+		self.x *= rhs;
+		self.y *= rhs;
+		self.z *= rhs;
+	}
+}
+
+impl<T: Num + Copy> Mul<T> for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: T) -> Self::Output {
+		Self::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+	}
+}
+
+impl<T: Num + DivAssign + Copy> DivAssign<T> for Quat<T> {
+	#[inline]
+	fn div_assign(&mut self, rhs: T) {
+		self.w /= rhs;
+		self.x /= rhs;
+		self.y /= rhs;
+		self.z /= rhs;
+	}
+}
+
+impl<T: Num + Copy> Div<T> for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, rhs: T) -> Self::Output {
+		Self::new(self.w / rhs, self.x / rhs, self.y / rhs, self.z / rhs)
+	}
+}
+
+impl<T: Neg<Output = T>> Neg for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self::new(-self.w, -self.x, -self.y, -self.z)
+	}
+}
+
+/// Hamilton product, the composition of the rotations with `rhs` applied first
+impl<T: Num + Copy> Mul for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		Self::new(
+			self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+			self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+			self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+			self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+		)
+	}
+}
+
+impl<T: Num + Copy> MulAssign for Quat<T> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+/// Multiplication by the inverse of `rhs`
+impl<T: Float> Div for Quat<T> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, rhs: Self) -> Self::Output {
+		self * rhs.conjugate() / rhs.dot(&rhs)
+	}
+}
+
+impl<T: Float> DivAssign for Quat<T> {
+	#[inline]
+	fn div_assign(&mut self, rhs: Self) {
+		*self = *self / rhs;
+	}
+}
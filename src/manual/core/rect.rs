@@ -3,13 +3,17 @@ use std::{
 	ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, Sub, SubAssign},
 };
 
-use num_traits::{NumCast, NumOps, ToPrimitive, Zero};
+use num_traits::{NumCast, NumOps, One, ToPrimitive, Zero};
+
+pub use nms::*;
 
 use crate::{
-	core::{prelude::*, Point_, RotatedRect, Size_},
+	core::{prelude::*, Point2f, Point_, Rect, Rect2f, RotatedRect, Size_},
 	opencv_type_simple_generic,
 };
 
+mod nms;
+
 #[inline(always)]
 fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
 	if a <= b {
@@ -28,8 +32,20 @@ fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
 	}
 }
 
+/// Converts `val` to `T` rounding it to the nearest integer if `T` is an integer type
+#[inline]
+fn from_f64_rounded<T: NumCast + ToPrimitive>(val: f64) -> Option<T> {
+	let is_integer = T::from(0.5).and_then(|half| half.to_f64()) != Some(0.5);
+	T::from(if is_integer {
+		val.round()
+	} else {
+		val
+	})
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/d2/d44/classcv_1_1Rect__.html)
 pub struct Rect_<T> {
 	pub x: T,
@@ -107,6 +123,88 @@ impl<T> Rect_<T> {
 		self.x <= pt.x && pt.x < self.x + self.width && self.y <= pt.y && pt.y < self.y + self.height
 	}
 
+	/// Center of the rectangle, for the integer types the coordinates are rounded towards the top-left corner
+	#[inline]
+	pub fn center(&self) -> Point_<T>
+	where
+		T: NumOps + One + Copy,
+	{
+		let two = T::one() + T::one();
+		Point_::new(self.x + self.width / two, self.y + self.height / two)
+	}
+
+	/// Checks whether the rectangles have a non-empty intersection
+	#[inline]
+	pub fn intersects(&self, other: &Self) -> bool
+	where
+		T: PartialOrd + Add<Output = T> + Zero + Copy,
+	{
+		!self.empty()
+			&& !other.empty()
+			&& self.x < other.x + other.width
+			&& other.x < self.x + self.width
+			&& self.y < other.y + other.height
+			&& other.y < self.y + self.height
+	}
+
+	/// Intersection over union (Jaccard index) of the rectangles, returns 0 if either of the rectangles is empty
+	#[inline]
+	pub fn iou(&self, other: &Self) -> f64
+	where
+		T: ToPrimitive + Copy,
+	{
+		match (self.to::<f64>(), other.to::<f64>()) {
+			(Some(a), Some(b)) if !a.empty() && !b.empty() => {
+				let intersection = (a & b).area();
+				intersection / (a.area() + b.area() - intersection)
+			}
+			_ => 0.,
+		}
+	}
+
+	/// Part of the rectangle that lies inside the image of the specified `size`
+	#[inline]
+	pub fn clamp_to(&self, size: Size_<T>) -> Self
+	where
+		T: PartialOrd + NumOps + Zero + Default + Copy,
+	{
+		*self & Self::new(T::zero(), T::zero(), size.width, size.height)
+	}
+
+	/// Rectangle grown by `dx` on the left and right sides and by `dy` on the top and bottom sides, negative values shrink the
+	/// rectangle
+	#[inline]
+	pub fn inflate(&self, dx: T, dy: T) -> Self
+	where
+		T: NumOps + Copy,
+	{
+		Self::new(self.x - dx, self.y - dy, self.width + dx + dx, self.height + dy + dy)
+	}
+
+	/// Rectangle with the size multiplied by `factor` keeping the same center, for the integer types the resulting
+	/// coordinates are rounded
+	///
+	/// Returns `None` if the result doesn't fit into `T`.
+	#[inline]
+	pub fn scale_about_center(&self, factor: f64) -> Option<Self>
+	where
+		T: NumCast + ToPrimitive + Copy,
+	{
+		let rect = self.to::<f64>()?;
+		let width = rect.width * factor;
+		let height = rect.height * factor;
+		let x = rect.x + (rect.width - width) / 2.;
+		let y = rect.y + (rect.height - height) / 2.;
+		let tl_x = from_f64_rounded(x)?;
+		let tl_y = from_f64_rounded(y)?;
+		Some(Self::new(
+			tl_x,
+			tl_y,
+			from_f64_rounded(x + width - tl_x.to_f64()?)?,
+			from_f64_rounded(y + height - tl_y.to_f64()?)?,
+		))
+	}
+
 	#[inline]
 	pub fn to<D: NumCast>(&self) -> Option<Rect_<D>>
 	where
@@ -284,6 +382,104 @@ impl fmt::Debug for RotatedRect {
 	}
 }
 
+/// Pure Rust geometry of `RotatedRect`, it doesn't call into OpenCV except for reading the fields
+impl RotatedRect {
+	/// Vertices of the rectangle in the order: bottom-left, top-left, top-right, bottom-right, same as `points()`
+	pub fn vertices(&self) -> [Point2f; 4] {
+		let center = self.center();
+		let size = self.size();
+		let angle = self.angle() as f64 * std::f64::consts::PI / 180.;
+		let b = angle.cos() as f32 * 0.5;
+		let a = angle.sin() as f32 * 0.5;
+		let pt0 = Point2f::new(
+			center.x - a * size.height - b * size.width,
+			center.y + b * size.height - a * size.width,
+		);
+		let pt1 = Point2f::new(
+			center.x + a * size.height - b * size.width,
+			center.y - b * size.height - a * size.width,
+		);
+		[
+			pt0,
+			pt1,
+			Point2f::new(2. * center.x - pt0.x, 2. * center.y - pt0.y),
+			Point2f::new(2. * center.x - pt1.x, 2. * center.y - pt1.y),
+		]
+	}
+
+	/// Minimal integer rectangle containing the rotated rectangle, same as `bounding_rect()`
+	pub fn bounding_box(&self) -> Rect {
+		let (min, max) = self.vertices_bounds();
+		let x = min.x.floor() as i32;
+		let y = min.y.floor() as i32;
+		Rect::new(x, y, max.x.ceil() as i32 - x + 1, max.y.ceil() as i32 - y + 1)
+	}
+
+	/// Minimal rectangle containing the rotated rectangle, same as `bounding_rect2f()`
+	pub fn bounding_box2f(&self) -> Rect2f {
+		let (min, max) = self.vertices_bounds();
+		Rect2f::from_points(min, max)
+	}
+
+	/// Minimal and maximal coordinates of the vertices
+	fn vertices_bounds(&self) -> (Point2f, Point2f) {
+		let pts = self.vertices();
+		pts[1..].iter().fold((pts[0], pts[0]), |(min, max), pt| {
+			(
+				Point2f::new(partial_min(min.x, pt.x), partial_min(min.y, pt.y)),
+				Point2f::new(partial_max(max.x, pt.x), partial_max(max.y, pt.y)),
+			)
+		})
+	}
+
+	/// Intersection over union of the rotated rectangles, returns 0 if either of the rectangles is empty
+	pub fn iou(&self, other: &Self) -> f64 {
+		let a = self.vertices().map(|pt| Point_::new(pt.x as f64, pt.y as f64));
+		let b = other.vertices().map(|pt| Point_::new(pt.x as f64, pt.y as f64));
+		let area_a = polygon_area(&a).abs();
+		let area_b = polygon_area(&b).abs();
+		if area_a <= 0. || area_b <= 0. {
+			return 0.;
+		}
+		let intersection = polygon_area(&clip_convex_polygon(&a, &b)).abs();
+		intersection / (area_a + area_b - intersection)
+	}
+}
+
+/// Signed area of the polygon, positive for the counter-clockwise vertex order in the y-up coordinate system
+fn polygon_area(pts: &[Point_<f64>]) -> f64 {
+	let sum = pts
+		.iter()
+		.zip(pts.iter().cycle().skip(1))
+		.fold(0., |acc, (a, b)| acc + a.x * b.y - b.x * a.y);
+	sum / 2.
+}
+
+/// Intersection of the `subject` polygon with the convex `clip` polygon (Sutherland–Hodgman algorithm)
+fn clip_convex_polygon(subject: &[Point_<f64>], clip: &[Point_<f64>]) -> Vec<Point_<f64>> {
+	let orientation = polygon_area(clip).signum();
+	let mut out = subject.to_vec();
+	for (&a, &b) in clip.iter().zip(clip.iter().cycle().skip(1)) {
+		if out.is_empty() {
+			break;
+		}
+		let side = |pt: Point_<f64>| orientation * ((b.x - a.x) * (pt.y - a.y) - (b.y - a.y) * (pt.x - a.x));
+		let input = std::mem::take(&mut out);
+		for (&cur, &next) in input.iter().zip(input.iter().cycle().skip(1)) {
+			let cur_side = side(cur);
+			let next_side = side(next);
+			if cur_side >= 0. {
+				out.push(cur);
+			}
+			if (cur_side >= 0.) != (next_side >= 0.) {
+				let t = cur_side / (cur_side - next_side);
+				out.push(Point_::new(cur.x + (next.x - cur.x) * t, cur.y + (next.y - cur.y) * t));
+			}
+		}
+	}
+	out
+}
+
 #[test]
 fn test_partial() {
 	assert_eq!(1., partial_min(1., 2.));
@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+
+use num_traits::ToPrimitive;
+
+use super::Rect_;
+use crate::core::RotatedRect;
+
+/// Shape that can be used with `non_max_suppression()` and `soft_non_max_suppression()`
+pub trait NmsBox {
+	/// Intersection over union of the shapes
+	fn iou(&self, other: &Self) -> f64;
+}
+
+impl<T: ToPrimitive + Copy> NmsBox for Rect_<T> {
+	#[inline]
+	fn iou(&self, other: &Self) -> f64 {
+		Rect_::iou(self, other)
+	}
+}
+
+impl NmsBox for RotatedRect {
+	#[inline]
+	fn iou(&self, other: &Self) -> f64 {
+		RotatedRect::iou(self, other)
+	}
+}
+
+/// Score decay function for `soft_non_max_suppression()`, mirrors `cv::dnn::SoftNMSMethod`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SoftNmsMethod {
+	/// Score is multiplied by `1 - iou` if `iou` is greater than the threshold
+	Linear,
+	/// Score is multiplied by `exp(-iou^2 / sigma)`
+	Gaussian,
+}
+
+/// Indices of the boxes with the score greater than `score_threshold` sorted by the score in the descending order and limited
+/// to `top_k` items (0 means no limit)
+fn sorted_candidates<B>(boxes: &[(B, f32)], score_threshold: f32, top_k: usize) -> Vec<usize> {
+	let mut out = boxes
+		.iter()
+		.enumerate()
+		.filter(|(_, (_, score))| *score > score_threshold)
+		.map(|(i, _)| i)
+		.collect::<Vec<_>>();
+	out.sort_by(|&a, &b| boxes[b].1.partial_cmp(&boxes[a].1).unwrap_or(Ordering::Equal));
+	if top_k > 0 {
+		out.truncate(top_k);
+	}
+	out
+}
+
+/// Greedy non-maximum suppression, same as `dnn::nms_boxes()` but works with any `NmsBox`
+///
+/// Takes the boxes with their scores and returns the indices of the kept boxes sorted by the score in the descending order.
+/// Boxes with the score not greater than `score_threshold` are dropped, a box is suppressed if its IoU with any of the
+/// already kept boxes is greater than `nms_threshold`. If `eta` is less than 1 the threshold is multiplied by `eta` after
+/// each kept box while it's greater than 0.5. `top_k` limits the number of considered boxes, 0 means no limit.
+pub fn non_max_suppression<B: NmsBox>(
+	boxes: &[(B, f32)],
+	score_threshold: f32,
+	nms_threshold: f32,
+	eta: f32,
+	top_k: usize,
+) -> Vec<usize> {
+	let mut threshold = f64::from(nms_threshold);
+	let mut out: Vec<usize> = vec![];
+	for idx in sorted_candidates(boxes, score_threshold, top_k) {
+		let keep = out.iter().all(|&kept| boxes[idx].0.iou(&boxes[kept].0) <= threshold);
+		if keep {
+			out.push(idx);
+			if eta < 1. && threshold > 0.5 {
+				threshold *= f64::from(eta);
+			}
+		}
+	}
+	out
+}
+
+/// Soft non-maximum suppression, same as `dnn::soft_nms_boxes()` but works with any `NmsBox`
+///
+/// Instead of dropping the overlapping boxes their scores are decayed according to `method`, `sigma` is used only by
+/// `SoftNmsMethod::Gaussian`. Returns the indices of the kept boxes together with their updated scores sorted by the updated
+/// score in the descending order. Boxes with the updated score less than `score_threshold` are dropped, `top_k` limits the
+/// number of the returned boxes, 0 means no limit.
+pub fn soft_non_max_suppression<B: NmsBox>(
+	boxes: &[(B, f32)],
+	score_threshold: f32,
+	nms_threshold: f32,
+	top_k: usize,
+	sigma: f32,
+	method: SoftNmsMethod,
+) -> Vec<(usize, f32)> {
+	let mut candidates = boxes
+		.iter()
+		.enumerate()
+		.map(|(i, (_, score))| (i, *score))
+		.collect::<Vec<_>>();
+	let mut out = vec![];
+	while top_k == 0 || out.len() < top_k {
+		// the first of the equal scores is taken to match the stable sort used by OpenCV
+		let best_pos = match candidates
+			.iter()
+			.enumerate()
+			.min_by(|(_, (_, a)), (_, (_, b))| b.partial_cmp(a).unwrap_or(Ordering::Equal))
+		{
+			Some((pos, _)) => pos,
+			None => break,
+		};
+		let (best, best_score) = candidates.remove(best_pos);
+		if best_score < score_threshold {
+			break;
+		}
+		out.push((best, best_score));
+		for (idx, score) in &mut candidates {
+			let iou = boxes[best].0.iou(&boxes[*idx].0);
+			match method {
+				SoftNmsMethod::Linear => {
+					if iou > f64::from(nms_threshold) {
+						*score *= (1. - iou) as f32;
+					}
+				}
+				SoftNmsMethod::Gaussian => {
+					*score *= (-(iou * iou) / f64::from(sigma)).exp() as f32;
+				}
+			}
+		}
+	}
+	out
+}
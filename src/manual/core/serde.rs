@@ -0,0 +1,232 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Error as _, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core::{
+	Affine3, DMatch, KeyPoint, KeyPointTraitConst, Matx, Matx44, MatxTrait, Point2f, RotatedRect, RotatedRectTraitConst, Size2f,
+	SizedArray, VecN, Vector, VectorElement, VectorExtern,
+};
+
+/// Reads exactly `len` elements from the sequence
+fn read_exact<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(mut seq: A, len: usize) -> Result<Vec<T>, A::Error> {
+	let mut out = Vec::with_capacity(len);
+	while let Some(elem) = seq.next_element()? {
+		if out.len() == len {
+			return Err(A::Error::invalid_length(len + 1, &format!("{len} elements").as_str()));
+		}
+		out.push(elem);
+	}
+	if out.len() == len {
+		Ok(out)
+	} else {
+		Err(A::Error::invalid_length(out.len(), &format!("{len} elements").as_str()))
+	}
+}
+
+fn serialize_elems<T: Serialize, S: Serializer>(elems: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+	let mut tup = serializer.serialize_tuple(elems.len())?;
+	for elem in elems {
+		tup.serialize_element(elem)?;
+	}
+	tup.end()
+}
+
+struct ElemsVisitor<T> {
+	len: usize,
+	_d: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ElemsVisitor<T> {
+	type Value = Vec<T>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a sequence of {} elements", self.len)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+		read_exact(seq, self.len)
+	}
+}
+
+fn deserialize_elems<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D, len: usize) -> Result<Vec<T>, D::Error> {
+	deserializer.deserialize_tuple(len, ElemsVisitor { len, _d: PhantomData })
+}
+
+/// Serialized as a tuple of `N` elements
+impl<T: Serialize, const N: usize> Serialize for VecN<T, N> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_elems(&self.0, serializer)
+	}
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for VecN<T, N> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let elems = deserialize_elems(deserializer, N)?;
+		// length is checked by deserialize_elems()
+		elems.try_into().map(Self).map_err(|_| D::Error::invalid_length(N, &"VecN"))
+	}
+}
+
+/// Serialized as a tuple of `ROWS * COLS` elements in the row-major order
+impl<T: Serialize, A: SizedArray<T>> Serialize for Matx<T, A> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_elems(self.val(), serializer)
+	}
+}
+
+impl<'de, T: Deserialize<'de> + Clone, A: SizedArray<T>> Deserialize<'de> for Matx<T, A> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let elems = deserialize_elems::<T, _>(deserializer, A::ROWS * A::COLS)?;
+		let mut out = Self::all(elems[0].clone());
+		out.val_mut().clone_from_slice(&elems);
+		Ok(out)
+	}
+}
+
+/// Serialized as its 4x4 `matrix`
+impl<T: Serialize + Copy> Serialize for Affine3<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.matrix.serialize(serializer)
+	}
+}
+
+impl<'de, T: Deserialize<'de> + Copy> Deserialize<'de> for Affine3<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Matx44::deserialize(deserializer).map(|matrix| Self { matrix })
+	}
+}
+
+/// Serialized as a sequence of elements
+impl<T: VectorElement + Serialize> Serialize for Vector<T>
+where
+	Self: VectorExtern<T>,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.iter_owned())
+	}
+}
+
+struct VectorVisitor<T>(PhantomData<T>);
+
+impl<'de, T: VectorElement + Deserialize<'de>> Visitor<'de> for VectorVisitor<T>
+where
+	Vector<T>: VectorExtern<T>,
+{
+	type Value = Vector<T>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a sequence")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		// the size hint comes from the input, so it's capped to not reserve arbitrary amounts of memory upfront
+		let mut out = Vector::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+		while let Some(elem) = seq.next_element()? {
+			out.push_owned(elem);
+		}
+		Ok(out)
+	}
+}
+
+impl<'de, T: VectorElement + Deserialize<'de>> Deserialize<'de> for Vector<T>
+where
+	Self: VectorExtern<T>,
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_seq(VectorVisitor(PhantomData))
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "KeyPoint")]
+struct KeyPointRepr {
+	pt: Point2f,
+	size: f32,
+	angle: f32,
+	response: f32,
+	octave: i32,
+	class_id: i32,
+}
+
+impl Serialize for KeyPoint {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		KeyPointRepr {
+			pt: self.pt(),
+			size: self.size(),
+			angle: self.angle(),
+			response: self.response(),
+			octave: self.octave(),
+			class_id: self.class_id(),
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for KeyPoint {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let kp = KeyPointRepr::deserialize(deserializer)?;
+		KeyPoint::new_point(kp.pt, kp.size, kp.angle, kp.response, kp.octave, kp.class_id).map_err(D::Error::custom)
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DMatch")]
+struct DMatchRepr {
+	query_idx: i32,
+	train_idx: i32,
+	img_idx: i32,
+	distance: f32,
+}
+
+impl Serialize for DMatch {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		DMatchRepr {
+			query_idx: self.query_idx,
+			train_idx: self.train_idx,
+			img_idx: self.img_idx,
+			distance: self.distance,
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for DMatch {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		DMatchRepr::deserialize(deserializer).map(|m| Self {
+			query_idx: m.query_idx,
+			train_idx: m.train_idx,
+			img_idx: m.img_idx,
+			distance: m.distance,
+		})
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RotatedRect")]
+struct RotatedRectRepr {
+	center: Point2f,
+	size: Size2f,
+	angle: f32,
+}
+
+impl Serialize for RotatedRect {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		RotatedRectRepr {
+			center: self.center(),
+			size: self.size(),
+			angle: self.angle(),
+		}
+		.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for RotatedRect {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let rect = RotatedRectRepr::deserialize(deserializer)?;
+		RotatedRect::new(rect.center, rect.size, rect.angle).map_err(D::Error::custom)
+	}
+}
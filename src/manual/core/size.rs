@@ -6,6 +6,7 @@ use crate::{core::Point_, opencv_type_simple_generic};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/d6/d50/classcv_1_1Size__.html)
 pub struct Size_<T> {
 	pub width: T,
@@ -6,6 +6,19 @@ pub trait SizedArray<T>: Copy + Clone {
 	fn all(alpha: T) -> Self::Storage;
 }
 
+/// Shape of the transposed matrix, `COLS` × `ROWS`
+pub trait SizedArrayTranspose<T>: SizedArray<T> {
+	type Output: SizedArray<T>;
+}
+
+/// Shape of the matrix product, `Self::ROWS` × `Rhs::COLS`, only implemented when `Self::COLS` matches `Rhs::ROWS`
+pub trait SizedArrayMul<T, Rhs: SizedArray<T>>: SizedArray<T> {
+	type Output: SizedArray<T>;
+}
+
+/// Marker for the square shapes
+pub trait SizedArraySquare<T>: SizedArray<T> {}
+
 macro_rules! sized_array_impl {
 	($type: ident, $rows: expr, $cols: expr) => {
 		#[derive(Copy, Clone)]
@@ -24,6 +37,22 @@ macro_rules! sized_array_impl {
 	};
 }
 
+macro_rules! sized_array_transpose_impl {
+	($type: ident, $transposed: ident) => {
+		impl<T: Copy> $crate::core::SizedArrayTranspose<T> for $type {
+			type Output = $transposed;
+		}
+	};
+}
+
+macro_rules! sized_array_mul_impl {
+	($lhs: ident * $rhs: ident => $output: ident) => {
+		impl<T: Copy> $crate::core::SizedArrayMul<T, $rhs> for $lhs {
+			type Output = $output;
+		}
+	};
+}
+
 sized_array_impl!(SizedArray12, 1, 2);
 sized_array_impl!(SizedArray13, 1, 3);
 sized_array_impl!(SizedArray14, 1, 4);
@@ -45,3 +74,66 @@ sized_array_impl!(SizedArray43, 4, 3);
 
 sized_array_impl!(SizedArray44, 4, 4);
 sized_array_impl!(SizedArray66, 6, 6);
+
+sized_array_transpose_impl!(SizedArray12, SizedArray21);
+sized_array_transpose_impl!(SizedArray13, SizedArray31);
+sized_array_transpose_impl!(SizedArray14, SizedArray41);
+sized_array_transpose_impl!(SizedArray16, SizedArray61);
+sized_array_transpose_impl!(SizedArray21, SizedArray12);
+sized_array_transpose_impl!(SizedArray31, SizedArray13);
+sized_array_transpose_impl!(SizedArray41, SizedArray14);
+sized_array_transpose_impl!(SizedArray61, SizedArray16);
+sized_array_transpose_impl!(SizedArray22, SizedArray22);
+sized_array_transpose_impl!(SizedArray23, SizedArray32);
+sized_array_transpose_impl!(SizedArray32, SizedArray23);
+sized_array_transpose_impl!(SizedArray33, SizedArray33);
+sized_array_transpose_impl!(SizedArray34, SizedArray43);
+sized_array_transpose_impl!(SizedArray43, SizedArray34);
+sized_array_transpose_impl!(SizedArray44, SizedArray44);
+sized_array_transpose_impl!(SizedArray66, SizedArray66);
+
+sized_array_mul_impl!(SizedArray12 * SizedArray22 => SizedArray12);
+sized_array_mul_impl!(SizedArray12 * SizedArray23 => SizedArray13);
+sized_array_mul_impl!(SizedArray13 * SizedArray32 => SizedArray12);
+sized_array_mul_impl!(SizedArray13 * SizedArray33 => SizedArray13);
+sized_array_mul_impl!(SizedArray13 * SizedArray34 => SizedArray14);
+sized_array_mul_impl!(SizedArray14 * SizedArray43 => SizedArray13);
+sized_array_mul_impl!(SizedArray14 * SizedArray44 => SizedArray14);
+sized_array_mul_impl!(SizedArray16 * SizedArray66 => SizedArray16);
+sized_array_mul_impl!(SizedArray21 * SizedArray12 => SizedArray22);
+sized_array_mul_impl!(SizedArray21 * SizedArray13 => SizedArray23);
+sized_array_mul_impl!(SizedArray31 * SizedArray12 => SizedArray32);
+sized_array_mul_impl!(SizedArray31 * SizedArray13 => SizedArray33);
+sized_array_mul_impl!(SizedArray31 * SizedArray14 => SizedArray34);
+sized_array_mul_impl!(SizedArray41 * SizedArray13 => SizedArray43);
+sized_array_mul_impl!(SizedArray41 * SizedArray14 => SizedArray44);
+sized_array_mul_impl!(SizedArray61 * SizedArray16 => SizedArray66);
+sized_array_mul_impl!(SizedArray22 * SizedArray21 => SizedArray21);
+sized_array_mul_impl!(SizedArray22 * SizedArray22 => SizedArray22);
+sized_array_mul_impl!(SizedArray22 * SizedArray23 => SizedArray23);
+sized_array_mul_impl!(SizedArray23 * SizedArray31 => SizedArray21);
+sized_array_mul_impl!(SizedArray23 * SizedArray32 => SizedArray22);
+sized_array_mul_impl!(SizedArray23 * SizedArray33 => SizedArray23);
+sized_array_mul_impl!(SizedArray32 * SizedArray21 => SizedArray31);
+sized_array_mul_impl!(SizedArray32 * SizedArray22 => SizedArray32);
+sized_array_mul_impl!(SizedArray32 * SizedArray23 => SizedArray33);
+sized_array_mul_impl!(SizedArray33 * SizedArray31 => SizedArray31);
+sized_array_mul_impl!(SizedArray33 * SizedArray32 => SizedArray32);
+sized_array_mul_impl!(SizedArray33 * SizedArray33 => SizedArray33);
+sized_array_mul_impl!(SizedArray33 * SizedArray34 => SizedArray34);
+sized_array_mul_impl!(SizedArray34 * SizedArray41 => SizedArray31);
+sized_array_mul_impl!(SizedArray34 * SizedArray43 => SizedArray33);
+sized_array_mul_impl!(SizedArray34 * SizedArray44 => SizedArray34);
+sized_array_mul_impl!(SizedArray43 * SizedArray31 => SizedArray41);
+sized_array_mul_impl!(SizedArray43 * SizedArray33 => SizedArray43);
+sized_array_mul_impl!(SizedArray43 * SizedArray34 => SizedArray44);
+sized_array_mul_impl!(SizedArray44 * SizedArray41 => SizedArray41);
+sized_array_mul_impl!(SizedArray44 * SizedArray43 => SizedArray43);
+sized_array_mul_impl!(SizedArray44 * SizedArray44 => SizedArray44);
+sized_array_mul_impl!(SizedArray66 * SizedArray61 => SizedArray61);
+sized_array_mul_impl!(SizedArray66 * SizedArray66 => SizedArray66);
+
+impl<T: Copy> SizedArraySquare<T> for SizedArray22 {}
+impl<T: Copy> SizedArraySquare<T> for SizedArray33 {}
+impl<T: Copy> SizedArraySquare<T> for SizedArray44 {}
+impl<T: Copy> SizedArraySquare<T> for SizedArray66 {}
@@ -1,12 +1,17 @@
-use std::borrow::Borrow;
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
 use std::ffi::c_void;
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::{fmt, slice};
 
-pub use iter::{VectorIterator, VectorRefIterator};
-pub use vector_extern::{VectorElement, VectorExtern, VectorExternCopyNonBool};
+pub use iter::{VectorIterator, VectorIteratorMut, VectorRefIterator};
+pub use vector_extern::{
+	VectorElement, VectorElementRef, VectorElementRefMut, VectorExtern, VectorExternBoxed, VectorExternCopyNonBool,
+};
+
+pub use crate::traits::{BoxedRef, BoxedRefMut};
 
 use crate::platform_types::size_t;
 use crate::traits::OpenCVTypeExternContainerMove;
@@ -142,6 +147,119 @@ where
 		unsafe { self.extern_push_owned(val.opencv_as_extern()) }
 	}
 
+	/// Same as `set_unchecked()` but takes the owned element
+	/// # Safety
+	/// Caller must ensure that the specified `index` is within the `Vector` bounds
+	#[inline]
+	pub(crate) unsafe fn set_owned_unchecked(&mut self, index: size_t, val: T) {
+		let val = val.opencv_into_extern_container_nofail();
+		self.extern_set_owned(index, val.opencv_as_extern())
+	}
+
+	/// Shorten the Vector keeping the first `len` elements, has no effect if `len` is greater or equal to the current length
+	#[inline]
+	pub fn truncate(&mut self, len: size_t) {
+		let cur_len = self.len();
+		(len..cur_len).rev().for_each(|index| unsafe { self.extern_remove(index) });
+	}
+
+	/// Append clones of all the elements of the slice
+	#[inline]
+	pub fn extend_from_slice(&mut self, s: &[T])
+	where
+		T: Clone,
+	{
+		self.reserve(s.len());
+		s.iter().for_each(|elem| self.push_owned(elem.clone()));
+	}
+
+	/// Retain only the elements for which the predicate `f` returns `true`, preserves the order of the retained elements
+	///
+	/// The predicate receives borrowed elements (`BoxedRef` for boxed types) so boxed elements are not cloned. Retained
+	/// elements are moved to the front with swaps, the rest is then removed from the back of the vector one by one so no
+	/// elements need to be shifted.
+	pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+		let len = self.len();
+		let mut retained = 0;
+		for index in 0..len {
+			let keep = f(unsafe { T::opencv_vector_get_ref_unchecked(self, index) }.borrow());
+			if keep {
+				if index != retained {
+					unsafe { self.extern_swap(index, retained) };
+				}
+				retained += 1;
+			}
+		}
+		self.truncate(retained);
+	}
+
+	/// Remove consecutive elements for which `same_bucket` returns `true`, like `Vec::dedup_by()`
+	///
+	/// `same_bucket` receives the current element as the first argument and the last retained element as the second one.
+	pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&T, &T) -> bool) {
+		let len = self.len();
+		if len <= 1 {
+			return;
+		}
+		let mut retained = 1;
+		for index in 1..len {
+			// the last retained element is always at `retained - 1` because the retained elements are swapped to the front
+			let same = unsafe {
+				same_bucket(
+					T::opencv_vector_get_ref_unchecked(self, index).borrow(),
+					T::opencv_vector_get_ref_unchecked(self, retained - 1).borrow(),
+				)
+			};
+			if !same {
+				if index != retained {
+					unsafe { self.extern_swap(index, retained) };
+				}
+				retained += 1;
+			}
+		}
+		self.truncate(retained);
+	}
+
+	/// Sort the Vector with the comparator function `compare`, the sort is stable
+	///
+	/// The comparator receives borrowed elements (`BoxedRef` for boxed types), only the resulting index permutation is stored
+	/// on the Rust side. The elements are then reordered in place with swaps so the underlying C++ objects are preserved.
+	pub fn sort_by(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
+		let order = {
+			let elems = self.iter().collect::<Vec<_>>();
+			let mut order = (0..elems.len()).collect::<Vec<_>>();
+			order.sort_by(|&a, &b| compare(elems[a].borrow(), elems[b].borrow()));
+			order
+		};
+		// `positions[i]` is the current position of the element originally at index `i`, `originals` is the reverse mapping
+		let mut positions = (0..order.len()).collect::<Vec<_>>();
+		let mut originals = positions.clone();
+		for (target, &orig) in order.iter().enumerate() {
+			let cur = positions[orig];
+			if cur != target {
+				unsafe { self.extern_swap(target, cur) };
+				let displaced = originals[target];
+				originals[cur] = displaced;
+				positions[displaced] = cur;
+				originals[target] = orig;
+				positions[orig] = target;
+			}
+		}
+	}
+
+	/// Return an iterator over the mutable references to the elements
+	///
+	/// For boxed types (e.g. `Mat`) it yields `BoxedRefMut`s pointing to the elements inside the `Vector`, for Copy types
+	/// (except bool) it's the same as `as_mut_slice().iter_mut()`. Not available for `String` and `bool`, see
+	/// `VectorElementRefMut`.
+	#[inline]
+	pub fn iter_mut<'v>(&'v mut self) -> <T as VectorElementRefMut<'v>>::IterMut
+	where
+		T: VectorElementRefMut<'v>,
+	{
+		T::opencv_vector_iter_mut(self)
+	}
+
 	/// Insert a new element at the specified `index`
 	#[inline]
 	pub fn insert(&mut self, index: size_t, val: <T as OpenCVType>::Arg) -> Result<()> {
@@ -185,11 +303,68 @@ where
 		T::opencv_from_extern(val)
 	}
 
+	/// Get the borrowed element at the specified `index`, available for boxed element types like `Mat`
+	///
+	/// Unlike `get()` the returned `BoxedRef` can't outlive the `Vector` and gives only read-only access to the element.
+	#[inline]
+	pub fn get_ref(&self, index: size_t) -> Result<BoxedRef<T>>
+	where
+		T: Boxed,
+		Self: VectorExternBoxed<T>,
+	{
+		vector_index_check(index, self.len())?;
+		Ok(unsafe { self.get_ref_unchecked(index) })
+	}
+
+	/// Same as `get_ref()` but without bounds checking
+	/// # Safety
+	/// Caller must ensure that the specified `index` is within the `Vector` bounds
+	#[inline]
+	pub unsafe fn get_ref_unchecked(&self, index: size_t) -> BoxedRef<T>
+	where
+		T: Boxed,
+		Self: VectorExternBoxed<T>,
+	{
+		BoxedRef::from_raw(self.extern_get_ref(index) as *mut c_void)
+	}
+
+	/// Get the mutably borrowed element at the specified `index`, available for boxed element types like `Mat`
+	///
+	/// Modifications made through the returned `BoxedRefMut` are applied directly to the element inside the `Vector`.
+	#[inline]
+	pub fn get_mut(&mut self, index: size_t) -> Result<BoxedRefMut<T>>
+	where
+		T: Boxed,
+		Self: VectorExternBoxed<T>,
+	{
+		vector_index_check(index, self.len())?;
+		Ok(unsafe { self.get_mut_unchecked(index) })
+	}
+
+	/// Same as `get_mut()` but without bounds checking
+	/// # Safety
+	/// Caller must ensure that the specified `index` is within the `Vector` bounds
+	#[inline]
+	pub unsafe fn get_mut_unchecked(&mut self, index: size_t) -> BoxedRefMut<T>
+	where
+		T: Boxed,
+		Self: VectorExternBoxed<T>,
+	{
+		BoxedRefMut::from_raw(self.extern_get_mut(index))
+	}
+
+	/// Return an iterator over the elements, for boxed element types it yields `BoxedRef`s borrowing the `Vector`
 	#[inline]
 	pub fn iter(&self) -> VectorRefIterator<T> {
 		VectorRefIterator::new(self)
 	}
 
+	/// Iterator over the owned elements, same as calling `get()` for every index
+	#[inline]
+	pub(crate) fn iter_owned(&self) -> impl Iterator<Item = T> + '_ {
+		(0..self.len()).map(move |index| unsafe { self.get_unchecked(index) })
+	}
+
 	/// Return slice to the elements of the array.
 	///
 	/// This method is only available for OpenCV types that are Copy, with the exception of bool
@@ -270,6 +445,16 @@ where
 	}
 }
 
+impl<T: VectorElement> AsMut<[T]> for Vector<T>
+where
+	Self: VectorExtern<T> + VectorExternCopyNonBool<T>,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
 impl<T: VectorElement> Borrow<[T]> for Vector<T>
 where
 	Self: VectorExtern<T> + VectorExternCopyNonBool<T>,
@@ -280,13 +465,23 @@ where
 	}
 }
 
+impl<T: VectorElement> BorrowMut<[T]> for Vector<T>
+where
+	Self: VectorExtern<T> + VectorExternCopyNonBool<T>,
+{
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
 impl<T: VectorElement + fmt::Debug> fmt::Debug for Vector<T>
 where
 	Self: VectorExtern<T>,
 {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		f.debug_list().entries(self.iter()).finish()
+		f.debug_list().entries(self.iter_owned()).finish()
 	}
 }
 
@@ -1,8 +1,10 @@
 use std::iter::FusedIterator;
+use std::marker::PhantomData;
 
 use crate::{
-	core::{Vector, VectorElement, VectorExtern},
+	core::{BoxedRefMut, Vector, VectorElement, VectorElementRef, VectorExtern, VectorExternBoxed},
 	platform_types::size_t,
+	traits::Boxed,
 };
 
 impl<T: VectorElement> IntoIterator for Vector<T>
@@ -22,7 +24,7 @@ impl<'v, T: VectorElement> IntoIterator for &'v Vector<T>
 where
 	Vector<T>: VectorExtern<T>,
 {
-	type Item = T;
+	type Item = <T as VectorElementRef<'v>>::Ref;
 	type IntoIter = VectorRefIterator<'v, T>;
 
 	#[inline]
@@ -112,11 +114,11 @@ where
 	}
 }
 
-impl<T: VectorElement> Iterator for VectorRefIterator<'_, T>
+impl<'v, T: VectorElement> Iterator for VectorRefIterator<'v, T>
 where
 	Vector<T>: VectorExtern<T>,
 {
-	type Item = T;
+	type Item = <T as VectorElementRef<'v>>::Ref;
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
@@ -134,7 +136,7 @@ where
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		self.i += n;
 		if self.i < self.len {
-			let out = Some(unsafe { self.vec.get_unchecked(self.i) });
+			let out = Some(unsafe { T::opencv_vector_get_ref_unchecked(self.vec, self.i) });
 			self.i += 1;
 			out
 		} else {
@@ -155,3 +157,71 @@ where
 		Self { ..*self }
 	}
 }
+
+/// Iterator over the mutable references to the boxed `Vector` elements, see `Vector::iter_mut()`
+///
+/// It yields `BoxedRefMut`s that point directly to the elements inside the `Vector`.
+pub struct VectorIteratorMut<'v, T: VectorElement + Boxed>
+where
+	Vector<T>: VectorExtern<T> + VectorExternBoxed<T>,
+{
+	vec: *mut Vector<T>,
+	i: size_t,
+	len: size_t,
+	_d: PhantomData<&'v mut Vector<T>>,
+}
+
+impl<'v, T: VectorElement + Boxed> VectorIteratorMut<'v, T>
+where
+	Vector<T>: VectorExtern<T> + VectorExternBoxed<T>,
+{
+	#[inline]
+	pub fn new(vec: &'v mut Vector<T>) -> Self {
+		Self {
+			len: vec.len(),
+			vec,
+			i: 0,
+			_d: PhantomData,
+		}
+	}
+}
+
+impl<'v, T: VectorElement + Boxed> Iterator for VectorIteratorMut<'v, T>
+where
+	Vector<T>: VectorExtern<T> + VectorExternBoxed<T>,
+{
+	type Item = BoxedRefMut<'v, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		#![allow(clippy::iter_nth_zero)]
+		self.nth(0)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len - self.i;
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.i += n;
+		if self.i < self.len {
+			// safe because the index is within bounds and every index is yielded at most once while the Vector is mutably
+			// borrowed for 'v
+			let out = Some(unsafe { BoxedRefMut::from_raw((*self.vec).extern_get_mut(self.i)) });
+			self.i += 1;
+			out
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: VectorElement + Boxed> ExactSizeIterator for VectorIteratorMut<'_, T> where
+	Vector<T>: VectorExtern<T> + VectorExternBoxed<T>
+{
+}
+
+impl<T: VectorElement + Boxed> FusedIterator for VectorIteratorMut<'_, T> where Vector<T>: VectorExtern<T> + VectorExternBoxed<T> {}
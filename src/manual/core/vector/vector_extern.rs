@@ -1,18 +1,45 @@
+use std::borrow::Borrow;
+use std::ffi::c_void;
+
 use crate::core::Vector;
 use crate::platform_types::size_t;
-use crate::traits::OpenCVType;
+use crate::traits::{Boxed, OpenCVType};
 use crate::{extern_arg_send, extern_container_send, extern_receive, extern_send};
 
 /// This trait is implemented by any type that can be stored inside `Vector`.
 ///
 /// It is mostly used internally, feasibility of implementing it for your own types hasn't been
 /// considered. Use is mostly for external type constraints.
-pub trait VectorElement: for<'a> OpenCVType<'a>
+pub trait VectorElement: for<'a> OpenCVType<'a> + for<'v> VectorElementRef<'v>
 where
 	Vector<Self>: VectorExtern<Self>,
 {
 	#[doc(hidden)]
 	fn opencv_vector_to_vec(v: &Vector<Self>) -> Vec<Self>;
+	#[doc(hidden)]
+	unsafe fn opencv_vector_get_ref_unchecked<'v>(v: &'v Vector<Self>, index: size_t) -> <Self as VectorElementRef<'v>>::Ref;
+}
+
+/// Type of the element returned when iterating over `&'v Vector<T>`
+///
+/// For boxed types (e.g. `Mat`) it's `BoxedRef<'v, T>` that borrows the element inside the `Vector`, for all other types
+/// it's the element itself.
+pub trait VectorElementRef<'v> {
+	type Ref: Borrow<Self>;
+}
+
+/// Type of the iterator returned by `Vector::iter_mut()`
+///
+/// For boxed types (e.g. `Mat`) it's `VectorIteratorMut<'v, T>` that yields `BoxedRefMut<'v, T>` pointing to the elements
+/// inside the `Vector`, for Copy types (except bool) it's the iterator over `as_mut_slice()`. It's not implemented for
+/// `String` and `bool` because they are stored in a different form on the C++ side, use `set()` for those.
+pub trait VectorElementRefMut<'v>: VectorElement
+where
+	Vector<Self>: VectorExtern<Self>,
+{
+	type IterMut: Iterator;
+	#[doc(hidden)]
+	fn opencv_vector_iter_mut(v: &'v mut Vector<Self>) -> Self::IterMut;
 }
 
 #[doc(hidden)]
@@ -49,6 +76,8 @@ pub trait VectorExtern<T: for<'a> OpenCVType<'a>> {
 	unsafe fn extern_insert<'a>(&mut self, index: size_t, val: extern_arg_send!(T: 'a));
 	#[doc(hidden)]
 	unsafe fn extern_set<'a>(&mut self, index: size_t, val: extern_arg_send!(T: 'a));
+	#[doc(hidden)]
+	unsafe fn extern_set_owned(&mut self, index: size_t, val: extern_container_send!(T));
 }
 
 #[doc(hidden)]
@@ -166,6 +195,11 @@ macro_rules! vector_extern {
 			unsafe fn extern_set<'a>(&mut self, index: $crate::platform_types::size_t, val: extern_arg_send!($type: 'a)) {
 				$extern_set(self.as_raw_mut(), index, val)
 			}
+
+			#[inline]
+			unsafe fn extern_set_owned(&mut self, index: $crate::platform_types::size_t, val: extern_container_send!($type)) {
+				$extern_set(self.as_raw_mut(), index, val)
+			}
 		}
 	};
 }
@@ -215,6 +249,10 @@ macro_rules! vector_copy_non_bool {
 			}
 		}
 
+		impl $crate::core::VectorElementRef<'_> for $type {
+			type Ref = $type;
+		}
+
 		impl $crate::core::VectorElement for $type
 		where
 			$crate::core::Vector<$type>: $crate::core::VectorExtern<$type>,
@@ -223,6 +261,23 @@ macro_rules! vector_copy_non_bool {
 			fn opencv_vector_to_vec(v: &$crate::core::Vector<$type>) -> std::vec::Vec<$type> {
 				v.as_slice().to_vec()
 			}
+
+			#[inline]
+			unsafe fn opencv_vector_get_ref_unchecked<'v>(
+				v: &'v $crate::core::Vector<$type>,
+				index: $crate::platform_types::size_t,
+			) -> $type {
+				v.get_unchecked(index)
+			}
+		}
+
+		impl<'v> $crate::core::VectorElementRefMut<'v> for $type {
+			type IterMut = std::slice::IterMut<'v, $type>;
+
+			#[inline]
+			fn opencv_vector_iter_mut(v: &'v mut $crate::core::Vector<$type>) -> std::slice::IterMut<'v, $type> {
+				v.as_mut_slice().iter_mut()
+			}
 		}
 
 		impl std::clone::Clone for $crate::core::Vector<$type>
@@ -272,6 +327,10 @@ macro_rules! vector_non_copy_or_bool {
 		vector_non_copy_or_bool! { $type }
 	};
 	($type: ty) => {
+		impl $crate::core::VectorElementRef<'_> for $type {
+			type Ref = $type;
+		}
+
 		impl $crate::core::VectorElement for $type
 		where
 			$crate::core::Vector<$type>: $crate::core::VectorExtern<$type>,
@@ -280,6 +339,96 @@ macro_rules! vector_non_copy_or_bool {
 			fn opencv_vector_to_vec(v: &$crate::core::Vector<$type>) -> std::vec::Vec<$type> {
 				(0..v.len()).map(|x| unsafe { v.get_unchecked(x) }).collect()
 			}
+
+			#[inline]
+			unsafe fn opencv_vector_get_ref_unchecked<'v>(
+				v: &'v $crate::core::Vector<$type>,
+				index: $crate::platform_types::size_t,
+			) -> $type {
+				v.get_unchecked(index)
+			}
+		}
+	};
+}
+
+#[doc(hidden)]
+pub trait VectorExternBoxed<T: VectorElement + Boxed>
+where
+	Vector<T>: VectorExtern<T>,
+{
+	#[doc(hidden)]
+	unsafe fn extern_get_ref(&self, index: size_t) -> *const c_void;
+	#[doc(hidden)]
+	unsafe fn extern_get_mut(&mut self, index: size_t) -> *mut c_void;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! vector_boxed {
+	(clone $type: ty, $extern_get_ref: ident, $extern_get_mut: ident $(,)?) => {
+		impl std::clone::Clone for $crate::core::Vector<$type>
+		where
+			Self: $crate::core::VectorExtern<$type>,
+		{
+			#[inline]
+			fn clone(&self) -> Self {
+				let mut out = Self::with_capacity(self.capacity());
+				self.iter().for_each(|elem| out.push_owned((*elem).clone()));
+				out
+			}
+		}
+		vector_boxed! { $type, $extern_get_ref, $extern_get_mut }
+	};
+	($type: ty, $extern_get_ref: ident, $extern_get_mut: ident $(,)?) => {
+		extern "C" {
+			fn $extern_get_ref(
+				instance: *const ::std::ffi::c_void,
+				index: $crate::platform_types::size_t,
+			) -> *const ::std::ffi::c_void;
+			fn $extern_get_mut(instance: *mut ::std::ffi::c_void, index: $crate::platform_types::size_t) -> *mut ::std::ffi::c_void;
+		}
+
+		impl $crate::core::VectorExternBoxed<$type> for $crate::core::Vector<$type> {
+			#[inline]
+			unsafe fn extern_get_ref(&self, index: $crate::platform_types::size_t) -> *const ::std::ffi::c_void {
+				$extern_get_ref(self.as_raw(), index)
+			}
+
+			#[inline]
+			unsafe fn extern_get_mut(&mut self, index: $crate::platform_types::size_t) -> *mut ::std::ffi::c_void {
+				$extern_get_mut(self.as_raw_mut(), index)
+			}
+		}
+
+		impl<'v> $crate::core::VectorElementRef<'v> for $type {
+			type Ref = $crate::core::BoxedRef<'v, $type>;
+		}
+
+		impl $crate::core::VectorElement for $type
+		where
+			$crate::core::Vector<$type>: $crate::core::VectorExtern<$type>,
+		{
+			#[inline]
+			fn opencv_vector_to_vec(v: &$crate::core::Vector<$type>) -> std::vec::Vec<$type> {
+				(0..v.len()).map(|x| unsafe { v.get_unchecked(x) }).collect()
+			}
+
+			#[inline]
+			unsafe fn opencv_vector_get_ref_unchecked<'v>(
+				v: &'v $crate::core::Vector<$type>,
+				index: $crate::platform_types::size_t,
+			) -> $crate::core::BoxedRef<'v, $type> {
+				v.get_ref_unchecked(index)
+			}
+		}
+
+		impl<'v> $crate::core::VectorElementRefMut<'v> for $type {
+			type IterMut = $crate::core::VectorIteratorMut<'v, $type>;
+
+			#[inline]
+			fn opencv_vector_iter_mut(v: &'v mut $crate::core::Vector<$type>) -> $crate::core::VectorIteratorMut<'v, $type> {
+				$crate::core::VectorIteratorMut::new(v)
+			}
 		}
 	};
 }
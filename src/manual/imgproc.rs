@@ -0,0 +1,85 @@
+use crate::core::{Bgr, Bgra, DataType, Gray, Hls, Hsv, Lab, Luv, Mat, Mat_, Rgb, Rgba, Xyz, YCrCb};
+use crate::{imgproc, Result};
+
+/// Pixel type that can be converted to the pixel type `Dst` using `cvt_color_typed()`
+///
+/// Implemented only for the pairs of pixel types of the same depth that are supported by `imgproc::cvt_color()`, so an
+/// unsupported conversion is a compile time error.
+pub trait ColorConversion<Dst: DataType>: DataType {
+	/// `COLOR_*` code passed to `imgproc::cvt_color()`
+	const CODE: i32;
+}
+
+macro_rules! color_conversion {
+	($src: ident => $dst: ident, $code: ident, [$($depth: ty),+]) => {
+		$(
+			impl ColorConversion<$dst<$depth>> for $src<$depth> {
+				const CODE: i32 = imgproc::$code;
+			}
+		)+
+	};
+}
+
+color_conversion!(Bgr => Bgra, COLOR_BGR2BGRA, [u8, u16, f32]);
+color_conversion!(Bgr => Rgb, COLOR_BGR2RGB, [u8, u16, f32]);
+color_conversion!(Bgr => Rgba, COLOR_BGR2RGBA, [u8, u16, f32]);
+color_conversion!(Bgr => Gray, COLOR_BGR2GRAY, [u8, u16, f32]);
+color_conversion!(Bgra => Bgr, COLOR_BGRA2BGR, [u8, u16, f32]);
+color_conversion!(Bgra => Rgb, COLOR_BGRA2RGB, [u8, u16, f32]);
+color_conversion!(Bgra => Rgba, COLOR_BGRA2RGBA, [u8, u16, f32]);
+color_conversion!(Bgra => Gray, COLOR_BGRA2GRAY, [u8, u16, f32]);
+color_conversion!(Rgb => Bgr, COLOR_RGB2BGR, [u8, u16, f32]);
+color_conversion!(Rgb => Bgra, COLOR_RGB2BGRA, [u8, u16, f32]);
+color_conversion!(Rgb => Rgba, COLOR_RGB2RGBA, [u8, u16, f32]);
+color_conversion!(Rgb => Gray, COLOR_RGB2GRAY, [u8, u16, f32]);
+color_conversion!(Rgba => Bgr, COLOR_RGBA2BGR, [u8, u16, f32]);
+color_conversion!(Rgba => Bgra, COLOR_RGBA2BGRA, [u8, u16, f32]);
+color_conversion!(Rgba => Rgb, COLOR_RGBA2RGB, [u8, u16, f32]);
+color_conversion!(Rgba => Gray, COLOR_RGBA2GRAY, [u8, u16, f32]);
+color_conversion!(Gray => Bgr, COLOR_GRAY2BGR, [u8, u16, f32]);
+color_conversion!(Gray => Bgra, COLOR_GRAY2BGRA, [u8, u16, f32]);
+color_conversion!(Gray => Rgb, COLOR_GRAY2RGB, [u8, u16, f32]);
+color_conversion!(Gray => Rgba, COLOR_GRAY2RGBA, [u8, u16, f32]);
+
+color_conversion!(Bgr => Hsv, COLOR_BGR2HSV, [u8, f32]);
+color_conversion!(Rgb => Hsv, COLOR_RGB2HSV, [u8, f32]);
+color_conversion!(Hsv => Bgr, COLOR_HSV2BGR, [u8, f32]);
+color_conversion!(Hsv => Rgb, COLOR_HSV2RGB, [u8, f32]);
+color_conversion!(Bgr => Hls, COLOR_BGR2HLS, [u8, f32]);
+color_conversion!(Rgb => Hls, COLOR_RGB2HLS, [u8, f32]);
+color_conversion!(Hls => Bgr, COLOR_HLS2BGR, [u8, f32]);
+color_conversion!(Hls => Rgb, COLOR_HLS2RGB, [u8, f32]);
+color_conversion!(Bgr => Lab, COLOR_BGR2Lab, [u8, f32]);
+color_conversion!(Rgb => Lab, COLOR_RGB2Lab, [u8, f32]);
+color_conversion!(Lab => Bgr, COLOR_Lab2BGR, [u8, f32]);
+color_conversion!(Lab => Rgb, COLOR_Lab2RGB, [u8, f32]);
+color_conversion!(Bgr => Luv, COLOR_BGR2Luv, [u8, f32]);
+color_conversion!(Rgb => Luv, COLOR_RGB2Luv, [u8, f32]);
+color_conversion!(Luv => Bgr, COLOR_Luv2BGR, [u8, f32]);
+color_conversion!(Luv => Rgb, COLOR_Luv2RGB, [u8, f32]);
+
+color_conversion!(Bgr => Xyz, COLOR_BGR2XYZ, [u8, u16, f32]);
+color_conversion!(Rgb => Xyz, COLOR_RGB2XYZ, [u8, u16, f32]);
+color_conversion!(Xyz => Bgr, COLOR_XYZ2BGR, [u8, u16, f32]);
+color_conversion!(Xyz => Rgb, COLOR_XYZ2RGB, [u8, u16, f32]);
+color_conversion!(Bgr => YCrCb, COLOR_BGR2YCrCb, [u8, u16, f32]);
+color_conversion!(Rgb => YCrCb, COLOR_RGB2YCrCb, [u8, u16, f32]);
+color_conversion!(YCrCb => Bgr, COLOR_YCrCb2BGR, [u8, u16, f32]);
+color_conversion!(YCrCb => Rgb, COLOR_YCrCb2RGB, [u8, u16, f32]);
+
+/// Typed version of `cvt_color()`, the conversion code is selected from the source and destination pixel types
+///
+/// ```no_run
+/// # use opencv::core::{Bgr8, Hsv8, Mat_};
+/// # use opencv::imgproc::cvt_color_typed;
+/// # fn main() -> opencv::Result<()> {
+/// let bgr = Mat_::<Bgr8>::zeros(10, 10)?;
+/// let hsv = cvt_color_typed::<_, Hsv8>(&bgr)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn cvt_color_typed<Src: ColorConversion<Dst>, Dst: DataType>(src: &Mat_<Src>) -> Result<Mat_<Dst>> {
+	let mut dst = Mat::default();
+	imgproc::cvt_color(src, &mut dst, Src::CODE, Dst::opencv_channels())?;
+	dst.try_into_typed()
+}
@@ -4,6 +4,8 @@ pub mod core;
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod imgproc;
 pub mod sys;
 pub mod types;
 
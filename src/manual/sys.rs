@@ -10,7 +10,8 @@ use crate::{types::Unit, Error, Result as CrateResult};
 #[repr(C)]
 pub struct Result<S, O = S> {
 	pub error_code: i32,
-	pub error_msg: *mut c_void,
+	/// Boxed `Error` created by `ocvrs_create_error`, null on success
+	pub error: *mut c_void,
 	pub result: MaybeUninit<S>,
 	_p: PhantomData<O>,
 }
@@ -18,12 +19,10 @@ pub struct Result<S, O = S> {
 impl<S: Into<O>, O> Result<S, O> {
 	#[inline]
 	pub fn into_result(self) -> CrateResult<O> {
-		if self.error_msg.is_null() {
+		if self.error.is_null() {
 			Ok(unsafe { self.result.assume_init() }.into())
 		} else {
-			Err(Error::new(self.error_code, unsafe {
-				crate::templ::receive_string(self.error_msg as *mut String)
-			}))
+			Err(unsafe { crate::templ::receive_error(self.error as *mut Error) })
 		}
 	}
 }
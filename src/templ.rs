@@ -1,6 +1,10 @@
-use std::{ffi::CStr, os::raw::c_char, slice};
+use std::{
+	ffi::CStr,
+	os::raw::{c_char, c_int},
+	slice,
+};
 
-use crate::platform_types::size_t;
+use crate::{platform_types::size_t, Error};
 
 macro_rules! extern_container_arg {
 	(nofail mut $name: ident) => {
@@ -124,6 +128,27 @@ unsafe extern "C" fn ocvrs_create_byte_string(v: *const u8, len: size_t) -> *mut
 	Box::into_raw(Box::new(v))
 }
 
+/// The return type of this function goes into `receive_error`
+#[no_mangle]
+unsafe extern "C" fn ocvrs_create_error(
+	code: c_int,
+	message: *const c_char,
+	err: *const c_char,
+	func: *const c_char,
+	file: *const c_char,
+	line: c_int,
+) -> *mut Error {
+	let out = Error::with_context(
+		code,
+		CStr::from_ptr(message).to_string_lossy().into_owned(),
+		CStr::from_ptr(err).to_string_lossy().into_owned(),
+		CStr::from_ptr(func).to_string_lossy().into_owned(),
+		CStr::from_ptr(file).to_string_lossy().into_owned(),
+		line,
+	);
+	Box::into_raw(Box::new(out))
+}
+
 #[inline]
 pub unsafe fn receive_string(s: *mut String) -> String {
 	if s.is_null() {
@@ -132,6 +157,14 @@ pub unsafe fn receive_string(s: *mut String) -> String {
 	*Box::from_raw(s)
 }
 
+#[inline]
+pub unsafe fn receive_error(e: *mut Error) -> Error {
+	if e.is_null() {
+		panic!("Got null pointer for receive_error()");
+	}
+	*Box::from_raw(e)
+}
+
 #[inline]
 pub unsafe fn receive_byte_string(s: *mut Vec<u8>) -> Vec<u8> {
 	if s.is_null() {
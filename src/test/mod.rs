@@ -1 +1,13 @@
+use std::mem::size_of;
+
+use crate::manual::core::MatHeader;
+
 mod sys;
+
+#[test]
+fn mat_header_layout() {
+	extern "C" {
+		fn cv_manual_Mat_sizeof() -> usize;
+	}
+	assert_eq!(unsafe { cv_manual_Mat_sizeof() }, size_of::<MatHeader>());
+}
@@ -1,4 +1,9 @@
+use std::borrow::Borrow;
 use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
 
 /// Trait for structures that are created on the C++ side, usually only the raw void pointer is stored to point to the allocated
 /// data on the heap.
@@ -27,6 +32,118 @@ pub trait Boxed: Sized {
 	fn as_raw_mut(&mut self) -> *mut c_void;
 }
 
+/// Non-owning wrapper of the boxed object that lives inside another object, e.g. an element of `Vector`, for the lifetime `'r`
+///
+/// It gives access to the read-only (`*TraitConst`) methods of the wrapped type through `Deref` and the trait
+/// implementations, but it never frees the referenced object.
+pub struct BoxedRef<'r, T: Boxed> {
+	reference: ManuallyDrop<T>,
+	_d: PhantomData<&'r T>,
+}
+
+impl<T: Boxed> BoxedRef<'_, T> {
+	/// Wrap the specified raw pointer without taking ownership
+	/// # Safety
+	/// Caller must ensure that the passed pointer is pointing to a valid object data that outlives the returned wrapper and
+	/// is not mutated during its lifetime
+	#[inline]
+	pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+		Self {
+			reference: ManuallyDrop::new(T::from_raw(ptr)),
+			_d: PhantomData,
+		}
+	}
+
+	/// Return the underlying raw pointer
+	#[inline]
+	pub fn as_raw(&self) -> *const c_void {
+		self.reference.as_raw()
+	}
+}
+
+impl<T: Boxed> Deref for BoxedRef<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.reference
+	}
+}
+
+impl<T: Boxed> Borrow<T> for BoxedRef<'_, T> {
+	#[inline]
+	fn borrow(&self) -> &T {
+		&self.reference
+	}
+}
+
+impl<T: Boxed + fmt::Debug> fmt::Debug for BoxedRef<'_, T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.reference.fmt(f)
+	}
+}
+
+/// Non-owning mutable wrapper of the boxed object that lives inside another object for the lifetime `'r`
+///
+/// Same as `BoxedRef`, but it also implements the mutable (`*Trait`) traits of the wrapped type. There is intentionally no
+/// `DerefMut` implementation: `&mut T` would allow swapping the referenced object with an owned one making the owner
+/// free the object it doesn't own.
+pub struct BoxedRefMut<'r, T: Boxed> {
+	reference: ManuallyDrop<T>,
+	_d: PhantomData<&'r mut T>,
+}
+
+impl<T: Boxed> BoxedRefMut<'_, T> {
+	/// Wrap the specified raw pointer without taking ownership
+	/// # Safety
+	/// Caller must ensure that the passed pointer is pointing to a valid object data that outlives the returned wrapper and
+	/// is not accessed by anything else during its lifetime
+	#[inline]
+	pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+		Self {
+			reference: ManuallyDrop::new(T::from_raw(ptr)),
+			_d: PhantomData,
+		}
+	}
+
+	/// Return the underlying raw pointer
+	#[inline]
+	pub fn as_raw(&self) -> *const c_void {
+		self.reference.as_raw()
+	}
+
+	/// Return the underlying mutable raw pointer
+	#[inline]
+	pub fn as_raw_mut(&mut self) -> *mut c_void {
+		self.reference.as_raw_mut()
+	}
+
+	/// Mutable reference to the wrapper of the referenced object
+	/// # Safety
+	/// Caller must not move out of or replace the returned value, e.g. with `std::mem::swap()`
+	#[inline]
+	pub(crate) unsafe fn as_inner_mut(&mut self) -> &mut T {
+		&mut self.reference
+	}
+}
+
+impl<T: Boxed> Deref for BoxedRefMut<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.reference
+	}
+}
+
+impl<T: Boxed + fmt::Debug> fmt::Debug for BoxedRefMut<'_, T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.reference.fmt(f)
+	}
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! opencv_type_boxed {
@@ -143,3 +260,30 @@ macro_rules! boxed_cast_descendant {
 		}
 	};
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! boxed_ref {
+	($type: ty, $trait_const: path, $extern_const: ident, $trait_mut: path, $extern_mut: ident $(,)?) => {
+		impl $trait_const for $crate::traits::BoxedRef<'_, $type> {
+			#[inline]
+			fn $extern_const(&self) -> *const ::std::ffi::c_void {
+				self.as_raw()
+			}
+		}
+
+		impl $trait_const for $crate::traits::BoxedRefMut<'_, $type> {
+			#[inline]
+			fn $extern_const(&self) -> *const ::std::ffi::c_void {
+				self.as_raw()
+			}
+		}
+
+		impl $trait_mut for $crate::traits::BoxedRefMut<'_, $type> {
+			#[inline]
+			fn $extern_mut(&mut self) -> *mut ::std::ffi::c_void {
+				self.as_raw_mut()
+			}
+		}
+	};
+}
@@ -2,7 +2,6 @@
 
 template struct Result<void*>;
 template struct Result<cv::Size>;
-template struct Result<const unsigned char*>;
 
 template<typename T> inline void ocvrs_input_array(const T* instance, Result<void*>* ocvrs_return) {
 	try {
@@ -22,6 +21,146 @@ template<typename T> inline void ocvrs_input_output_array(T* instance, Result<vo
 	} OCVRS_CATCH(Result<void*>, ocvrs_return)
 }
 
+// The Rust side reads the fields of these classes directly through the mirror structs in src/manual/core/mat/header.rs,
+// keep the layouts in sync
+#define OCVRS_MAT_HEADER_SIZE (4 * sizeof(int) + 10 * sizeof(void*))
+static_assert(sizeof(cv::Mat) == OCVRS_MAT_HEADER_SIZE, "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, flags) == 0, "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, dims) == sizeof(int), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, rows) == 2 * sizeof(int), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, cols) == 3 * sizeof(int), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, data) == 4 * sizeof(int), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, datastart) == 4 * sizeof(int) + sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, dataend) == 4 * sizeof(int) + 2 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, datalimit) == 4 * sizeof(int) + 3 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, allocator) == 4 * sizeof(int) + 4 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, u) == 4 * sizeof(int) + 5 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, size) == 4 * sizeof(int) + 6 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(offsetof(cv::Mat, step) == 4 * sizeof(int) + 7 * sizeof(void*), "cv::Mat layout doesn't match MatHeader");
+static_assert(sizeof(cv::MatSize) == sizeof(int*), "cv::MatSize layout doesn't match MatSizeHeader");
+static_assert(sizeof(cv::MatStep) == 3 * sizeof(size_t), "cv::MatStep layout doesn't match MatStepHeader");
+static_assert(offsetof(cv::MatStep, p) == 0, "cv::MatStep layout doesn't match MatStepHeader");
+static_assert(offsetof(cv::MatStep, buf) == sizeof(size_t*), "cv::MatStep layout doesn't match MatStepHeader");
+
+// defined in src/manual/core/mat/allocator.rs
+extern "C" void* ocvrs_mat_allocator_allocate(const void* userdata, size_t size);
+extern "C" void ocvrs_mat_allocator_deallocate(const void* userdata, void* ptr, size_t size);
+
+#if CV_VERSION_MAJOR >= 4
+	typedef cv::AccessFlag ocvrs_AccessFlag;
+#else
+	typedef int ocvrs_AccessFlag;
+#endif
+
+// Forwards the buffer allocations to the Rust MatAllocatorImpl, the rest of the logic is the same as in cv::StdMatAllocator
+class RustMatAllocator : public cv::MatAllocator {
+public:
+	explicit RustMatAllocator(const void* userdata) : userdata(userdata) {}
+
+	cv::UMatData* allocate(int dims, const int* sizes, int type, void* data0, size_t* step, ocvrs_AccessFlag, cv::UMatUsageFlags) const CV_OVERRIDE {
+		size_t total = CV_ELEM_SIZE(type);
+		for (int i = dims - 1; i >= 0; i--) {
+			if (step) {
+				if (data0 && step[i] != CV_AUTOSTEP) {
+					CV_Assert(total <= step[i]);
+					total = step[i];
+				} else {
+					step[i] = total;
+				}
+			}
+			total *= sizes[i];
+		}
+		uchar* data = (uchar*)data0;
+		if (!data) {
+			data = (uchar*)ocvrs_mat_allocator_allocate(userdata, total);
+			if (!data) {
+				CV_Error(cv::Error::StsNoMem, cv::format("Failed to allocate %llu bytes", (unsigned long long)total));
+			}
+		}
+		cv::UMatData* u = new cv::UMatData(this);
+		u->data = u->origdata = data;
+		u->size = total;
+		if (data0) {
+			u->flags |= cv::UMatData::USER_ALLOCATED;
+		}
+		return u;
+	}
+
+	bool allocate(cv::UMatData* u, ocvrs_AccessFlag, cv::UMatUsageFlags) const CV_OVERRIDE {
+		return u != NULL;
+	}
+
+	void deallocate(cv::UMatData* u) const CV_OVERRIDE {
+		if (!u) {
+			return;
+		}
+		CV_Assert(u->urefcount == 0);
+		CV_Assert(u->refcount == 0);
+		if (!(u->flags & cv::UMatData::USER_ALLOCATED)) {
+			ocvrs_mat_allocator_deallocate(userdata, u->origdata, u->size);
+			u->origdata = 0;
+		}
+		delete u;
+	}
+
+private:
+	const void* userdata;
+};
+
+// defined in src/manual/core/mat/vec_storage.rs
+extern "C" void ocvrs_vec_storage_drop(void* storage);
+
+// Owner of the Mat buffers moved from Rust Vec, UMatData::userdata points to the Rust VecStorage that is dropped together
+// with the last reference to the buffer. New allocations are delegated to the standard allocator.
+class RustVecAllocator : public cv::MatAllocator {
+public:
+	cv::UMatData* allocate(int dims, const int* sizes, int type, void* data, size_t* step, ocvrs_AccessFlag flags, cv::UMatUsageFlags usageFlags) const CV_OVERRIDE {
+		return cv::Mat::getStdAllocator()->allocate(dims, sizes, type, data, step, flags, usageFlags);
+	}
+
+	bool allocate(cv::UMatData* u, ocvrs_AccessFlag flags, cv::UMatUsageFlags usageFlags) const CV_OVERRIDE {
+		return cv::Mat::getStdAllocator()->allocate(u, flags, usageFlags);
+	}
+
+	void deallocate(cv::UMatData* u) const CV_OVERRIDE {
+		if (!u) {
+			return;
+		}
+		CV_Assert(u->urefcount == 0);
+		CV_Assert(u->refcount == 0);
+		if (u->userdata) {
+			ocvrs_vec_storage_drop(u->userdata);
+			u->userdata = 0;
+		}
+		delete u;
+	}
+
+	static RustVecAllocator* instance() {
+		// intentionally leaked, the buffers can outlive the static destructors
+		static RustVecAllocator* out = new RustVecAllocator();
+		return out;
+	}
+};
+
+// Exposes the protected _InputArray::init() to create the arrays with explicit flags, the result is then copied to the
+// respective base class
+class RustRawArray : public cv::_InputOutputArray {
+public:
+	RustRawArray(int flags, void* obj) {
+		init(flags, obj);
+	}
+
+	RustRawArray(int flags, const void* obj, cv::Size sz) {
+		init(flags, obj, sz);
+	}
+};
+
+template<typename T> inline void ocvrs_mat_array_fixed_type(cv::Mat* instance, int type, int access, Result<void*>* ocvrs_return) {
+	try {
+		Ok<void*>(new T(RustRawArray(cv::_InputArray::FIXED_TYPE + cv::_InputArray::MAT + CV_MAT_TYPE(type) + access, instance)), ocvrs_return);
+	} OCVRS_CATCH(Result<void*>, ocvrs_return)
+}
+
 #define ocvrs_ioa(base) \
 	void cv_##base##_input_array(const cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); } \
 	void cv_##base##_output_array(cv::base* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); } \
@@ -40,12 +179,6 @@ template<typename T> inline void ocvrs_input_output_array(T* instance, Result<vo
 	ocvrs_ioa(base##w)
 
 extern "C" {
-	void cv_manual_Mat_size(const cv::Mat* instance, Result<cv::Size>* ocvrs_return) {
-		try {
-			Ok<cv::Size>(instance->size(), ocvrs_return);
-		} OCVRS_CATCH(Result<cv::Size>, ocvrs_return)
-	}
-
 	void cv_manual_Mat_set(cv::Mat* instance, cv::Scalar s, Result_void* ocvrs_return) {
 		try {
 			*instance = s;
@@ -53,8 +186,15 @@ extern "C" {
 		} OCVRS_CATCH(Result_void, ocvrs_return)
 	}
 
-	const unsigned char* cv_manual_Mat_data(const cv::Mat* instance) {
-		return instance->data;
+	void cv_manual_MatExpr_assign_to(const cv::MatExpr* instance, cv::Mat* m, int type, Result_void* ocvrs_return) {
+		try {
+			if (instance->op) {
+				instance->op->assign(*instance, *m, type);
+			} else {
+				m->release();
+			}
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
 	}
 
 	void cv_manual_UMat_size(const cv::UMat* instance, Result<cv::Size>* ocvrs_return) {
@@ -63,12 +203,45 @@ extern "C" {
 		} OCVRS_CATCH(Result<cv::Size>, ocvrs_return)
 	}
 
-	int cv_manual_MatSize_dims(const cv::MatSize* instance) {
-		return *(instance->p - 1);
+	void cv_manual_MatAllocator_new(const void* userdata, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(static_cast<cv::MatAllocator*>(new RustMatAllocator(userdata)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_Mat_from_vec(int rows, int cols, int type, void* data, size_t size, void* storage, Result<void*>* ocvrs_return) {
+		try {
+			cv::UMatData* u = new cv::UMatData(RustVecAllocator::instance());
+			u->data = u->origdata = (uchar*)data;
+			u->size = size;
+			u->userdata = storage;
+			u->refcount = 1;
+			cv::Mat* ret = new cv::Mat(rows, cols, type, data);
+			ret->u = u;
+			Ok<void*>(ret, ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void* cv_manual_Mat_detach_vec_storage(cv::Mat* instance) {
+		cv::UMatData* u = instance->u;
+		if (
+			u && u->currAllocator == RustVecAllocator::instance() && u->userdata
+			&& u->refcount == 1 && u->urefcount == 0
+			&& instance->data == u->origdata && instance->isContinuous() && instance->total() * instance->elemSize() == u->size
+		) {
+			void* out = u->userdata;
+			u->userdata = 0;
+			return out;
+		}
+		return 0;
 	}
 
-	const size_t* cv_manual_MatStep_deref(const cv::MatStep* instance) {
-		return instance->p;
+	void cv_manual_Mat_setDefaultAllocator(cv::MatAllocator* allocator) {
+		cv::Mat::setDefaultAllocator(allocator);
+	}
+
+	size_t cv_manual_Mat_sizeof() {
+		return sizeof(cv::Mat);
 	}
 
 	int cv_manual_MatConstIterator_type(const cv::MatConstIterator* instance) {
@@ -79,6 +252,26 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	// const Mat& overloads create output arrays with FIXED_SIZE and FIXED_TYPE flags so the Mat can't be reallocated
+	void cv_manual_Mat_output_array_fixed(const cv::Mat* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); }
+	void cv_manual_Mat_input_output_array_fixed(const cv::Mat* instance, Result<void*>* ocvrs_return) { return ocvrs_input_output_array(instance, ocvrs_return); }
+
+	// same flags as the cv::_InputArray(const _Tp* vec, int n) constructor
+	void cv_manual_InputArray_from_slice(int type, const void* data, int len, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_InputArray(RustRawArray(cv::_InputArray::FIXED_TYPE + cv::_InputArray::FIXED_SIZE + cv::_InputArray::MATX + CV_MAT_TYPE(type) + cv::ACCESS_READ, data, cv::Size(len, 1))), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	// Mat can be reallocated to any size, but only with the specified type
+	void cv_manual_Mat_output_array_fixed_type(cv::Mat* instance, int type, Result<void*>* ocvrs_return) {
+		return ocvrs_mat_array_fixed_type<cv::_OutputArray>(instance, type, cv::ACCESS_WRITE, ocvrs_return);
+	}
+
+	void cv_manual_Mat_input_output_array_fixed_type(cv::Mat* instance, int type, Result<void*>* ocvrs_return) {
+		return ocvrs_mat_array_fixed_type<cv::_InputOutputArray>(instance, type, cv::ACCESS_RW, ocvrs_return);
+	}
+
 	void cv_InputArray_input_array(cv::_InputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); }
 	void cv_OutputArray_output_array(cv::_OutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); }
 	void cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_output_array(instance, ocvrs_return); }
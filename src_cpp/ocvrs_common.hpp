@@ -20,8 +20,7 @@
 
 #define OCVRS_HANDLE(code, msg, return_type, return_name) Err<return_type>(code, msg, return_name)
 
-#define OCVRS_HANDLE_OPENCV(e, return_type, return_name) \
-OCVRS_HANDLE(e.code, e.what(), OCVRS_TYPE(return_type), return_name)
+#define OCVRS_HANDLE_OPENCV(e, return_type, return_name) Err<return_type>(e, return_name)
 
 #define OCVRS_HANDLE_UNSPECIFIED(return_type, return_name) \
 OCVRS_HANDLE(-99999, "unspecified error in OpenCV guts", OCVRS_TYPE(return_type), return_name)
@@ -36,33 +35,39 @@ catch (cv::Exception& e) { \
 // defined in src/templ.rs
 extern "C" void* ocvrs_create_string(const char*);
 extern "C" void* ocvrs_create_byte_string(const char*, size_t);
+extern "C" void* ocvrs_create_error(int, const char*, const char*, const char*, const char*, int);
 
 template<typename T> struct Result {
 	int error_code;
-	void* error_msg;
+	void* error;
 	T result;
 };
 
 struct Result_void {
 	int error_code;
-	void* error_msg;
+	void* error;
 };
 
 template<typename T, typename R> inline void Ok(T result, Result<R>* ocvrs_return) {
 	ocvrs_return->error_code = 0;
-	ocvrs_return->error_msg = NULL;
+	ocvrs_return->error = NULL;
 	ocvrs_return->result = *const_cast<R*>(&result);
 }
 
 inline void Ok(Result_void* ocvrs_return) {
 	ocvrs_return->error_code = 0;
-	ocvrs_return->error_msg = NULL;
+	ocvrs_return->error = NULL;
 }
 
 template<typename T> inline void Err(int code, const char* msg, T* ocvrs_return) {
 	ocvrs_return->error_code = code;
-	ocvrs_return->error_msg = ocvrs_create_string(msg);
-	// it's ok to leave result uninitialized because the Rust implementation only assumes it as init if error_msg is NULL
+	ocvrs_return->error = ocvrs_create_error(code, msg, "", "", "", 0);
+	// it's ok to leave result uninitialized because the Rust implementation only assumes it as init if error is NULL
+}
+
+template<typename T> inline void Err(const cv::Exception& e, T* ocvrs_return) {
+	ocvrs_return->error_code = e.code;
+	ocvrs_return->error = ocvrs_create_error(e.code, e.what(), e.err.c_str(), e.func.c_str(), e.file.c_str(), e.line);
 }
 
 #endif
@@ -0,0 +1,94 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use opencv::core::{Affine3d, Matx33d, Point3d, Quatd, Vec3d, Vec4d};
+use opencv::prelude::*;
+
+fn assert_close(expected: &[f64], actual: &[f64]) {
+	assert_eq!(expected.len(), actual.len());
+	assert!(
+		expected.iter().zip(actual).all(|(e, a)| (e - a).abs() < 1e-9),
+		"expected: {expected:?}, actual: {actual:?}"
+	);
+}
+
+#[test]
+fn affine3_construct() {
+	let rvec = Vec3d::from([0.1, -0.4, 0.7]);
+	let t = Vec3d::from([1., 2., 3.]);
+	let a = Affine3d::from_rvec(rvec, t);
+	assert_close(&rvec.0, &a.rvec().0);
+	assert_close(&t.0, &a.translation().0);
+	assert_close(&[0., 0., 0., 1.], &a.matrix.val()[12..]);
+
+	let a = Affine3d::from_rotation(Matx33d::eye(), t);
+	assert_eq!(Affine3d::identity().translate(t), a);
+	assert_close(&[0.; 3], &a.rvec().0);
+
+	let pi = Vec3d::from([0., PI, 0.]);
+	assert_close(&pi.0, &Affine3d::from_rvec(pi, t).rvec().0);
+
+	// 90 degrees around z axis, non-normalized quaternion
+	let a = Affine3d::from_quat(Quatd::new(2., 0., 0., 2.), Vec3d::all(0.));
+	assert_close(&[0., 0., FRAC_PI_2], &a.rvec().0);
+	assert_close(
+		Affine3d::from_rvec(Vec3d::from([0., 0., FRAC_PI_2]), Vec3d::all(0.))
+			.matrix
+			.val(),
+		a.matrix.val(),
+	);
+	let b = Affine3d::from_quat(Vec4d::from([2., 0., 0., 2.]), Vec3d::all(0.));
+	assert_eq!(a.matrix.val(), b.matrix.val());
+}
+
+#[test]
+fn affine3_rotation() {
+	let rvec = Vec3d::from([0.3, 0.2, -0.1]);
+	let mut a = Affine3d::from_rvec(rvec, Vec3d::from([1., 0., 0.]));
+	let rotation = a.rotation();
+	assert_close(a.linear().val(), rotation.val());
+	let mut scaled = rotation;
+	scaled.val_mut().iter_mut().for_each(|x| *x *= 3.);
+	a.set_linear(scaled);
+	assert_close(scaled.val(), a.rotation().val());
+	assert_close(&rvec.0, &a.rvec().0);
+	a.set_rvec(Vec3d::all(0.));
+	assert_eq!(Matx33d::eye(), a.linear());
+
+	// rotation by pi with the rounding errors on the diagonal
+	let a = Affine3d::from_rotation(
+		Matx33d::from_array([-1., 0., 0., 0., -1. - f64::EPSILON, 0., 0., 0., 1.]),
+		Vec3d::all(0.),
+	);
+	assert_close(&[0., 0., PI], &a.rvec().0);
+}
+
+#[test]
+fn affine3_transform() {
+	let a = Affine3d::from_rvec(Vec3d::from([0., 0., FRAC_PI_2]), Vec3d::from([1., 2., 3.]));
+	let pt = a * Point3d::new(1., 0., 0.);
+	assert_close(&[1., 3., 3.], &[pt.x, pt.y, pt.z]);
+	assert_close(&[0., 3., 4.], &(a * Vec3d::from([1., 1., 1.])).0);
+
+	let inv = a.inv().unwrap();
+	assert_close(Affine3d::identity().matrix.val(), (a * inv).matrix.val());
+	assert_close(&[1., 1., 1.], &(inv * (a * Vec3d::from([1., 1., 1.]))).0);
+
+	let b = Affine3d::from_rvec(Vec3d::from([0.3, 0.2, 0.1]), Vec3d::from([-1., 0., 5.]));
+	let v = Vec3d::from([4., 5., 6.]);
+	assert_close(&(b * (a * v)).0, &(a.concatenate(&b) * v).0);
+	assert_close(
+		(b * a).matrix.val(),
+		a.rotate(b.linear()).translate(b.translation()).matrix.val(),
+	);
+	assert_close(
+		a.rotate_rvec(Vec3d::from([0.3, 0.2, 0.1])).matrix.val(),
+		a.rotate(b.linear()).matrix.val(),
+	);
+	let mut c = b;
+	c *= a;
+	assert_eq!(b * a, c);
+
+	let mut singular = a;
+	singular.set_linear(Matx33d::zeros());
+	assert!(singular.inv().is_none());
+}
@@ -1,8 +1,8 @@
 use opencv::{
-	core::{self, Moments, Point2f, RotatedRect, Scalar, Size2f, Vec3b, CV_32S, CV_64F, CV_8U, CV_MAKETYPE},
+	core::{self, Moments, Point2f, Rect, RotatedRect, Scalar, Size2f, Vec3b, CV_32S, CV_64F, CV_8U, CV_MAKETYPE},
 	prelude::*,
 	types::VectorOfMat,
-	Result,
+	Error, ErrorCode, Result,
 };
 
 #[test]
@@ -12,6 +12,36 @@ fn make_type() {
 	assert_eq!(6, CV_MAKETYPE(CV_64F, 1));
 }
 
+#[test]
+fn error_code() {
+	assert_eq!(ErrorCode::StsOutOfRange, ErrorCode::from(core::StsOutOfRange));
+	assert_eq!(ErrorCode::OpenCLNoAMDBlasFft, ErrorCode::from(-223));
+	assert_eq!(ErrorCode::Unknown(-99999), ErrorCode::from(-99999));
+	assert_eq!(core::StsBadArg, i32::from(ErrorCode::StsBadArg));
+	assert_eq!(42, i32::from(ErrorCode::Unknown(42)));
+
+	let err = Error::new(core::StsBadSize, "test");
+	assert_eq!(ErrorCode::StsBadSize, err.error_code());
+	assert_eq!("test", err.message);
+	assert!(err.func().is_empty());
+	assert!(err.file().is_empty());
+	assert_eq!(0, err.line());
+}
+
+#[test]
+fn error_context() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(2, 2, CV_8U, Scalar::all(0.))?;
+	let err = Mat::roi(&mat, Rect::new(1, 1, 5, 5)).unwrap_err();
+	assert_eq!(ErrorCode::StsAssert, err.error_code());
+	assert_eq!(core::StsAssert, err.code);
+	assert!(!err.err().is_empty());
+	assert!(err.message.contains(err.err()));
+	assert!(!err.func().is_empty());
+	assert!(err.file().ends_with(".cpp") || err.file().ends_with(".hpp"));
+	assert!(err.line() > 0);
+	Ok(())
+}
+
 #[test]
 fn moments() -> Result<()> {
 	let moments = Moments::default()?;
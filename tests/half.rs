@@ -0,0 +1,23 @@
+#![cfg(all(feature = "half", not(ocvrs_opencv_branch_32)))]
+
+use half::f16;
+
+use opencv::core::{Mat_, Scalar};
+use opencv::prelude::*;
+use opencv::{core, Result};
+
+#[test]
+fn half_mat() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[f16::from_f32(1.5), f16::from_f32(-2.)], [f16::ZERO, f16::ONE]])?;
+	assert_eq!(core::CV_16F, mat.depth());
+	assert_eq!(1, mat.channels());
+	assert_eq!(f16::from_f32(-2.), *mat.at_2d::<f16>(0, 1)?);
+	*mat.at_2d_mut::<f16>(1, 0)? = f16::from_f32(3.25);
+	let mut mat_f32 = Mat::default();
+	mat.convert_to(&mut mat_f32, core::CV_32F, 1., 0.)?;
+	assert_eq!(&[1.5, -2., 3.25, 1.], mat_f32.data_typed::<f32>()?);
+
+	let typed = Mat_::<f16>::new_rows_cols_with_default(2, 2, Scalar::all(0.5))?;
+	assert!(typed.data_typed()?.iter().all(|&x| x == f16::from_f32(0.5)));
+	Ok(())
+}
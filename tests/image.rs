@@ -0,0 +1,75 @@
+#![cfg(feature = "image")]
+
+use std::convert::TryFrom;
+
+use image::{ImageBuffer, Luma, Rgb, Rgba};
+use matches::assert_matches;
+
+use opencv::core::{BorrowedMat, BorrowedMatMut, ChannelOrder, Rect, Scalar, Vec3b, Vec4w};
+use opencv::prelude::*;
+use opencv::{core, Error, Result};
+
+#[test]
+fn image_data_type() {
+	assert_eq!(core::CV_8UC3, Rgb::<u8>::opencv_type());
+	assert_eq!(core::CV_16UC4, Rgba::<u16>::opencv_type());
+	assert_eq!(core::CV_32FC1, Luma::<f32>::opencv_type());
+}
+
+#[test]
+fn image_to_mat() -> Result<()> {
+	let img = ImageBuffer::from_fn(3, 2, |x, y| Rgb([x as u8, y as u8, 100]));
+	let mat = Mat::try_from(&img)?;
+	assert_eq!(Vec3b::opencv_type(), mat.typ());
+	assert_eq!(2, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert_eq!(Vec3b::from([100, 1, 2]), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	let mat = Mat::from_image_buffer(&img, ChannelOrder::Rgb)?;
+	assert_eq!(Vec3b::from([2, 1, 100]), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	let img = ImageBuffer::from_fn(2, 2, |x, _| Luma([x as u16 * 1000]));
+	let mat = Mat::try_from(img)?;
+	assert_eq!(1000, *mat.at_2d::<u16>(0, 1)?);
+	Ok(())
+}
+
+#[test]
+fn mat_to_image() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(4, 4, Vec4w::opencv_type(), Scalar::all(0.))?;
+	*mat.at_2d_mut::<Vec4w>(2, 3)? = Vec4w::from([1, 2, 3, 4]);
+	let img = ImageBuffer::<Rgba<u16>, _>::try_from(&mat)?;
+	assert_eq!((4, 4), img.dimensions());
+	assert_eq!(&Rgba([3, 2, 1, 4]), img.get_pixel(3, 2));
+
+	let roi = Mat::roi(&mat, Rect::new(2, 1, 2, 2))?;
+	assert!(!roi.is_continuous());
+	let img = roi.to_image_buffer::<Rgba<u16>>(ChannelOrder::Rgb)?;
+	assert_eq!((2, 2), img.dimensions());
+	assert_eq!(&Rgba([1, 2, 3, 4]), img.get_pixel(1, 1));
+
+	assert_matches!(
+		ImageBuffer::<Rgb<u16>, _>::try_from(mat),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn image_borrow() -> Result<()> {
+	let mut img = ImageBuffer::from_pixel(5, 3, Rgb([10u8, 20, 30]));
+	{
+		let mat = BorrowedMat::try_from(&img)?;
+		assert_eq!(img.as_ptr(), mat.data());
+		assert_eq!(Vec3b::from([10, 20, 30]), *mat.at_2d::<Vec3b>(2, 4)?);
+	}
+	{
+		let mut mat = BorrowedMatMut::try_from(&mut img)?;
+		*mat.at_2d_mut::<Vec3b>(1, 1)? = Vec3b::from([1, 2, 3]);
+	}
+	assert_eq!(&Rgb([1, 2, 3]), img.get_pixel(1, 1));
+	Ok(())
+}
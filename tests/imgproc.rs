@@ -1,7 +1,7 @@
 #![cfg(ocvrs_has_module_imgproc)]
 
 use opencv::{
-	core::{Mat_AUTO_STEP, Point, Point2f, Scalar, Size, Vec2f},
+	core::{Bgr8, Bgra8, Gray8, Hsv8, Lab32f, Mat_, Mat_AUTO_STEP, Point, Point2f, Rgb32f, Rgb8, Scalar, Size, Vec2f},
 	imgproc,
 	prelude::*,
 	types::VectorOfPoint,
@@ -58,3 +58,26 @@ fn line_iterator() -> Result<()> {
 	assert_eq!(9, unsafe { *line_iter.try_deref_mut()?.as_ref().unwrap() });
 	Ok(())
 }
+
+#[test]
+fn cvt_color_typed() -> Result<()> {
+	let bgr = Mat_::from_slice_2d(&[[Bgr8::new(255, 0, 0), Bgr8::new(0, 0, 255)]])?;
+	let rgb = imgproc::cvt_color_typed::<_, Rgb8>(&bgr)?;
+	assert_eq!(&[Rgb8::new(0, 0, 255), Rgb8::new(255, 0, 0)], rgb.data_typed()?);
+	let bgra = imgproc::cvt_color_typed::<_, Bgra8>(&bgr)?;
+	assert_eq!(Bgra8::new(0, 0, 255, 255), bgra[(0, 1)]);
+	let gray = imgproc::cvt_color_typed::<_, Gray8>(&bgr)?;
+	assert_eq!(&[Gray8::new(29), Gray8::new(76)], gray.data_typed()?);
+	let hsv = imgproc::cvt_color_typed::<_, Hsv8>(&bgr)?;
+	assert_eq!(&[Hsv8::new(120, 255, 255), Hsv8::new(0, 255, 255)], hsv.data_typed()?);
+	let back = imgproc::cvt_color_typed::<_, Bgr8>(&hsv)?;
+	assert_eq!(bgr.data_typed()?, back.data_typed()?);
+
+	let white = Mat_::from_slice(&[Rgb32f::new(1., 1., 1.)])?;
+	let lab = imgproc::cvt_color_typed::<_, Lab32f>(&white)?;
+	let px = lab[(0, 0)];
+	assert!((px.l - 100.).abs() < 1e-2);
+	assert!(px.a.abs() < 1e-2);
+	assert!(px.b.abs() < 1e-2);
+	Ok(())
+}
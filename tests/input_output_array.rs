@@ -5,7 +5,7 @@ use opencv::core::AccessFlag::ACCESS_READ;
 #[cfg(not(ocvrs_opencv_branch_4))]
 use opencv::core::ACCESS_READ;
 use opencv::{
-	core::{self, Matx12d, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray, UMat, UMatUsageFlags, Vec2b, VecN},
+	core::{self, Matx12d, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray, UMat, UMatUsageFlags, Vec2b, VecArray, VecN},
 	prelude::*,
 	types::{VectorOff64, VectorOfu8},
 	Result,
@@ -116,3 +116,52 @@ fn no_array() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+fn input_output_array_rust_containers() -> Result<()> {
+	let slice: &[f64] = &[1., 2., 3.];
+	let arr = [4., 5., 6.];
+	let vec = vec![7., 8., 9.];
+	assert!(slice.input_array()?.is_matx()?);
+	assert_eq!(Scalar::from(6.), core::sum_elems(&slice)?);
+	assert_eq!(Scalar::from(15.), core::sum_elems(&arr)?);
+	assert_eq!(Scalar::from(24.), core::sum_elems(&vec)?);
+
+	{
+		let mut out = Vec::<f64>::new();
+		let mut arr_out = VecArray::new(&mut out)?;
+		core::add(&slice, &arr, &mut arr_out, &core::no_array(), -1)?;
+		arr_out.finish()?;
+		assert_eq!(vec![5., 7., 9.], out);
+	}
+
+	{
+		let mut out = vec![0.; 3];
+		let data = out.as_ptr();
+		core::add(&arr, &vec, &mut VecArray::new(&mut out)?, &core::no_array(), -1)?;
+		assert_eq!(vec![11., 13., 15.], out);
+		assert_eq!(data, out.as_ptr());
+	}
+
+	{
+		let mut out = vec![0.; 10];
+		core::transpose(&vec, &mut &mut VecArray::new(&mut out)?)?;
+		assert_eq!(vec![7., 8., 9.], out);
+	}
+
+	{
+		let mut out = vec![5.; 3];
+		core::set_identity(&mut VecArray::new(&mut out)?, Scalar::all(2.))?;
+		assert_eq!(vec![2., 0., 0.], out);
+	}
+
+	{
+		let mut out = Vec::<u8>::new();
+		let mut arr_out = VecArray::new(&mut out)?;
+		core::add(&slice, &arr, &mut arr_out, &core::no_array(), -1)?;
+		arr_out.finish()?;
+		assert_eq!(vec![5, 7, 9], out);
+	}
+
+	Ok(())
+}
@@ -2,7 +2,10 @@ use std::ffi::c_void;
 
 use matches::assert_matches;
 
-use opencv::core::{MatConstIterator, Point, Point2d, Rect, Scalar, Size, Vec2b, Vec2s, Vec3d, Vec3f, Vec4w};
+use opencv::core::{
+	ElemAbs, ElemCmp, ElemMax, ElemMin, MatConstIterator, Mat_, Point, Point2d, Range, Rect, Scalar, Size, Vec2b, Vec2s, Vec3b,
+	Vec3d, Vec3f, Vec4w,
+};
 use opencv::prelude::*;
 use opencv::types::{VectorOfMat, VectorOfi32};
 use opencv::{core, Error, Result};
@@ -748,6 +751,66 @@ fn mat_convert() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_typed() -> Result<()> {
+	let mut mat = Mat_::<i32>::from_slice_2d(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	assert_eq!(i32::opencv_type(), mat.typ());
+	assert_eq!(6, *mat.at_2d(1, 2)?);
+	assert_eq!(8, *mat.at_pt(Point::new(1, 2))?);
+	assert_eq!(&[4, 5, 6], mat.at_row(1)?);
+	assert_matches!(
+		mat.at_2d(3, 0),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	assert_eq!(5, mat[(1, 1)]);
+	mat[(1, 1)] = 50;
+	*mat.at_2d_mut(0, 0)? = 10;
+	mat.at_row_mut(2)?[2] = 90;
+	assert_eq!(&[10, 2, 3, 4, 50, 6, 7, 8, 90], mat.data_typed()?);
+
+	let roi = mat.roi(Rect::new(1, 1, 2, 2))?;
+	assert_eq!(Size::new(2, 2), roi.size()?);
+	assert_eq!(vec![&[50, 6][..], &[8, 90][..]], roi.row_iter()?.collect::<Vec<_>>());
+	assert_eq!(
+		&[2, 50, 8],
+		mat.col(1)?.iter()?.map(|(_, x)| x).collect::<Vec<_>>().as_slice()
+	);
+	let mut row = mat.row(0)?;
+	row.iter_mut()?.for_each(|(pos, x)| *x = pos.x);
+	assert_eq!(&[0, 1, 2], mat.at_row(0)?);
+
+	let mut cloned = mat.clone();
+	cloned[(2, 2)] = -1;
+	assert_eq!(90, mat[(2, 2)]);
+	let mat2 = mat.try_clone()?;
+	assert_eq!(mat.data_typed()?, mat2.data_typed()?);
+
+	let zeros = Mat_::<f32>::zeros(2, 3)?;
+	assert_eq!(Size::new(3, 2), zeros.size()?);
+	assert!(zeros.data_typed()?.iter().all(|&x| x == 0.));
+	let ones = Mat_::<u8>::ones(2, 2)?;
+	assert_eq!(&[1, 1, 1, 1], ones.data_typed()?);
+	let eye = Mat_::<f64>::eye(2, 2)?;
+	assert_eq!(&[1., 0., 0., 1.], eye.data_typed()?);
+	let mut filled = Mat_::<Vec3b>::new_rows_cols_with_default(2, 2, Scalar::new(1., 2., 3., 0.))?;
+	assert_eq!(Vec3b::from([1, 2, 3]), filled[(1, 1)]);
+	filled.row_iter_mut()?.for_each(|row| row[0] = Vec3b::all(7));
+	assert_eq!(Vec3b::all(7), filled[(1, 0)]);
+	let vec = Mat_::from_slice(&[1u16, 2, 3])?;
+	assert_eq!(Size::new(3, 1), vec.size()?);
+	Ok(())
+}
+
+#[test]
+#[should_panic]
+fn mat_typed_index_out_of_bounds() {
+	let mat = Mat_::<u8>::zeros(2, 2).unwrap();
+	let _ = mat[(2, 0)];
+}
+
 #[test]
 fn mat_mul() -> Result<()> {
 	{
@@ -779,6 +842,103 @@ fn mat_mul() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_ops_bitwise_cmp() -> Result<()> {
+	let a = Mat::from_slice(&[0b1100u8, 0b1010, 0xff])?;
+	let b = Mat::from_slice(&[0b1010u8, 0b0110, 0x0f])?;
+	let res = (&a & &b).into_result()?.to_mat()?;
+	assert_eq!(&[0b1000, 0b0010, 0x0f], res.data_typed::<u8>()?);
+	let res = (&a | &b).into_result()?.to_mat()?;
+	assert_eq!(&[0b1110, 0b1110, 0xff], res.data_typed::<u8>()?);
+	let res = (&a ^ Scalar::all(1.)).into_result()?.to_mat()?;
+	assert_eq!(&[0b1101, 0b1011, 0xfe], res.data_typed::<u8>()?);
+	let res = (!&a).into_result()?.to_mat()?;
+	assert_eq!(&[0b1111_0011, 0b1111_0101, 0], res.data_typed::<u8>()?);
+
+	let c = Mat::from_slice(&[-1f32, 2., -3.])?;
+	let res = (-&c).into_result()?.to_mat()?;
+	assert_eq!(&[1., -2., 3.], res.data_typed::<f32>()?);
+	let res = (-(&c + &c)).into_result()?.to_mat()?;
+	assert_eq!(&[2., -4., 6.], res.data_typed::<f32>()?);
+	let res = (&c).elem_abs().into_result()?.to_mat()?;
+	assert_eq!(&[1., 2., 3.], res.data_typed::<f32>()?);
+	let res = (&c).elem_min(0.).into_result()?.to_mat()?;
+	assert_eq!(&[-1., 0., -3.], res.data_typed::<f32>()?);
+	let res = (&c)
+		.elem_max(&res.elem_abs().into_result()?.to_mat()?)
+		.into_result()?
+		.to_mat()?;
+	assert_eq!(&[1., 2., 3.], res.data_typed::<f32>()?);
+
+	let mask = (&c).lt_mask(0.).into_result()?.to_mat()?;
+	assert_eq!(u8::opencv_type(), mask.typ());
+	assert_eq!(&[255, 0, 255], mask.data_typed::<u8>()?);
+	let mask = (&a).ge_mask(&b).into_result()?.to_mat()?;
+	assert_eq!(&[255, 255, 255], mask.data_typed::<u8>()?);
+	let mask = (&a).eq_mask(0xff as f64).into_result()?.to_mat()?;
+	assert_eq!(&[0, 0, 255], mask.data_typed::<u8>()?);
+	let mask = 2f64.gt_mask(&c).into_result()?.to_mat()?;
+	assert_eq!(&[255, 0, 255], mask.data_typed::<u8>()?);
+	Ok(())
+}
+
+#[test]
+fn mat_ops_assign() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1f64, 2.], [3., 4.]])?;
+	let data = mat.data();
+	let other = Mat::from_slice_2d(&[[1f64, 1.], [1., 1.]])?;
+	mat += &other;
+	assert_eq!(&[2., 3., 4., 5.], mat.data_typed::<f64>()?);
+	mat -= Scalar::all(2.);
+	assert_eq!(&[0., 1., 2., 3.], mat.data_typed::<f64>()?);
+	mat *= 2.;
+	assert_eq!(&[0., 2., 4., 6.], mat.data_typed::<f64>()?);
+	mat /= 2.;
+	assert_eq!(&[0., 1., 2., 3.], mat.data_typed::<f64>()?);
+	mat += (&other * 3.).into_result()?;
+	assert_eq!(&[3., 4., 5., 6.], mat.data_typed::<f64>()?);
+	mat /= &other;
+	assert_eq!(&[3., 4., 5., 6.], mat.data_typed::<f64>()?);
+	// in-place operations reuse the existing buffer
+	assert_eq!(data, mat.data());
+
+	mat *= Mat::from_slice_2d(&[[0f64, 1.], [1., 0.]])?;
+	assert_eq!(&[4., 3., 6., 5.], mat.data_typed::<f64>()?);
+	Ok(())
+}
+
+#[test]
+fn mat_ops_eval() -> Result<()> {
+	let a = Mat::from_slice(&[1f32, 2., 3.])?;
+	let b = Mat::from_slice(&[4f32, 5., 6.])?;
+	let sum = (&a + &b).eval()?;
+	assert_eq!(&[5., 7., 9.], sum.data_typed::<f32>()?);
+	let diff = Mat::try_from(&b - &a)?;
+	assert_eq!(&[3., 3., 3.], diff.data_typed::<f32>()?);
+	let scaled: Result<core::MatExpr> = (&a * 2.).into();
+	assert_eq!(&[2., 4., 6.], scaled?.eval()?.data_typed::<f32>()?);
+
+	let mut dst = Mat::new_rows_cols_with_default(1, 3, f32::opencv_type(), Scalar::all(0.))?;
+	let data = dst.data();
+	(&a + &b * 2.).eval_into(&mut dst)?;
+	assert_eq!(&[9., 12., 15.], dst.data_typed::<f32>()?);
+	core::sub_mat_mat(&b, &a)?.eval_into(&mut dst)?;
+	assert_eq!(&[3., 3., 3.], dst.data_typed::<f32>()?);
+	assert_eq!(data, dst.data());
+
+	core::mul_mat_f64(&a, 2.)?.assign_to(&mut dst, core::CV_8U)?;
+	assert_eq!(u8::opencv_type(), dst.typ());
+	assert_eq!(&[2, 4, 6], dst.data_typed::<u8>()?);
+
+	let mut empty = Mat::default();
+	(&a - Scalar::all(1.)).eval_into(&mut empty)?;
+	assert_eq!(&[0., 1., 2.], empty.data_typed::<f32>()?);
+
+	let short = Mat::from_slice(&[1f32])?;
+	assert!((&a + &short).eval().is_err());
+	Ok(())
+}
+
 #[test]
 fn mat_data() -> Result<()> {
 	{
@@ -909,3 +1069,392 @@ fn mat_custom_data_type() -> Result<()> {
 	assert!(data.iter().all(|el| el.a == -10 && el.b == 20));
 	Ok(())
 }
+
+#[test]
+fn mat_view() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1u16, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])?;
+	let view = mat.view::<u16>()?;
+	assert_eq!(3, view.rows());
+	assert_eq!(&[5, 6, 7, 8], view.at_row(1)?);
+
+	let view = mat.view_rows::<u16>(1, 3)?;
+	assert_eq!(2, view.rows());
+	assert_eq!(
+		vec![&[5, 6, 7, 8][..], &[9, 10, 11, 12]],
+		view.row_iter()?.collect::<Vec<_>>()
+	);
+
+	let view = mat.view_cols::<u16>(2, 4)?;
+	assert_eq!(2, view.cols());
+	assert_eq!(vec![&[3, 4][..], &[7, 8], &[11, 12]], view.row_iter()?.collect::<Vec<_>>());
+
+	let view = mat.view_rows_cols::<u16>(&Range::new(0, 2)?, &Range::new(1, 2)?)?;
+	assert_eq!(Size::new(1, 2), view.size()?);
+	assert_eq!(6, *view.at_2d(1, 0)?);
+
+	let view = mat.view_rect::<u16>(Rect::new(1, 1, 3, 2))?;
+	assert!(!view.is_continuous());
+	assert_eq!(vec![&[6, 7, 8][..], &[10, 11, 12]], view.row_iter()?.collect::<Vec<_>>());
+	let sub_view = view.view_cols(1, 2)?;
+	assert_eq!(&[11], sub_view.at_row(1)?);
+
+	assert_matches!(
+		mat.view::<u8>(),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	assert!(mat.view_rect::<u16>(Rect::new(2, 2, 3, 3)).is_err());
+	assert_eq!(0, Mat::default().view::<u8>()?.row_iter()?.len());
+	Ok(())
+}
+
+#[test]
+fn mat_view_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(4, 3, i32::opencv_type(), Scalar::all(0.))?;
+	{
+		let mut view = mat.view_rect_mut::<i32>(Rect::new(1, 1, 2, 2))?;
+		view.at_row_mut(0)?.fill(1);
+		for row in view.row_iter_mut()? {
+			row[1] += 2;
+		}
+	}
+	assert_eq!(&[0, 0, 0], mat.at_row::<i32>(0)?);
+	assert_eq!(&[0, 1, 3], mat.at_row::<i32>(1)?);
+	assert_eq!(&[0, 0, 2], mat.at_row::<i32>(2)?);
+
+	{
+		let (mut top, mut bottom) = mat.split_rows_mut::<i32>(1)?;
+		assert_eq!(1, top.rows());
+		assert_eq!(3, bottom.rows());
+		top.set(Scalar::all(5.))?;
+		let (mut left, mut right) = bottom.split_cols_mut(2)?;
+		left.at_row_mut(2)?.fill(7);
+		right.at_row_mut(2)?.fill(8);
+	}
+	assert_eq!(&[5, 5, 5], mat.at_row::<i32>(0)?);
+	assert_eq!(&[7, 7, 8], mat.at_row::<i32>(3)?);
+
+	assert_matches!(
+		mat.split_rows_mut::<i32>(5),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_view_channels() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::opencv_type(), Scalar::new(1., 2., 3., 0.))?;
+	{
+		let view = mat.view_channels::<u8>(1, 3)?;
+		assert_eq!(3, view.dims());
+		assert_eq!(&[2, 3, 2], &*view.mat_size());
+		assert_eq!(2, *view.at_3d(1, 2, 0)?);
+		assert_eq!(3, *view.at_3d(1, 2, 1)?);
+	}
+	{
+		let mut view = mat.view_channels_mut::<u8>(2, 3)?;
+		*view.at_3d_mut(0, 1, 0)? = 10;
+	}
+	assert_eq!(Vec3b::from([1, 2, 10]), *mat.at_2d::<Vec3b>(0, 1)?);
+	assert_eq!(Vec3b::from([1, 2, 3]), *mat.at_2d::<Vec3b>(0, 0)?);
+
+	assert_matches!(
+		mat.view_channels::<u8>(2, 4),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	assert_matches!(
+		mat.view_channels::<u16>(0, 1),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_row_iter() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1u8, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	assert_eq!(
+		vec![&[1, 2, 3][..], &[4, 5, 6], &[7, 8, 9]],
+		mat.row_iter::<u8>()?.collect::<Vec<_>>()
+	);
+	assert_eq!(Some(&[7, 8, 9][..]), mat.row_iter::<u8>()?.next_back());
+	assert_eq!(3, mat.row_iter::<u8>()?.len());
+	assert_matches!(
+		mat.row_iter::<u16>(),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+
+	let mut roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous());
+	assert_eq!(vec![&[5, 6][..], &[8, 9]], roi.row_iter::<u8>()?.collect::<Vec<_>>());
+	for (i, row) in roi.row_iter_mut::<u8>()?.enumerate() {
+		row[0] = i as u8 * 10;
+	}
+	assert_eq!(&[4, 0, 6], mat.at_row::<u8>(1)?);
+	assert_eq!(&[7, 10, 9], mat.at_row::<u8>(2)?);
+
+	for row in mat.row_iter_mut::<u8>()? {
+		row.reverse();
+	}
+	assert_eq!(&[3, 2, 1], mat.at_row::<u8>(0)?);
+
+	assert_eq!(0, Mat::default().row_iter::<u8>()?.count());
+	let mat = Mat::new_nd_with_default(&[2, 2, 2], u8::opencv_type(), Scalar::all(0.))?;
+	assert_matches!(
+		mat.row_iter::<u8>(),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_iter_fast() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous());
+	let iter = roi.iter::<u16>()?;
+	assert_eq!(4, iter.len());
+	assert_eq!(
+		vec![
+			(Point::new(0, 0), 5),
+			(Point::new(1, 0), 6),
+			(Point::new(0, 1), 8),
+			(Point::new(1, 1), 9)
+		],
+		iter.collect::<Vec<_>>()
+	);
+	assert_matches!(
+		mat.iter::<f32>(),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+
+	for (pos, x) in mat.iter_mut::<u16>()? {
+		*x += (pos.y * 10) as u16;
+	}
+	assert_eq!(&[1, 2, 3], mat.at_row::<u16>(0)?);
+	assert_eq!(&[27, 28, 29], mat.at_row::<u16>(2)?);
+	Ok(())
+}
+
+#[test]
+fn mat_iter_nd() -> Result<()> {
+	let mut mat = Mat::new_nd_with_default(&[2, 3, 4], i32::opencv_type(), Scalar::all(0.))?;
+	for (idx, x) in mat.iter_nd_mut::<i32, 3>()? {
+		*x = idx[0] * 100 + idx[1] * 10 + idx[2];
+	}
+	assert_eq!(123, *mat.at_3d::<i32>(1, 2, 3)?);
+	assert_eq!(12, *mat.at_3d::<i32>(0, 1, 2)?);
+
+	let items = mat.iter_nd::<i32, 3>()?.collect::<Vec<_>>();
+	assert_eq!(24, items.len());
+	assert_eq!(([0, 0, 0], 0), items[0]);
+	assert_eq!(([0, 1, 0], 10), items[4]);
+	assert_eq!(([1, 2, 3], 123), items[23]);
+
+	let (pos, x) = mat.iter::<i32>()?.nth(13).unwrap();
+	assert_eq!(Point::new(1, 1), pos);
+	assert_eq!(101, x);
+
+	assert_matches!(
+		mat.iter_nd::<i32, 2>(),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+	assert_eq!(0, Mat::default().iter_nd::<u8, 2>()?.count());
+	Ok(())
+}
+
+#[test]
+fn mat_header() -> Result<()> {
+	let empty = Mat::default();
+	assert!(empty.empty());
+	assert_eq!(0, empty.total());
+	assert_eq!(0, empty.rows());
+	assert_eq!(0, empty.cols());
+	assert!(empty.data().is_null());
+	assert_eq!(Size::new(0, 0), empty.size()?);
+
+	let mut mat = Mat::new_rows_cols_with_default(4, 5, Vec3b::opencv_type(), Scalar::all(1.))?;
+	assert_eq!(Vec3b::opencv_type(), mat.typ());
+	assert_eq!(u8::opencv_depth(), mat.depth());
+	assert_eq!(3, mat.channels());
+	assert_eq!(2, mat.dims());
+	assert_eq!(4, mat.rows());
+	assert_eq!(5, mat.cols());
+	assert_eq!(Size::new(5, 4), mat.size()?);
+	assert_eq!(20, mat.total());
+	assert!(!mat.empty());
+	assert!(mat.is_continuous());
+	assert_eq!(mat.typ(), mat.flags() & core::Mat_TYPE_MASK);
+	assert_eq!(&[4, 5], &*mat.mat_size());
+	assert_eq!(&[15, 3], &*mat.mat_step());
+	assert_eq!(mat.data(), mat.data_mut() as *const u8);
+	assert_eq!(mat.data(), mat.ptr(0)?);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 3, 2))?;
+	assert_eq!(2, roi.rows());
+	assert_eq!(3, roi.cols());
+	assert_eq!(Size::new(3, 2), roi.size()?);
+	assert_eq!(6, roi.total());
+	assert!(!roi.is_continuous());
+	assert_eq!(&[15, 3], &*roi.mat_step());
+	assert_eq!(mat.ptr_2d(1, 1)?, roi.data());
+
+	let nd = Mat::new_nd_with_default(&[2, 3, 4], f32::opencv_type(), Scalar::all(0.))?;
+	assert_eq!(3, nd.dims());
+	assert_eq!(-1, nd.rows());
+	assert_eq!(-1, nd.cols());
+	assert_eq!(Size::new(3, 2), nd.size()?);
+	assert_eq!(24, nd.total());
+	assert_eq!(&[2, 3, 4], &*nd.mat_size());
+	assert_eq!(&[48, 16], &*nd.mat_step());
+	assert_eq!(nd.total(), nd.mat_size().iter().product::<i32>() as usize);
+	Ok(())
+}
+
+#[test]
+fn mat_uninit() -> Result<()> {
+	let mat = Mat_::<i32>::new_uninit(3, 4)?.init_from_fn(|row, col| row * 10 + col);
+	assert_eq!(Size::new(4, 3), mat.size()?);
+	assert_eq!(0, *mat.at_2d(0, 0)?);
+	assert_eq!(23, *mat.at_2d(2, 3)?);
+	assert_eq!(&[10, 11, 12, 13], mat.at_row(1)?);
+
+	let mut uninit = Mat_::<Vec2s>::new_uninit(2, 2)?;
+	assert_eq!(4, uninit.as_uninit_slice_mut().len());
+	uninit.write_row(0, &[Vec2s::from([1, 2]), Vec2s::from([3, 4])])?;
+	uninit.write_row(1, &[Vec2s::from([5, 6]), Vec2s::from([7, 8])])?;
+	assert_matches!(
+		uninit.write_row(2, &[Vec2s::default(), Vec2s::default()]),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	assert_matches!(
+		uninit.write_row(0, &[Vec2s::default()]),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+	let mat = unsafe { uninit.assume_init() };
+	assert_eq!(Vec2s::from([7, 8]), mat[(1, 1)]);
+	assert_eq!(Vec2s::from([3, 4]), mat[(0, 1)]);
+
+	let empty = Mat_::<u8>::new_uninit(0, 0)?;
+	assert!(empty.init_from_fn(|_, _| unreachable!()).empty());
+	Ok(())
+}
+
+#[test]
+fn mat_from_vec() -> Result<()> {
+	let v = (0..12).map(|x| x as f32).collect::<Vec<_>>();
+	let ptr = v.as_ptr();
+	let mat = Mat::from_vec(v, 3, 4)?;
+	assert_eq!(Size::new(4, 3), mat.size()?);
+	assert_eq!(f32::opencv_type(), mat.typ());
+	assert_eq!(ptr as *const u8, mat.data());
+	assert_eq!(6., *mat.at_2d::<f32>(1, 2)?);
+	assert_matches!(
+		Mat::from_vec(vec![1u8; 5], 2, 3),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+
+	// sole owner, no copy
+	let mat = Mat_::<f32>::try_from(mat)?;
+	let v = mat.into_vec()?;
+	assert_eq!(ptr, v.as_ptr());
+	assert_eq!(11., v[11]);
+
+	// shared buffer is copied, the original stays valid
+	let mut mat = Mat_::from_vec(v, 2, 6)?;
+	let shared = Mat::copy(mat.as_untyped())?;
+	let copy = mat.try_clone()?.into_vec()?;
+	assert_eq!(12, copy.len());
+	let copy = Mat_::<f32>::try_from(shared)?.into_vec()?;
+	assert_ne!(ptr, copy.as_ptr());
+	*mat.at_2d_mut(1, 5)? = 100.;
+	assert_eq!(100., *mat.at_2d(1, 5)?);
+
+	// part of the buffer is copied
+	let roi = mat.roi(Rect::new(1, 0, 2, 2))?;
+	drop(mat);
+	assert_eq!(vec![1., 2., 7., 8.], roi.into_vec()?);
+
+	let mat = Mat_::from_vec(vec![Vec3b::from([1, 2, 3]); 4], 2, 2)?;
+	let v = mat.into_vec()?;
+	assert_eq!(4, v.len());
+	assert_eq!(Vec3b::from([1, 2, 3]), v[3]);
+	Ok(())
+}
+
+#[test]
+fn mat_borrow_slice() -> Result<()> {
+	let src = [1u8, 2, 3, 4, 5, 6];
+	let mut dst = [0u8; 6];
+	let dst_ptr = dst.as_ptr();
+	{
+		let src_mat = Mat::borrow_slice(&src, 2, 3)?;
+		assert_eq!(Size::new(3, 2), src_mat.size()?);
+		assert_eq!(src.as_ptr(), src_mat.data());
+		let mut dst_mat = Mat::borrow_slice_mut(&mut dst, 2, 3)?;
+		core::add(&src_mat, &src_mat, &mut dst_mat, &core::no_array(), -1)?;
+		assert_eq!(dst_ptr, dst_mat.data());
+		core::bitwise_not(&src_mat, &mut dst_mat, &core::no_array())?;
+	}
+	assert_eq!([254, 253, 252, 251, 250, 249], dst);
+
+	{
+		let src_mat = Mat::borrow_slice(&src, 2, 3)?;
+		let mut dst_mat = Mat::borrow_slice_mut(&mut dst, 3, 2)?;
+		assert_matches!(
+			core::add(&src_mat, &src_mat, &mut dst_mat, &core::no_array(), -1),
+			Err(Error {
+				code: core::StsAssert,
+				..
+			})
+		);
+		assert_eq!(dst_ptr, dst_mat.data());
+	}
+
+	assert_matches!(
+		Mat::borrow_slice(&src, 2, 2),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+	assert_matches!(
+		Mat::borrow_slice_mut(&mut dst, 4, 2),
+		Err(Error {
+			code: core::StsUnmatchedSizes,
+			..
+		})
+	);
+	Ok(())
+}
@@ -0,0 +1,68 @@
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use opencv::core::{GlobalMatAllocator, MatAllocator, MatAllocatorImpl, Scalar, MAT_ALLOC_ALIGN};
+use opencv::prelude::*;
+use opencv::Result;
+
+#[derive(Default)]
+struct CountingAllocator {
+	allocations: AtomicUsize,
+	deallocations: AtomicUsize,
+}
+
+impl MatAllocatorImpl for &'static CountingAllocator {
+	fn allocate(&self, layout: Layout) -> *mut u8 {
+		assert_eq!(MAT_ALLOC_ALIGN, layout.align());
+		self.allocations.fetch_add(1, Ordering::Relaxed);
+		let out = GlobalMatAllocator.allocate(layout);
+		assert_eq!(0, out as usize % MAT_ALLOC_ALIGN);
+		out
+	}
+
+	unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout) {
+		self.deallocations.fetch_add(1, Ordering::Relaxed);
+		GlobalMatAllocator.deallocate(ptr, layout)
+	}
+}
+
+/// Single test because the default allocator is global and would interfere with the concurrently running tests
+#[test]
+fn mat_allocator() -> Result<()> {
+	let counter: &'static CountingAllocator = Box::leak(Box::default());
+	let allocator = MatAllocator::new(counter)?;
+	assert_eq!(0, allocator.bytes_in_use());
+
+	// per-Mat allocator
+	{
+		let mat = Mat::new_rows_cols_with_allocator(10, 20, f32::opencv_type(), Scalar::all(3.), &allocator)?;
+		assert_eq!(10 * 20 * 4, allocator.bytes_in_use());
+		assert_eq!(1, counter.allocations.load(Ordering::Relaxed));
+		assert_eq!(3., *mat.at_2d::<f32>(9, 19)?);
+		let shallow = Mat::copy(&mat)?;
+		drop(mat);
+		assert_eq!(10 * 20 * 4, allocator.bytes_in_use());
+		let deep = shallow.try_clone()?;
+		assert_eq!(1, counter.allocations.load(Ordering::Relaxed));
+		drop(deep);
+	}
+	assert_eq!(0, allocator.bytes_in_use());
+	assert_eq!(10 * 20 * 4, allocator.peak());
+	assert_eq!(1, counter.deallocations.load(Ordering::Relaxed));
+
+	// default allocator
+	allocator.set_default();
+	let a = Mat::new_rows_cols_with_default(100, 100, u8::opencv_type(), Scalar::all(1.))?;
+	let b = Mat::new_rows_cols_with_default(50, 50, u16::opencv_type(), Scalar::all(1.))?;
+	MatAllocator::reset_default();
+	let c = Mat::new_rows_cols_with_default(100, 100, u8::opencv_type(), Scalar::all(1.))?;
+	assert_eq!(100 * 100 + 50 * 50 * 2, allocator.bytes_in_use());
+	assert_eq!(100 * 100 + 50 * 50 * 2, allocator.peak());
+	drop(a);
+	drop(b);
+	drop(c);
+	assert_eq!(0, allocator.bytes_in_use());
+	assert_eq!(3, counter.allocations.load(Ordering::Relaxed));
+	assert_eq!(3, counter.deallocations.load(Ordering::Relaxed));
+	Ok(())
+}
@@ -1,7 +1,7 @@
 use matches::assert_matches;
 
 use opencv::core;
-use opencv::core::{Matx22d, Matx23f, Matx32f, Matx33d, Matx66f, Scalar};
+use opencv::core::{Matx22d, Matx22f, Matx23f, Matx32f, Matx33d, Matx33f, Matx66f, Scalar, Vec3d};
 use opencv::prelude::*;
 use opencv::Result;
 
@@ -94,3 +94,50 @@ fn matx_all() -> Result<()> {
 	assert_eq!(mat[(3, 4)], 81.);
 	Ok(())
 }
+
+#[test]
+fn matx_arithmetic() {
+	let a = Matx23f::from_array([1., 2., 3., 4., 5., 6.]);
+	let b = Matx32f::from_array([1., 0., 0., 1., 1., 1.]);
+	let c: Matx22f = a * b;
+	assert_eq!(Matx22f::from_array([4., 5., 10., 11.]), c);
+	assert_eq!(b, a.t() - a.t() + b);
+	assert_eq!(Matx23f::from_array([2., 4., 6., 8., 10., 12.]), a + a);
+	assert_eq!(a * 2., -a * -2.);
+	assert_eq!(Matx23f::from_array([0.5, 1., 1.5, 2., 2.5, 3.]), a / 2.);
+	assert_eq!(Matx23f::from_array([1., 4., 9., 16., 25., 36.]), a.mul(a));
+	assert_eq!(Matx23f::all(1.), a.div(a));
+	assert_eq!(91., a.dot(&a));
+	assert_eq!(91., a.ddot(&a));
+
+	let m = Matx33d::from_array([2., 1., 1., 1., 3., 2., 1., 0., 0.]);
+	assert_eq!(Vec3d::from([7., 13., 1.]), m * Vec3d::from([1., 2., 3.]));
+	assert_eq!(5., m.trace());
+	assert_eq!(Vec3d::from([2., 3., 0.]), m.diag());
+	assert_eq!(Matx33d::eye(), Matx33d::from_diag(Vec3d::all(1.)));
+}
+
+#[test]
+fn matx_linalg() -> Result<()> {
+	let m = Matx33d::from_array([2., 1., 1., 1., 3., 2., 1., 0., 0.]);
+	assert!((m.det() + 1.).abs() < 1e-12);
+	let inv = m.inv().unwrap();
+	assert!((m * inv - Matx33d::eye()).val.iter().all(|x| x.abs() < 1e-12));
+	let rhs = Vec3d::from([4., 5., 6.]);
+	let x = m.solve_vec(rhs).unwrap();
+	assert!((m * x).iter().zip(rhs.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+	let rhs = Matx32f::from_array([1., 2., 3., 4., 5., 6.]);
+	let x = Matx33f::from_array([2., 1., 1., 1., 3., 2., 1., 0., 0.]).solve(&rhs).unwrap();
+	assert!((Matx33f::from_array([2., 1., 1., 1., 3., 2., 1., 0., 0.]) * x - rhs)
+		.val
+		.iter()
+		.all(|x| x.abs() < 1e-5));
+
+	let singular = Matx22d::all(1.);
+	assert_eq!(0., singular.det());
+	assert!(singular.inv().is_none());
+
+	let r = Matx66f::randu(-1., 1.)?;
+	assert!(r.val.iter().all(|&x| (-1. ..1.).contains(&x)));
+	Ok(())
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "ndarray")]
+
+use matches::assert_matches;
+use ndarray::{s, Array2, Array3, Axis};
+
+use opencv::core::{Rect, Scalar, Vec3b};
+use opencv::prelude::*;
+use opencv::{core, Error, Result};
+
+#[test]
+fn mat_as_array_view() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	let view = mat.as_array_view_2d::<u16>()?;
+	assert_eq!(&[3, 3], view.shape());
+	assert_eq!(5, view[(1, 1)]);
+	assert_eq!(8, view[(2, 1)]);
+	assert_matches!(
+		mat.as_array_view_2d::<f32>(),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous());
+	let view = roi.as_array_view_2d::<u16>()?;
+	assert_eq!(&[2, 2], view.shape());
+	assert_eq!(&[3, 1], view.strides());
+	assert_eq!(vec![5, 6, 8, 9], view.iter().copied().collect::<Vec<_>>());
+
+	let empty = Mat::default();
+	assert_eq!(0, empty.as_array_view_2d::<u8>()?.len());
+	Ok(())
+}
+
+#[test]
+fn mat_as_array_view_channels() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::opencv_type(), Scalar::new(1., 2., 3., 0.))?;
+	let view = mat.as_array_view::<u8>()?;
+	assert_eq!(&[2, 3, 3], view.shape());
+	assert_eq!(
+		vec![1, 2, 3],
+		view
+			.index_axis(Axis(0), 1)
+			.index_axis(Axis(0), 2)
+			.iter()
+			.copied()
+			.collect::<Vec<_>>()
+	);
+
+	let view = mat.as_array_view::<Vec3b>()?;
+	assert_eq!(&[2, 3], view.shape());
+	assert_eq!(Vec3b::from([1, 2, 3]), view[[0, 0]]);
+
+	let mat = Mat::new_nd_with_default(&[2, 3, 4], f32::opencv_type(), Scalar::all(0.))?;
+	assert_eq!(&[2, 3, 4], mat.as_array_view::<f32>()?.shape());
+	Ok(())
+}
+
+#[test]
+fn mat_as_array_view_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(3, 4, i32::opencv_type(), Scalar::all(0.))?;
+	{
+		let mut view = mat.as_array_view_2d_mut::<i32>()?;
+		view.slice_mut(s![1.., ..2]).fill(7);
+	}
+	assert_eq!(0, *mat.at_2d::<i32>(0, 0)?);
+	assert_eq!(7, *mat.at_2d::<i32>(1, 1)?);
+	assert_eq!(7, *mat.at_2d::<i32>(2, 0)?);
+	assert_eq!(0, *mat.at_2d::<i32>(2, 2)?);
+
+	let mut typed = mat.try_into_typed::<i32>()?;
+	typed.as_array_view_mut()?[[0, 3]] = 9;
+	assert_eq!(9, *typed.at_2d::<i32>(0, 3)?);
+	Ok(())
+}
+
+#[test]
+fn mat_from_array_view() -> Result<()> {
+	let arr = Array2::from_shape_fn((3, 4), |(r, c)| (r * 4 + c) as f64);
+	let mat = Mat::from_array_view(arr.view())?;
+	assert_eq!(f64::opencv_type(), mat.typ());
+	assert_eq!(3, mat.rows());
+	assert_eq!(4, mat.cols());
+	assert_eq!(arr.as_ptr() as *const u8, mat.data());
+	assert_eq!(6., *mat.at_2d::<f64>(1, 2)?);
+
+	let sliced = arr.slice(s![1.., 1..]);
+	let mat = Mat::from_array_view(sliced)?;
+	assert_eq!(2, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert!(!mat.is_continuous());
+	assert_eq!(5., *mat.at_2d::<f64>(0, 0)?);
+	assert_eq!(11., *mat.at_2d::<f64>(1, 2)?);
+
+	assert_matches!(
+		Mat::from_array_view(arr.t()),
+		Err(Error {
+			code: core::StsBadArg,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_from_array_view_channels() -> Result<()> {
+	let mut arr = Array3::<u8>::zeros((2, 3, 3));
+	{
+		let mut mat = Mat::from_array_view_with_channels_mut(arr.view_mut())?;
+		assert_eq!(Vec3b::opencv_type(), mat.typ());
+		assert_eq!(2, mat.dims());
+		*mat.at_2d_mut::<Vec3b>(1, 2)? = Vec3b::from([10, 20, 30]);
+	}
+	assert_eq!(vec![10, 20, 30], arr.slice(s![1, 2, ..]).to_vec());
+	Ok(())
+}
@@ -0,0 +1,150 @@
+use std::io::Cursor;
+
+use matches::assert_matches;
+
+use opencv::core::{NpzReader, NpzWriter, Rect, Scalar, Vec3s, CV_16SC3, CV_32FC1, CV_8UC1};
+use opencv::prelude::*;
+use opencv::{core, Error, Result};
+
+fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+	let fortran_order = if fortran_order {
+		"True"
+	} else {
+		"False"
+	};
+	let header = format!("{{'descr': '{descr}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}\n");
+	let mut out = b"\x93NUMPY\x01\x00".to_vec();
+	out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+	out.extend_from_slice(header.as_bytes());
+	out.extend_from_slice(data);
+	out
+}
+
+#[test]
+fn npy_round_trip() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1.5f32, 2., 3.], [4., 5., -6.]])?;
+	let mut buf = vec![];
+	mat.write_npy_to(&mut buf)?;
+	assert_eq!(b"\x93NUMPY", &buf[..6]);
+	let header_end = buf.iter().position(|&b| b == b'\n').unwrap() + 1;
+	assert_eq!(0, header_end % 64);
+	assert!(String::from_utf8_lossy(&buf[..header_end]).contains("'shape': (2, 3)"));
+	let mat_out = Mat::read_npy_from(&mut buf.as_slice())?;
+	assert_eq!(CV_32FC1, mat_out.typ());
+	assert_eq!(mat.data_typed::<f32>()?, mat_out.data_typed::<f32>()?);
+
+	// multichannel non-continuous Mat
+	let mut mat = Mat::new_rows_cols_with_default(5, 6, CV_16SC3, Scalar::all(0.))?;
+	for (i, x) in mat.data_typed_mut::<i16>()?.iter_mut().enumerate() {
+		*x = i as i16 - 40;
+	}
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 3, 2))?;
+	let mut buf = vec![];
+	roi.write_npy_to(&mut buf)?;
+	assert!(String::from_utf8_lossy(&buf).contains("'shape': (2, 3, 3)"));
+	let roi_out = Mat::read_npy_from(&mut buf.as_slice())?;
+	assert_eq!(CV_16SC3, roi_out.typ());
+	assert_eq!(roi.to_vec_2d::<Vec3s>()?, roi_out.to_vec_2d::<Vec3s>()?);
+
+	// N-dimensional Mat
+	let mat = Mat::new_nd_with_default(&[2, 3, 4, 5], core::CV_64FC1, Scalar::all(7.))?;
+	let mut buf = vec![];
+	mat.write_npy_to(&mut buf)?;
+	let mat_out = Mat::read_npy_from(&mut buf.as_slice())?;
+	assert_eq!(&[2, 3, 4, 5], &*mat_out.mat_size());
+	assert!(mat_out.data_typed::<f64>()?.iter().all(|&x| x == 7.));
+
+	// file
+	let path = std::env::temp_dir().join("opencv_rust_test.npy");
+	let mat = Mat::from_slice_2d(&[[1u8, 2], [3, 4]])?;
+	mat.write_npy(&path)?;
+	let mat_out = Mat::read_npy(&path)?;
+	std::fs::remove_file(&path).ok();
+	assert_eq!(&[1, 2, 3, 4], mat_out.data_bytes()?);
+	Ok(())
+}
+
+#[test]
+fn npy_read() -> Result<()> {
+	// 1-dimensional array becomes a column
+	let mat = Mat::read_npy_from(&mut npy_bytes("|u1", false, "(4,)", &[1, 2, 3, 4]).as_slice())?;
+	assert_eq!((4, 1), (mat.rows(), mat.cols()));
+	assert_eq!(CV_8UC1, mat.typ());
+
+	// big endian
+	let data = [1i16, -2, 300, 4].iter().flat_map(|x| x.to_be_bytes()).collect::<Vec<_>>();
+	let mat = Mat::read_npy_from(&mut npy_bytes(">i2", false, "(2, 2)", &data).as_slice())?;
+	assert_eq!(&[1, -2, 300, 4], mat.data_typed::<i16>()?);
+
+	// Fortran order: [[0, 1, 2], [3, 4, 5]] stored column by column
+	let data = [0i32, 3, 1, 4, 2, 5].iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>();
+	let mat = Mat::read_npy_from(&mut npy_bytes("<i4", true, "(2, 3)", &data).as_slice())?;
+	assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5]], mat.to_vec_2d::<i32>()?);
+
+	// empty array
+	let mat = Mat::read_npy_from(&mut npy_bytes("<f8", false, "(0, 3)", &[]).as_slice())?;
+	assert!(mat.empty());
+
+	let res = Mat::read_npy_from(&mut npy_bytes("<u8", false, "(1,)", &[0; 8]).as_slice());
+	assert_matches!(
+		res,
+		Err(Error {
+			code: core::StsUnsupportedFormat,
+			..
+		})
+	);
+	let res = Mat::read_npy_from(&mut npy_bytes("<f4", false, "(2, 2)", &[0; 12]).as_slice());
+	assert_matches!(
+		res,
+		Err(Error {
+			code: core::StsError,
+			..
+		})
+	);
+	// shape in the header doesn't match the actual data length
+	let res = Mat::read_npy_from(&mut npy_bytes("|u1", false, "(1000000000, 1000000000)", &[0; 16]).as_slice());
+	assert_matches!(
+		res,
+		Err(Error {
+			code: core::StsError,
+			..
+		})
+	);
+	assert!(Mat::read_npy_from(&mut &b"not an npy file"[..]).is_err());
+	Ok(())
+}
+
+#[test]
+fn npz() -> Result<()> {
+	let a = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6]])?;
+	let b = Mat::new_rows_cols_with_default(3, 3, core::CV_64FC2, Scalar::new(1., 2., 0., 0.))?;
+	let mut writer = NpzWriter::new(Cursor::new(vec![]));
+	writer.add("a", &a)?;
+	writer.add("b", &b)?;
+	assert_matches!(
+		writer.add("a", &b),
+		Err(Error {
+			code: core::StsBadArg,
+			..
+		})
+	);
+	let archive = writer.finish()?.into_inner();
+
+	let mut reader = NpzReader::new(Cursor::new(archive))?;
+	assert_eq!(2, reader.len());
+	assert_eq!(vec!["a", "b"], reader.names());
+	let b_out = reader.by_name("b.npy")?;
+	assert_eq!(b.typ(), b_out.typ());
+	assert_eq!(b.data_bytes()?, b_out.data_bytes()?);
+	let all = reader.read_all()?;
+	assert_eq!("a", all[0].0);
+	assert_eq!(a.data_typed::<u16>()?, all[0].1.data_typed::<u16>()?);
+	assert_matches!(
+		reader.by_name("c"),
+		Err(Error {
+			code: core::StsObjectNotFound,
+			..
+		})
+	);
+	Ok(())
+}
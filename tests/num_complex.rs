@@ -0,0 +1,34 @@
+#![cfg(feature = "num-complex")]
+
+use num_complex::{Complex32, Complex64};
+
+use opencv::core::Mat_;
+use opencv::prelude::*;
+use opencv::{core, Result};
+
+#[test]
+fn complex_mat() -> Result<()> {
+	let mat = Mat::from_slice(&[Complex64::new(1., 2.), Complex64::new(-3., 0.5)])?;
+	assert_eq!(core::CV_64FC2, mat.typ());
+	assert_eq!(Complex64::new(-3., 0.5), *mat.at::<Complex64>(1)?);
+	assert_eq!(&[1., 2., -3., 0.5], mat.reshape(1, 0)?.data_typed::<f64>()?);
+	Ok(())
+}
+
+#[test]
+fn complex_dft() -> Result<()> {
+	let src = Mat::from_slice(&[1f32, 2., 3., 4.])?;
+	let mut dst = Mat::default();
+	core::dft(&src, &mut dst, core::DFT_COMPLEX_OUTPUT, 0)?;
+	let dst = Mat_::<Complex32>::try_from(dst)?;
+	assert_eq!(
+		&[
+			Complex32::new(10., 0.),
+			Complex32::new(-2., 2.),
+			Complex32::new(-2., 0.),
+			Complex32::new(-2., -2.)
+		],
+		dst.data_typed()?
+	);
+	Ok(())
+}
@@ -0,0 +1,159 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use opencv::core::{Mat, QuatEnum_EulerAnglesType, Quatd, Quatf, Vec3d, Vec4d, CV_32FC4, CV_64FC4};
+use opencv::prelude::*;
+use opencv::Result;
+
+const EULER_ANGLES_TYPES: [QuatEnum_EulerAnglesType; 24] = {
+	use QuatEnum_EulerAnglesType::*;
+	[
+		INT_XYZ, INT_XZY, INT_YXZ, INT_YZX, INT_ZXY, INT_ZYX, INT_XYX, INT_XZX, INT_YXY, INT_YZY, INT_ZXZ, INT_ZYZ, EXT_XYZ,
+		EXT_XZY, EXT_YXZ, EXT_YZX, EXT_ZXY, EXT_ZYX, EXT_XYX, EXT_XZX, EXT_YXY, EXT_YZY, EXT_ZXZ, EXT_ZYZ,
+	]
+};
+
+fn assert_close(expected: &[f64], actual: &[f64]) {
+	assert_eq!(expected.len(), actual.len());
+	assert!(
+		expected.iter().zip(actual).all(|(e, a)| (e - a).abs() < 1e-9),
+		"expected: {expected:?}, actual: {actual:?}"
+	);
+}
+
+/// Checks that the quaternions represent the same rotation
+fn assert_same_rotation(expected: Quatd, actual: Quatd) {
+	let sign = if expected.dot(&actual) < 0. {
+		-1.
+	} else {
+		1.
+	};
+	assert_close(&expected.to_vec().0, &(actual * sign).to_vec().0);
+}
+
+#[test]
+fn quat_rotation() {
+	let rvec = Vec3d::from([0.1, -0.4, 0.7]);
+	let q = Quatd::create_from_rvec(rvec);
+	assert!(q.is_normal(1e-12));
+	assert_close(&rvec.0, &q.to_rot_vec().0);
+	assert_same_rotation(q, Quatd::create_from_rot_mat(&q.to_rot_mat3()));
+	for rvec in [[PI * 0.99, 0., 0.], [0., PI * 0.99, 0.1], [0.1, 0., PI * 0.99], [2., 2., 0.]] {
+		let q = Quatd::create_from_rvec(Vec3d::from(rvec));
+		assert_same_rotation(q, Quatd::create_from_rot_mat(&q.to_rot_mat3()));
+	}
+	assert_eq!(Quatd::new(1., 0., 0., 0.), Quatd::create_from_rvec(Vec3d::all(0.)));
+
+	let p = Quatd::create_from_rvec(Vec3d::from([0.3, 0.2, 0.1]));
+	assert_close((p * q).to_rot_mat3().val(), (p.to_rot_mat3() * q.to_rot_mat3()).val());
+	assert_close(&[0., 0., 0., 1.], &p.to_rot_mat4().val()[12..]);
+
+	let q = Quatd::create_from_angle_axis(FRAC_PI_2, Vec3d::from([0., 0., 2.])).unwrap();
+	assert_same_rotation(Quatd::create_from_z_rot(FRAC_PI_2), q);
+	assert!((q.get_angle() - FRAC_PI_2).abs() < 1e-12);
+	assert_close(&[0., 0., 1.], &q.get_axis().0);
+	assert!(Quatd::create_from_angle_axis(1., Vec3d::all(0.)).is_none());
+}
+
+#[test]
+fn quat_euler_angles() {
+	let angles = Vec3d::from([0.3, 0.5, -1.1]);
+	for typ in EULER_ANGLES_TYPES {
+		let q = Quatd::create_from_euler_angles(angles, typ);
+		assert_close(&angles.0, &q.to_euler_angles(typ).0);
+		// negative middle angle has an equivalent solution for the proper Euler angles
+		let q = Quatd::create_from_euler_angles(Vec3d::from([0.3, -0.5, 1.1]), typ);
+		assert_same_rotation(q, Quatd::create_from_euler_angles(q.to_euler_angles(typ), typ));
+	}
+
+	// gimbal lock
+	for typ in EULER_ANGLES_TYPES {
+		let proper = format!("{typ:?}").as_bytes()[4] == format!("{typ:?}").as_bytes()[6];
+		let middle_angles = if proper {
+			[0., PI]
+		} else {
+			[FRAC_PI_2, -FRAC_PI_2]
+		};
+		for middle in middle_angles {
+			let q = Quatd::create_from_euler_angles(Vec3d::from([0.3, middle, 1.1]), typ);
+			let angles = q.to_euler_angles(typ);
+			let zero_idx = if (typ as i32) < 12 {
+				2
+			} else {
+				0
+			};
+			assert_eq!(0., angles[zero_idx]);
+			assert_same_rotation(q, Quatd::create_from_euler_angles(angles, typ));
+		}
+	}
+
+	let q = Quatd::create_from_euler_angles(Vec3d::from([0.1, 0.2, 0.3]), QuatEnum_EulerAnglesType::INT_XYZ);
+	assert_same_rotation(
+		q,
+		Quatd::create_from_euler_angles(Vec3d::from([0.3, 0.2, 0.1]), QuatEnum_EulerAnglesType::EXT_ZYX),
+	);
+	assert_same_rotation(
+		q,
+		Quatd::create_from_x_rot(0.1) * Quatd::create_from_y_rot(0.2) * Quatd::create_from_z_rot(0.3),
+	);
+}
+
+#[test]
+fn quat_arithmetic() {
+	let q = Quatd::new(1., 2., 3., 4.);
+	let one = Quatd::new(1., 0., 0., 0.);
+	assert_eq!(Quatd::new(2., 4., 6., 8.), q + q);
+	assert_eq!(Quatd::default(), q - q);
+	assert_eq!(q * 2., q / 0.5);
+	assert_eq!(Quatd::new(-1., 2., 3., 4.), -q.conjugate());
+	assert_eq!(30., q.dot(&q));
+	assert_eq!(Quatd::new(0., -1., 2., -1.), q.cross(&Quatd::new(0., 1., 1., 1.)));
+	assert_close(&one.to_vec().0, &(q * q.inv().unwrap()).to_vec().0);
+	assert_close(&one.to_vec().0, &(q / q).to_vec().0);
+	assert!(Quatd::default().inv().is_none());
+	assert!(Quatd::default().normalize().is_none());
+	assert!((q.normalize().unwrap().norm() - 1.).abs() < 1e-12);
+	assert!(q.assert_normal(1e-6).is_err());
+	assert!(one.assert_normal(1e-6).is_ok());
+
+	assert_close(&q.to_vec().0, &q.log().exp().to_vec().0);
+	assert_close(&q.to_vec().0, &(q.sqrt() * q.sqrt()).to_vec().0);
+	assert_close(&(q * q).to_vec().0, &q.pow(2.).to_vec().0);
+	assert_same_rotation(Quatd::create_from_z_rot(0.3), Quatd::create_from_z_rot(0.1).pow(3.));
+}
+
+#[test]
+fn quat_interpolation() {
+	let q0 = Quatd::create_from_z_rot(0.);
+	let q1 = Quatd::create_from_z_rot(1.);
+	let q2 = Quatd::create_from_z_rot(2.);
+	assert_eq!(
+		Quatd::new(1., 1., 1., 1.),
+		Quatd::lerp(Quatd::default(), Quatd::new(2., 2., 2., 2.), 0.5)
+	);
+	assert_same_rotation(Quatd::create_from_z_rot(0.5), Quatd::nlerp(q0, q1, 0.5));
+	assert_same_rotation(Quatd::create_from_z_rot(0.25), Quatd::slerp(q0, q1, 0.25));
+	// shortest path
+	assert_same_rotation(Quatd::create_from_z_rot(0.25), Quatd::slerp(q0, -q1, 0.25));
+	assert_same_rotation(
+		Quatd::create_from_z_rot(0.005),
+		Quatd::slerp(q0, Quatd::create_from_z_rot(0.01), 0.5),
+	);
+
+	let s1 = Quatd::inter_point(q0, q1, q2);
+	assert_same_rotation(q1, s1);
+	assert_same_rotation(q0, Quatd::squad(q0, q0, s1, q1, 0.));
+	assert_same_rotation(q1, Quatd::squad(q0, q0, s1, q1, 1.));
+	assert_same_rotation(Quatd::create_from_z_rot(0.5), Quatd::squad(q0, q0, q1, q1, 0.5));
+}
+
+#[test]
+fn quat_data_type() -> Result<()> {
+	assert_eq!(CV_32FC4, Quatf::opencv_type());
+	assert_eq!(CV_64FC4, Quatd::opencv_type());
+	let src = [Quatf::new(1., 2., 3., 4.), Quatf::new(5., 6., 7., 8.)];
+	let mat = Mat::from_slice(&src)?;
+	assert_eq!(CV_32FC4, mat.typ());
+	assert_eq!(src[1], *mat.at::<Quatf>(1)?);
+	assert_eq!(Vec4d::from([1., 2., 3., 4.]), Quatd::new(1., 2., 3., 4.).into());
+	Ok(())
+}
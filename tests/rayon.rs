@@ -0,0 +1,53 @@
+#![cfg(feature = "rayon")]
+
+use matches::assert_matches;
+use rayon::prelude::*;
+
+use opencv::core::{Rect, Scalar};
+use opencv::prelude::*;
+use opencv::{core, Error, Result};
+
+#[test]
+fn mat_par_rows_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(100, 50, i32::opencv_type(), Scalar::all(0.))?;
+	mat.par_rows_mut::<i32>()?.enumerate().for_each(|(row_n, row)| {
+		row.iter_mut()
+			.enumerate()
+			.for_each(|(col_n, x)| *x = (row_n * 1000 + col_n) as i32);
+	});
+	assert_eq!(0, *mat.at_2d::<i32>(0, 0)?);
+	assert_eq!(42049, *mat.at_2d::<i32>(42, 49)?);
+	assert_eq!(99001, *mat.at_2d::<i32>(99, 1)?);
+
+	let mut roi = Mat::roi(&mat, Rect::new(10, 10, 5, 5))?;
+	assert!(!roi.is_continuous());
+	roi.par_rows_mut::<i32>()?.for_each(|row| row.fill(-1));
+	assert_eq!(-1, *mat.at_2d::<i32>(14, 14)?);
+	assert_eq!(15014, *mat.at_2d::<i32>(15, 14)?);
+	assert_eq!(14009, *mat.at_2d::<i32>(14, 9)?);
+	Ok(())
+}
+
+#[test]
+fn mat_par_chunks_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(10, 4, u8::opencv_type(), Scalar::all(0.))?;
+	let chunks = mat.par_chunks_mut::<u8>(3)?;
+	assert_eq!(4, chunks.len());
+	chunks.enumerate().for_each(|(chunk_n, mut chunk)| {
+		for row in chunk.row_iter_mut().unwrap() {
+			row.fill(chunk_n as u8 + 1);
+		}
+	});
+	assert_eq!(&[1, 1, 1, 1], mat.at_row::<u8>(2)?);
+	assert_eq!(&[2, 2, 2, 2], mat.at_row::<u8>(3)?);
+	assert_eq!(&[4, 4, 4, 4], mat.at_row::<u8>(9)?);
+
+	assert_matches!(
+		mat.par_chunks_mut::<u8>(0),
+		Err(Error {
+			code: core::StsBadArg,
+			..
+		})
+	);
+	Ok(())
+}
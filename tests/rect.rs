@@ -1,4 +1,7 @@
-use opencv::core::{self, Point2d, Point2f, Point2i, Rect, Rect2d, Rect2f, Rect2i, Size2d, Size2f, Size2i};
+use opencv::core::{
+	self, Point2d, Point2f, Point2i, Rect, Rect2d, Rect2f, Rect2i, RotatedRect, Size2d, Size2f, Size2i, SoftNmsMethod,
+};
+use opencv::Result;
 
 #[test]
 fn rect_add() {
@@ -174,6 +177,91 @@ fn rect_methods() {
 	assert_eq!(rect.empty(), rect.size().empty());
 }
 
+#[test]
+fn rect_geometry() {
+	let a = Rect::new(0, 0, 10, 10);
+	let b = Rect::new(5, 0, 10, 10);
+	assert_eq!(Point2i::new(5, 5), a.center());
+	assert_eq!(Point2d::new(2.5, 3.5), Rect2d::new(0., 1., 5., 5.).center());
+	assert!(a.intersects(&b));
+	assert!(!a.intersects(&Rect::new(10, 0, 5, 5)));
+	assert!(!a.intersects(&Rect::new(5, 5, 0, 5)));
+	assert!((a.iou(&b) - 1. / 3.).abs() < 1e-12);
+	assert_eq!(1., a.iou(&a));
+	assert_eq!(0., a.iou(&Rect::new(20, 20, 5, 5)));
+	assert_eq!(0., a.iou(&Rect::default()));
+	assert_eq!(Rect::new(5, 0, 5, 10), b.clamp_to(Size2i::new(10, 20)));
+	assert_eq!(Rect::default(), b.clamp_to(Size2i::new(5, 5)));
+	assert_eq!(Rect::new(-1, -2, 12, 14), a.inflate(1, 2));
+	assert_eq!(Rect::new(1, 1, 8, 8), a.inflate(-1, -1));
+	assert_eq!(Some(Rect::new(-5, -5, 20, 20)), a.scale_about_center(2.));
+	assert_eq!(Some(Rect::new(3, 3, 5, 5)), Rect::new(0, 0, 11, 11).scale_about_center(0.5));
+	assert_eq!(
+		Some(Rect2f::new(1.25, 1.25, 2.5, 2.5)),
+		Rect2f::new(0., 0., 5., 5.).scale_about_center(0.5)
+	);
+}
+
+#[test]
+fn rotated_rect_geometry() -> Result<()> {
+	let rect = RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 90.)?;
+	let mut pts = [Point2f::default(); 4];
+	rect.points(&mut pts)?;
+	assert_eq!(pts, rect.vertices());
+	assert_eq!(rect.bounding_rect()?, rect.bounding_box());
+	assert_eq!(rect.bounding_rect2f()?, rect.bounding_box2f());
+
+	let rect = RotatedRect::new(Point2f::new(10., 20.), Size2f::new(30., 15.), 33.)?;
+	rect.points(&mut pts)?;
+	assert_eq!(pts, rect.vertices());
+	assert_eq!(rect.bounding_rect()?, rect.bounding_box());
+	assert_eq!(rect.bounding_rect2f()?, rect.bounding_box2f());
+
+	let a = RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 0.)?;
+	assert!((a.iou(&a) - 1.).abs() < 1e-9);
+	let b = RotatedRect::new(Point2f::new(150., 100.), Size2f::new(100., 100.), 90.)?;
+	assert!((a.iou(&b) - 1. / 3.).abs() < 1e-6);
+	// the intersection of the square and the same square rotated by 45 degrees is a regular octagon
+	let b = RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 45.)?;
+	let intersection = 20000. * (2f64.sqrt() - 1.);
+	assert!((a.iou(&b) - intersection / (20000. - intersection)).abs() < 1e-4);
+	let b = RotatedRect::new(Point2f::new(1000., 100.), Size2f::new(10., 10.), 30.)?;
+	assert_eq!(0., a.iou(&b));
+	let b = RotatedRect::new(Point2f::new(100., 100.), Size2f::new(0., 10.), 30.)?;
+	assert_eq!(0., a.iou(&b));
+	Ok(())
+}
+
+#[test]
+fn rect_nms() -> Result<()> {
+	let boxes = [
+		(Rect::new(0, 0, 10, 10), 0.9),
+		(Rect::new(1, 1, 10, 10), 0.8),
+		(Rect::new(20, 20, 10, 10), 0.7),
+		(Rect::new(0, 0, 10, 9), 0.95),
+		(Rect::new(50, 50, 5, 5), 0.1),
+	];
+	assert_eq!(vec![3, 2], core::non_max_suppression(&boxes, 0.2, 0.5, 1., 0));
+	assert_eq!(vec![3, 2, 4], core::non_max_suppression(&boxes, 0., 0.5, 1., 0));
+	assert_eq!(vec![3], core::non_max_suppression(&boxes, 0.2, 0.5, 1., 1));
+	assert_eq!(vec![3, 0, 1, 2], core::non_max_suppression(&boxes, 0.2, 1., 1., 0));
+
+	let res = core::soft_non_max_suppression(&boxes, 0.05, 0.5, 0, 0.5, SoftNmsMethod::Linear);
+	assert_eq!(vec![3, 2, 1, 4], res.iter().map(|(i, _)| *i).collect::<Vec<_>>());
+	assert_eq!(0.95, res[0].1);
+	assert!((res[2].1 - 0.8 * (1. - 72. / 118.)).abs() < 1e-6);
+	let res = core::soft_non_max_suppression(&boxes, 0.05, 0.5, 2, 0.5, SoftNmsMethod::Gaussian);
+	assert_eq!(vec![3, 2], res.iter().map(|(i, _)| *i).collect::<Vec<_>>());
+
+	let rotated = [
+		(RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 0.)?, 0.5),
+		(RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 45.)?, 0.6),
+		(RotatedRect::new(Point2f::new(1000., 100.), Size2f::new(10., 10.), 30.)?, 0.4),
+	];
+	assert_eq!(vec![1, 2], core::non_max_suppression(&rotated, 0., 0.5, 1., 0));
+	Ok(())
+}
+
 #[test]
 fn rect_conv() {
 	let rectf = Rect2d::new(1.2, 2.3, 3.4, 4.5);
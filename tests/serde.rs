@@ -0,0 +1,146 @@
+#![cfg(feature = "serde")]
+
+use opencv::core::{
+	Affine3d, DMatch, KeyPoint, Matx23f, Point, Point2f, Point3d, Rect, RotatedRect, Scalar, Size, Size2f, Vec2f, Vec3b, Vec3s,
+	Vec4d, Vector, CV_16SC3, CV_32FC2,
+};
+use opencv::prelude::*;
+use opencv::Result;
+
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(val: &T) -> T {
+	let json = serde_json::to_string(val).unwrap();
+	serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn geometry() {
+	let pt = Point::new(10, -20);
+	assert_eq!(r#"{"x":10,"y":-20}"#, serde_json::to_string(&pt).unwrap());
+	assert_eq!(pt, round_trip(&pt));
+	let pt = Point3d::new(1.5, 2., -3.25);
+	assert_eq!(pt, round_trip(&pt));
+	let sz = Size::new(640, 480);
+	assert_eq!(r#"{"width":640,"height":480}"#, serde_json::to_string(&sz).unwrap());
+	assert_eq!(sz, round_trip(&sz));
+	let rect = Rect::new(1, 2, 3, 4);
+	assert_eq!(r#"{"x":1,"y":2,"width":3,"height":4}"#, serde_json::to_string(&rect).unwrap());
+	assert_eq!(rect, round_trip(&rect));
+}
+
+#[test]
+fn vec_matx() {
+	let v = Vec3b::from([1, 2, 255]);
+	assert_eq!("[1,2,255]", serde_json::to_string(&v).unwrap());
+	assert_eq!(v, round_trip(&v));
+	let v = Vec4d::from([1.5, -2., 0., 4.25]);
+	assert_eq!(v, round_trip(&v));
+	assert!(serde_json::from_str::<Vec3b>("[1,2]").is_err());
+	assert!(serde_json::from_str::<Vec3b>("[1,2,3,4]").is_err());
+
+	let m = Matx23f::from_array([1., 2., 3., 4., 5., 6.]);
+	assert_eq!("[1.0,2.0,3.0,4.0,5.0,6.0]", serde_json::to_string(&m).unwrap());
+	assert_eq!(m.val, round_trip(&m).val);
+	assert!(serde_json::from_str::<Matx23f>("[1,2,3]").is_err());
+
+	let mut affine = Affine3d::default();
+	affine.matrix[(0, 3)] = 10.;
+	affine.matrix[(1, 3)] = -5.;
+	assert_eq!(affine.matrix.val, round_trip(&affine).matrix.val);
+}
+
+#[test]
+fn vector() -> Result<()> {
+	let vec = Vector::<Point2f>::from_iter(vec![Point2f::new(1., 2.), Point2f::new(3., 4.)]);
+	assert_eq!(
+		r#"[{"x":1.0,"y":2.0},{"x":3.0,"y":4.0}]"#,
+		serde_json::to_string(&vec).unwrap()
+	);
+	assert_eq!(vec.to_vec(), round_trip(&vec).to_vec());
+
+	let vec = Vector::<String>::from_iter(vec!["first", "second"]);
+	assert_eq!(vec.to_vec(), round_trip(&vec).to_vec());
+
+	let mut vec_of_vec = Vector::<Vector<i32>>::new();
+	vec_of_vec.push(Vector::from_slice(&[1, 2, 3]));
+	vec_of_vec.push(Vector::new());
+	let vec_of_vec = round_trip(&vec_of_vec);
+	assert_eq!(2, vec_of_vec.len());
+	assert_eq!(vec![1, 2, 3], vec_of_vec.get(0)?.to_vec());
+	assert!(vec_of_vec.get(1)?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn features() -> Result<()> {
+	let kp = KeyPoint::new_point(Point2f::new(10.5, 20.), 3., 90., 0.5, 2, 7)?;
+	let kp_out = round_trip(&kp);
+	assert_eq!(kp.pt(), kp_out.pt());
+	assert_eq!(kp.size(), kp_out.size());
+	assert_eq!(kp.angle(), kp_out.angle());
+	assert_eq!(kp.response(), kp_out.response());
+	assert_eq!(kp.octave(), kp_out.octave());
+	assert_eq!(kp.class_id(), kp_out.class_id());
+
+	let m = DMatch::new_index(1, 2, 3, 0.25)?;
+	assert_eq!(
+		r#"{"query_idx":1,"train_idx":2,"img_idx":3,"distance":0.25}"#,
+		serde_json::to_string(&m).unwrap()
+	);
+	let m_out = round_trip(&m);
+	assert_eq!(
+		(1, 2, 3, 0.25),
+		(m_out.query_idx, m_out.train_idx, m_out.img_idx, m_out.distance)
+	);
+
+	let rect = RotatedRect::new(Point2f::new(5., 6.), Size2f::new(10., 20.), 30.)?;
+	let rect_out = round_trip(&rect);
+	assert_eq!(rect.center(), rect_out.center());
+	assert_eq!(rect.size(), rect_out.size());
+	assert_eq!(rect.angle(), rect_out.angle());
+	Ok(())
+}
+
+#[test]
+fn mat() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6]])?;
+	let mat_out = round_trip(&mat);
+	assert_eq!(mat.typ(), mat_out.typ());
+	assert_eq!(mat.size()?, mat_out.size()?);
+	assert_eq!(mat.data_typed::<u16>()?, mat_out.data_typed::<u16>()?);
+
+	let mut mat = Mat::new_rows_cols_with_default(10, 10, CV_16SC3, Scalar::all(0.))?;
+	for (i, x) in mat.data_typed_mut::<i16>()?.iter_mut().enumerate() {
+		*x = i as i16;
+	}
+	let roi = Mat::roi(&mat, Rect::new(2, 3, 4, 5))?;
+	assert!(!roi.is_continuous());
+	let roi_out = round_trip(&roi);
+	assert!(roi_out.is_continuous());
+	assert_eq!(roi.typ(), roi_out.typ());
+	assert_eq!(roi.size()?, roi_out.size()?);
+	assert_eq!(roi.to_vec_2d::<Vec3s>()?, roi_out.to_vec_2d::<Vec3s>()?);
+
+	let mat = Mat::new_nd_with_default(&[2, 3, 4], CV_32FC2, Scalar::new(1., -2., 0., 0.))?;
+	let mat_out = round_trip(&mat);
+	assert_eq!(3, mat_out.dims());
+	assert_eq!(&[2, 3, 4], &*mat_out.mat_size());
+	assert_eq!(mat.data_typed::<Vec2f>()?, mat_out.data_typed::<Vec2f>()?);
+
+	let mat_out = round_trip(&Mat::default());
+	assert!(mat_out.empty());
+
+	// padded rows coming from elsewhere
+	let mat_out: Mat = serde_json::from_str(r#"{"typ":0,"sizes":[2,2],"steps":[3,1],"data":[1,2,255,3,4]}"#).unwrap();
+	assert!(mat_out.is_continuous());
+	assert_eq!(&[1, 2, 3, 4], mat_out.data_bytes()?);
+	assert!(serde_json::from_str::<Mat>(r#"{"typ":0,"sizes":[2,2],"steps":[3,1],"data":[1,2,255,3]}"#).is_err());
+	// overlapping rows
+	assert!(serde_json::from_str::<Mat>(r#"{"typ":0,"sizes":[2,2],"steps":[1,1],"data":[1,2,3]}"#).is_err());
+	// step not aligned to the element size
+	assert!(serde_json::from_str::<Mat>(r#"{"typ":2,"sizes":[2,2],"steps":[5,2],"data":[1,0,2,0,0,3,0,4,0]}"#).is_err());
+	// data length overflow
+	assert!(
+		serde_json::from_str::<Mat>(r#"{"typ":0,"sizes":[2,2147483647],"steps":[18446744073709551615,1],"data":[1]}"#).is_err()
+	);
+	Ok(())
+}
@@ -1,6 +1,6 @@
 use matches::assert_matches;
 
-use opencv::core::{DMatch, Point2d, Point2f, Scalar, SparseMat_Hdr, Vec4i};
+use opencv::core::{DMatch, Point2d, Point2f, Scalar, Size, SparseMat_Hdr, Vec4i};
 use opencv::prelude::*;
 use opencv::types::VectorOfKeyPoint;
 use opencv::types::{
@@ -65,6 +65,53 @@ fn boxed() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn boxed_ref() -> Result<()> {
+	let mut vec = VectorOfMat::new();
+	vec.push(Mat::new_rows_cols_with_default(2, 2, f64::opencv_type(), Scalar::all(1.))?);
+	vec.push(Mat::new_rows_cols_with_default(2, 2, f64::opencv_type(), Scalar::all(2.))?);
+	{
+		let m = vec.get_ref(1)?;
+		assert_eq!(f64::opencv_type(), m.typ());
+		assert_eq!(2., *m.at_2d::<f64>(1, 1)?);
+		assert_eq!(m.as_raw(), vec.get_ref(1)?.as_raw());
+	}
+	{
+		let mut m = vec.get_mut(0)?;
+		m.set_to(&Scalar::all(5.), &core::no_array())?;
+		*m.at_2d_mut::<f64>(0, 1)? = 7.;
+	}
+	assert_eq!(5., *vec.get(0)?.at_2d::<f64>(0, 0)?);
+	assert_eq!(7., *vec.get(0)?.at_2d::<f64>(0, 1)?);
+	assert_eq!(
+		vec![5., 2.],
+		vec.iter().map(|m| *m.at_2d::<f64>(1, 1).unwrap()).collect::<Vec<_>>()
+	);
+
+	let src = vec.get(1)?;
+	core::add(&src, &src, &mut vec.get_mut(0)?, &core::no_array(), -1)?;
+	assert_eq!(4., core::mean(&vec.get_ref(0)?, &core::no_array())?[0]);
+	for m in &vec {
+		assert_eq!(Size::new(2, 2), m.size()?);
+	}
+
+	assert_matches!(
+		vec.get_ref(2),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	assert_matches!(
+		vec.get_mut(2),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	Ok(())
+}
+
 #[test]
 fn string() -> Result<()> {
 	let mut vec = VectorOfString::new();
@@ -762,6 +809,103 @@ fn from_slice() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn iter_mut() -> Result<()> {
+	{
+		let mut vec = VectorOfMat::new();
+		vec.push(Mat::new_rows_cols_with_default(1, 2, f64::opencv_type(), Scalar::all(1.))?);
+		vec.push(Mat::new_rows_cols_with_default(1, 2, f64::opencv_type(), Scalar::all(1.))?);
+		let ptr = vec.get_ref(1)?.as_raw();
+		for (i, mut m) in vec.iter_mut().enumerate() {
+			m.set_to(&Scalar::all(i as f64 + 10.), &core::no_array())?;
+			*m.at_mut::<f64>(1)? = 5.;
+		}
+		assert_eq!(ptr, vec.get_ref(1)?.as_raw());
+		assert_eq!(
+			vec![(10., 5.), (11., 5.)],
+			vec.iter()
+				.map(|m| (*m.at::<f64>(0).unwrap(), *m.at::<f64>(1).unwrap()))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	{
+		let mut vec = VectorOfPoint2f::from_iter(vec![Point2f::new(1., 2.), Point2f::new(3., 4.)]);
+		for pt in vec.iter_mut() {
+			pt.x *= 10.;
+		}
+		assert_eq!(&[Point2f::new(10., 2.), Point2f::new(30., 4.)], vec.as_slice());
+	}
+
+	{
+		let mut vec = VectorOfi32::from_slice(&[1, 2, 3]);
+		vec.iter_mut().for_each(|x| *x *= 2);
+		assert_eq!(&[2, 4, 6], vec.as_slice());
+		vec.as_mut().reverse();
+		assert_eq!(&[6, 4, 2], vec.as_slice());
+	}
+	Ok(())
+}
+
+#[test]
+fn truncate_extend() -> Result<()> {
+	let mut vec = VectorOfString::from_iter(vec!["a", "b", "c", "d"]);
+	vec.truncate(10);
+	assert_eq!(4, vec.len());
+	vec.truncate(2);
+	assert_eq!(vec!["a", "b"], vec.to_vec());
+	vec.extend_from_slice(&["e".to_string(), "f".to_string()]);
+	assert_eq!(vec!["a", "b", "e", "f"], vec.to_vec());
+
+	let mut vec = VectorOfi32::new();
+	vec.extend_from_slice(&[1, 2, 3]);
+	vec.truncate(0);
+	assert!(vec.is_empty());
+	Ok(())
+}
+
+#[test]
+fn retain_dedup() -> Result<()> {
+	let mut vec = VectorOfi32::from_slice(&[1, 2, 3, 4, 5, 6]);
+	vec.retain(|&x| x % 2 == 0);
+	assert_eq!(&[2, 4, 6], vec.as_slice());
+
+	let mut vec = VectorOfString::from_iter(vec!["a", "bb", "cc", "d", "ee", "ff", "gg"]);
+	vec.retain(|s| s.len() == 2);
+	assert_eq!(vec!["bb", "cc", "ee", "ff", "gg"], vec.to_vec());
+
+	let mut vec = VectorOfi32::from_slice(&[1, 1, 2, 3, 3, 3, 1, 4, 4]);
+	vec.dedup_by(|a, b| a == b);
+	assert_eq!(&[1, 2, 3, 1, 4], vec.as_slice());
+
+	let mut vec = VectorOfString::from_iter(vec!["apple", "avocado", "banana", "blueberry", "cherry"]);
+	vec.dedup_by(|a, b| a.chars().next() == b.chars().next());
+	assert_eq!(vec!["apple", "banana", "cherry"], vec.to_vec());
+	Ok(())
+}
+
+#[test]
+fn sort_by() -> Result<()> {
+	let mut vec = VectorOfi32::from_slice(&[5, 3, 8, 1, 9, 2]);
+	vec.sort_by(|a, b| a.cmp(b));
+	assert_eq!(&[1, 2, 3, 5, 8, 9], vec.as_slice());
+
+	let mut vec = VectorOfString::from_iter(vec!["ccc", "a", "bb", "dd", "e"]);
+	vec.sort_by(|a, b| a.len().cmp(&b.len()));
+	assert_eq!(vec!["a", "e", "bb", "dd", "ccc"], vec.to_vec());
+
+	let mut vec = VectorOfMat::new();
+	for i in [3., 1., 2.] {
+		vec.push(Mat::new_rows_cols_with_default(1, 1, f64::opencv_type(), Scalar::all(i))?);
+	}
+	vec.sort_by(|a, b| a.at::<f64>(0).unwrap().partial_cmp(b.at::<f64>(0).unwrap()).unwrap());
+	assert_eq!(
+		vec![1., 2., 3.],
+		vec.iter().map(|m| *m.at::<f64>(0).unwrap()).collect::<Vec<_>>()
+	);
+	Ok(())
+}
+
 #[test]
 fn must_be_clone() {
 	fn must_be_clone(_: impl Clone) {}